@@ -50,6 +50,11 @@ pub async fn run_frequent_cron_jobs(
         .cleanup_user_and_metadata_association()
         .await
         .trace_ok();
+    app_services
+        .miscellaneous_service
+        .process_pending_notification_deliveries()
+        .await
+        .trace_ok();
     Ok(())
 }
 
@@ -170,7 +175,10 @@ pub async fn perform_lp_application_job(
 ) -> Result<(), Error> {
     ryot_log!(trace, "Started job {:?}", information);
     let status = match information {
-        LpApplicationJob::HandleEntityAddedToCollectionEvent(collection_to_entity_id) => {
+        LpApplicationJob::HandleEntityAddedToCollectionEvent(
+            collection_to_entity_id,
+            triggered_by_automation,
+        ) => {
             app_services
                 .integration_service
                 .handle_entity_added_to_collection_event(collection_to_entity_id)
@@ -178,7 +186,10 @@ pub async fn perform_lp_application_job(
                 .ok();
             app_services
                 .collection_service
-                .handle_entity_added_to_collection_event(collection_to_entity_id)
+                .handle_entity_added_to_collection_event(
+                    collection_to_entity_id,
+                    triggered_by_automation,
+                )
                 .await
         }
         LpApplicationJob::HandleOnSeenComplete(id) => {
@@ -199,6 +210,36 @@ pub async fn perform_lp_application_job(
                 .handle_metadata_eligible_for_smart_collection_moving(metadata_id)
                 .await
         }
+        LpApplicationJob::SyncUserSmartCollections(user_id, metadata_id) => {
+            app_services
+                .collection_service
+                .sync_user_smart_collections(&user_id, metadata_id.as_ref())
+                .await
+        }
+        LpApplicationJob::PushMetadataToIntegration(integration_id, metadata_id) => {
+            app_services
+                .integration_service
+                .push_metadata_to_integration(integration_id, metadata_id)
+                .await
+        }
+        LpApplicationJob::HandleAutomationRuleEvent(event) => {
+            app_services
+                .user_service
+                .handle_automation_rule_event(event)
+                .await
+        }
+        LpApplicationJob::UpdateUserMetadataTranslations(user_id, metadata_id) => {
+            app_services
+                .miscellaneous_service
+                .update_user_metadata_translations(&user_id, &metadata_id)
+                .await
+        }
+        LpApplicationJob::MirrorMetadataProgressUpdates(user_id, input) => {
+            app_services
+                .miscellaneous_service
+                .mirror_metadata_progress_updates(user_id, input)
+                .await
+        }
     };
     status.map_err(|e| Error::Failed(Arc::new(e.to_string().into())))
 }
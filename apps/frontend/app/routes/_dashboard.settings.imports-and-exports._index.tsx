@@ -417,18 +417,22 @@ export default function Page() {
 														/>
 													</>
 												))
-												.with(
-													ImportSource.Anilist,
-													ImportSource.GenericJson,
-													() => (
-														<FileInput
-															required
-															accept=".json"
-															name="exportPath"
-															label="JSON export file"
-														/>
-													),
-												)
+												.with(ImportSource.Anilist, () => (
+													<FileInput
+														required
+														accept=".json"
+														name="exportPath"
+														label="JSON export file"
+													/>
+												))
+												.with(ImportSource.GenericJson, () => (
+													<FileInput
+														required
+														accept=".zip,.json"
+														name="exportPath"
+														label="Ryot export file"
+													/>
+												))
 												.with(ImportSource.Netflix, () => (
 													<>
 														<FileInput
@@ -47,6 +47,25 @@ pub struct AnilistConfig {
     pub preferred_language: AnilistPreferredLanguage,
 }
 
+derive_enum!(
+    #[derive(ConfigEnum, Default)]
+    pub enum BangumiPreferredLanguage {
+        #[default]
+        Chinese,
+        Native,
+    }
+);
+
+#[derive(Debug, Serialize, Deserialize, Clone, Config, MaskedConfig)]
+#[config(rename_all = "snake_case", env_prefix = "ANIME_AND_MANGA_BANGUMI_")]
+pub struct BangumiConfig {
+    /// The access token to be used for the Bangumi API, required to see NSFW subjects.
+    #[mask]
+    pub access_token: String,
+    /// The preferred language for media from this source.
+    pub preferred_language: BangumiPreferredLanguage,
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone, Config, MaskedConfig)]
 #[config(
     rename_all = "snake_case",
@@ -67,6 +86,10 @@ pub struct AnimeAndMangaConfig {
     /// Settings related to MangaUpdates.
     #[setting(nested)]
     pub manga_updates: MangaUpdatesConfig,
+    /// Settings related to Bangumi.
+    #[setting(nested)]
+    #[mask_nested]
+    pub bangumi: BangumiConfig,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, Config, MaskedConfig)]
@@ -175,6 +198,40 @@ pub struct BookConfig {
     pub google_books: GoogleBooksConfig,
 }
 
+#[derive(Debug, Serialize, Deserialize, Clone, Config, MaskedConfig)]
+#[config(rename_all = "snake_case", env_prefix = "BOARD_GAMES_BOARD_GAME_GEEK_")]
+pub struct BoardGameGeekConfig {
+    /// The application token to be used for the BoardGameGeek XML API.
+    #[mask]
+    pub api_token: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Config, MaskedConfig)]
+#[config(rename_all = "snake_case")]
+pub struct BoardGameConfig {
+    /// Settings related to BoardGameGeek.
+    #[setting(nested)]
+    #[mask_nested]
+    pub board_game_geek: BoardGameGeekConfig,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Config, MaskedConfig)]
+#[config(rename_all = "snake_case", env_prefix = "COMICS_COMIC_VINE_")]
+pub struct ComicVineConfig {
+    /// The API key to be used for the ComicVine API.
+    #[mask]
+    pub api_key: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Config, MaskedConfig)]
+#[config(rename_all = "snake_case")]
+pub struct ComicConfig {
+    /// Settings related to ComicVine.
+    #[setting(nested)]
+    #[mask_nested]
+    pub comic_vine: ComicVineConfig,
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone, Config, PartialEq, Eq, MaskedConfig)]
 #[config(rename_all = "snake_case", env_prefix = "DATABASE_")]
 pub struct DatabaseConfig {
@@ -403,15 +460,17 @@ pub struct SmtpConfig {
     pub password: String,
     #[setting(default = "Ryot <no-reply@ryot.io>")]
     pub mailbox: String,
+    /// The port to connect to. Defaults to the standard port for the connection type.
+    pub port: Option<u16>,
+    /// Connect without TLS, for example to a local SMTP sink. Credentials are optional
+    /// in this mode.
+    pub disable_tls: bool,
 }
 
 impl SmtpConfig {
     pub fn is_enabled(&self) -> bool {
-        let mut enabled = false;
-        if !self.server.is_empty() && !self.user.is_empty() && !self.password.is_empty() {
-            enabled = true;
-        }
-        enabled
+        let has_credentials = !self.user.is_empty() && !self.password.is_empty();
+        !self.server.is_empty() && (has_credentials || self.disable_tls)
     }
 }
 
@@ -510,6 +569,14 @@ pub struct AppConfig {
     #[setting(nested)]
     #[mask_nested]
     pub books: BookConfig,
+    /// Settings related to board games.
+    #[setting(nested)]
+    #[mask_nested]
+    pub board_games: BoardGameConfig,
+    /// Settings related to comics.
+    #[setting(nested)]
+    #[mask_nested]
+    pub comics: ComicConfig,
     /// Settings related to users.
     #[setting(nested)]
     pub users: UsersConfig,
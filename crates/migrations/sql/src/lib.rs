@@ -34,6 +34,17 @@ mod m20250907_changes_for_issue_1533;
 mod m20250908_changes_for_issue_1551;
 mod m20250914_changes_for_issue_1561;
 mod m20251021_changes_for_issue_1583;
+mod m20251026_changes_for_comics;
+mod m20251027_changes_for_board_games;
+mod m20251028_changes_for_notification_delivery;
+mod m20251029_create_user_notification;
+mod m20251030_changes_for_smart_collections;
+mod m20251031_create_automation_rule;
+mod m20251101_create_metadata_translation;
+mod m20251102_create_share_link;
+mod m20251103_create_user_follow_and_feed_event;
+mod m20251104_create_watch_group;
+mod m20251105_create_user_invite;
 
 pub struct Migrator;
 
@@ -74,6 +85,17 @@ impl MigratorTrait for Migrator {
             Box::new(m20250908_changes_for_issue_1551::Migration),
             Box::new(m20250914_changes_for_issue_1561::Migration),
             Box::new(m20251021_changes_for_issue_1583::Migration),
+            Box::new(m20251026_changes_for_comics::Migration),
+            Box::new(m20251027_changes_for_board_games::Migration),
+            Box::new(m20251028_changes_for_notification_delivery::Migration),
+            Box::new(m20251029_create_user_notification::Migration),
+            Box::new(m20251030_changes_for_smart_collections::Migration),
+            Box::new(m20251031_create_automation_rule::Migration),
+            Box::new(m20251101_create_metadata_translation::Migration),
+            Box::new(m20251102_create_share_link::Migration),
+            Box::new(m20251103_create_user_follow_and_feed_event::Migration),
+            Box::new(m20251104_create_watch_group::Migration),
+            Box::new(m20251105_create_user_invite::Migration),
         ]
     }
 }
@@ -0,0 +1,63 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        let db = manager.get_connection();
+
+        if !manager
+            .has_column("metadata", "board_game_specifics")
+            .await?
+        {
+            db.execute_unprepared(
+                r#"ALTER TABLE "metadata" ADD COLUMN "board_game_specifics" JSONB"#,
+            )
+            .await?;
+        }
+
+        if !manager
+            .has_column("seen", "board_game_extra_information")
+            .await?
+        {
+            db.execute_unprepared(
+                r#"ALTER TABLE "seen" ADD COLUMN "board_game_extra_information" JSONB"#,
+            )
+            .await?;
+        }
+
+        if !manager
+            .has_column("daily_user_activity", "board_game_count")
+            .await?
+        {
+            db.execute_unprepared(
+                r#"
+ALTER TABLE "daily_user_activity" ADD COLUMN "board_game_count" INTEGER NOT NULL DEFAULT 0;
+ALTER TABLE "daily_user_activity" ADD COLUMN "board_game_duration" INTEGER NOT NULL DEFAULT 0;
+"#,
+            )
+            .await?;
+        }
+
+        db.execute_unprepared(
+            r#"
+UPDATE "user" SET "preferences" = JSONB_SET(
+    "preferences",
+    '{features_enabled,media,specific}',
+    "preferences" -> 'features_enabled' -> 'media' -> 'specific' || '["board_game"]'
+)
+WHERE JSONB_TYPEOF("preferences" -> 'features_enabled' -> 'media' -> 'specific') = 'array'
+AND NOT "preferences" -> 'features_enabled' -> 'media' -> 'specific' ? 'board_game'
+"#,
+        )
+        .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, _manager: &SchemaManager) -> Result<(), DbErr> {
+        Ok(())
+    }
+}
@@ -0,0 +1,54 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        let db = manager.get_connection();
+
+        if !manager
+            .has_column("notification_platform", "delivery_mode")
+            .await?
+        {
+            db.execute_unprepared(
+                r#"
+ALTER TABLE "notification_platform" ADD COLUMN "delivery_mode" TEXT NOT NULL DEFAULT 'instant';
+ALTER TABLE "notification_platform" ADD COLUMN "quiet_hours" JSONB;
+"#,
+            )
+            .await?;
+        }
+
+        if !manager.has_table("notification_delivery").await? {
+            db.execute_unprepared(
+                r#"
+CREATE TABLE "notification_delivery" (
+    "id" TEXT NOT NULL PRIMARY KEY,
+    "attempts" INTEGER NOT NULL DEFAULT 0,
+    "message" TEXT NOT NULL,
+    "user_id" TEXT NOT NULL REFERENCES "user" ("id") ON DELETE CASCADE ON UPDATE CASCADE,
+    "error" TEXT,
+    "platform_id" TEXT NOT NULL REFERENCES "notification_platform" ("id") ON DELETE CASCADE ON UPDATE CASCADE,
+    "created_on" TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    "sent_on" TIMESTAMP WITH TIME ZONE,
+    "status" TEXT NOT NULL,
+    "next_attempt_on" TIMESTAMP WITH TIME ZONE,
+    "event" TEXT NOT NULL,
+    "content" JSONB NOT NULL
+);
+CREATE INDEX "notification_delivery__platform_id_created_on" ON "notification_delivery" ("platform_id", "created_on");
+CREATE INDEX "notification_delivery__status_next_attempt_on" ON "notification_delivery" ("status", "next_attempt_on");
+"#,
+            )
+            .await?;
+        }
+
+        Ok(())
+    }
+
+    async fn down(&self, _manager: &SchemaManager) -> Result<(), DbErr> {
+        Ok(())
+    }
+}
@@ -0,0 +1,37 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        let db = manager.get_connection();
+
+        if !manager.has_table("user_notification").await? {
+            db.execute_unprepared(
+                r#"
+CREATE TABLE "user_notification" (
+    "id" TEXT NOT NULL PRIMARY KEY,
+    "is_read" BOOLEAN NOT NULL DEFAULT FALSE,
+    "message" TEXT NOT NULL,
+    "user_id" TEXT NOT NULL REFERENCES "user" ("id") ON DELETE CASCADE ON UPDATE CASCADE,
+    "created_on" TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    "entity_id" TEXT,
+    "entity_lot" TEXT,
+    "event" TEXT NOT NULL,
+    "content" JSONB NOT NULL
+);
+CREATE INDEX "user_notification__user_id_is_read_created_on" ON "user_notification" ("user_id", "is_read", "created_on");
+"#,
+            )
+            .await?;
+        }
+
+        Ok(())
+    }
+
+    async fn down(&self, _manager: &SchemaManager) -> Result<(), DbErr> {
+        Ok(())
+    }
+}
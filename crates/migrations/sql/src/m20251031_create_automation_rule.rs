@@ -0,0 +1,38 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        let db = manager.get_connection();
+
+        if !manager.has_table("automation_rule").await? {
+            db.execute_unprepared(
+                r#"
+CREATE TABLE "automation_rule" (
+    "id" TEXT NOT NULL PRIMARY KEY,
+    "name" TEXT NOT NULL,
+    "user_id" TEXT NOT NULL REFERENCES "user" ("id") ON DELETE CASCADE ON UPDATE CASCADE,
+    "created_on" TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    "last_error" TEXT,
+    "is_disabled" BOOLEAN,
+    "trigger" TEXT NOT NULL,
+    "last_triggered_on" TIMESTAMP WITH TIME ZONE,
+    "actions" JSONB NOT NULL,
+    "conditions" JSONB NOT NULL
+);
+CREATE INDEX "automation_rule__user_id_trigger" ON "automation_rule" ("user_id", "trigger");
+"#,
+            )
+            .await?;
+        }
+
+        Ok(())
+    }
+
+    async fn down(&self, _manager: &SchemaManager) -> Result<(), DbErr> {
+        Ok(())
+    }
+}
@@ -0,0 +1,34 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        let db = manager.get_connection();
+
+        if !manager.has_table("metadata_translation").await? {
+            db.execute_unprepared(
+                r#"
+CREATE TABLE "metadata_translation" (
+    "id" TEXT NOT NULL PRIMARY KEY,
+    "language" TEXT NOT NULL,
+    "metadata_id" TEXT NOT NULL REFERENCES "metadata" ("id") ON DELETE CASCADE ON UPDATE CASCADE,
+    "title" TEXT,
+    "created_on" TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    "description" TEXT
+);
+CREATE UNIQUE INDEX "metadata_translation__metadata_id_language" ON "metadata_translation" ("metadata_id", "language");
+"#,
+            )
+            .await?;
+        }
+
+        Ok(())
+    }
+
+    async fn down(&self, _manager: &SchemaManager) -> Result<(), DbErr> {
+        Ok(())
+    }
+}
@@ -0,0 +1,37 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        let db = manager.get_connection();
+
+        if !manager.has_table("share_link").await? {
+            db.execute_unprepared(
+                r#"
+CREATE TABLE "share_link" (
+    "id" TEXT NOT NULL PRIMARY KEY,
+    "name" TEXT,
+    "user_id" TEXT NOT NULL REFERENCES "user" ("id") ON DELETE CASCADE ON UPDATE CASCADE,
+    "entity_id" TEXT NOT NULL,
+    "times_viewed" INTEGER NOT NULL DEFAULT 0,
+    "created_on" TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    "is_revoked" BOOLEAN,
+    "entity_lot" TEXT NOT NULL,
+    "expires_on" TIMESTAMP WITH TIME ZONE
+);
+CREATE INDEX "share_link__user_id" ON "share_link" ("user_id");
+"#,
+            )
+            .await?;
+        }
+
+        Ok(())
+    }
+
+    async fn down(&self, _manager: &SchemaManager) -> Result<(), DbErr> {
+        Ok(())
+    }
+}
@@ -0,0 +1,54 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        let db = manager.get_connection();
+
+        if !manager.has_table("user_follow").await? {
+            db.execute_unprepared(
+                r#"
+CREATE TABLE "user_follow" (
+    "id" TEXT NOT NULL PRIMARY KEY,
+    "created_on" TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    "follower_id" TEXT NOT NULL REFERENCES "user" ("id") ON DELETE CASCADE ON UPDATE CASCADE,
+    "following_id" TEXT NOT NULL REFERENCES "user" ("id") ON DELETE CASCADE ON UPDATE CASCADE
+);
+CREATE UNIQUE INDEX "user_follow__follower_id_following_id" ON "user_follow" ("follower_id", "following_id");
+CREATE INDEX "user_follow__following_id" ON "user_follow" ("following_id");
+"#,
+            )
+            .await?;
+        }
+
+        if !manager.has_table("user_feed_event").await? {
+            db.execute_unprepared(
+                r#"
+CREATE TABLE "user_feed_event" (
+    "id" TEXT NOT NULL PRIMARY KEY,
+    "user_id" TEXT NOT NULL REFERENCES "user" ("id") ON DELETE CASCADE ON UPDATE CASCADE,
+    "lot" TEXT NOT NULL,
+    "entity_id" TEXT NOT NULL,
+    "entity_lot" TEXT NOT NULL,
+    "origin_id" TEXT NOT NULL,
+    "created_on" TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    "rating" DECIMAL,
+    "personal_bests_achieved" INTEGER
+);
+CREATE UNIQUE INDEX "user_feed_event__lot_origin_id" ON "user_feed_event" ("lot", "origin_id");
+CREATE INDEX "user_feed_event__user_id_created_on" ON "user_feed_event" ("user_id", "created_on");
+"#,
+            )
+            .await?;
+        }
+
+        Ok(())
+    }
+
+    async fn down(&self, _manager: &SchemaManager) -> Result<(), DbErr> {
+        Ok(())
+    }
+}
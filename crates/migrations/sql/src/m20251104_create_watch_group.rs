@@ -0,0 +1,66 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        let db = manager.get_connection();
+
+        if !manager.has_table("watch_group").await? {
+            db.execute_unprepared(
+                r#"
+CREATE TABLE "watch_group" (
+    "id" TEXT NOT NULL PRIMARY KEY,
+    "name" TEXT NOT NULL,
+    "created_on" TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    "created_by_user_id" TEXT NOT NULL REFERENCES "user" ("id") ON DELETE CASCADE ON UPDATE CASCADE
+);
+"#,
+            )
+            .await?;
+        }
+
+        if !manager.has_table("watch_group_member").await? {
+            db.execute_unprepared(
+                r#"
+CREATE TABLE "watch_group_member" (
+    "id" TEXT NOT NULL PRIMARY KEY,
+    "user_id" TEXT NOT NULL REFERENCES "user" ("id") ON DELETE CASCADE ON UPDATE CASCADE,
+    "joined_on" TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    "accepted_on" TIMESTAMP WITH TIME ZONE,
+    "watch_group_id" TEXT NOT NULL REFERENCES "watch_group" ("id") ON DELETE CASCADE ON UPDATE CASCADE,
+    "excluded_metadata_ids" TEXT[] NOT NULL DEFAULT '{}'
+);
+CREATE UNIQUE INDEX "watch_group_member__watch_group_id_user_id" ON "watch_group_member" ("watch_group_id", "user_id");
+CREATE INDEX "watch_group_member__user_id" ON "watch_group_member" ("user_id");
+"#,
+            )
+            .await?;
+        }
+
+        if !manager.has_column("seen", "mirrored_from_user_id").await? {
+            db.execute_unprepared(
+                r#"ALTER TABLE "seen" ADD COLUMN "mirrored_from_user_id" TEXT REFERENCES "user" ("id") ON DELETE SET NULL ON UPDATE CASCADE"#,
+            )
+            .await?;
+        }
+
+        if !manager
+            .has_column("daily_user_activity", "mirrored_metadata_count")
+            .await?
+        {
+            db.execute_unprepared(
+                r#"ALTER TABLE "daily_user_activity" ADD COLUMN "mirrored_metadata_count" INTEGER NOT NULL DEFAULT 0"#,
+            )
+            .await?;
+        }
+
+        Ok(())
+    }
+
+    async fn down(&self, _manager: &SchemaManager) -> Result<(), DbErr> {
+        Ok(())
+    }
+}
@@ -0,0 +1,42 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        let db = manager.get_connection();
+
+        if !manager.has_table("user_invite").await? {
+            db.execute_unprepared(
+                r#"
+CREATE TABLE "user_invite" (
+    "id" TEXT NOT NULL PRIMARY KEY,
+    "lot" TEXT NOT NULL,
+    "created_on" TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    "limits" JSONB,
+    "used_on" TIMESTAMP WITH TIME ZONE,
+    "expires_on" TIMESTAMP WITH TIME ZONE,
+    "used_by_user_id" TEXT REFERENCES "user" ("id") ON DELETE SET NULL ON UPDATE CASCADE,
+    "created_by_user_id" TEXT NOT NULL REFERENCES "user" ("id") ON DELETE CASCADE ON UPDATE CASCADE,
+    "preferences" JSONB
+);
+CREATE INDEX "user_invite__created_by_user_id" ON "user_invite" ("created_by_user_id");
+"#,
+            )
+            .await?;
+        }
+
+        if !manager.has_column("user", "limits").await? {
+            db.execute_unprepared(r#"ALTER TABLE "user" ADD COLUMN "limits" JSONB"#)
+                .await?;
+        }
+
+        Ok(())
+    }
+
+    async fn down(&self, _manager: &SchemaManager) -> Result<(), DbErr> {
+        Ok(())
+    }
+}
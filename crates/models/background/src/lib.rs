@@ -1,5 +1,7 @@
 use common_models::ChangeCollectionToEntitiesInput;
-use media_models::{DeployImportJobInput, MetadataProgressUpdateInput, ReviewPostedEvent};
+use media_models::{
+    AutomationRuleEvent, DeployImportJobInput, MetadataProgressUpdateInput, ReviewPostedEvent,
+};
 use sea_orm::prelude::DateTimeUtc;
 use serde::{Deserialize, Serialize};
 use strum::Display;
@@ -32,9 +34,14 @@ pub enum MpApplicationJob {
 #[derive(Debug, Deserialize, Serialize, Display, Clone)]
 pub enum LpApplicationJob {
     HandleOnSeenComplete(String),
-    HandleEntityAddedToCollectionEvent(Uuid),
+    HandleEntityAddedToCollectionEvent(Uuid, bool),
     UpdateUserLastActivityPerformed(String, DateTimeUtc),
     HandleMetadataEligibleForSmartCollectionMoving(String),
+    SyncUserSmartCollections(String, Option<String>),
+    PushMetadataToIntegration(String, String),
+    HandleAutomationRuleEvent(AutomationRuleEvent),
+    UpdateUserMetadataTranslations(String, String),
+    MirrorMetadataProgressUpdates(String, Vec<MetadataProgressUpdateInput>),
 }
 
 #[derive(Debug, Deserialize, Serialize, Display, Clone)]
@@ -125,6 +125,10 @@ pub struct EntityAssets {
     pub remote_images: Vec<String>,
     /// The urls of the remote videos.
     pub remote_videos: Vec<EntityRemoteVideo>,
+    /// The keys of other S3 objects, for example the streams of an imported activity.
+    #[serde(default)]
+    #[graphql(default)]
+    pub s3_files: Vec<String>,
 }
 
 impl EntityAssets {
@@ -156,6 +160,13 @@ pub enum CollectionExtraInformationLot {
     Boolean,
     DateTime,
     StringArray,
+    /// A user rating on the stored scale of 0 to 100.
+    Rating,
+    Url,
+    /// A list of values that must all be one of the `possible_values`.
+    EnumMultiSelect,
+    /// An object with the `entity_id` and `entity_lot` of another entity.
+    EntityReference,
 }
 
 #[skip_serializing_none]
@@ -249,6 +260,7 @@ pub enum BackendError {
     NoUserId,
     NoSessionId,
     SessionExpired,
+    RoleNotAllowed,
     AdminOnlyAction,
     MutationNotAllowed,
 }
@@ -403,6 +415,7 @@ pub struct PersonSourceSpecifics {
     pub is_tvdb_company: Option<bool>,
     pub is_tmdb_company: Option<bool>,
     pub is_anilist_studio: Option<bool>,
+    pub is_bangumi_character: Option<bool>,
     pub is_giant_bomb_company: Option<bool>,
     pub is_hardcover_publisher: Option<bool>,
 }
@@ -438,6 +451,23 @@ pub struct MetadataLookupCacheInput {
     pub language: Option<String>,
 }
 
+/// The lists of noteworthy media that providers can surface on the dashboard.
+#[derive(Debug, Hash, Default, Serialize, Deserialize, Clone, Copy, Eq, PartialEq, EnumIter)]
+pub enum TrendingMediaList {
+    #[default]
+    Trending,
+    Popular,
+    /// Media that has been released recently or is yet to be released.
+    Upcoming,
+}
+
+#[derive(Clone, Hash, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TrendingMetadataCacheInput {
+    pub lot: MediaLot,
+    pub source: MediaSource,
+    pub list: TrendingMediaList,
+}
+
 #[skip_serializing_none]
 #[derive(Clone, Hash, Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub struct YoutubeMusicSongListened {
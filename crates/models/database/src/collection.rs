@@ -3,6 +3,7 @@
 use async_graphql::SimpleObject;
 use async_trait::async_trait;
 use common_models::CollectionExtraInformation;
+use media_models::SmartCollectionFilter;
 use nanoid::nanoid;
 use schematic::Schematic;
 use sea_orm::ActiveValue;
@@ -33,6 +34,9 @@ pub struct Model {
     pub last_updated_on: DateTimeUtc,
     #[sea_orm(column_type = "Json")]
     pub information_template: Option<Vec<CollectionExtraInformation>>,
+    #[schema(exclude)]
+    #[sea_orm(column_type = "Json")]
+    pub smart_filter: Option<SmartCollectionFilter>,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
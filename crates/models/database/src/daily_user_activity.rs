@@ -18,6 +18,7 @@ pub struct Model {
 
     pub total_metadata_count: i32,
     pub metadata_review_count: i32,
+    pub mirrored_metadata_count: i32,
     pub metadata_collection_count: i32,
 
     pub total_metadata_group_count: i32,
@@ -37,6 +38,9 @@ pub struct Model {
     pub anime_count: i32,
     pub book_count: i32,
     pub book_pages: i32,
+    pub comic_count: i32,
+    pub board_game_count: i32,
+    pub board_game_duration: i32,
     pub podcast_count: i32,
     pub podcast_duration: i32,
     pub manga_count: i32,
@@ -4,6 +4,7 @@ pub mod prelude;
 
 pub mod access_link;
 pub mod application_cache;
+pub mod automation_rule;
 pub mod calendar_event;
 pub mod collection;
 pub mod collection_entity_membership;
@@ -21,12 +22,21 @@ pub mod metadata_to_genre;
 pub mod metadata_to_metadata;
 pub mod metadata_to_metadata_group;
 pub mod metadata_to_person;
+pub mod metadata_translation;
+pub mod notification_delivery;
 pub mod notification_platform;
 pub mod person;
 pub mod review;
 pub mod seen;
+pub mod share_link;
 pub mod user;
+pub mod user_feed_event;
+pub mod user_follow;
+pub mod user_invite;
 pub mod user_measurement;
+pub mod user_notification;
 pub mod user_to_entity;
+pub mod watch_group;
+pub mod watch_group_member;
 pub mod workout;
 pub mod workout_template;
@@ -5,9 +5,10 @@ use chrono::NaiveDate;
 use common_models::EntityAssets;
 use enum_models::{MediaLot, MediaSource};
 use media_models::{
-    AnimeSpecifics, AudioBookSpecifics, BookSpecifics, MangaSpecifics, MetadataExternalIdentifiers,
-    MetadataFreeCreator, MovieSpecifics, MusicSpecifics, PodcastSpecifics, ShowSpecifics,
-    VideoGameSpecifics, VisualNovelSpecifics, WatchProvider,
+    AnimeSpecifics, AudioBookSpecifics, BoardGameSpecifics, BookSpecifics, ComicSpecifics,
+    MangaSpecifics, MetadataExternalIdentifiers, MetadataFreeCreator, MovieSpecifics,
+    MusicSpecifics, PodcastSpecifics, ShowSpecifics, VideoGameSpecifics, VisualNovelSpecifics,
+    WatchProvider,
 };
 use nanoid::nanoid;
 use rust_decimal::Decimal;
@@ -39,6 +40,8 @@ pub struct Model {
     pub created_by_user_id: Option<String>,
     pub book_specifics: Option<BookSpecifics>,
     pub show_specifics: Option<ShowSpecifics>,
+    pub comic_specifics: Option<ComicSpecifics>,
+    pub board_game_specifics: Option<BoardGameSpecifics>,
     pub anime_specifics: Option<AnimeSpecifics>,
     pub manga_specifics: Option<MangaSpecifics>,
     pub music_specifics: Option<MusicSpecifics>,
@@ -67,6 +70,8 @@ pub enum Relation {
     MetadataToMetadataGroup,
     #[sea_orm(has_many = "super::metadata_to_person::Entity")]
     MetadataToPerson,
+    #[sea_orm(has_many = "super::metadata_translation::Entity")]
+    MetadataTranslation,
     #[sea_orm(has_many = "super::review::Entity")]
     Review,
     #[sea_orm(has_many = "super::seen::Entity")]
@@ -113,6 +118,12 @@ impl Related<super::metadata_to_person::Entity> for Entity {
     }
 }
 
+impl Related<super::metadata_translation::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::MetadataTranslation.def()
+    }
+}
+
 impl Related<super::review::Entity> for Entity {
     fn to() -> RelationDef {
         Relation::Review.def()
@@ -2,7 +2,10 @@
 
 use async_graphql::SimpleObject;
 use async_trait::async_trait;
-use enum_models::{NotificationPlatformLot, UserNotificationContentDiscriminants};
+use enum_models::{
+    NotificationDeliveryMode, NotificationPlatformLot, UserNotificationContentDiscriminants,
+};
+use media_models::NotificationQuietHours;
 use nanoid::nanoid;
 use sea_orm::{ActiveValue, entity::prelude::*};
 use user_models::NotificationPlatformSpecifics;
@@ -22,6 +25,9 @@ pub struct Model {
     #[graphql(skip)]
     pub platform_specifics: NotificationPlatformSpecifics,
     pub configured_events: Vec<UserNotificationContentDiscriminants>,
+    pub delivery_mode: NotificationDeliveryMode,
+    #[sea_orm(column_type = "Json")]
+    pub quiet_hours: Option<NotificationQuietHours>,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
@@ -34,6 +40,14 @@ pub enum Relation {
         on_delete = "Cascade"
     )]
     User,
+    #[sea_orm(has_many = "super::notification_delivery::Entity")]
+    NotificationDelivery,
+}
+
+impl Related<super::notification_delivery::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::NotificationDelivery.def()
+    }
 }
 
 impl Related<super::user::Entity> for Entity {
@@ -2,6 +2,7 @@
 
 pub use super::access_link::Entity as AccessLink;
 pub use super::application_cache::Entity as ApplicationCache;
+pub use super::automation_rule::Entity as AutomationRule;
 pub use super::calendar_event::Entity as CalendarEvent;
 pub use super::collection::Entity as Collection;
 pub use super::collection_entity_membership::Entity as CollectionEntityMembership;
@@ -18,12 +19,21 @@ pub use super::metadata_to_genre::Entity as MetadataToGenre;
 pub use super::metadata_to_metadata::Entity as MetadataToMetadata;
 pub use super::metadata_to_metadata_group::Entity as MetadataToMetadataGroup;
 pub use super::metadata_to_person::Entity as MetadataToPerson;
+pub use super::metadata_translation::Entity as MetadataTranslation;
+pub use super::notification_delivery::Entity as NotificationDelivery;
 pub use super::notification_platform::Entity as NotificationPlatform;
 pub use super::person::Entity as Person;
 pub use super::review::Entity as Review;
 pub use super::seen::Entity as Seen;
+pub use super::share_link::Entity as ShareLink;
 pub use super::user::Entity as User;
+pub use super::user_feed_event::Entity as UserFeedEvent;
+pub use super::user_follow::Entity as UserFollow;
+pub use super::user_invite::Entity as UserInvite;
 pub use super::user_measurement::Entity as UserMeasurement;
+pub use super::user_notification::Entity as UserNotification;
 pub use super::user_to_entity::Entity as UserToEntity;
+pub use super::watch_group::Entity as WatchGroup;
+pub use super::watch_group_member::Entity as WatchGroupMember;
 pub use super::workout::Entity as Workout;
 pub use super::workout_template::Entity as WorkoutTemplate;
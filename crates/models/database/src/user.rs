@@ -8,7 +8,7 @@ use async_trait::async_trait;
 use enum_models::UserLot;
 use sea_orm::{ActiveValue, entity::prelude::*};
 use serde::{Deserialize, Serialize};
-use user_models::{UserExtraInformation, UserPreferences, UserTwoFactorInformation};
+use user_models::{UserExtraInformation, UserLimits, UserPreferences, UserTwoFactorInformation};
 
 #[derive(Clone, Debug, PartialEq, DeriveEntityModel, Eq, Serialize, Deserialize)]
 #[sea_orm(table_name = "user")]
@@ -20,6 +20,7 @@ pub struct Model {
     pub created_on: DateTimeUtc,
     pub password: Option<String>,
     pub is_disabled: Option<bool>,
+    pub limits: Option<UserLimits>,
     pub preferences: UserPreferences,
     pub oidc_issuer_id: Option<String>,
     pub last_login_on: Option<DateTimeUtc>,
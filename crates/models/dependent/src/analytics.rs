@@ -22,6 +22,9 @@ pub struct DailyUserActivityItem {
     pub anime_count: i64,
     pub book_count: i64,
     pub total_book_pages: i64,
+    pub comic_count: i64,
+    pub board_game_count: i64,
+    pub total_board_game_duration: i64,
     pub podcast_count: i64,
     pub total_podcast_duration: i64,
     pub manga_count: i64,
@@ -41,6 +44,7 @@ pub struct DailyUserActivityItem {
     pub total_workout_distance: i64,
     pub total_workout_rest_time: i64,
     pub total_metadata_count: i64,
+    pub mirrored_metadata_count: i64,
     pub total_review_count: i64,
     pub total_count: i64,
     pub total_duration: i64,
@@ -2,15 +2,16 @@ use std::collections::HashMap;
 
 use common_models::{
     ApplicationDateRange, EntityRecentlyConsumedCacheInput, MetadataGroupSearchInput,
-    MetadataLookupCacheInput, PeopleSearchInput, UserAnalyticsInput, UserLevelCacheKey,
-    YoutubeMusicSongListened,
+    MetadataLookupCacheInput, PeopleSearchInput, TrendingMetadataCacheInput, UserAnalyticsInput,
+    UserLevelCacheKey, YoutubeMusicSongListened,
 };
+use enum_models::UserLot;
 use fitness_models::{UserExercisesListInput, UserMeasurementsListInput};
 use media_models::{
     GenreDetailsInput, GraphqlMetadataDetails, MetadataLookupResponse,
     MetadataProgressUpdateCacheInput, TmdbMetadataLookupResult,
 };
-use sea_orm::FromJsonQueryResult;
+use sea_orm::{FromJsonQueryResult, prelude::DateTimeUtc};
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
 use strum::{Display, EnumDiscriminants};
@@ -42,11 +43,23 @@ pub struct EmptyCacheValue {
 pub struct UserTwoFactorSetupCacheValue {
     pub secret: String,
 }
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Eq)]
+pub struct NotificationPlatformVerificationCacheValue {
+    pub code: String,
+    #[serde(default)]
+    pub failed_attempts: u8,
+    pub sent_at: Option<DateTimeUtc>,
+}
 #[skip_serializing_none]
 #[derive(Clone, Hash, Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub struct UserSessionValue {
     pub user_id: String,
     pub access_link_id: Option<String>,
+    /// The role of the user, kept here so that it does not have to be looked up on every
+    /// request. Sessions created before this was stored do not have it.
+    #[serde(default)]
+    pub user_lot: Option<UserLot>,
 }
 
 #[derive(Clone, Hash, Debug, PartialEq, Eq, Serialize, Deserialize)]
@@ -75,17 +88,18 @@ pub enum ApplicationCacheKey {
     SpotifyAccessToken,
     ListennotesSettings,
     UserSession(String),
-    TrendingMetadataIds,
     PersonDetails(String),
     MetadataDetails(String),
     MetadataGroupDetails(String),
     UserPasswordChangeSession(String),
     CollectionRecommendations(String),
     MetadataLookup(MetadataLookupCacheInput),
+    TrendingMetadataIds(TrendingMetadataCacheInput),
     TmdbMultiSearch(MetadataLookupCacheInput),
     UserTwoFactorSetup(UserLevelCacheKey<()>),
     UserCollectionsList(UserLevelCacheKey<()>),
     UserPersonDetails(UserLevelCacheKey<String>),
+    NotificationPlatformVerification(UserLevelCacheKey<String>),
     UserTwoFactorRateLimit(UserLevelCacheKey<()>),
     UserWorkoutDetails(UserLevelCacheKey<String>),
     UserAnalyticsParameters(UserLevelCacheKey<()>),
@@ -149,6 +163,7 @@ pub enum ApplicationCacheValue {
     TmdbMultiSearch(Vec<TmdbMetadataLookupResult>),
     MetadataGroupDetails(Box<MetadataGroupDetails>),
     UserTwoFactorSetup(UserTwoFactorSetupCacheValue),
+    NotificationPlatformVerification(NotificationPlatformVerificationCacheValue),
     TrendingMetadataIds(TrendingMetadataIdsResponse),
     UserCollectionsList(UserCollectionsListResponse),
     MetadataGroupSearch(MetadataGroupSearchResponse),
@@ -1,6 +1,6 @@
 use async_graphql::{InputObject, InputType, OutputType, SimpleObject};
 use common_models::{ApplicationDateRange, SearchDetails, SearchInput};
-use database_models::{collection, metadata_group};
+use database_models::{collection, metadata_group, share_link, workout, workout_template};
 use enum_models::MediaLot;
 use media_models::{
     CollectionContentsFilter, CollectionContentsSortBy, EntityWithLot, GenreListItem,
@@ -34,7 +34,6 @@ pub struct SearchResults<T: OutputType> {
     name = "UserWorkoutsListSortInput",
     params(UserTemplatesOrWorkoutsListSortBy)
 ))]
-#[graphql(concrete(name = "CollectionContentsSortInput", params(CollectionContentsSortBy)))]
 pub struct SortInput<T: InputType + Default> {
     #[graphql(default)]
     pub by: T,
@@ -124,7 +123,17 @@ pub struct CollectionContentsInput {
     pub collection_id: String,
     pub search: Option<SearchInput>,
     pub filter: Option<CollectionContentsFilter>,
-    pub sort: Option<SortInput<CollectionContentsSortBy>>,
+    pub sort: Option<CollectionContentsSortInput>,
+}
+
+#[derive(Debug, PartialEq, Eq, Hash, Serialize, Deserialize, InputObject, Clone, Default)]
+pub struct CollectionContentsSortInput {
+    #[graphql(default)]
+    pub by: CollectionContentsSortBy,
+    #[graphql(default)]
+    pub order: GraphqlSortOrder,
+    /// The information template field to order by, needed when sorting by `Information`.
+    pub information_field: Option<String>,
 }
 
 #[derive(Debug, Hash, PartialEq, Eq, Clone, Serialize, Deserialize, InputObject)]
@@ -172,6 +181,26 @@ pub struct UserTemplatesOrWorkoutsListInput {
     pub sort: Option<SortInput<UserTemplatesOrWorkoutsListSortBy>>,
 }
 
+#[derive(Debug, Hash, PartialEq, Eq, Clone, Serialize, Deserialize, InputObject)]
+pub struct SharedEntityDetailsInput {
+    pub share_link_id: String,
+    /// Used to paginate through the contents of a shared collection.
+    pub search: Option<SearchInput>,
+}
+
+/// The data that can be seen through a share link. Only the field matching the kind of
+/// entity the link was created for is set.
+#[derive(Debug, SimpleObject, Clone)]
+pub struct SharedEntityDetails {
+    pub owner_name: String,
+    pub link: share_link::Model,
+    pub review: Option<ReviewItem>,
+    pub workout: Option<workout::Model>,
+    pub year_summary: Option<UserAnalytics>,
+    pub collection: Option<CollectionContents>,
+    pub workout_template: Option<workout_template::Model>,
+}
+
 // Type aliases for different response types
 pub type PeopleSearchResponse = SearchResults<String>;
 pub type MetadataSearchResponse = SearchResults<String>;
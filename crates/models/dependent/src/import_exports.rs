@@ -1,16 +1,22 @@
 use common_models::PersonSourceSpecifics;
 use database_models::{exercise, user_measurement, workout, workout_template};
-use enum_models::{MediaLot, MediaSource};
+use enum_models::{
+    IntegrationLot, IntegrationProvider, MediaLot, MediaSource, NotificationDeliveryMode,
+    NotificationPlatformLot, UserNotificationContentDiscriminants,
+};
 use fitness_models::UserWorkoutInput;
 use importer_models::ImportFailedItem;
 use media_models::{
     CollectionItem, CreateOrUpdateCollectionInput, ImportOrExportItemRating,
-    ImportOrExportMetadataItemSeen,
+    ImportOrExportMetadataItemSeen, IntegrationExtraSettings, IntegrationProviderSpecifics,
+    NotificationQuietHours,
 };
+use rust_decimal::Decimal;
 use schematic::Schematic;
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
 use strum::Display;
+use user_models::{NotificationPlatformSpecifics, UserPreferences};
 
 use crate::user_details::CollectionToEntityDetails;
 
@@ -105,6 +111,61 @@ pub struct ImportOrExportExerciseItem {
     pub collections: Vec<CollectionToEntityDetails>,
 }
 
+/// Details about the account of the user that needs to be exported.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "snake_case")]
+pub struct ImportOrExportAccountItem {
+    /// The id of the user on the instance the export was made. Used to remap references to the
+    /// user, for example in review comments.
+    pub id: String,
+    /// The name of the user.
+    pub name: String,
+    /// The preferences of the user.
+    pub preferences: UserPreferences,
+}
+
+/// Details about an integration of the user that needs to be exported.
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "snake_case")]
+pub struct ImportOrExportIntegrationItem {
+    pub lot: IntegrationLot,
+    pub name: Option<String>,
+    pub is_disabled: Option<bool>,
+    pub provider: IntegrationProvider,
+    pub minimum_progress: Option<Decimal>,
+    pub maximum_progress: Option<Decimal>,
+    pub sync_to_owned_collection: Option<bool>,
+    pub extra_settings: IntegrationExtraSettings,
+    pub provider_specifics: Option<IntegrationProviderSpecifics>,
+}
+
+/// Details about a notification platform of the user that needs to be exported.
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "snake_case")]
+pub struct ImportOrExportNotificationPlatformItem {
+    pub description: String,
+    pub is_disabled: Option<bool>,
+    pub lot: NotificationPlatformLot,
+    pub delivery_mode: NotificationDeliveryMode,
+    pub quiet_hours: Option<NotificationQuietHours>,
+    pub platform_specifics: NotificationPlatformSpecifics,
+    pub configured_events: Vec<UserNotificationContentDiscriminants>,
+}
+
+/// A file from the file storage that is referenced by the exported data.
+#[derive(Debug, Serialize, Deserialize, Clone, Schematic)]
+#[serde(rename_all = "snake_case")]
+pub struct ImportOrExportAssetItem {
+    /// The key of the file in the file storage it was exported from.
+    pub key: String,
+    /// The content type of the file.
+    pub content_type: String,
+    /// The path of the file inside the export archive.
+    pub path: String,
+}
+
 /// Complete export of the user.
 #[skip_serializing_none]
 #[derive(Debug, Serialize, Deserialize, Clone, Schematic)]
@@ -126,6 +187,20 @@ pub struct CompleteExport {
     pub metadata_groups: Option<Vec<ImportOrExportMetadataGroupItem>>,
     /// Data about user's workout templates.
     pub workout_templates: Option<Vec<ImportOrExportWorkoutTemplateItem>>,
+    /// Details about the user's account.
+    #[schema(exclude)]
+    pub account: Option<ImportOrExportAccountItem>,
+    /// Files from the file storage referenced by the other data.
+    pub assets: Option<Vec<ImportOrExportAssetItem>>,
+    /// Data about user's integrations.
+    #[schema(exclude)]
+    pub integrations: Option<Vec<ImportOrExportIntegrationItem>>,
+    /// Data about exercises created by the user.
+    #[schema(exclude)]
+    pub custom_exercises: Option<Vec<exercise::Model>>,
+    /// Data about user's notification platforms.
+    #[schema(exclude)]
+    pub notification_platforms: Option<Vec<ImportOrExportNotificationPlatformItem>>,
 }
 
 #[derive(Debug, Default, Display, Clone, Serialize)]
@@ -139,7 +214,12 @@ pub enum ImportCompletedItem {
     Measurement(user_measurement::Model),
     Collection(CreateOrUpdateCollectionInput),
     MetadataGroup(ImportOrExportMetadataGroupItem),
+    Preferences(Box<UserPreferences>),
+    Integration(Box<ImportOrExportIntegrationItem>),
+    ApplicationExercise(ImportOrExportExerciseItem),
     ApplicationWorkout(Box<ImportOrExportWorkoutItem>),
+    ApplicationWorkoutTemplate(Box<ImportOrExportWorkoutTemplateItem>),
+    NotificationPlatform(Box<ImportOrExportNotificationPlatformItem>),
 }
 
 #[derive(Debug, Default, Clone, Serialize)]
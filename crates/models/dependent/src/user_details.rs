@@ -1,9 +1,9 @@
 use async_graphql::{SimpleObject, Union};
 use chrono::NaiveDate;
-use common_models::{EntityAssets, PersonSourceSpecifics};
+use common_models::{EntityAssets, PersonSourceSpecifics, SearchDetails, StringIdAndNamedObject};
 use database_models::{
-    exercise, metadata_group::MetadataGroupWithoutId, person, seen, user_to_entity, workout,
-    workout_template,
+    exercise, metadata_group::MetadataGroupWithoutId, person, seen, user_feed_event,
+    user_notification, user_to_entity, watch_group, watch_group_member, workout, workout_template,
 };
 use enum_models::{UserLot, UserToMediaReason};
 use fitness_models::UserToExerciseHistoryExtraInformation;
@@ -16,7 +16,7 @@ use schematic::Schematic;
 use sea_orm::prelude::DateTimeUtc;
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
-use user_models::{UserExtraInformation, UserPreferences};
+use user_models::{UserExtraInformation, UserLimits, UserPreferences};
 use uuid::Uuid;
 
 #[skip_serializing_none]
@@ -84,6 +84,7 @@ pub struct UserDetails {
     pub name: String,
     pub lot: UserLot,
     pub is_disabled: Option<bool>,
+    pub limits: Option<UserLimits>,
     pub preferences: UserPreferences,
     pub oidc_issuer_id: Option<String>,
     pub access_link_id: Option<String>,
@@ -125,6 +126,10 @@ pub struct UserMetadataGroupDetails {
 #[skip_serializing_none]
 #[derive(Clone, SimpleObject, Debug, PartialEq, Serialize, Deserialize, Eq)]
 pub struct UserMetadataDetails {
+    /// The title in the user's preferred language, falling back to the original title.
+    pub title: String,
+    /// The description in the user's preferred language, falling back to the original one.
+    pub description: Option<String>,
     /// Whether this media has been interacted with
     pub has_interacted: bool,
     /// The number of users who have seen this media.
@@ -178,3 +183,36 @@ pub struct UpdateCustomExerciseInput {
     pub update: exercise::Model,
     pub should_delete: Option<bool>,
 }
+
+#[derive(Debug, SimpleObject, Clone)]
+pub struct UserNotificationsListResponse {
+    pub unread_count: u64,
+    pub details: SearchDetails,
+    pub items: Vec<user_notification::Model>,
+}
+
+#[derive(Debug, SimpleObject, Clone)]
+pub struct UserFollowsResponse {
+    pub followers: Vec<StringIdAndNamedObject>,
+    pub following: Vec<StringIdAndNamedObject>,
+}
+
+#[derive(Debug, SimpleObject, Clone)]
+pub struct UserActivityFeedItem {
+    pub user: StringIdAndNamedObject,
+    pub event: user_feed_event::Model,
+}
+
+#[derive(Debug, SimpleObject, Clone)]
+pub struct UserWatchGroupDetails {
+    pub details: watch_group::Model,
+    pub members: Vec<StringIdAndNamedObject>,
+    /// The membership of the current user, which holds the media they excluded.
+    pub membership: watch_group_member::Model,
+}
+
+#[derive(Debug, SimpleObject, Clone)]
+pub struct UserActivityFeedResponse {
+    pub details: SearchDetails,
+    pub items: Vec<UserActivityFeedItem>,
+}
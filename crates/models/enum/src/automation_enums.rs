@@ -0,0 +1,62 @@
+use async_graphql::Enum;
+use sea_orm::{DeriveActiveEnum, EnumIter};
+use sea_orm_migration::prelude::*;
+use serde::{Deserialize, Serialize};
+use strum::Display;
+
+#[derive(
+    Eq,
+    Enum,
+    Copy,
+    Hash,
+    Clone,
+    Debug,
+    Display,
+    EnumIter,
+    PartialEq,
+    Serialize,
+    Deserialize,
+    DeriveActiveEnum,
+)]
+#[sea_orm(
+    rs_type = "String",
+    rename_all = "snake_case",
+    db_type = "String(StringLen::None)"
+)]
+#[serde(rename_all = "snake_case")]
+pub enum AutomationRuleTrigger {
+    SeenDropped,
+    ReviewPosted,
+    SeenCompleted,
+    ReleaseDateReached,
+    EntityAddedToCollection,
+    MonitoredPersonCredited,
+}
+
+#[derive(
+    Eq,
+    Enum,
+    Copy,
+    Hash,
+    Clone,
+    Debug,
+    Display,
+    EnumIter,
+    PartialEq,
+    Serialize,
+    Deserialize,
+    DeriveActiveEnum,
+)]
+#[sea_orm(
+    rs_type = "String",
+    rename_all = "snake_case",
+    db_type = "String(StringLen::None)"
+)]
+#[serde(rename_all = "snake_case")]
+pub enum AutomationRuleActionLot {
+    AddToCollection,
+    SendNotification,
+    PushToIntegration,
+    RemoveFromCollection,
+    SetCollectionExtraInformation,
+}
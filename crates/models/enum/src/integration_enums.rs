@@ -54,6 +54,7 @@ pub enum IntegrationProvider {
     Emby,
     Kodi,
     Komga,
+    Kavita,
     Radarr,
     Sonarr,
     PlexSink,
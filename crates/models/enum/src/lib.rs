@@ -1,3 +1,6 @@
+mod automation_enums;
+pub use automation_enums::*;
+
 mod exercise_enums;
 pub use exercise_enums::*;
 
@@ -35,23 +35,32 @@ pub enum MediaLot {
     Anime,
     Manga,
     Music,
+    Comic,
     Podcast,
     AudioBook,
     VideoGame,
+    BoardGame,
     VisualNovel,
 }
 
 meta! {
     MediaLot, Vec<MediaSource>;
 
-    VisualNovel, vec![MediaSource::Vndb];
+    VisualNovel, vec![MediaSource::Vndb, MediaSource::Bangumi];
     AudioBook, vec![MediaSource::Audible];
-    Show, vec![MediaSource::Tmdb, MediaSource::Tvdb];
-    Movie, vec![MediaSource::Tmdb, MediaSource::Tvdb];
-    VideoGame, vec![MediaSource::Igdb, MediaSource::GiantBomb];
-    Music, vec![MediaSource::YoutubeMusic, MediaSource::Spotify];
+    Comic, vec![MediaSource::ComicVine];
+    BoardGame, vec![MediaSource::BoardGameGeek];
+    Show, vec![MediaSource::Tmdb, MediaSource::Tvdb, MediaSource::Douban];
+    Movie, vec![MediaSource::Tmdb, MediaSource::Tvdb, MediaSource::Douban];
+    VideoGame, vec![MediaSource::Igdb, MediaSource::GiantBomb, MediaSource::Bangumi];
+    Music, vec![
+        MediaSource::YoutubeMusic,
+        MediaSource::Spotify,
+        MediaSource::MusicBrainz,
+    ];
     Anime, vec![
         MediaSource::Anilist,
+        MediaSource::Bangumi,
         MediaSource::Myanimelist,
     ];
     Podcast, vec![
@@ -65,6 +74,7 @@ meta! {
     ];
     Manga, vec![
         MediaSource::Anilist,
+        MediaSource::Bangumi,
         MediaSource::Myanimelist,
         MediaSource::MangaUpdates,
     ];
@@ -100,17 +110,22 @@ pub enum MediaSource {
     #[default]
     Custom,
     Itunes,
+    Douban,
     Anilist,
     Audible,
+    Bangumi,
     Spotify,
+    ComicVine,
     GiantBomb,
     Hardcover,
     Myanimelist,
+    MusicBrainz,
     Listennotes,
     GoogleBooks,
     Openlibrary,
     MangaUpdates,
     YoutubeMusic,
+    BoardGameGeek,
 }
 
 meta! {
@@ -119,9 +134,11 @@ meta! {
     Tvdb, None;
     Vndb, None;
     Custom, None;
+    Douban, None;
     Itunes, None;
     Anilist, None;
     Audible, None;
+    Bangumi, None;
     Myanimelist, None;
     Listennotes, None;
     GoogleBooks, None;
@@ -129,10 +146,13 @@ meta! {
     MangaUpdates, None;
     Tmdb, Some(MediaLot::Movie);
     Spotify, Some(MediaLot::Music);
+    MusicBrainz, Some(MediaLot::Music);
     Igdb, Some(MediaLot::VideoGame);
     Hardcover, Some(MediaLot::Book);
     YoutubeMusic, Some(MediaLot::Music);
     GiantBomb, Some(MediaLot::VideoGame);
+    ComicVine, Some(MediaLot::Comic);
+    BoardGameGeek, Some(MediaLot::BoardGame);
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, EnumIter, DeriveActiveEnum, Deserialize, Serialize)]
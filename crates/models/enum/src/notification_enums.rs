@@ -1,6 +1,6 @@
 use async_graphql::Enum;
 use rust_decimal::Decimal;
-use sea_orm::{DeriveActiveEnum, EnumIter, prelude::Date};
+use sea_orm::{DeriveActiveEnum, EnumIter, FromJsonQueryResult, prelude::Date};
 use sea_orm_migration::prelude::*;
 use serde::{Deserialize, Serialize};
 use strum::{Display, EnumDiscriminants};
@@ -28,16 +28,84 @@ use crate::EntityLot;
 #[serde(rename_all = "snake_case")]
 pub enum NotificationPlatformLot {
     Ntfy,
+    Email,
+    Slack,
     Gotify,
+    Matrix,
     Apprise,
     Discord,
+    Webhook,
     PushOver,
     Telegram,
     PushSafer,
     PushBullet,
 }
 
-#[derive(Debug, Clone, Display, PartialEq, Serialize, Deserialize, EnumDiscriminants)]
+#[derive(
+    Eq,
+    Enum,
+    Copy,
+    Clone,
+    Debug,
+    Default,
+    Display,
+    EnumIter,
+    PartialEq,
+    Serialize,
+    Deserialize,
+    DeriveActiveEnum,
+)]
+#[sea_orm(
+    rs_type = "String",
+    rename_all = "snake_case",
+    db_type = "String(StringLen::None)"
+)]
+#[serde(rename_all = "snake_case")]
+pub enum NotificationDeliveryMode {
+    #[default]
+    Instant,
+    DailyDigest,
+    HourlyDigest,
+}
+
+#[derive(
+    Eq,
+    Enum,
+    Copy,
+    Clone,
+    Debug,
+    Display,
+    EnumIter,
+    PartialEq,
+    Serialize,
+    Deserialize,
+    DeriveActiveEnum,
+)]
+#[sea_orm(
+    rs_type = "String",
+    rename_all = "snake_case",
+    db_type = "String(StringLen::None)"
+)]
+#[serde(rename_all = "snake_case")]
+pub enum NotificationDeliveryStatus {
+    Sent,
+    Failed,
+    Pending,
+    /// The platform was disabled or removed before the delivery was sent.
+    Skipped,
+}
+
+#[derive(
+    Eq,
+    Debug,
+    Clone,
+    Display,
+    PartialEq,
+    Serialize,
+    Deserialize,
+    EnumDiscriminants,
+    FromJsonQueryResult,
+)]
 #[strum_discriminants(
     derive(Enum, EnumIter, Serialize, Deserialize, DeriveActiveEnum),
     sea_orm(
@@ -58,37 +126,58 @@ pub enum UserNotificationContent {
         workout_name: String,
     },
     MetadataStatusChanged {
+        entity_id: Option<String>,
+        #[serde(default)]
+        entity_lot: EntityLot,
         old_status: String,
         new_status: String,
         entity_title: String,
     },
     PersonMetadataAssociated {
+        entity_id: Option<String>,
+        #[serde(default)]
+        entity_lot: EntityLot,
         role: String,
         person_name: String,
         metadata_title: String,
     },
     MetadataEpisodeImagesChanged {
+        entity_id: Option<String>,
+        #[serde(default)]
+        entity_lot: EntityLot,
         episode_number: i32,
         entity_title: String,
         season_number: Option<i32>,
     },
     PersonMetadataGroupAssociated {
+        entity_id: Option<String>,
+        #[serde(default)]
+        entity_lot: EntityLot,
         role: String,
         person_name: String,
         metadata_group_title: String,
     },
     MetadataNumberOfSeasonsChanged {
+        entity_id: Option<String>,
+        #[serde(default)]
+        entity_lot: EntityLot,
         old_seasons: usize,
         new_seasons: usize,
         entity_title: String,
     },
     MetadataEpisodeReleased {
+        entity_id: Option<String>,
+        #[serde(default)]
+        entity_lot: EntityLot,
         entity_title: String,
         old_episode_count: usize,
         new_episode_count: usize,
         season_number: Option<i32>,
     },
     MetadataChaptersOrEpisodesChanged {
+        entity_id: Option<String>,
+        #[serde(default)]
+        entity_lot: EntityLot,
         old_count: Decimal,
         new_count: Decimal,
         entity_title: String,
@@ -108,6 +197,7 @@ pub enum UserNotificationContent {
         show_extra: Option<(i32, i32)>,
     },
     OutdatedSeenEntries {
+        entity_id: Option<String>,
         seen_state: String,
         days_threshold: i64,
         entity_title: String,
@@ -115,6 +205,9 @@ pub enum UserNotificationContent {
         last_updated_on: Date,
     },
     MetadataReleaseDateChanged {
+        entity_id: Option<String>,
+        #[serde(default)]
+        entity_lot: EntityLot,
         old_date: String,
         new_date: String,
         entity_title: String,
@@ -122,6 +215,9 @@ pub enum UserNotificationContent {
         episode_number: Option<i32>,
     },
     MetadataEpisodeNameChanged {
+        entity_id: Option<String>,
+        #[serde(default)]
+        entity_lot: EntityLot,
         old_name: String,
         new_name: String,
         episode_number: i32,
@@ -133,4 +229,11 @@ pub enum UserNotificationContent {
         entity_title: String,
         entity_lot: EntityLot,
     },
+    AutomationRuleTriggered {
+        rule_name: String,
+        entity_id: String,
+        entity_title: String,
+        entity_lot: EntityLot,
+        message: Option<String>,
+    },
 }
@@ -6,7 +6,17 @@ use serde::{Deserialize, Serialize};
 use strum::Display;
 
 #[derive(
-    Debug, Clone, Copy, PartialEq, Eq, EnumIter, DeriveActiveEnum, Deserialize, Serialize, Enum,
+    Debug,
+    Clone,
+    Copy,
+    Hash,
+    PartialEq,
+    Eq,
+    EnumIter,
+    DeriveActiveEnum,
+    Deserialize,
+    Serialize,
+    Enum,
 )]
 #[sea_orm(
     rs_type = "String",
@@ -16,6 +26,10 @@ use strum::Display;
 pub enum UserLot {
     Admin,
     Normal,
+    /// Can only view data, all mutations are denied.
+    Guest,
+    /// Can only use the fitness tracking features.
+    FitnessOnly,
 }
 
 #[derive(
@@ -137,6 +151,7 @@ pub enum ImportSource {
     Trakt,
     Movary,
     Anilist,
+    Bangumi,
     Grouvee,
     Netflix,
     Jellyfin,
@@ -144,10 +159,14 @@ pub enum ImportSource {
     StrongApp,
     Goodreads,
     Hardcover,
+    AppleHealth,
     Storygraph,
     Myanimelist,
     GenericJson,
     Mediatracker,
+    ActivityFile,
+    HealthConnect,
+    BoardGameGeek,
     Audiobookshelf,
 }
 
@@ -183,3 +202,58 @@ pub enum UserToMediaReason {
     Collection,
     Monitoring,
 }
+
+#[derive(
+    Eq,
+    Enum,
+    Copy,
+    Hash,
+    Clone,
+    Debug,
+    Display,
+    EnumIter,
+    PartialEq,
+    Serialize,
+    Deserialize,
+    DeriveActiveEnum,
+)]
+#[serde(rename_all = "snake_case")]
+#[sea_orm(
+    rs_type = "String",
+    rename_all = "snake_case",
+    db_type = "String(StringLen::None)"
+)]
+pub enum ShareLinkEntityLot {
+    Review,
+    Workout,
+    Collection,
+    YearSummary,
+    WorkoutTemplate,
+}
+
+#[derive(
+    Eq,
+    Enum,
+    Copy,
+    Hash,
+    Clone,
+    Debug,
+    Display,
+    EnumIter,
+    PartialEq,
+    Serialize,
+    Deserialize,
+    DeriveActiveEnum,
+)]
+#[serde(rename_all = "snake_case")]
+#[sea_orm(
+    rs_type = "String",
+    rename_all = "snake_case",
+    db_type = "String(StringLen::None)"
+)]
+pub enum UserFeedEventLot {
+    Review,
+    Workout,
+    SeenCompleted,
+    CollectionCreated,
+}
@@ -4,6 +4,9 @@ pub use exercise_models::*;
 mod input_models;
 pub use input_models::*;
 
+mod progression_models;
+pub use progression_models::*;
+
 mod set_and_workout_models;
 pub use set_and_workout_models::*;
 
@@ -0,0 +1,83 @@
+use async_graphql::{Enum, InputObject, SimpleObject};
+use educe::Educe;
+use rust_decimal::{Decimal, dec};
+use sea_orm::FromJsonQueryResult;
+use serde::{Deserialize, Serialize};
+
+use crate::WorkoutSetStatistic;
+
+/// The strategy used to increase the load of weighted exercises.
+#[derive(
+    Eq, Enum, Copy, Clone, Debug, Default, PartialEq, Serialize, Deserialize, FromJsonQueryResult,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum ExerciseProgressionStrategy {
+    /// Add reps until the top of the rep range is reached, then increase the load.
+    #[default]
+    DoubleProgression,
+    /// Increase the load every session that was completed without failure.
+    LinearLoad,
+}
+
+/// Settings that control the targets suggested for the next session of an exercise.
+#[derive(
+    Eq,
+    Debug,
+    Clone,
+    Educe,
+    PartialEq,
+    Serialize,
+    Deserialize,
+    InputObject,
+    SimpleObject,
+    FromJsonQueryResult,
+)]
+#[graphql(input_name = "ExerciseProgressionSettingsInput")]
+#[educe(Default)]
+pub struct ExerciseProgressionSettings {
+    pub strategy: ExerciseProgressionStrategy,
+    #[educe(Default = 8)]
+    pub min_reps: u8,
+    #[educe(Default = 12)]
+    pub max_reps: u8,
+    /// The load (in kilograms) added when progressing a weighted exercise.
+    #[educe(Default = dec!(2.5))]
+    pub load_increment: Decimal,
+    /// The number of consecutive failed sessions after which a deload is suggested.
+    #[educe(Default = 2)]
+    pub deload_after_failed_sessions: u8,
+    #[educe(Default = dec!(10))]
+    pub deload_percentage: Decimal,
+    /// The improvement applied to duration and pace targets of cardio exercises.
+    #[educe(Default = dec!(2))]
+    pub cardio_improvement_percentage: Decimal,
+}
+
+/// The kind of change that was suggested for the next session of an exercise.
+#[derive(Eq, Enum, Copy, Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub enum ExerciseProgressionAction {
+    Deload,
+    Repeat,
+    IncreaseReps,
+    IncreaseLoad,
+    IncreasePace,
+    IncreaseDuration,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, SimpleObject)]
+pub struct ExerciseProgressionSuggestion {
+    pub exercise_id: String,
+    /// The index of the exercise in the template or workout that was opened.
+    pub exercise_idx: usize,
+    pub action: ExerciseProgressionAction,
+    /// The workout whose performance the suggestion is based on.
+    pub based_on_workout_id: String,
+    /// The targets for each working (non warm-up) set.
+    pub sets: Vec<WorkoutSetStatistic>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, InputObject)]
+pub struct UserWorkoutProgressionSuggestionsInput {
+    pub workout_id: Option<String>,
+    pub workout_template_id: Option<String>,
+}
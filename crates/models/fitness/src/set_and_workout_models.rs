@@ -33,6 +33,24 @@ pub enum SetLot {
     Failure,
 }
 
+/// The formula used to estimate the one rep max of a set.
+#[derive(
+    Eq, Enum, Copy, Clone, Debug, Default, Serialize, PartialEq, Deserialize, FromJsonQueryResult,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum OneRmFormula {
+    /// Brzycki below 10 reps and Epley from 10 reps onwards.
+    #[default]
+    BrzyckiEpley,
+    Brzycki,
+    Epley,
+    Lombardi,
+    Wathan,
+    Mayhew,
+    /// The percentage of the one rep max from a RPE chart, using the reps in reserve.
+    RpeChart,
+}
+
 /// Details about the statistics of the set performed.
 #[skip_serializing_none]
 #[derive(
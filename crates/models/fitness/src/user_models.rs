@@ -51,6 +51,12 @@ pub struct UserMeasurementStatistic {
 pub struct UserMeasurementInformation {
     pub assets: EntityAssets,
     pub statistics: Vec<UserMeasurementStatistic>,
+    /// The statistics derived using the formulas and trends in the measurement
+    /// preferences. They are calculated when listing measurements and never stored.
+    #[serde(default)]
+    #[graphql(skip_input)]
+    #[schema(exclude)]
+    pub computed_statistics: Vec<UserMeasurementStatistic>,
 }
 
 #[skip_serializing_none]
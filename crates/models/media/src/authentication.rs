@@ -1,6 +1,6 @@
 use async_graphql::{Enum, InputObject, OneofObject, SimpleObject, Union};
 use common_models::StringIdObject;
-use enum_models::UserLot;
+use enum_models::{ShareLinkEntityLot, UserLot};
 use sea_orm::prelude::DateTimeUtc;
 use serde::{Deserialize, Serialize};
 
@@ -44,11 +44,14 @@ pub struct RegisterUserInput {
     pub user_id: Option<String>,
     /// If registration is disabled, this can be used to override it.
     pub admin_access_token: Option<String>,
+    /// An invite created by an admin. Registration is allowed even if it is disabled.
+    pub invite_code: Option<String>,
 }
 
 #[derive(Enum, Clone, Debug, Copy, PartialEq, Eq)]
 pub enum RegisterErrorVariant {
     Disabled,
+    InvalidInviteCode,
     IdentifierAlreadyExists,
 }
 
@@ -116,6 +119,31 @@ pub struct CreateAccessLinkInput {
     pub is_mutation_allowed: Option<bool>,
 }
 
+#[derive(Debug, Serialize, Deserialize, InputObject, Clone)]
+pub struct CreateShareLinkInput {
+    pub name: Option<String>,
+    /// The id of the entity to share. For a year summary, this is the year.
+    pub entity_id: String,
+    pub entity_lot: ShareLinkEntityLot,
+    pub expires_on: Option<DateTimeUtc>,
+}
+
+#[derive(Debug, Serialize, Deserialize, InputObject, Clone)]
+pub struct CreateOrUpdateWatchGroupInput {
+    pub name: String,
+    pub update_id: Option<String>,
+    /// The users other than the creator who are part of this group. New members are
+    /// invited and only join once they accept.
+    pub member_ids: Vec<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, InputObject, Clone)]
+pub struct UpdateWatchGroupExclusionInput {
+    pub metadata_id: String,
+    pub is_excluded: bool,
+    pub watch_group_id: String,
+}
+
 #[derive(Debug, Serialize, Deserialize, OneofObject, Clone)]
 pub enum ProcessAccessLinkInput {
     Id(String),
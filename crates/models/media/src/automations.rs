@@ -0,0 +1,103 @@
+use async_graphql::{InputObject, SimpleObject};
+use enum_models::{AutomationRuleActionLot, AutomationRuleTrigger, EntityLot, MediaLot};
+use sea_orm::FromJsonQueryResult;
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+/// Something that happened to an entity of a user which can set off their automation rules.
+#[skip_serializing_none]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AutomationRuleEvent {
+    pub user_id: String,
+    pub entity_id: String,
+    pub entity_lot: EntityLot,
+    pub trigger: AutomationRuleTrigger,
+    /// The collection the entity was added to, for `EntityAddedToCollection` events.
+    pub collection_name: Option<String>,
+    /// Whether the change was made by the action of an automation rule. Such events do
+    /// not set off any rules, so that rules can not keep triggering each other.
+    #[serde(default)]
+    pub triggered_by_automation: bool,
+}
+
+/// Extra checks an event has to pass before the actions of a rule are performed.
+#[skip_serializing_none]
+#[derive(
+    Eq,
+    Clone,
+    Debug,
+    Default,
+    PartialEq,
+    Serialize,
+    Deserialize,
+    InputObject,
+    SimpleObject,
+    FromJsonQueryResult,
+)]
+#[serde(rename_all = "snake_case")]
+#[graphql(input_name = "AutomationRuleConditionsInput")]
+pub struct AutomationRuleConditions {
+    pub entity_lots: Option<Vec<EntityLot>>,
+    pub metadata_lots: Option<Vec<MediaLot>>,
+    pub collection_names: Option<Vec<String>>,
+}
+
+impl AutomationRuleConditions {
+    pub fn matches(&self, event: &AutomationRuleEvent, metadata_lot: Option<MediaLot>) -> bool {
+        if let Some(lots) = self.entity_lots.as_ref().filter(|l| !l.is_empty())
+            && !lots.contains(&event.entity_lot)
+        {
+            return false;
+        }
+        if let Some(lots) = self.metadata_lots.as_ref().filter(|l| !l.is_empty())
+            && !metadata_lot.is_some_and(|l| lots.contains(&l))
+        {
+            return false;
+        }
+        if let Some(names) = self.collection_names.as_ref().filter(|n| !n.is_empty())
+            && !event
+                .collection_name
+                .as_ref()
+                .is_some_and(|n| names.contains(n))
+        {
+            return false;
+        }
+        true
+    }
+}
+
+#[skip_serializing_none]
+#[derive(
+    Eq,
+    Clone,
+    Debug,
+    PartialEq,
+    Serialize,
+    Deserialize,
+    InputObject,
+    SimpleObject,
+    FromJsonQueryResult,
+)]
+#[serde(rename_all = "snake_case")]
+#[graphql(input_name = "AutomationRuleActionInput")]
+pub struct AutomationRuleAction {
+    pub lot: AutomationRuleActionLot,
+    /// The text of the notification for `SendNotification`.
+    pub message: Option<String>,
+    /// The Radarr or Sonarr integration to push to for `PushToIntegration`.
+    pub integration_id: Option<String>,
+    /// The collection to change for the collection actions.
+    pub collection_name: Option<String>,
+    /// The extra information to set for `SetCollectionExtraInformation`.
+    pub information: Option<serde_json::Value>,
+}
+
+#[derive(Debug, Serialize, Deserialize, InputObject, Clone)]
+pub struct CreateOrUpdateUserAutomationRuleInput {
+    pub name: String,
+    pub rule_id: Option<String>,
+    pub is_disabled: Option<bool>,
+    pub trigger: AutomationRuleTrigger,
+    pub actions: Vec<AutomationRuleAction>,
+    pub conditions: Option<AutomationRuleConditions>,
+}
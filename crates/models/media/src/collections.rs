@@ -2,7 +2,8 @@ use async_graphql::{Enum, InputObject, SimpleObject};
 use common_models::{
     CollectionExtraInformation, StringIdAndNamedObject, UserToCollectionExtraInformation,
 };
-use enum_models::{EntityLot, MediaLot};
+use enum_models::{EntityLot, MediaLot, MediaSource, SeenState};
+use rust_decimal::Decimal;
 use schematic::Schematic;
 use sea_orm::{FromJsonQueryResult, FromQueryResult};
 use serde::{Deserialize, Serialize};
@@ -16,6 +17,40 @@ pub struct CreateOrUpdateCollectionInput {
     pub collaborators: Option<Vec<String>>,
     pub extra_information: Option<UserToCollectionExtraInformation>,
     pub information_template: Option<Vec<CollectionExtraInformation>>,
+    pub smart_filter: Option<SmartCollectionFilter>,
+}
+
+/// The rules that decide which media belong to a smart collection. Every rule that is
+/// set must match. User ratings are on the stored scale of 0 to 100.
+#[skip_serializing_none]
+#[derive(
+    Eq,
+    Clone,
+    Debug,
+    Default,
+    PartialEq,
+    Serialize,
+    Deserialize,
+    InputObject,
+    SimpleObject,
+    FromJsonQueryResult,
+)]
+#[serde(rename_all = "snake_case")]
+#[graphql(input_name = "SmartCollectionFilterInput")]
+pub struct SmartCollectionFilter {
+    pub lots: Option<Vec<MediaLot>>,
+    pub genre_ids: Option<Vec<String>>,
+    pub person_ids: Option<Vec<String>>,
+    pub min_publish_year: Option<i32>,
+    pub max_publish_year: Option<i32>,
+    pub sources: Option<Vec<MediaSource>>,
+    pub min_user_rating: Option<Decimal>,
+    pub max_user_rating: Option<Decimal>,
+    pub seen_states: Option<Vec<SeenState>>,
+    pub min_provider_rating: Option<Decimal>,
+    pub max_provider_rating: Option<Decimal>,
+    pub in_collection_ids: Option<Vec<String>>,
+    pub not_in_collection_ids: Option<Vec<String>>,
 }
 
 #[derive(Debug, Serialize, Hash, Deserialize, Enum, Clone, PartialEq, Eq, Copy, Default)]
@@ -26,12 +61,25 @@ pub enum CollectionContentsSortBy {
     Title,
     Random,
     LastUpdatedOn,
+    /// Order by the value of the information template field named in the sort input.
+    Information,
 }
 
 #[derive(Debug, Hash, PartialEq, Eq, Clone, Serialize, Deserialize, InputObject, Default)]
 pub struct CollectionContentsFilter {
     pub entity_lot: Option<EntityLot>,
     pub metadata_lot: Option<MediaLot>,
+    pub information: Option<CollectionContentsInformationFilter>,
+}
+
+/// Match collection entities on the value of one information template field. Bounds are
+/// compared using the field's type, and list fields match when any element equals `value`.
+#[derive(Debug, Hash, PartialEq, Eq, Clone, Serialize, Deserialize, InputObject)]
+pub struct CollectionContentsInformationFilter {
+    pub name: String,
+    pub value: Option<String>,
+    pub min_value: Option<String>,
+    pub max_value: Option<String>,
 }
 
 #[skip_serializing_none]
@@ -66,4 +114,6 @@ pub struct CollectionItem {
     pub creator: StringIdAndNamedObject,
     pub collaborators: Vec<CollectionItemCollaboratorInformation>,
     pub information_template: Option<Vec<CollectionExtraInformation>>,
+    #[schema(exclude)]
+    pub smart_filter: Option<SmartCollectionFilter>,
 }
@@ -40,6 +40,9 @@ pub struct MetadataProgressUpdateCommonInput {
     pub manual_time_spent: Option<Decimal>,
     pub podcast_episode_number: Option<i32>,
     pub manga_chapter_number: Option<Decimal>,
+    pub board_game_duration: Option<i32>,
+    pub board_game_winner: Option<String>,
+    pub board_game_player_count: Option<i32>,
     pub providers_consumed_on: Option<Vec<String>>,
 }
 
@@ -253,4 +256,6 @@ pub struct GroupedCalendarEvent {
 #[derive(Debug, Default)]
 pub struct UpdateMediaEntityResult {
     pub notifications: Vec<UserNotificationContent>,
+    /// The media that were newly associated with the person that was updated.
+    pub credited_metadata_ids: Vec<String>,
 }
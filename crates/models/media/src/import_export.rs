@@ -36,6 +36,12 @@ pub struct ImportOrExportMetadataItemSeen {
     pub podcast_episode_number: Option<i32>,
     /// If for a manga, the chapter which was seen.
     pub manga_chapter_number: Option<Decimal>,
+    /// If for a board game, the duration (in minutes) of the play.
+    pub board_game_duration: Option<i32>,
+    /// If for a board game, the name of the winner(s) of the play.
+    pub board_game_winner: Option<String>,
+    /// If for a board game, the number of players in the play.
+    pub board_game_player_count: Option<i32>,
     /// The providers this item was consumed on.
     pub providers_consumed_on: Option<Vec<String>>,
 }
@@ -1,6 +1,8 @@
 use async_graphql::{InputObject, SimpleObject, Union};
+use chrono::NaiveTime;
+use common_models::SearchInput;
 use enum_models::{
-    IntegrationProvider, MediaLot, MediaSource, NotificationPlatformLot,
+    IntegrationProvider, MediaLot, MediaSource, NotificationDeliveryMode,
     UserNotificationContentDiscriminants,
 };
 use rust_decimal::Decimal;
@@ -86,6 +88,10 @@ pub struct IntegrationProviderSpecifics {
     pub komga_password: Option<String>,
     pub komga_provider: Option<MediaSource>,
 
+    pub kavita_api_key: Option<String>,
+    pub kavita_base_url: Option<String>,
+    pub kavita_provider: Option<MediaSource>,
+
     pub radarr_api_key: Option<String>,
     pub radarr_profile_id: Option<i32>,
     pub radarr_base_url: Option<String>,
@@ -123,21 +129,60 @@ pub struct CreateOrUpdateUserIntegrationInput {
     pub provider_specifics: Option<IntegrationProviderSpecifics>,
 }
 
+/// A daily window during which notifications are held back. The window may wrap around
+/// midnight, in which case `end_time` is earlier than `start_time`.
+#[derive(
+    Eq,
+    Copy,
+    Clone,
+    Debug,
+    Serialize,
+    PartialEq,
+    Deserialize,
+    InputObject,
+    SimpleObject,
+    FromJsonQueryResult,
+)]
+#[graphql(input_name = "NotificationQuietHoursInput")]
+pub struct NotificationQuietHours {
+    pub start_time: NaiveTime,
+    pub end_time: NaiveTime,
+}
+
+impl NotificationQuietHours {
+    pub fn contains(&self, time: NaiveTime) -> bool {
+        match self.start_time <= self.end_time {
+            true => time >= self.start_time && time < self.end_time,
+            false => time >= self.start_time || time < self.end_time,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, InputObject, Clone)]
+pub struct VerifyUserNotificationPlatformInput {
+    pub code: String,
+    pub notification_id: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, InputObject, Clone)]
+pub struct UserNotificationsListInput {
+    pub unread_only: Option<bool>,
+    pub search: Option<SearchInput>,
+}
+
 #[derive(Debug, Serialize, Deserialize, InputObject, Clone)]
-pub struct CreateUserNotificationPlatformInput {
-    pub priority: Option<i32>,
-    pub chat_id: Option<String>,
-    pub base_url: Option<String>,
-    #[graphql(secret)]
-    pub api_token: Option<String>,
-    #[graphql(secret)]
-    pub auth_header: Option<String>,
-    pub lot: NotificationPlatformLot,
+pub struct MarkUserNotificationsAsReadInput {
+    /// The notifications to mark as read. All notifications are marked if this is absent.
+    pub notification_ids: Option<Vec<String>>,
 }
 
 #[derive(Debug, Serialize, Deserialize, InputObject, Clone)]
 pub struct UpdateUserNotificationPlatformInput {
     pub notification_id: String,
     pub is_disabled: Option<bool>,
+    pub delivery_mode: Option<NotificationDeliveryMode>,
+    pub quiet_hours: Option<NotificationQuietHours>,
+    /// Remove the quiet hours of the platform. Takes precedence over `quiet_hours`.
+    pub clear_quiet_hours: Option<bool>,
     pub configured_events: Option<Vec<UserNotificationContentDiscriminants>>,
 }
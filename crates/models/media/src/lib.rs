@@ -1,6 +1,9 @@
 mod authentication;
 pub use authentication::*;
 
+mod automations;
+pub use automations::*;
+
 mod collections;
 pub use collections::*;
 
@@ -24,3 +27,6 @@ pub use metadata_models::*;
 
 pub use user_interactions::*;
 mod user_interactions;
+
+#[cfg(test)]
+mod tests;
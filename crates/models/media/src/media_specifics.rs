@@ -259,6 +259,47 @@ pub struct VisualNovelSpecifics {
     pub length: Option<i32>,
 }
 
+#[skip_serializing_none]
+#[derive(
+    Eq,
+    Debug,
+    Clone,
+    Default,
+    PartialEq,
+    Serialize,
+    Deserialize,
+    InputObject,
+    SimpleObject,
+    FromJsonQueryResult,
+)]
+#[graphql(input_name = "BoardGameSpecificsInput")]
+pub struct BoardGameSpecifics {
+    pub min_age: Option<i32>,
+    pub min_players: Option<i32>,
+    pub max_players: Option<i32>,
+    pub min_play_time: Option<i32>,
+    pub max_play_time: Option<i32>,
+}
+
+#[skip_serializing_none]
+#[derive(
+    Eq,
+    Debug,
+    Clone,
+    Default,
+    PartialEq,
+    Serialize,
+    Deserialize,
+    InputObject,
+    SimpleObject,
+    FromJsonQueryResult,
+)]
+#[graphql(input_name = "ComicSpecificsInput")]
+pub struct ComicSpecifics {
+    pub volume: Option<String>,
+    pub issue_number: Option<String>,
+}
+
 #[skip_serializing_none]
 #[derive(
     Eq,
@@ -11,8 +11,9 @@ use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
 
 use crate::{
-    AnimeSpecifics, AudioBookSpecifics, BookSpecifics, MangaSpecifics, MovieSpecifics,
-    MusicSpecifics, PodcastSpecifics, ShowSpecifics, VideoGameSpecifics, VisualNovelSpecifics,
+    AnimeSpecifics, AudioBookSpecifics, BoardGameSpecifics, BookSpecifics, ComicSpecifics,
+    MangaSpecifics, MovieSpecifics, MusicSpecifics, PodcastSpecifics, ShowSpecifics,
+    VideoGameSpecifics, VisualNovelSpecifics,
 };
 
 #[derive(Debug, PartialEq, Eq, Default, SimpleObject, Serialize, Deserialize, Clone)]
@@ -125,6 +126,8 @@ pub struct MetadataDetails {
     pub groups: Vec<CommitMetadataGroupInput>,
     pub show_specifics: Option<ShowSpecifics>,
     pub book_specifics: Option<BookSpecifics>,
+    pub comic_specifics: Option<ComicSpecifics>,
+    pub board_game_specifics: Option<BoardGameSpecifics>,
     pub movie_specifics: Option<MovieSpecifics>,
     pub anime_specifics: Option<AnimeSpecifics>,
     pub manga_specifics: Option<MangaSpecifics>,
@@ -137,6 +140,12 @@ pub struct MetadataDetails {
     pub external_identifiers: Option<MetadataExternalIdentifiers>,
 }
 
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct MetadataTranslationDetails {
+    pub title: Option<String>,
+    pub description: Option<String>,
+}
+
 #[derive(Debug, Default, InputObject)]
 pub struct CommitPersonInput {
     pub name: String,
@@ -233,6 +242,8 @@ pub struct CreateCustomMetadataInput {
     pub creator_ids: Option<Vec<String>>,
     pub show_specifics: Option<ShowSpecifics>,
     pub book_specifics: Option<BookSpecifics>,
+    pub comic_specifics: Option<ComicSpecifics>,
+    pub board_game_specifics: Option<BoardGameSpecifics>,
     pub music_specifics: Option<MusicSpecifics>,
     pub movie_specifics: Option<MovieSpecifics>,
     pub manga_specifics: Option<MangaSpecifics>,
@@ -301,6 +312,8 @@ pub struct GraphqlMetadataDetails {
     pub watch_providers: Vec<WatchProvider>,
     pub show_specifics: Option<ShowSpecifics>,
     pub book_specifics: Option<BookSpecifics>,
+    pub comic_specifics: Option<ComicSpecifics>,
+    pub board_game_specifics: Option<BoardGameSpecifics>,
     pub movie_specifics: Option<MovieSpecifics>,
     pub music_specifics: Option<MusicSpecifics>,
     pub manga_specifics: Option<MangaSpecifics>,
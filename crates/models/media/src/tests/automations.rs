@@ -0,0 +1,79 @@
+use super::*;
+
+#[test]
+fn test_empty_conditions_match_everything() {
+    let conditions = AutomationRuleConditions::default();
+    assert!(conditions.matches(&event(EntityLot::Person, None), None));
+    assert!(conditions.matches(
+        &event(EntityLot::Metadata, Some("Watchlist")),
+        Some(MediaLot::Movie)
+    ));
+}
+
+#[test]
+fn test_empty_lists_match_everything() {
+    let conditions = AutomationRuleConditions {
+        entity_lots: Some(vec![]),
+        metadata_lots: Some(vec![]),
+        collection_names: Some(vec![]),
+    };
+    assert!(conditions.matches(&event(EntityLot::Workout, None), None));
+}
+
+#[rstest]
+#[case(EntityLot::Metadata, true)]
+#[case(EntityLot::Person, true)]
+#[case(EntityLot::Exercise, false)]
+fn test_entity_lots(#[case] entity_lot: EntityLot, #[case] expected: bool) {
+    let conditions = AutomationRuleConditions {
+        entity_lots: Some(vec![EntityLot::Metadata, EntityLot::Person]),
+        ..Default::default()
+    };
+    assert_eq!(conditions.matches(&event(entity_lot, None), None), expected);
+}
+
+#[rstest]
+#[case(Some(MediaLot::Show), true)]
+#[case(Some(MediaLot::Book), false)]
+#[case(None, false)]
+fn test_metadata_lots(#[case] metadata_lot: Option<MediaLot>, #[case] expected: bool) {
+    let conditions = AutomationRuleConditions {
+        metadata_lots: Some(vec![MediaLot::Show, MediaLot::Movie]),
+        ..Default::default()
+    };
+    assert_eq!(
+        conditions.matches(&event(EntityLot::Metadata, None), metadata_lot),
+        expected
+    );
+}
+
+#[rstest]
+#[case(Some("Watchlist"), true)]
+#[case(Some("Owned"), false)]
+#[case(None, false)]
+fn test_collection_names(#[case] collection_name: Option<&str>, #[case] expected: bool) {
+    let conditions = AutomationRuleConditions {
+        collection_names: Some(vec!["Watchlist".to_owned()]),
+        ..Default::default()
+    };
+    assert_eq!(
+        conditions.matches(&event(EntityLot::Metadata, collection_name), None),
+        expected
+    );
+}
+
+#[test]
+fn test_every_condition_has_to_match() {
+    let conditions = AutomationRuleConditions {
+        entity_lots: Some(vec![EntityLot::Metadata]),
+        metadata_lots: Some(vec![MediaLot::Movie]),
+        collection_names: Some(vec!["Watchlist".to_owned()]),
+    };
+    let matching = event(EntityLot::Metadata, Some("Watchlist"));
+    assert!(conditions.matches(&matching, Some(MediaLot::Movie)));
+    assert!(!conditions.matches(&matching, Some(MediaLot::Book)));
+    assert!(!conditions.matches(
+        &event(EntityLot::Metadata, Some("Owned")),
+        Some(MediaLot::Movie)
+    ));
+}
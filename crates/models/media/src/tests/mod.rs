@@ -0,0 +1,17 @@
+use enum_models::{AutomationRuleTrigger, EntityLot, MediaLot};
+use rstest::rstest;
+
+use super::*;
+
+mod automations;
+
+fn event(entity_lot: EntityLot, collection_name: Option<&str>) -> AutomationRuleEvent {
+    AutomationRuleEvent {
+        entity_lot,
+        user_id: "usr_1".to_owned(),
+        entity_id: "ent_1".to_owned(),
+        triggered_by_automation: false,
+        trigger: AutomationRuleTrigger::EntityAddedToCollection,
+        collection_name: collection_name.map(|n| n.to_owned()),
+    }
+}
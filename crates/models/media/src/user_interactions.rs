@@ -40,6 +40,16 @@ pub struct SeenMangaExtraInformation {
     pub chapter: Option<Decimal>,
 }
 
+#[derive(
+    Debug, PartialEq, Eq, Serialize, Deserialize, Clone, SimpleObject, FromJsonQueryResult,
+)]
+pub struct SeenBoardGameExtraInformation {
+    pub winner: Option<String>,
+    /// The duration of the play in minutes.
+    pub duration: Option<i32>,
+    pub player_count: Option<i32>,
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct ReviewPostedEvent {
     pub obj_id: String,
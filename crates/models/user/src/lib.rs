@@ -1,7 +1,11 @@
 use async_graphql::{Enum, InputObject, SimpleObject};
+use chrono::NaiveDate;
 use educe::Educe;
-use enum_models::{MediaLot, UserLot};
-use fitness_models::{SetRestTimersSettings, UserUnitSystem};
+use enum_models::{MediaLot, NotificationPlatformLot, UserLot};
+use fitness_models::{
+    ExerciseProgressionSettings, OneRmFormula, SetRestTimersSettings, UserUnitSystem,
+};
+use rust_decimal::Decimal;
 use sea_orm::{FromJsonQueryResult, Iterable, prelude::DateTimeUtc};
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
@@ -132,6 +136,12 @@ pub struct UserFitnessExercisesPreferences {
     #[educe(Default = UserUnitSystem::Metric)]
     pub unit_system: UserUnitSystem,
     pub set_rest_timers: SetRestTimersSettings,
+    #[serde(default)]
+    #[graphql(default)]
+    pub progression: ExerciseProgressionSettings,
+    #[serde(default)]
+    #[graphql(default)]
+    pub one_rm_formula: OneRmFormula,
 }
 
 #[derive(
@@ -153,6 +163,78 @@ pub struct UserStatisticsMeasurement {
     pub unit: Option<String>,
 }
 
+#[derive(Debug, Serialize, Deserialize, Enum, Clone, Eq, PartialEq, FromJsonQueryResult, Copy)]
+pub enum UserBiologicalSex {
+    Male,
+    Female,
+}
+
+/// Details about the user that can be referenced by the formulas of computed statistics.
+#[skip_serializing_none]
+#[derive(
+    Eq,
+    Clone,
+    Debug,
+    Default,
+    PartialEq,
+    Serialize,
+    Deserialize,
+    InputObject,
+    SimpleObject,
+    FromJsonQueryResult,
+)]
+#[graphql(input_name = "UserMeasurementsProfileInput")]
+pub struct UserMeasurementsProfile {
+    /// In centimeters. Used when a measurement does not log a `height` itself.
+    pub height: Option<Decimal>,
+    pub birth_date: Option<NaiveDate>,
+    pub sex: Option<UserBiologicalSex>,
+}
+
+/// A statistic calculated from the other statistics of a measurement. The formula can
+/// reference any statistic by its name, along with `height`, `age` and `is_male`.
+#[skip_serializing_none]
+#[derive(
+    Eq,
+    Clone,
+    Debug,
+    Default,
+    PartialEq,
+    Serialize,
+    Deserialize,
+    InputObject,
+    SimpleObject,
+    FromJsonQueryResult,
+)]
+#[graphql(input_name = "UserComputedMeasurementStatisticInput")]
+pub struct UserComputedMeasurementStatistic {
+    pub name: String,
+    pub formula: String,
+    pub unit: Option<String>,
+}
+
+/// A smoothed series of a statistic, calculated as its moving average over a window.
+#[derive(
+    Eq,
+    Clone,
+    Debug,
+    Educe,
+    PartialEq,
+    Serialize,
+    Deserialize,
+    InputObject,
+    SimpleObject,
+    FromJsonQueryResult,
+)]
+#[graphql(input_name = "UserMeasurementTrendInput")]
+#[educe(Default)]
+pub struct UserMeasurementTrend {
+    pub name: String,
+    pub statistic: String,
+    #[educe(Default = 7)]
+    pub window_days: u16,
+}
+
 #[derive(
     Eq,
     Educe,
@@ -179,6 +261,45 @@ pub struct UserFitnessMeasurementsPreferences {
         },
     ]))]
     pub statistics: Vec<UserStatisticsMeasurement>,
+    #[serde(default)]
+    #[graphql(default)]
+    pub profile: UserMeasurementsProfile,
+    #[serde(default = "default_computed_statistics")]
+    #[graphql(default_with = "default_computed_statistics()")]
+    #[educe(Default(expression = default_computed_statistics()))]
+    pub computed_statistics: Vec<UserComputedMeasurementStatistic>,
+    #[serde(default = "default_measurement_trends")]
+    #[graphql(default_with = "default_measurement_trends()")]
+    #[educe(Default(expression = default_measurement_trends()))]
+    pub trends: Vec<UserMeasurementTrend>,
+}
+
+fn default_computed_statistics() -> Vec<UserComputedMeasurementStatistic> {
+    vec![
+        UserComputedMeasurementStatistic {
+            name: "bmi".to_owned(),
+            formula: "weight / (height / 100) ^ 2".to_owned(),
+            ..Default::default()
+        },
+        UserComputedMeasurementStatistic {
+            name: "lean_mass".to_owned(),
+            formula: "weight * (1 - body_fat / 100)".to_owned(),
+            ..Default::default()
+        },
+        UserComputedMeasurementStatistic {
+            name: "ffmi".to_owned(),
+            formula: "lean_mass / (height / 100) ^ 2".to_owned(),
+            ..Default::default()
+        },
+    ]
+}
+
+fn default_measurement_trends() -> Vec<UserMeasurementTrend> {
+    vec![UserMeasurementTrend {
+        name: "weight_trend".to_owned(),
+        statistic: "weight".to_owned(),
+        ..Default::default()
+    }]
 }
 
 #[derive(
@@ -387,6 +508,41 @@ pub struct UserGeneralPreferences {
         },
     ]))]
     pub dashboard: Vec<UserGeneralDashboardElement>,
+    /// An IANA timezone name. The server timezone is used if this is not set.
+    pub timezone: Option<String>,
+    /// Languages like `zh` or `zh-TW` to show media details in, in order of preference.
+    #[serde(default)]
+    #[graphql(default)]
+    pub metadata_languages: Vec<String>,
+}
+
+#[derive(
+    Eq,
+    Educe,
+    Debug,
+    Clone,
+    PartialEq,
+    Serialize,
+    Deserialize,
+    InputObject,
+    SimpleObject,
+    FromJsonQueryResult,
+)]
+#[educe(Default)]
+#[graphql(input_name = "UserSocialPreferencesInput")]
+pub struct UserSocialPreferences {
+    /// Whether other users can follow this user.
+    #[educe(Default = true)]
+    pub allow_followers: bool,
+    /// Show public reviews and ratings in the feed of followers.
+    #[educe(Default = true)]
+    pub share_reviews: bool,
+    #[educe(Default = false)]
+    pub share_workouts: bool,
+    #[educe(Default = false)]
+    pub share_completions: bool,
+    #[educe(Default = false)]
+    pub share_collections: bool,
 }
 
 #[derive(
@@ -406,6 +562,16 @@ pub struct UserPreferences {
     pub fitness: UserFitnessPreferences,
     pub general: UserGeneralPreferences,
     pub features_enabled: UserFeaturesEnabledPreferences,
+    #[serde(default)]
+    #[graphql(default)]
+    pub social: UserSocialPreferences,
+}
+
+#[derive(Debug, Serialize, Deserialize, InputObject, Clone, PartialEq, Eq)]
+pub struct NotificationPlatformHeader {
+    pub name: String,
+    #[graphql(secret)]
+    pub value: String,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, Eq, PartialEq, FromJsonQueryResult)]
@@ -418,11 +584,20 @@ pub enum NotificationPlatformSpecifics {
     Discord {
         url: String,
     },
+    Email {
+        email: String,
+        is_verified: bool,
+    },
     Gotify {
         url: String,
         token: String,
         priority: Option<i32>,
     },
+    Matrix {
+        url: String,
+        room_id: String,
+        access_token: String,
+    },
     Ntfy {
         url: Option<String>,
         topic: String,
@@ -439,10 +614,36 @@ pub enum NotificationPlatformSpecifics {
     PushSafer {
         key: String,
     },
+    Slack {
+        url: String,
+    },
     Telegram {
         bot_token: String,
         chat_id: String,
     },
+    Webhook {
+        url: String,
+        secret: Option<String>,
+        headers: Vec<NotificationPlatformHeader>,
+    },
+}
+
+#[derive(Debug, Serialize, Deserialize, InputObject, Clone)]
+pub struct CreateUserNotificationPlatformInput {
+    pub email: Option<String>,
+    pub room_id: Option<String>,
+    pub priority: Option<i32>,
+    pub chat_id: Option<String>,
+    pub base_url: Option<String>,
+    #[graphql(secret)]
+    pub api_token: Option<String>,
+    #[graphql(secret)]
+    pub auth_header: Option<String>,
+    /// Secret used to sign the body of webhook requests with HMAC-SHA256.
+    #[graphql(secret)]
+    pub signing_secret: Option<String>,
+    pub headers: Option<Vec<NotificationPlatformHeader>>,
+    pub lot: NotificationPlatformLot,
 }
 
 #[derive(
@@ -462,6 +663,32 @@ pub struct UserExtraInformation {
     pub scheduled_for_workout_revision: bool,
 }
 
+/// Quotas an admin can place on a user. A `None` value means that there is no limit.
+#[skip_serializing_none]
+#[derive(
+    Eq,
+    Clone,
+    Debug,
+    Default,
+    Serialize,
+    PartialEq,
+    Deserialize,
+    InputObject,
+    SimpleObject,
+    FromJsonQueryResult,
+)]
+#[graphql(input_name = "UserLimitsInput")]
+pub struct UserLimits {
+    pub max_integrations: Option<u64>,
+    pub max_custom_metadata: Option<u64>,
+    /// The minimum number of hours between two exports.
+    pub export_interval_hours: Option<i64>,
+    /// The maximum number of bytes the user's exports can occupy in file storage. Uploaded
+    /// files do not count towards it.
+    #[serde(alias = "max_storage_bytes")]
+    pub max_export_storage_bytes: Option<i64>,
+}
+
 #[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
 pub struct UserTwoFactorInformationBackupCode {
     pub code: String,
@@ -480,6 +707,16 @@ pub struct UpdateUserInput {
     pub lot: Option<UserLot>,
     pub username: Option<String>,
     pub is_disabled: Option<bool>,
+    pub limits: Option<UserLimits>,
     pub admin_access_token: Option<String>,
     pub is_onboarding_tour_completed: Option<bool>,
 }
+
+#[derive(Debug, InputObject)]
+pub struct CreateUserInviteInput {
+    /// The role to assign to the user who redeems the invite. Defaults to `Normal`.
+    pub lot: Option<UserLot>,
+    pub limits: Option<UserLimits>,
+    pub expires_on: Option<DateTimeUtc>,
+    pub preferences: Option<UserPreferences>,
+}
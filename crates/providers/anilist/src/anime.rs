@@ -1,15 +1,15 @@
 use anyhow::Result;
 use async_trait::async_trait;
-use common_models::SearchDetails;
+use common_models::{SearchDetails, TrendingMediaList};
 use common_utils::PAGE_SIZE;
 use dependent_models::{MetadataSearchSourceSpecifics, SearchResults};
 use media_models::MetadataDetails;
-use media_models::MetadataSearchItem;
+use media_models::{MetadataSearchItem, MetadataTranslationDetails, PartialMetadataWithoutId};
 use traits::MediaProvider;
 
 use crate::{
     base::AnilistService,
-    models::{MediaType, media_details, search},
+    models::{MediaType, media_details, media_translation, search, trending},
 };
 
 #[derive(Debug, Clone)]
@@ -28,6 +28,14 @@ impl MediaProvider for AnilistAnimeService {
         Ok(details)
     }
 
+    async fn metadata_translation(
+        &self,
+        identifier: &str,
+        language: &str,
+    ) -> Result<MetadataTranslationDetails> {
+        media_translation(&self.0.client, identifier, language).await
+    }
+
     async fn metadata_search(
         &self,
         page: u64,
@@ -53,4 +61,17 @@ impl MediaProvider for AnilistAnimeService {
             },
         })
     }
+
+    async fn get_trending_media(
+        &self,
+        list: TrendingMediaList,
+    ) -> Result<Vec<PartialMetadataWithoutId>> {
+        trending(
+            &self.0.client,
+            MediaType::Anime,
+            list,
+            &self.0.preferred_language,
+        )
+        .await
+    }
 }
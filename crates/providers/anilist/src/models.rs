@@ -1,6 +1,7 @@
 use anyhow::{Result, anyhow};
 use common_models::{
     EntityAssets, EntityRemoteVideo, EntityRemoteVideoSource, PersonSourceSpecifics,
+    TrendingMediaList,
 };
 use common_utils::compute_next_page;
 use convert_case::{Case, Casing};
@@ -8,7 +9,8 @@ use enum_models::{MediaLot, MediaSource};
 use itertools::Itertools;
 use media_models::{
     AnimeAiringScheduleSpecifics, AnimeSpecifics, MangaSpecifics, MetadataDetails,
-    MetadataSearchItem, PartialMetadataPerson, PartialMetadataWithoutId,
+    MetadataSearchItem, MetadataTranslationDetails, PartialMetadataPerson,
+    PartialMetadataWithoutId,
 };
 use nest_struct::nest_struct;
 use reqwest::Client;
@@ -120,6 +122,26 @@ pub struct MediaDetailsResponse {
     pub media: Option<MediaDetails>,
 }
 
+#[nest_struct]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MediaTranslation {
+    pub title: Option<
+        nest! {
+            pub english: Option<String>,
+            pub native: Option<String>,
+        },
+    >,
+    pub description: Option<String>,
+    #[serde(rename = "countryOfOrigin")]
+    pub country_of_origin: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MediaTranslationResponse {
+    #[serde(rename = "Media")]
+    pub media: Option<MediaTranslation>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct StaffDetailsResponse {
     #[serde(rename = "Staff")]
@@ -602,6 +624,66 @@ pub async fn media_details(
     })
 }
 
+/// AniList only has English titles and descriptions, and native titles in the language of
+/// the country the media comes from. Other languages get an empty translation.
+pub async fn media_translation(
+    client: &Client,
+    id: &str,
+    language: &str,
+) -> Result<MetadataTranslationDetails> {
+    let query = r#"
+        query MediaTranslationQuery($id: Int!) {
+          Media(id: $id) {
+            title {
+              english
+              native
+            }
+            description
+            countryOfOrigin
+          }
+        }
+    "#;
+    let body = serde_json::json!({
+        "query": query,
+        "variables": { "id": id.parse::<i64>()? }
+    });
+    let media = client
+        .post(URL)
+        .json(&body)
+        .send()
+        .await?
+        .json::<GraphQLResponse<MediaTranslationResponse>>()
+        .await?
+        .data
+        .and_then(|d| d.media)
+        .ok_or_else(|| anyhow!("No media in data"))?;
+    let language = language.split('-').next().unwrap_or_default();
+    let native_language = match media.country_of_origin.as_deref() {
+        Some("JP") => Some("ja"),
+        Some("KR") => Some("ko"),
+        Some("CN" | "TW") => Some("zh"),
+        _ => None,
+    };
+    let (english, native) = media
+        .title
+        .map(|t| (t.english, t.native))
+        .unwrap_or_default();
+    let translation = if language.eq_ignore_ascii_case("en") {
+        MetadataTranslationDetails {
+            title: english,
+            description: media.description,
+        }
+    } else if native_language.is_some_and(|l| language.eq_ignore_ascii_case(l)) {
+        MetadataTranslationDetails {
+            title: native,
+            description: None,
+        }
+    } else {
+        MetadataTranslationDetails::default()
+    };
+    Ok(translation)
+}
+
 pub async fn search(
     client: &Client,
     media_type: MediaType,
@@ -690,6 +772,85 @@ pub async fn search(
     Ok((media, total, next_page))
 }
 
+pub async fn trending(
+    client: &Client,
+    media_type: MediaType,
+    list: TrendingMediaList,
+    preferred_language: &config_definition::AnilistPreferredLanguage,
+) -> Result<Vec<PartialMetadataWithoutId>> {
+    let query_str = r#"
+        query MediaTrendingQuery(
+          $type: MediaType!
+          $perPage: Int!
+          $sort: [MediaSort]
+          $status: MediaStatus
+        ) {
+          Page(page: 1, perPage: $perPage) {
+            media(type: $type, sort: $sort, status: $status, isAdult: false) {
+              id
+              title {
+                english
+                native
+                romaji
+              }
+              coverImage {
+                extraLarge
+              }
+              bannerImage
+            }
+          }
+        }
+    "#;
+
+    let (sort, status) = match list {
+        TrendingMediaList::Trending => ("TRENDING_DESC", None),
+        TrendingMediaList::Popular => ("POPULARITY_DESC", None),
+        TrendingMediaList::Upcoming => ("POPULARITY_DESC", Some("NOT_YET_RELEASED")),
+    };
+    let body = serde_json::json!({
+        "query": query_str,
+        "variables": { "type": media_type, "perPage": 50, "sort": [sort], "status": status }
+    });
+
+    let lot = match media_type {
+        MediaType::Anime => MediaLot::Anime,
+        MediaType::Manga => MediaLot::Manga,
+    };
+    let response = client
+        .post(URL)
+        .json(&body)
+        .send()
+        .await?
+        .json::<GraphQLResponse<MediaSearchResponse>>()
+        .await?;
+    let media = response
+        .data
+        .and_then(|d| d.page)
+        .and_then(|p| p.media)
+        .ok_or_else(|| anyhow!("No trending media found"))?
+        .into_iter()
+        .flatten()
+        .filter_map(|b| {
+            let title = b.title?;
+            let title = get_in_preferred_language(
+                title.native,
+                title.english,
+                title.romaji,
+                preferred_language,
+            );
+            Some(PartialMetadataWithoutId {
+                lot,
+                title,
+                source: MediaSource::Anilist,
+                identifier: b.id.to_string(),
+                image: b.cover_image.and_then(|l| l.extra_large).or(b.banner_image),
+                ..Default::default()
+            })
+        })
+        .collect();
+    Ok(media)
+}
+
 pub fn build_staff_search_query(search: &str, page: u64, per_page: u64) -> serde_json::Value {
     let query = r#"
         query StaffSearchQuery(
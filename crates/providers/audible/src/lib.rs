@@ -1,6 +1,8 @@
-use anyhow::Result;
+use anyhow::{Result, bail};
 use async_trait::async_trait;
-use common_models::{EntityAssets, NamedObject, PersonSourceSpecifics, SearchDetails};
+use common_models::{
+    EntityAssets, NamedObject, PersonSourceSpecifics, SearchDetails, TrendingMediaList,
+};
 use common_utils::get_base_http_client;
 use common_utils::{PAGE_SIZE, compute_next_page, convert_date_to_year, convert_string_to_date};
 use config_definition::AudibleLocale;
@@ -12,8 +14,8 @@ use enum_models::{MediaLot, MediaSource};
 use itertools::Itertools;
 use media_models::{
     AudioBookSpecifics, CommitMetadataGroupInput, MetadataDetails, MetadataFreeCreator,
-    MetadataSearchItem, PartialMetadataPerson, PartialMetadataWithoutId, PeopleSearchItem,
-    UniqueMediaIdentifier,
+    MetadataSearchItem, MetadataTranslationDetails, PartialMetadataPerson,
+    PartialMetadataWithoutId, PeopleSearchItem, UniqueMediaIdentifier,
 };
 use paginate::Pages;
 use reqwest::Client;
@@ -150,6 +152,31 @@ impl AudibleService {
         format!("https://api.audible.{suffix}/1.0/catalog/products")
     }
 
+    /// The marketplace that lists products in a language like `de` or, to pick a region,
+    /// `en-GB`.
+    fn locale_from_language(language: &str) -> Option<AudibleLocale> {
+        let (language, region) = match language.split_once('-') {
+            Some((language, region)) => (language, Some(region.to_uppercase())),
+            None => (language, None),
+        };
+        let locale = match language.to_lowercase().as_str() {
+            "en" => match region.as_deref() {
+                Some("AU") => AudibleLocale::AU,
+                Some("CA") => AudibleLocale::CA,
+                Some("IN") => AudibleLocale::IN,
+                Some("GB" | "UK") => AudibleLocale::UK,
+                _ => AudibleLocale::US,
+            },
+            "fr" => AudibleLocale::FR,
+            "de" => AudibleLocale::DE,
+            "it" => AudibleLocale::IT,
+            "ja" => AudibleLocale::JP,
+            "es" => AudibleLocale::ES,
+            _ => return None,
+        };
+        Some(locale)
+    }
+
     pub async fn new(config: &config_definition::AudibleConfig) -> Result<Self> {
         let url = Self::url_from_locale(&config.locale);
         let client = get_base_http_client(None);
@@ -341,6 +368,32 @@ impl MediaProvider for AudibleService {
         Ok(item)
     }
 
+    /// Products are fetched from the marketplace of the language, where they usually only
+    /// exist if they were published in that language.
+    async fn metadata_translation(
+        &self,
+        identifier: &str,
+        language: &str,
+    ) -> Result<MetadataTranslationDetails> {
+        let Some(locale) = Self::locale_from_language(language) else {
+            return Ok(MetadataTranslationDetails::default());
+        };
+        let rsp = self
+            .client
+            .get(format!("{}/{}", Self::url_from_locale(&locale), identifier))
+            .query(&PrimaryQuery::default())
+            .send()
+            .await?;
+        let data: AudibleItemResponse = rsp.json().await?;
+        Ok(MetadataTranslationDetails {
+            title: Some(data.product.title),
+            description: data
+                .product
+                .publisher_summary
+                .or(data.product.merchandising_summary),
+        })
+    }
+
     async fn metadata_search(
         &self,
         page: u64,
@@ -388,6 +441,42 @@ impl MediaProvider for AudibleService {
             },
         })
     }
+
+    async fn get_trending_media(
+        &self,
+        list: TrendingMediaList,
+    ) -> Result<Vec<PartialMetadataWithoutId>> {
+        #[derive(Serialize, Deserialize, Debug)]
+        struct AudibleBestSellersResponse {
+            products: Vec<AudibleItem>,
+        }
+        let sort_by = match list {
+            TrendingMediaList::Trending => "BestSellers",
+            TrendingMediaList::Upcoming => "-ReleaseDate",
+            TrendingMediaList::Popular => bail!("Audible does not have a list of popular books"),
+        };
+        let rsp = self
+            .client
+            .get(&self.url)
+            .query(&[("num_results", "50"), ("products_sort_by", sort_by)])
+            .query(&PrimaryQuery::default())
+            .send()
+            .await?;
+        let best_sellers: AudibleBestSellersResponse = rsp.json().await?;
+        Ok(best_sellers
+            .products
+            .into_iter()
+            .filter(|p| !p.is_adult_product.unwrap_or(false))
+            .map(|p| PartialMetadataWithoutId {
+                title: p.title,
+                identifier: p.asin,
+                lot: MediaLot::AudioBook,
+                source: MediaSource::Audible,
+                image: p.product_images.and_then(|i| i.image_500.or(i.image_2400)),
+                ..Default::default()
+            })
+            .collect())
+    }
 }
 
 impl AudibleService {
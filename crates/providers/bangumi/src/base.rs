@@ -0,0 +1,97 @@
+use anyhow::Result;
+use common_utils::get_base_http_client;
+use config_definition::BangumiPreferredLanguage;
+use enum_models::MediaLot;
+use reqwest::{
+    Client,
+    header::{AUTHORIZATION, HeaderValue},
+};
+use serde::de::DeserializeOwned;
+
+use crate::models::*;
+
+/// The game subjects carrying any of these tags are treated as visual novels.
+static VISUAL_NOVEL_TAGS: [&str; 4] = ["视觉小说", "galgame", "Galgame", "GALGAME"];
+
+#[derive(Debug, Clone)]
+pub struct BangumiService {
+    pub client: Client,
+    pub preferred_language: BangumiPreferredLanguage,
+}
+
+impl BangumiService {
+    pub async fn new(config: &config_definition::BangumiConfig) -> Result<Self> {
+        let headers = match config.access_token.is_empty() {
+            true => None,
+            false => Some(vec![(
+                AUTHORIZATION,
+                HeaderValue::from_str(&format!("Bearer {}", config.access_token))?,
+            )]),
+        };
+        Ok(Self {
+            client: get_base_http_client(headers),
+            preferred_language: config.preferred_language.clone(),
+        })
+    }
+
+    pub async fn get<T: DeserializeOwned>(&self, path: &str, query: &[(&str, &str)]) -> Result<T> {
+        let response = self
+            .client
+            .get(format!("{URL}/{path}"))
+            .query(query)
+            .send()
+            .await?
+            .error_for_status()?;
+        Ok(response.json().await?)
+    }
+
+    pub async fn post<T: DeserializeOwned>(
+        &self,
+        path: &str,
+        query: &[(&str, &str)],
+        body: &serde_json::Value,
+    ) -> Result<T> {
+        let response = self
+            .client
+            .post(format!("{URL}/{path}"))
+            .query(query)
+            .json(body)
+            .send()
+            .await?
+            .error_for_status()?;
+        Ok(response.json().await?)
+    }
+
+    pub fn get_title(&self, name: &str, name_cn: &str) -> String {
+        match self.preferred_language {
+            BangumiPreferredLanguage::Chinese if !name_cn.is_empty() => name_cn.to_owned(),
+            _ => name.to_owned(),
+        }
+    }
+}
+
+/// Bangumi subject types are 1 for books, 2 for anime, 3 for music, 4 for games and
+/// 6 for live action, of which only books, anime and games are tracked.
+pub fn get_lot_from_subject_type(subject_type: u8, tags: &[String]) -> Option<MediaLot> {
+    match subject_type {
+        1 => Some(MediaLot::Manga),
+        2 => Some(MediaLot::Anime),
+        4 if tags.iter().any(|t| VISUAL_NOVEL_TAGS.contains(&t.as_str())) => {
+            Some(MediaLot::VisualNovel)
+        }
+        4 => Some(MediaLot::VideoGame),
+        _ => None,
+    }
+}
+
+pub fn get_subject_type_from_lot(lot: MediaLot) -> u8 {
+    match lot {
+        MediaLot::Manga => 1,
+        MediaLot::VideoGame | MediaLot::VisualNovel => 4,
+        _ => 2,
+    }
+}
+
+pub fn get_subject_url(id: i64) -> String {
+    format!("{SITE_URL}/subject/{id}")
+}
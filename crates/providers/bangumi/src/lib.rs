@@ -0,0 +1,10 @@
+mod base;
+pub use base::{BangumiService, get_lot_from_subject_type};
+
+mod models;
+
+mod non_metadata;
+pub use non_metadata::NonMediaBangumiService;
+
+mod subjects;
+pub use subjects::BangumiSubjectService;
@@ -0,0 +1,175 @@
+use itertools::Itertools;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+pub static URL: &str = "https://api.bgm.tv/v0";
+pub static SITE_URL: &str = "https://bgm.tv";
+
+/// The relations that connect the entries of a franchise, used to build metadata groups.
+pub static PREQUEL_RELATION: &str = "前传";
+pub static SEQUEL_RELATION: &str = "续集";
+/// The maximum number of entries walked through when following prequels and sequels.
+pub const MAX_SERIES_LENGTH: usize = 30;
+
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct BangumiImages {
+    pub large: Option<String>,
+    pub common: Option<String>,
+    pub medium: Option<String>,
+}
+
+impl BangumiImages {
+    pub fn best(&self) -> Option<String> {
+        [&self.large, &self.common, &self.medium]
+            .into_iter()
+            .flatten()
+            .find(|i| !i.is_empty())
+            .cloned()
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BangumiTag {
+    pub name: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BangumiRating {
+    pub score: Option<Decimal>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BangumiInfoboxItem {
+    pub v: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum BangumiInfoboxValue {
+    Text(String),
+    List(Vec<BangumiInfoboxItem>),
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BangumiInfobox {
+    pub key: String,
+    pub value: BangumiInfoboxValue,
+}
+
+impl BangumiInfobox {
+    pub fn values(&self) -> Vec<String> {
+        match &self.value {
+            BangumiInfoboxValue::Text(t) => t.split(['、', '/']).map(String::from).collect_vec(),
+            BangumiInfoboxValue::List(l) => l.iter().map(|i| i.v.clone()).collect(),
+        }
+        .into_iter()
+        .map(|v| v.trim().to_owned())
+        .filter(|v| !v.is_empty())
+        .collect()
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BangumiSubject {
+    pub id: i64,
+    #[serde(rename = "type")]
+    pub subject_type: u8,
+    pub name: String,
+    #[serde(default)]
+    pub name_cn: String,
+    #[serde(default)]
+    pub summary: String,
+    #[serde(default)]
+    pub nsfw: bool,
+    pub date: Option<String>,
+    pub eps: Option<i32>,
+    pub volumes: Option<i32>,
+    pub platform: Option<String>,
+    pub images: Option<BangumiImages>,
+    pub rating: Option<BangumiRating>,
+    #[serde(default)]
+    pub tags: Vec<BangumiTag>,
+    #[serde(default)]
+    pub meta_tags: Vec<String>,
+    #[serde(default)]
+    pub infobox: Vec<BangumiInfobox>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct BangumiPaginated<T> {
+    pub data: Vec<T>,
+    pub total: u64,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct BangumiEpisode {
+    pub ep: Option<Decimal>,
+    pub sort: Decimal,
+    pub airdate: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct BangumiRelatedPerson {
+    pub id: i64,
+    pub name: String,
+    pub relation: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct BangumiRelatedCharacter {
+    pub id: i64,
+    pub name: String,
+    #[serde(default)]
+    pub actors: Vec<BangumiRelatedPerson>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct BangumiRelatedSubject {
+    pub id: i64,
+    #[serde(rename = "type")]
+    pub subject_type: u8,
+    pub name: String,
+    #[serde(default)]
+    pub name_cn: String,
+    pub relation: String,
+    pub images: Option<BangumiImages>,
+}
+
+/// A person or a character, which share the same shape in the API.
+#[derive(Debug, Deserialize)]
+pub struct BangumiPerson {
+    pub id: i64,
+    pub name: String,
+    #[serde(default)]
+    pub summary: String,
+    pub gender: Option<String>,
+    pub birth_year: Option<i32>,
+    pub birth_mon: Option<u32>,
+    pub birth_day: Option<u32>,
+    pub images: Option<BangumiImages>,
+    #[serde(default)]
+    pub infobox: Vec<BangumiInfobox>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct BangumiPersonSubject {
+    pub id: i64,
+    #[serde(rename = "type")]
+    pub subject_type: u8,
+    pub name: String,
+    #[serde(default)]
+    pub name_cn: String,
+    pub image: Option<String>,
+    #[serde(alias = "relation")]
+    pub staff: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct BangumiPersonCharacter {
+    pub name: String,
+    pub subject_id: i64,
+    pub subject_type: u8,
+    pub subject_name: String,
+    #[serde(default)]
+    pub subject_name_cn: String,
+}
@@ -0,0 +1,184 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use chrono::NaiveDate;
+use common_models::{EntityAssets, PersonSourceSpecifics, SearchDetails};
+use common_utils::{PAGE_SIZE, compute_next_page};
+use dependent_models::{MetadataPersonRelated, PersonDetails, SearchResults};
+use enum_models::MediaSource;
+use futures::try_join;
+use itertools::Itertools;
+use media_models::{PartialMetadataWithoutId, PeopleSearchItem};
+use serde_json::json;
+use traits::MediaProvider;
+
+use crate::{
+    base::{BangumiService, get_lot_from_subject_type},
+    models::*,
+};
+
+pub struct NonMediaBangumiService(BangumiService);
+
+impl NonMediaBangumiService {
+    pub async fn new(config: &config_definition::BangumiConfig) -> Result<Self> {
+        Ok(Self(BangumiService::new(config).await?))
+    }
+
+    fn get_related_metadata(
+        &self,
+        subject_id: i64,
+        subject_type: u8,
+        name: &str,
+        name_cn: &str,
+        image: Option<String>,
+    ) -> Option<PartialMetadataWithoutId> {
+        Some(PartialMetadataWithoutId {
+            image,
+            source: MediaSource::Bangumi,
+            identifier: subject_id.to_string(),
+            title: self.0.get_title(name, name_cn),
+            lot: get_lot_from_subject_type(subject_type, &[])?,
+            ..Default::default()
+        })
+    }
+}
+
+#[async_trait]
+impl MediaProvider for NonMediaBangumiService {
+    async fn people_search(
+        &self,
+        page: u64,
+        query: &str,
+        _display_nsfw: bool,
+        _source_specifics: &Option<PersonSourceSpecifics>,
+    ) -> Result<SearchResults<PeopleSearchItem>> {
+        let offset = (page.saturating_sub(1) * PAGE_SIZE).to_string();
+        let response: BangumiPaginated<BangumiPerson> = self
+            .0
+            .post(
+                "search/persons",
+                &[("limit", &PAGE_SIZE.to_string()), ("offset", &offset)],
+                &json!({ "keyword": query }),
+            )
+            .await?;
+        let items = response
+            .data
+            .into_iter()
+            .map(|p| PeopleSearchItem {
+                name: p.name,
+                birth_year: p.birth_year,
+                identifier: p.id.to_string(),
+                image: p.images.and_then(|i| i.best()),
+            })
+            .collect();
+        Ok(SearchResults {
+            items,
+            details: SearchDetails {
+                total_items: response.total,
+                next_page: compute_next_page(page, PAGE_SIZE, response.total),
+            },
+        })
+    }
+
+    /// Characters are fetched from a separate endpoint, they are marked using the
+    /// source specifics when linked to a subject.
+    async fn person_details(
+        &self,
+        identifier: &str,
+        source_specifics: &Option<PersonSourceSpecifics>,
+    ) -> Result<PersonDetails> {
+        let is_character = source_specifics
+            .as_ref()
+            .and_then(|s| s.is_bangumi_character)
+            .unwrap_or_default();
+        let path = match is_character {
+            true => "characters",
+            false => "persons",
+        };
+        let mut related_metadata = vec![];
+        let person_path = format!("{path}/{identifier}");
+        let subjects_path = format!("{person_path}/subjects");
+        let (person, subjects) = try_join!(
+            self.0.get::<BangumiPerson>(&person_path, &[]),
+            self.0.get::<Vec<BangumiPersonSubject>>(&subjects_path, &[]),
+        )?;
+        for subject in subjects {
+            let Some(metadata) = self.get_related_metadata(
+                subject.id,
+                subject.subject_type,
+                &subject.name,
+                &subject.name_cn,
+                subject.image.filter(|i| !i.is_empty()),
+            ) else {
+                continue;
+            };
+            related_metadata.push(MetadataPersonRelated {
+                metadata,
+                role: subject.staff.unwrap_or_else(|| "Staff".to_owned()),
+                ..Default::default()
+            });
+        }
+        if !is_character {
+            let characters: Vec<BangumiPersonCharacter> = self
+                .0
+                .get(&format!("persons/{identifier}/characters"), &[])
+                .await?;
+            for character in characters {
+                let Some(metadata) = self.get_related_metadata(
+                    character.subject_id,
+                    character.subject_type,
+                    &character.subject_name,
+                    &character.subject_name_cn,
+                    None,
+                ) else {
+                    continue;
+                };
+                related_metadata.push(MetadataPersonRelated {
+                    metadata,
+                    role: "Voice Actor".to_owned(),
+                    character: Some(character.name),
+                });
+            }
+        }
+        let infobox_values = |key: &str| {
+            person
+                .infobox
+                .iter()
+                .filter(|i| i.key == key)
+                .flat_map(|i| i.values())
+                .collect_vec()
+        };
+        let alternate_names = [infobox_values("简体中文名"), infobox_values("别名")].concat();
+        let name = match infobox_values("简体中文名").first() {
+            Some(name_cn) => self.0.get_title(&person.name, name_cn),
+            None => person.name.clone(),
+        };
+        Ok(PersonDetails {
+            name,
+            source_specifics: source_specifics.clone(),
+            description: Some(person.summary).filter(|s| !s.is_empty()),
+            related_metadata: related_metadata
+                .into_iter()
+                .unique_by(|r| (r.metadata.identifier.clone(), r.role.clone()))
+                .collect(),
+            source_url: Some(format!(
+                "{SITE_URL}/{}/{identifier}",
+                path.trim_end_matches('s')
+            )),
+            alternate_names: (!alternate_names.is_empty()).then_some(alternate_names),
+            birth_date: match (person.birth_year, person.birth_mon, person.birth_day) {
+                (Some(year), Some(month), Some(day)) => NaiveDate::from_ymd_opt(year, month, day),
+                _ => None,
+            },
+            gender: person.gender.and_then(|g| match g.as_str() {
+                "male" => Some("Male".to_owned()),
+                "female" => Some("Female".to_owned()),
+                _ => None,
+            }),
+            assets: EntityAssets {
+                remote_images: Vec::from_iter(person.images.and_then(|i| i.best())),
+                ..Default::default()
+            },
+            ..Default::default()
+        })
+    }
+}
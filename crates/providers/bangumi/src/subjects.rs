@@ -0,0 +1,373 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use chrono::NaiveTime;
+use common_models::{EntityAssets, PersonSourceSpecifics, SearchDetails};
+use common_utils::{PAGE_SIZE, compute_next_page, convert_date_to_year, convert_string_to_date};
+use database_models::metadata_group::MetadataGroupWithoutId;
+use dependent_models::{MetadataSearchSourceSpecifics, SearchResults};
+use enum_models::{MediaLot, MediaSource};
+use futures::try_join;
+use itertools::Itertools;
+use media_models::{
+    AnimeAiringScheduleSpecifics, AnimeSpecifics, CommitMetadataGroupInput, MangaSpecifics,
+    MetadataDetails, MetadataSearchItem, PartialMetadataPerson, PartialMetadataWithoutId,
+    UniqueMediaIdentifier, VideoGameSpecifics, VideoGameSpecificsPlatformRelease,
+    VisualNovelSpecifics,
+};
+use rust_decimal::{Decimal, dec};
+use serde_json::json;
+use traits::MediaProvider;
+
+use crate::{
+    base::{BangumiService, get_subject_type_from_lot, get_subject_url},
+    models::*,
+};
+
+pub struct BangumiSubjectService {
+    base: BangumiService,
+    lot: MediaLot,
+}
+
+impl BangumiSubjectService {
+    pub async fn new(lot: MediaLot, config: &config_definition::BangumiConfig) -> Result<Self> {
+        Ok(Self {
+            lot,
+            base: BangumiService::new(config).await?,
+        })
+    }
+
+    async fn get_relations(&self, id: i64) -> Result<Vec<BangumiRelatedSubject>> {
+        self.base.get(&format!("subjects/{id}/subjects"), &[]).await
+    }
+
+    async fn get_episodes(&self, id: i64) -> Result<Vec<BangumiEpisode>> {
+        let limit = 200;
+        let subject_id = id.to_string();
+        let mut episodes = vec![];
+        loop {
+            let offset = episodes.len().to_string();
+            let response: BangumiPaginated<BangumiEpisode> = self
+                .base
+                .get(
+                    "episodes",
+                    &[
+                        ("type", "0"),
+                        ("offset", &offset),
+                        ("subject_id", &subject_id),
+                        ("limit", &limit.to_string()),
+                    ],
+                )
+                .await?;
+            let fetched = response.data.len();
+            episodes.extend(response.data);
+            if fetched < limit || episodes.len() as u64 >= response.total {
+                break;
+            }
+        }
+        Ok(episodes)
+    }
+
+    /// Follows the prequels of a subject to the first entry of its franchise, which
+    /// identifies the metadata group all the entries belong to.
+    async fn get_series_root(
+        &self,
+        subject: &BangumiSubject,
+        relations: &[BangumiRelatedSubject],
+    ) -> Result<Option<i64>> {
+        let is_series_relation = |r: &BangumiRelatedSubject| {
+            r.subject_type == subject.subject_type
+                && [PREQUEL_RELATION, SEQUEL_RELATION].contains(&r.relation.as_str())
+        };
+        if !relations.iter().any(is_series_relation) {
+            return Ok(None);
+        }
+        let mut root = subject.id;
+        let mut prequel = find_relation(relations, subject.subject_type, PREQUEL_RELATION);
+        let mut visited = vec![root];
+        while let Some(id) = prequel
+            && !visited.contains(&id)
+            && visited.len() < MAX_SERIES_LENGTH
+        {
+            root = id;
+            visited.push(id);
+            let relations = self.get_relations(id).await?;
+            prequel = find_relation(&relations, subject.subject_type, PREQUEL_RELATION);
+        }
+        Ok(Some(root))
+    }
+
+    fn get_partial_metadata(
+        &self,
+        id: i64,
+        name: &str,
+        name_cn: &str,
+        image: Option<String>,
+    ) -> PartialMetadataWithoutId {
+        PartialMetadataWithoutId {
+            image,
+            lot: self.lot,
+            identifier: id.to_string(),
+            source: MediaSource::Bangumi,
+            title: self.base.get_title(name, name_cn),
+            ..Default::default()
+        }
+    }
+}
+
+fn find_relation(
+    relations: &[BangumiRelatedSubject],
+    subject_type: u8,
+    relation: &str,
+) -> Option<i64> {
+    relations
+        .iter()
+        .find(|r| r.subject_type == subject_type && r.relation == relation)
+        .map(|r| r.id)
+}
+
+#[async_trait]
+impl MediaProvider for BangumiSubjectService {
+    async fn metadata_search(
+        &self,
+        page: u64,
+        query: &str,
+        display_nsfw: bool,
+        _source_specifics: &Option<MetadataSearchSourceSpecifics>,
+    ) -> Result<SearchResults<MetadataSearchItem>> {
+        let mut filter = json!({ "type": [get_subject_type_from_lot(self.lot)] });
+        if !display_nsfw {
+            filter["nsfw"] = json!(false);
+        }
+        let offset = (page.saturating_sub(1) * PAGE_SIZE).to_string();
+        let response: BangumiPaginated<BangumiSubject> = self
+            .base
+            .post(
+                "search/subjects",
+                &[("limit", &PAGE_SIZE.to_string()), ("offset", &offset)],
+                &json!({ "keyword": query, "filter": filter }),
+            )
+            .await?;
+        let items = response
+            .data
+            .into_iter()
+            .map(|s| MetadataSearchItem {
+                identifier: s.id.to_string(),
+                image: s.images.and_then(|i| i.best()),
+                title: self.base.get_title(&s.name, &s.name_cn),
+                publish_year: s.date.and_then(|d| convert_date_to_year(&d)),
+            })
+            .collect();
+        Ok(SearchResults {
+            items,
+            details: SearchDetails {
+                total_items: response.total,
+                next_page: compute_next_page(page, PAGE_SIZE, response.total),
+            },
+        })
+    }
+
+    async fn metadata_details(&self, identifier: &str) -> Result<MetadataDetails> {
+        let path = format!("subjects/{identifier}");
+        let persons_path = format!("{path}/persons");
+        let characters_path = format!("{path}/characters");
+        let (subject, persons, characters) = try_join!(
+            self.base.get::<BangumiSubject>(&path, &[]),
+            self.base
+                .get::<Vec<BangumiRelatedPerson>>(&persons_path, &[]),
+            self.base
+                .get::<Vec<BangumiRelatedCharacter>>(&characters_path, &[]),
+        )?;
+        let relations = self.get_relations(subject.id).await?;
+        let mut people = persons
+            .into_iter()
+            .map(|p| PartialMetadataPerson {
+                name: p.name,
+                role: p.relation,
+                identifier: p.id.to_string(),
+                source: MediaSource::Bangumi,
+                ..Default::default()
+            })
+            .collect_vec();
+        for character in characters {
+            people.extend(character.actors.into_iter().map(|a| PartialMetadataPerson {
+                name: a.name,
+                identifier: a.id.to_string(),
+                source: MediaSource::Bangumi,
+                role: "Voice Actor".to_owned(),
+                character: Some(character.name.clone()),
+                ..Default::default()
+            }));
+            people.push(PartialMetadataPerson {
+                name: character.name,
+                role: "Character".to_owned(),
+                source: MediaSource::Bangumi,
+                identifier: character.id.to_string(),
+                source_specifics: Some(PersonSourceSpecifics {
+                    is_bangumi_character: Some(true),
+                    ..Default::default()
+                }),
+                ..Default::default()
+            });
+        }
+        let groups = self
+            .get_series_root(&subject, &relations)
+            .await?
+            .map(|root| CommitMetadataGroupInput {
+                name: "Loading...".to_owned(),
+                unique: UniqueMediaIdentifier {
+                    lot: self.lot,
+                    source: MediaSource::Bangumi,
+                    identifier: root.to_string(),
+                },
+                ..Default::default()
+            })
+            .into_iter()
+            .collect();
+        let suggestions = relations
+            .iter()
+            .filter(|r| r.subject_type == subject.subject_type)
+            .map(|r| {
+                self.get_partial_metadata(
+                    r.id,
+                    &r.name,
+                    &r.name_cn,
+                    r.images.as_ref().and_then(|i| i.best()),
+                )
+            })
+            .collect();
+        let source_url = get_subject_url(subject.id);
+        let mut details = MetadataDetails {
+            people,
+            groups,
+            suggestions,
+            is_nsfw: Some(subject.nsfw),
+            source_url: Some(source_url.clone()),
+            title: self.base.get_title(&subject.name, &subject.name_cn),
+            description: Some(subject.summary.clone()).filter(|s| !s.is_empty()),
+            publish_date: subject.date.as_deref().and_then(convert_string_to_date),
+            publish_year: subject.date.as_deref().and_then(convert_date_to_year),
+            provider_rating: subject
+                .rating
+                .as_ref()
+                .and_then(|r| r.score)
+                .filter(|s| *s != dec!(0))
+                .map(|s| s * dec!(10)),
+            genres: match subject.meta_tags.is_empty() {
+                false => subject.meta_tags.clone(),
+                true => subject
+                    .tags
+                    .iter()
+                    .take(10)
+                    .map(|t| t.name.clone())
+                    .collect(),
+            },
+            assets: EntityAssets {
+                remote_images: Vec::from_iter(subject.images.as_ref().and_then(|i| i.best())),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        match self.lot {
+            MediaLot::Anime => {
+                let episodes = self.get_episodes(subject.id).await?;
+                let airing_schedule = episodes
+                    .iter()
+                    .filter_map(|e| {
+                        let date = convert_string_to_date(e.airdate.as_deref()?)?;
+                        let episode = e.ep.unwrap_or(e.sort).trunc().try_into().ok()?;
+                        Some(AnimeAiringScheduleSpecifics {
+                            episode,
+                            airing_at: date.and_time(NaiveTime::MIN),
+                        })
+                    })
+                    .collect_vec();
+                let total_episodes = match episodes.len() {
+                    0 => subject.eps.filter(|e| *e > 0),
+                    count => count.try_into().ok(),
+                };
+                details.anime_specifics = Some(AnimeSpecifics {
+                    episodes: total_episodes,
+                    airing_schedule: (!airing_schedule.is_empty()).then_some(airing_schedule),
+                });
+            }
+            MediaLot::Manga => {
+                details.manga_specifics = Some(MangaSpecifics {
+                    url: Some(source_url),
+                    volumes: subject.volumes.filter(|v| *v > 0),
+                    chapters: subject.eps.filter(|e| *e > 0).map(Decimal::from),
+                });
+            }
+            MediaLot::VideoGame => {
+                let platform_releases = subject
+                    .infobox
+                    .iter()
+                    .filter(|i| i.key == "平台")
+                    .flat_map(|i| i.values())
+                    .map(|name| VideoGameSpecificsPlatformRelease {
+                        name,
+                        ..Default::default()
+                    })
+                    .collect_vec();
+                details.video_game_specifics = Some(VideoGameSpecifics {
+                    platform_releases: (!platform_releases.is_empty()).then_some(platform_releases),
+                    ..Default::default()
+                });
+            }
+            MediaLot::VisualNovel => {
+                details.visual_novel_specifics = Some(VisualNovelSpecifics::default());
+            }
+            _ => {}
+        }
+        Ok(details)
+    }
+
+    /// The group of a franchise is identified by its first entry and contains all the
+    /// entries reachable by following the sequels.
+    async fn metadata_group_details(
+        &self,
+        identifier: &str,
+    ) -> Result<(MetadataGroupWithoutId, Vec<PartialMetadataWithoutId>)> {
+        let root: BangumiSubject = self
+            .base
+            .get(&format!("subjects/{identifier}"), &[])
+            .await?;
+        let image = root.images.as_ref().and_then(|i| i.best());
+        let mut items =
+            vec![self.get_partial_metadata(root.id, &root.name, &root.name_cn, image.clone())];
+        let mut visited = vec![root.id];
+        let mut relations = self.get_relations(root.id).await?;
+        while visited.len() < MAX_SERIES_LENGTH {
+            let Some(sequel) = relations.into_iter().find(|r| {
+                r.subject_type == root.subject_type
+                    && r.relation == SEQUEL_RELATION
+                    && !visited.contains(&r.id)
+            }) else {
+                break;
+            };
+            visited.push(sequel.id);
+            items.push(self.get_partial_metadata(
+                sequel.id,
+                &sequel.name,
+                &sequel.name_cn,
+                sequel.images.and_then(|i| i.best()),
+            ));
+            relations = self.get_relations(sequel.id).await?;
+        }
+        Ok((
+            MetadataGroupWithoutId {
+                lot: self.lot,
+                source: MediaSource::Bangumi,
+                identifier: root.id.to_string(),
+                parts: items.len().try_into().unwrap(),
+                source_url: Some(get_subject_url(root.id)),
+                title: self.base.get_title(&root.name, &root.name_cn),
+                description: Some(root.summary).filter(|s| !s.is_empty()),
+                assets: EntityAssets {
+                    remote_images: Vec::from_iter(image),
+                    ..Default::default()
+                },
+            },
+            items,
+        ))
+    }
+}
@@ -0,0 +1,86 @@
+use anyhow::Result;
+use common_utils::get_base_http_client;
+use itertools::Itertools;
+use reqwest::{
+    Client,
+    header::{AUTHORIZATION, HeaderValue},
+};
+use serde::de::DeserializeOwned;
+
+use crate::models::{BggThing, parse_things};
+
+pub static URL: &str = "https://boardgamegeek.com";
+pub static API_URL: &str = "https://boardgamegeek.com/xmlapi2";
+pub static GEEKDO_API_URL: &str = "https://api.geekdo.com/api";
+
+/// Expansions are grouped under their base game, which shares the id space with
+/// families, so these groups are namespaced.
+pub static EXPANSIONS_PREFIX: &str = "expansions-";
+
+/// The maximum number of ids the thing endpoint accepts in a single request.
+const THINGS_PER_REQUEST: usize = 20;
+
+#[derive(Clone)]
+pub struct BoardGameGeekService {
+    client: Client,
+}
+
+impl BoardGameGeekService {
+    pub async fn new(config: &config_definition::BoardGameGeekConfig) -> Result<Self> {
+        let headers = match config.api_token.is_empty() {
+            true => None,
+            false => Some(vec![(
+                AUTHORIZATION,
+                HeaderValue::from_str(&format!("Bearer {}", config.api_token))?,
+            )]),
+        };
+        Ok(Self {
+            client: get_base_http_client(headers),
+        })
+    }
+
+    pub async fn get_xml(&self, path: &str, query: &[(&str, &str)]) -> Result<String> {
+        let text = self
+            .client
+            .get(format!("{API_URL}/{path}"))
+            .query(query)
+            .send()
+            .await?
+            .error_for_status()?
+            .text()
+            .await?;
+        Ok(text)
+    }
+
+    pub async fn get_json<T: DeserializeOwned>(
+        &self,
+        path: &str,
+        query: &[(&str, &str)],
+    ) -> Result<T> {
+        let data = self
+            .client
+            .get(format!("{GEEKDO_API_URL}/{path}"))
+            .query(&[("nosession", "1")])
+            .query(query)
+            .send()
+            .await?
+            .error_for_status()?
+            .json()
+            .await?;
+        Ok(data)
+    }
+
+    pub async fn things(&self, ids: &[String], with_stats: bool) -> Result<Vec<BggThing>> {
+        let mut things = vec![];
+        for chunk in ids.chunks(THINGS_PER_REQUEST) {
+            let ids = chunk.iter().join(",");
+            let mut query = vec![("id", ids.as_str())];
+            if with_stats {
+                query.push(("stats", "1"));
+            }
+            let xml = self.get_xml("thing", &query).await?;
+            things.extend(parse_things(&xml)?);
+        }
+        Ok(things)
+    }
+}
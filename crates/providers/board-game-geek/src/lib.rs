@@ -0,0 +1,10 @@
+mod base;
+pub use base::BoardGameGeekService;
+
+mod models;
+pub use models::child;
+
+mod provider;
+
+#[cfg(test)]
+mod tests;
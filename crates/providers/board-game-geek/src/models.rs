@@ -0,0 +1,183 @@
+use anyhow::Result;
+use roxmltree::{Document, Node};
+use rust_decimal::Decimal;
+use serde::Deserialize;
+
+#[derive(Debug, Clone)]
+pub struct BggLink {
+    pub id: String,
+    pub kind: String,
+    pub value: String,
+    pub inbound: bool,
+}
+
+/// A `thing` (board game or expansion) or a `family` as returned by the XML API.
+#[derive(Debug, Default)]
+pub struct BggThing {
+    pub id: String,
+    pub kind: String,
+    pub name: String,
+    pub links: Vec<BggLink>,
+    pub min_age: Option<i32>,
+    pub image: Option<String>,
+    pub thumbnail: Option<String>,
+    pub min_players: Option<i32>,
+    pub max_players: Option<i32>,
+    pub description: Option<String>,
+    pub min_play_time: Option<i32>,
+    pub max_play_time: Option<i32>,
+    pub year_published: Option<i32>,
+    pub average_rating: Option<Decimal>,
+    pub alternate_names: Vec<String>,
+}
+
+impl BggThing {
+    pub fn links_of(&self, kind: &str, inbound: bool) -> impl Iterator<Item = &BggLink> {
+        self.links
+            .iter()
+            .filter(move |l| l.kind == kind && l.inbound == inbound)
+    }
+}
+
+#[derive(Debug)]
+pub struct BggSearchItem {
+    pub id: String,
+    pub name: String,
+    pub year_published: Option<i32>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+pub struct GeekdoImages {
+    pub original: Option<String>,
+    pub square200: Option<String>,
+    pub previewthumb: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct GeekdoPerson {
+    pub name: String,
+    pub href: Option<String>,
+    pub description: Option<String>,
+    #[serde(default)]
+    pub images: GeekdoImages,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct GeekdoPersonResponse {
+    pub item: GeekdoPerson,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct GeekdoLinkedItem {
+    pub objectid: String,
+    pub name: String,
+    pub yearpublished: Option<String>,
+    #[serde(default)]
+    pub images: GeekdoImages,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct GeekdoLinkedItemsResponse {
+    #[serde(default)]
+    pub items: Vec<GeekdoLinkedItem>,
+}
+
+/// The first child element with the given tag name, shared with the play importer.
+pub fn child<'a, 'input>(node: Node<'a, 'input>, name: &str) -> Option<Node<'a, 'input>> {
+    node.children().find(|n| n.tag_name().name() == name)
+}
+
+fn child_text(node: Node, name: &str) -> Option<String> {
+    child(node, name)
+        .and_then(|n| n.text())
+        .map(str::trim)
+        .filter(|t| !t.is_empty())
+        .map(String::from)
+}
+
+/// Most scalar values are stored in the `value` attribute of an empty element.
+fn child_value<T: std::str::FromStr>(node: Node, name: &str) -> Option<T> {
+    child(node, name)
+        .and_then(|n| n.attribute("value"))
+        .and_then(|v| v.parse().ok())
+}
+
+/// A value of zero means that the information is not known.
+fn positive(value: Option<i32>) -> Option<i32> {
+    value.filter(|v| *v > 0)
+}
+
+fn get_names(node: Node) -> (String, Vec<String>) {
+    let mut primary = String::new();
+    let mut alternates = vec![];
+    for name in node.children().filter(|n| n.tag_name().name() == "name") {
+        let value = name.attribute("value").unwrap_or_default().to_owned();
+        match name.attribute("type") {
+            Some("primary") => primary = value,
+            _ => alternates.push(value),
+        }
+    }
+    if primary.is_empty() && !alternates.is_empty() {
+        primary = alternates.remove(0);
+    }
+    (primary, alternates)
+}
+
+pub fn parse_things(xml: &str) -> Result<Vec<BggThing>> {
+    let document = Document::parse(xml)?;
+    let things = document
+        .root_element()
+        .children()
+        .filter(|n| n.tag_name().name() == "item")
+        .map(|item| {
+            let (name, alternate_names) = get_names(item);
+            let links = item
+                .children()
+                .filter(|n| n.tag_name().name() == "link")
+                .map(|link| BggLink {
+                    id: link.attribute("id").unwrap_or_default().to_owned(),
+                    kind: link.attribute("type").unwrap_or_default().to_owned(),
+                    value: link.attribute("value").unwrap_or_default().to_owned(),
+                    inbound: link.attribute("inbound") == Some("true"),
+                })
+                .collect();
+            let average_rating = child(item, "statistics")
+                .and_then(|s| child(s, "ratings"))
+                .and_then(|r| child_value::<Decimal>(r, "average"))
+                .filter(|r| !r.is_zero());
+            BggThing {
+                name,
+                links,
+                alternate_names,
+                average_rating,
+                image: child_text(item, "image"),
+                thumbnail: child_text(item, "thumbnail"),
+                description: child_text(item, "description"),
+                min_age: positive(child_value(item, "minage")),
+                id: item.attribute("id").unwrap_or_default().to_owned(),
+                kind: item.attribute("type").unwrap_or_default().to_owned(),
+                min_players: positive(child_value(item, "minplayers")),
+                max_players: positive(child_value(item, "maxplayers")),
+                min_play_time: positive(child_value(item, "minplaytime")),
+                max_play_time: positive(child_value(item, "maxplaytime")),
+                year_published: positive(child_value(item, "yearpublished")),
+            }
+        })
+        .collect();
+    Ok(things)
+}
+
+pub fn parse_search(xml: &str) -> Result<Vec<BggSearchItem>> {
+    let document = Document::parse(xml)?;
+    let items = document
+        .root_element()
+        .children()
+        .filter(|n| n.tag_name().name() == "item")
+        .map(|item| BggSearchItem {
+            name: get_names(item).0,
+            id: item.attribute("id").unwrap_or_default().to_owned(),
+            year_published: positive(child_value(item, "yearpublished")),
+        })
+        .collect();
+    Ok(items)
+}
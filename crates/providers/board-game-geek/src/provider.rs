@@ -0,0 +1,303 @@
+use anyhow::{Result, anyhow};
+use async_trait::async_trait;
+use common_models::{EntityAssets, PersonSourceSpecifics, SearchDetails};
+use common_utils::{PAGE_SIZE, compute_next_page};
+use database_models::metadata_group::MetadataGroupWithoutId;
+use dependent_models::{
+    MetadataPersonRelated, MetadataSearchSourceSpecifics, PersonDetails, SearchResults,
+};
+use enum_models::{MediaLot, MediaSource};
+use itertools::Itertools;
+use media_models::{
+    BoardGameSpecifics, CommitMetadataGroupInput, MetadataDetails, MetadataSearchItem,
+    PartialMetadataPerson, PartialMetadataWithoutId, UniqueMediaIdentifier,
+};
+use rust_decimal::dec;
+use traits::MediaProvider;
+
+use crate::{
+    base::{BoardGameGeekService, EXPANSIONS_PREFIX, URL},
+    models::{
+        BggThing, GeekdoLinkedItemsResponse, GeekdoPersonResponse, parse_search, parse_things,
+    },
+};
+
+fn get_images(thing: &BggThing) -> Vec<String> {
+    [thing.image.clone(), thing.thumbnail.clone()]
+        .into_iter()
+        .flatten()
+        .collect()
+}
+
+fn get_expansions_group(id: &str, name: &str) -> CommitMetadataGroupInput {
+    CommitMetadataGroupInput {
+        name: format!("{name} Expansions"),
+        unique: UniqueMediaIdentifier {
+            lot: MediaLot::BoardGame,
+            source: MediaSource::BoardGameGeek,
+            identifier: format!("{EXPANSIONS_PREFIX}{id}"),
+        },
+        ..Default::default()
+    }
+}
+
+fn to_partial_metadata(id: String, title: String) -> PartialMetadataWithoutId {
+    PartialMetadataWithoutId {
+        title,
+        identifier: id,
+        lot: MediaLot::BoardGame,
+        source: MediaSource::BoardGameGeek,
+        ..Default::default()
+    }
+}
+
+impl BoardGameGeekService {
+    async fn thing(&self, identifier: &str) -> Result<BggThing> {
+        self.things(&[identifier.to_owned()], true)
+            .await?
+            .into_iter()
+            .next()
+            .ok_or_else(|| anyhow!("No board game found with id {identifier}"))
+    }
+}
+
+#[async_trait]
+impl MediaProvider for BoardGameGeekService {
+    async fn metadata_search(
+        &self,
+        page: u64,
+        query: &str,
+        _display_nsfw: bool,
+        _source_specifics: &Option<MetadataSearchSourceSpecifics>,
+    ) -> Result<SearchResults<MetadataSearchItem>> {
+        let xml = self
+            .get_xml("search", &[("query", query), ("type", "boardgame")])
+            .await?;
+        let results = parse_search(&xml)?
+            .into_iter()
+            .unique_by(|r| r.id.clone())
+            .collect_vec();
+        let total_items = results.len() as u64;
+        // The search endpoint is not paginated and does not return images, so the
+        // images of the current page are fetched separately.
+        let page_items = results
+            .into_iter()
+            .skip((page.saturating_sub(1) * PAGE_SIZE) as usize)
+            .take(PAGE_SIZE as usize)
+            .collect_vec();
+        let ids = page_items.iter().map(|r| r.id.clone()).collect_vec();
+        let things = self.things(&ids, false).await.unwrap_or_default();
+        let items = page_items
+            .into_iter()
+            .map(|item| MetadataSearchItem {
+                image: things
+                    .iter()
+                    .find(|t| t.id == item.id)
+                    .and_then(|t| t.thumbnail.clone()),
+                title: item.name,
+                identifier: item.id,
+                publish_year: item.year_published,
+            })
+            .collect();
+        Ok(SearchResults {
+            items,
+            details: SearchDetails {
+                total_items,
+                next_page: compute_next_page(page, PAGE_SIZE, total_items),
+            },
+        })
+    }
+
+    async fn metadata_details(&self, identifier: &str) -> Result<MetadataDetails> {
+        let thing = self.thing(identifier).await?;
+
+        let people = thing
+            .links_of("boardgamedesigner", false)
+            .map(|designer| PartialMetadataPerson {
+                name: designer.value.clone(),
+                role: "Designer".to_owned(),
+                identifier: designer.id.clone(),
+                source: MediaSource::BoardGameGeek,
+                ..Default::default()
+            })
+            .collect();
+
+        let mut groups = thing
+            .links_of("boardgamefamily", false)
+            .map(|family| CommitMetadataGroupInput {
+                name: family.value.clone(),
+                unique: UniqueMediaIdentifier {
+                    lot: MediaLot::BoardGame,
+                    identifier: family.id.clone(),
+                    source: MediaSource::BoardGameGeek,
+                },
+                ..Default::default()
+            })
+            .collect_vec();
+        match thing.kind.as_str() {
+            "boardgameexpansion" => groups.extend(
+                thing
+                    .links_of("boardgameexpansion", true)
+                    .map(|base| get_expansions_group(&base.id, &base.value)),
+            ),
+            _ => {
+                if thing.links_of("boardgameexpansion", false).next().is_some() {
+                    groups.push(get_expansions_group(&thing.id, &thing.name));
+                }
+            }
+        }
+
+        let genres = thing
+            .links_of("boardgamecategory", false)
+            .chain(thing.links_of("boardgamemechanic", false))
+            .map(|l| l.value.clone())
+            .unique()
+            .collect();
+
+        Ok(MetadataDetails {
+            people,
+            groups,
+            genres,
+            title: thing.name.clone(),
+            description: thing.description.clone(),
+            publish_year: thing.year_published,
+            provider_rating: thing.average_rating.map(|r| r * dec!(10)),
+            source_url: Some(format!("{URL}/{}/{}", thing.kind, thing.id)),
+            board_game_specifics: Some(BoardGameSpecifics {
+                min_age: thing.min_age,
+                min_players: thing.min_players,
+                max_players: thing.max_players,
+                min_play_time: thing.min_play_time,
+                max_play_time: thing.max_play_time,
+            }),
+            assets: EntityAssets {
+                remote_images: get_images(&thing),
+                ..Default::default()
+            },
+            ..Default::default()
+        })
+    }
+
+    async fn metadata_group_details(
+        &self,
+        identifier: &str,
+    ) -> Result<(MetadataGroupWithoutId, Vec<PartialMetadataWithoutId>)> {
+        let (group, items) = match identifier.strip_prefix(EXPANSIONS_PREFIX) {
+            Some(base_id) => {
+                let base = self.thing(base_id).await?;
+                let items =
+                    std::iter::once(to_partial_metadata(base.id.clone(), base.name.clone()))
+                        .chain(
+                            base.links_of("boardgameexpansion", false)
+                                .map(|e| to_partial_metadata(e.id.clone(), e.value.clone())),
+                        )
+                        .collect_vec();
+                let group = MetadataGroupWithoutId {
+                    title: format!("{} Expansions", base.name),
+                    source_url: Some(format!("{URL}/{}/{}/expansions", base.kind, base.id)),
+                    assets: EntityAssets {
+                        remote_images: get_images(&base),
+                        ..Default::default()
+                    },
+                    ..Default::default()
+                };
+                (group, items)
+            }
+            None => {
+                let xml = self.get_xml("family", &[("id", identifier)]).await?;
+                let family = parse_things(&xml)?
+                    .into_iter()
+                    .next()
+                    .ok_or_else(|| anyhow!("No family found with id {identifier}"))?;
+                let items = family
+                    .links_of("boardgamefamily", true)
+                    .map(|g| to_partial_metadata(g.id.clone(), g.value.clone()))
+                    .collect_vec();
+                let group = MetadataGroupWithoutId {
+                    title: family.name.clone(),
+                    description: family.description.clone(),
+                    source_url: Some(format!("{URL}/{}/{}", family.kind, family.id)),
+                    assets: EntityAssets {
+                        remote_images: get_images(&family),
+                        ..Default::default()
+                    },
+                    ..Default::default()
+                };
+                (group, items)
+            }
+        };
+        Ok((
+            MetadataGroupWithoutId {
+                lot: MediaLot::BoardGame,
+                parts: items.len() as i32,
+                identifier: identifier.to_owned(),
+                source: MediaSource::BoardGameGeek,
+                ..group
+            },
+            items,
+        ))
+    }
+
+    async fn person_details(
+        &self,
+        identifier: &str,
+        _source_specifics: &Option<PersonSourceSpecifics>,
+    ) -> Result<PersonDetails> {
+        let query = [
+            ("objectid", identifier),
+            ("objecttype", "person"),
+            ("subtype", "boardgamedesigner"),
+        ];
+        let person = self
+            .get_json::<GeekdoPersonResponse>("geekitems", &query)
+            .await?
+            .item;
+        let linked = self
+            .get_json::<GeekdoLinkedItemsResponse>(
+                "geekitem/linkeditems",
+                &[
+                    query.as_slice(),
+                    &[
+                        ("pageid", "1"),
+                        ("showcount", "100"),
+                        ("sort", "yearpublished"),
+                        ("linkdata_index", "boardgame"),
+                    ],
+                ]
+                .concat(),
+            )
+            .await?;
+
+        let related_metadata = linked
+            .items
+            .into_iter()
+            .map(|item| MetadataPersonRelated {
+                role: "Designer".to_owned(),
+                metadata: PartialMetadataWithoutId {
+                    image: item.images.square200,
+                    publish_year: item.yearpublished.and_then(|y| y.parse().ok()),
+                    ..to_partial_metadata(item.objectid, item.name)
+                },
+                ..Default::default()
+            })
+            .collect();
+
+        Ok(PersonDetails {
+            related_metadata,
+            name: person.name,
+            description: person.description,
+            source_url: Some(match person.href {
+                Some(href) => format!("{URL}{href}"),
+                None => format!("{URL}/boardgamedesigner/{identifier}"),
+            }),
+            assets: EntityAssets {
+                remote_images: [person.images.original, person.images.previewthumb]
+                    .into_iter()
+                    .flatten()
+                    .collect(),
+                ..Default::default()
+            },
+            ..Default::default()
+        })
+    }
+}
@@ -0,0 +1,8 @@
+use rust_decimal::dec;
+
+use crate::models::{parse_search, parse_things};
+
+mod things;
+
+const THINGS: &str = include_str!("fixtures/things.xml");
+const SEARCH: &str = include_str!("fixtures/search.xml");
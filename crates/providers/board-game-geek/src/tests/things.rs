@@ -0,0 +1,86 @@
+use super::*;
+
+#[test]
+fn test_parse_things_reads_game_details() {
+    let things = parse_things(THINGS).unwrap();
+    assert_eq!(things.len(), 2);
+    let catan = &things[0];
+    assert_eq!(catan.id, "13");
+    assert_eq!(catan.kind, "boardgame");
+    assert_eq!(catan.name, "Catan");
+    assert_eq!(
+        catan.alternate_names,
+        vec!["Die Siedler von Catan", "Settlers of Catan"]
+    );
+    assert_eq!(
+        catan.description.as_deref(),
+        Some("Trade, build and settle the island of Catan.")
+    );
+    assert_eq!(catan.year_published, Some(1995));
+    assert_eq!(catan.min_players, Some(3));
+    assert_eq!(catan.max_players, Some(4));
+    assert_eq!(catan.min_play_time, Some(60));
+    assert_eq!(catan.max_play_time, Some(120));
+    assert_eq!(catan.min_age, Some(10));
+    assert_eq!(catan.average_rating, Some(dec!(7.1)));
+    assert_eq!(
+        catan.image.as_deref(),
+        Some("https://cf.geekdo-images.com/catan.jpg")
+    );
+}
+
+#[test]
+fn test_parse_things_separates_inbound_links() {
+    let things = parse_things(THINGS).unwrap();
+    let catan = &things[0];
+    let expansions = catan
+        .links_of("boardgameexpansion", false)
+        .map(|l| l.id.as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(expansions, vec!["926"]);
+    let base_games = catan
+        .links_of("boardgameexpansion", true)
+        .map(|l| l.id.as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(base_games, vec!["12"]);
+    assert_eq!(
+        catan
+            .links_of("boardgamedesigner", false)
+            .map(|l| l.value.as_str())
+            .collect::<Vec<_>>(),
+        vec!["Klaus Teuber"]
+    );
+}
+
+#[test]
+fn test_parse_things_treats_zero_values_as_unknown() {
+    let things = parse_things(THINGS).unwrap();
+    let expansion = &things[1];
+    assert_eq!(expansion.name, "Seafarers");
+    assert!(expansion.alternate_names.is_empty());
+    assert_eq!(expansion.description, None);
+    assert_eq!(expansion.year_published, None);
+    assert_eq!(expansion.min_players, None);
+    assert_eq!(expansion.average_rating, None);
+}
+
+#[test]
+fn test_parse_search_falls_back_to_alternate_names() {
+    let items = parse_search(SEARCH).unwrap();
+    let items = items
+        .iter()
+        .map(|i| (i.id.as_str(), i.name.as_str(), i.year_published))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        items,
+        vec![
+            ("13", "Catan", Some(1995)),
+            ("278", "Catan Card Game", None)
+        ]
+    );
+}
+
+#[test]
+fn test_parse_things_rejects_invalid_xml() {
+    assert!(parse_things("<items><item>").is_err());
+}
@@ -0,0 +1,125 @@
+use std::collections::HashMap;
+
+use anyhow::{Result, bail};
+use common_utils::get_base_http_client;
+use reqwest::Client;
+use serde::de::DeserializeOwned;
+
+use crate::models::{ComicVineIssue, ComicVineResponse};
+
+pub static BASE_URL: &str = "https://comicvine.gamespot.com/api";
+
+pub static ISSUE_PREFIX: &str = "4000";
+pub static PERSON_PREFIX: &str = "4040";
+pub static VOLUME_PREFIX: &str = "4050";
+
+/// The largest page of results the API returns for a single request.
+const ISSUES_PAGE_SIZE: u64 = 100;
+
+#[derive(Clone)]
+pub struct ComicVineService {
+    client: Client,
+    api_key: String,
+}
+
+impl ComicVineService {
+    pub async fn new(config: &config_definition::ComicVineConfig) -> Result<Self> {
+        Ok(Self {
+            client: get_base_http_client(None),
+            api_key: config.api_key.clone(),
+        })
+    }
+
+    pub async fn get<T: DeserializeOwned>(
+        &self,
+        path: &str,
+        query: &[(&str, &str)],
+    ) -> Result<ComicVineResponse<T>> {
+        if self.api_key.is_empty() {
+            bail!("ComicVine API key is not configured");
+        }
+        let response: ComicVineResponse<T> = self
+            .client
+            .get(format!("{BASE_URL}/{path}/"))
+            .query(&[("api_key", self.api_key.as_str()), ("format", "json")])
+            .query(query)
+            .send()
+            .await?
+            .error_for_status()?
+            .json()
+            .await?;
+        if response.error != "OK" {
+            bail!("ComicVine API error: {}", response.error);
+        }
+        Ok(response)
+    }
+
+    /// Finds the issue of a volume with the given issue number, used by integrations
+    /// which only know about the series a book belongs to.
+    pub async fn issue_identifier_by_number(
+        &self,
+        volume_id: &str,
+        issue_number: &str,
+    ) -> Result<Option<String>> {
+        let filter = format!("volume:{volume_id},issue_number:{issue_number}");
+        let response = self
+            .get::<Vec<ComicVineIssue>>("issues", &[("filter", &filter), ("field_list", "id")])
+            .await?;
+        Ok(response.results.first().map(|issue| issue.id.to_string()))
+    }
+
+    /// Maps the issue numbers of a volume to their identifiers, so that integrations
+    /// resolving many issues of the same series need a single lookup.
+    pub async fn volume_issue_identifiers(
+        &self,
+        volume_id: &str,
+    ) -> Result<HashMap<String, String>> {
+        let filter = format!("volume:{volume_id}");
+        let mut issues = HashMap::new();
+        let mut offset = 0;
+        loop {
+            let response = self
+                .get::<Vec<ComicVineIssue>>(
+                    "issues",
+                    &[
+                        ("filter", &filter),
+                        ("field_list", "id,issue_number"),
+                        ("limit", &ISSUES_PAGE_SIZE.to_string()),
+                        ("offset", &offset.to_string()),
+                    ],
+                )
+                .await?;
+            let fetched = response.results.len() as u64;
+            offset += fetched;
+            issues.extend(
+                response
+                    .results
+                    .into_iter()
+                    .filter_map(|i| i.issue_number.map(|n| (n, i.id.to_string()))),
+            );
+            if fetched < ISSUES_PAGE_SIZE || offset >= response.number_of_total_results {
+                break;
+            }
+        }
+        Ok(issues)
+    }
+}
+
+/// Site URLs embed the resource type and id as `<prefix>-<id>`, eg: `/batman/4050-796/`.
+fn extract_id(url: &str, prefix: &str) -> Option<String> {
+    let marker = format!("{prefix}-");
+    let start = url.find(&marker)? + marker.len();
+    let id = url[start..]
+        .chars()
+        .take_while(char::is_ascii_digit)
+        .collect::<String>();
+    (!id.is_empty()).then_some(id)
+}
+
+pub fn extract_volume_id(url: &str) -> Option<String> {
+    extract_id(url, VOLUME_PREFIX)
+}
+
+pub fn extract_issue_id(url: &str) -> Option<String> {
+    extract_id(url, ISSUE_PREFIX)
+}
@@ -0,0 +1,5 @@
+mod base;
+pub use base::{ComicVineService, extract_issue_id, extract_volume_id};
+
+mod models;
+mod provider;
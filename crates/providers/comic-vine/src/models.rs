@@ -0,0 +1,157 @@
+use chrono::{Datelike, NaiveDate};
+use itertools::Itertools;
+use serde::Deserialize;
+
+#[derive(Debug, Deserialize)]
+pub struct ComicVineResponse<T> {
+    pub error: String,
+    pub results: T,
+    #[serde(default)]
+    pub number_of_total_results: u64,
+}
+
+#[derive(Debug, Default, Deserialize)]
+pub struct ComicVineImage {
+    pub small_url: Option<String>,
+    pub super_url: Option<String>,
+    pub thumb_url: Option<String>,
+    pub medium_url: Option<String>,
+    pub original_url: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ComicVineResource {
+    pub id: i64,
+    pub name: Option<String>,
+    pub role: Option<String>,
+    pub issue_number: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ComicVineIssue {
+    pub id: i64,
+    pub name: Option<String>,
+    pub deck: Option<String>,
+    pub cover_date: Option<String>,
+    pub store_date: Option<String>,
+    pub description: Option<String>,
+    pub issue_number: Option<String>,
+    pub image: Option<ComicVineImage>,
+    pub site_detail_url: Option<String>,
+    pub volume: Option<ComicVineResource>,
+    pub person_credits: Option<Vec<ComicVineResource>>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ComicVineVolume {
+    pub id: i64,
+    pub name: String,
+    pub deck: Option<String>,
+    pub start_year: Option<String>,
+    pub description: Option<String>,
+    pub count_of_issues: Option<i32>,
+    pub image: Option<ComicVineImage>,
+    pub site_detail_url: Option<String>,
+    pub publisher: Option<ComicVineResource>,
+    pub issues: Option<Vec<ComicVineResource>>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ComicVineDeath {
+    pub date: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ComicVinePerson {
+    pub id: i64,
+    pub name: String,
+    pub deck: Option<String>,
+    pub birth: Option<String>,
+    pub gender: Option<i32>,
+    pub country: Option<String>,
+    pub website: Option<String>,
+    pub aliases: Option<String>,
+    pub hometown: Option<String>,
+    pub description: Option<String>,
+    pub death: Option<ComicVineDeath>,
+    pub image: Option<ComicVineImage>,
+    pub site_detail_url: Option<String>,
+    pub issue_credits: Option<Vec<ComicVineResource>>,
+    pub volume_credits: Option<Vec<ComicVineResource>>,
+}
+
+/// Dates are returned either as plain dates or with a time component.
+pub fn parse_date(date: &Option<String>) -> Option<NaiveDate> {
+    date.as_ref()
+        .and_then(|d| d.get(..10))
+        .and_then(|d| NaiveDate::parse_from_str(d, "%Y-%m-%d").ok())
+}
+
+pub fn extract_year_from_date(date: &Option<String>) -> Option<i32> {
+    parse_date(date).map(|d| d.year())
+}
+
+pub fn get_prioritized_images(image: Option<ComicVineImage>) -> Vec<String> {
+    image.map_or(vec![], |img| {
+        [
+            img.original_url,
+            img.super_url,
+            img.medium_url,
+            img.small_url,
+            img.thumb_url,
+        ]
+        .into_iter()
+        .flatten()
+        .collect()
+    })
+}
+
+pub fn get_cover_image(image: Option<ComicVineImage>) -> Option<String> {
+    image.and_then(|img| img.medium_url.or(img.original_url))
+}
+
+pub fn combine_description(deck: Option<String>, description: Option<String>) -> Option<String> {
+    [deck, description]
+        .into_iter()
+        .flatten()
+        .filter(|d| !d.trim().is_empty())
+        .reduce(|deck, description| format!("{deck}\n\n{description}"))
+}
+
+/// Issues are usually untitled, so they are named after their volume and number.
+pub fn get_issue_title(
+    volume_name: Option<&str>,
+    issue_number: &Option<String>,
+    name: &Option<String>,
+) -> String {
+    let name = name.as_ref().filter(|n| !n.trim().is_empty());
+    let prefix = [
+        volume_name.map(String::from),
+        issue_number.as_ref().map(|n| format!("#{n}")),
+    ]
+    .into_iter()
+    .flatten()
+    .join(" ");
+    match (prefix.is_empty(), name) {
+        (true, Some(name)) => name.clone(),
+        (false, Some(name)) => format!("{prefix}: {name}"),
+        (_, None) => prefix,
+    }
+}
+
+/// Credits list every role of a person as a single comma separated string.
+pub fn get_roles(role: &Option<String>) -> Vec<String> {
+    role.as_deref()
+        .unwrap_or("Creator")
+        .split(',')
+        .map(str::trim)
+        .filter(|r| !r.is_empty())
+        .map(|r| {
+            let mut chars = r.chars();
+            chars
+                .next()
+                .map(|c| c.to_uppercase().chain(chars).collect())
+                .unwrap_or_default()
+        })
+        .collect()
+}
@@ -0,0 +1,319 @@
+use anyhow::Result;
+use async_graphql::OutputType;
+use async_trait::async_trait;
+use common_models::{EntityAssets, PersonSourceSpecifics, SearchDetails};
+use common_utils::{PAGE_SIZE, compute_next_page};
+use database_models::metadata_group::MetadataGroupWithoutId;
+use dependent_models::{
+    MetadataGroupPersonRelated, MetadataPersonRelated, MetadataSearchSourceSpecifics,
+    PersonDetails, SearchResults,
+};
+use enum_models::{MediaLot, MediaSource};
+use itertools::Itertools;
+use media_models::{
+    ComicSpecifics, CommitMetadataGroupInput, MetadataDetails, MetadataGroupSearchItem,
+    MetadataSearchItem, PartialMetadataPerson, PartialMetadataWithoutId, PeopleSearchItem,
+    UniqueMediaIdentifier,
+};
+use serde::de::DeserializeOwned;
+use traits::MediaProvider;
+
+use crate::{
+    base::{ComicVineService, ISSUE_PREFIX, PERSON_PREFIX, VOLUME_PREFIX},
+    models::{
+        ComicVineIssue, ComicVinePerson, ComicVineVolume, combine_description,
+        extract_year_from_date, get_cover_image, get_issue_title, get_prioritized_images,
+        get_roles, parse_date,
+    },
+};
+
+impl ComicVineService {
+    async fn search<T: DeserializeOwned, R: OutputType>(
+        &self,
+        page: u64,
+        query: &str,
+        resource: &str,
+        mapper: impl Fn(T) -> R,
+    ) -> Result<SearchResults<R>> {
+        let response = self
+            .get::<Vec<T>>(
+                "search",
+                &[
+                    ("query", query),
+                    ("resources", resource),
+                    ("page", &page.to_string()),
+                    ("limit", &PAGE_SIZE.to_string()),
+                ],
+            )
+            .await?;
+        let total_items = response.number_of_total_results;
+        Ok(SearchResults {
+            items: response.results.into_iter().map(mapper).collect(),
+            details: SearchDetails {
+                total_items,
+                next_page: compute_next_page(page, PAGE_SIZE, total_items),
+            },
+        })
+    }
+}
+
+#[async_trait]
+impl MediaProvider for ComicVineService {
+    async fn metadata_search(
+        &self,
+        page: u64,
+        query: &str,
+        _display_nsfw: bool,
+        _source_specifics: &Option<MetadataSearchSourceSpecifics>,
+    ) -> Result<SearchResults<MetadataSearchItem>> {
+        self.search(page, query, "issue", |issue: ComicVineIssue| {
+            MetadataSearchItem {
+                identifier: issue.id.to_string(),
+                publish_year: extract_year_from_date(&issue.cover_date),
+                title: get_issue_title(
+                    issue.volume.as_ref().and_then(|v| v.name.as_deref()),
+                    &issue.issue_number,
+                    &issue.name,
+                ),
+                image: get_cover_image(issue.image),
+            }
+        })
+        .await
+    }
+
+    async fn metadata_details(&self, identifier: &str) -> Result<MetadataDetails> {
+        let issue = self
+            .get::<ComicVineIssue>(&format!("issue/{ISSUE_PREFIX}-{identifier}"), &[])
+            .await?
+            .results;
+
+        let people = issue
+            .person_credits
+            .unwrap_or_default()
+            .into_iter()
+            .flat_map(|person| {
+                get_roles(&person.role)
+                    .into_iter()
+                    .map(move |role| PartialMetadataPerson {
+                        role,
+                        source: MediaSource::ComicVine,
+                        identifier: person.id.to_string(),
+                        name: person.name.clone().unwrap_or_default(),
+                        ..Default::default()
+                    })
+            })
+            .collect();
+
+        let groups = issue
+            .volume
+            .iter()
+            .map(|volume| CommitMetadataGroupInput {
+                name: volume.name.clone().unwrap_or_default(),
+                unique: UniqueMediaIdentifier {
+                    lot: MediaLot::Comic,
+                    source: MediaSource::ComicVine,
+                    identifier: volume.id.to_string(),
+                },
+                ..Default::default()
+            })
+            .collect();
+
+        let release_date = issue.store_date.clone().or(issue.cover_date.clone());
+        Ok(MetadataDetails {
+            people,
+            groups,
+            source_url: issue.site_detail_url,
+            publish_date: parse_date(&release_date),
+            publish_year: extract_year_from_date(&release_date),
+            description: combine_description(issue.deck, issue.description),
+            title: get_issue_title(
+                issue.volume.as_ref().and_then(|v| v.name.as_deref()),
+                &issue.issue_number,
+                &issue.name,
+            ),
+            comic_specifics: Some(ComicSpecifics {
+                issue_number: issue.issue_number,
+                volume: issue.volume.and_then(|v| v.name),
+            }),
+            assets: EntityAssets {
+                remote_images: get_prioritized_images(issue.image),
+                ..Default::default()
+            },
+            ..Default::default()
+        })
+    }
+
+    async fn metadata_group_search(
+        &self,
+        page: u64,
+        query: &str,
+        _display_nsfw: bool,
+    ) -> Result<SearchResults<MetadataGroupSearchItem>> {
+        self.search(page, query, "volume", |volume: ComicVineVolume| {
+            MetadataGroupSearchItem {
+                identifier: volume.id.to_string(),
+                image: get_cover_image(volume.image),
+                parts: volume.count_of_issues.map(|c| c as usize),
+                name: match volume.start_year {
+                    Some(year) => format!("{} ({year})", volume.name),
+                    None => volume.name,
+                },
+            }
+        })
+        .await
+    }
+
+    async fn metadata_group_details(
+        &self,
+        identifier: &str,
+    ) -> Result<(MetadataGroupWithoutId, Vec<PartialMetadataWithoutId>)> {
+        let volume = self
+            .get::<ComicVineVolume>(&format!("volume/{VOLUME_PREFIX}-{identifier}"), &[])
+            .await?
+            .results;
+
+        let publish_year = volume.start_year.as_ref().and_then(|y| y.parse().ok());
+        let items = volume
+            .issues
+            .unwrap_or_default()
+            .into_iter()
+            .sorted_by(|a, b| {
+                let number = |n: &Option<String>| {
+                    n.as_ref()
+                        .and_then(|n| n.parse::<f64>().ok())
+                        .unwrap_or(f64::MAX)
+                };
+                number(&a.issue_number).total_cmp(&number(&b.issue_number))
+            })
+            .map(|issue| PartialMetadataWithoutId {
+                publish_year,
+                lot: MediaLot::Comic,
+                source: MediaSource::ComicVine,
+                identifier: issue.id.to_string(),
+                title: get_issue_title(Some(&volume.name), &issue.issue_number, &issue.name),
+                ..Default::default()
+            })
+            .collect_vec();
+
+        let description = [
+            volume
+                .publisher
+                .and_then(|p| p.name)
+                .map(|p| format!("Published by {p}")),
+            combine_description(volume.deck, volume.description),
+        ]
+        .into_iter()
+        .flatten()
+        .join("\n\n");
+        let group = MetadataGroupWithoutId {
+            title: volume.name,
+            lot: MediaLot::Comic,
+            source: MediaSource::ComicVine,
+            source_url: volume.site_detail_url,
+            identifier: volume.id.to_string(),
+            description: (!description.is_empty()).then_some(description),
+            parts: volume.count_of_issues.unwrap_or(items.len() as i32),
+            assets: EntityAssets {
+                remote_images: get_prioritized_images(volume.image),
+                ..Default::default()
+            },
+        };
+        Ok((group, items))
+    }
+
+    async fn people_search(
+        &self,
+        page: u64,
+        query: &str,
+        _display_nsfw: bool,
+        _source_specifics: &Option<PersonSourceSpecifics>,
+    ) -> Result<SearchResults<PeopleSearchItem>> {
+        self.search(page, query, "person", |person: ComicVinePerson| {
+            PeopleSearchItem {
+                name: person.name,
+                identifier: person.id.to_string(),
+                image: get_cover_image(person.image),
+                birth_year: extract_year_from_date(&person.birth),
+            }
+        })
+        .await
+    }
+
+    async fn person_details(
+        &self,
+        identifier: &str,
+        _source_specifics: &Option<PersonSourceSpecifics>,
+    ) -> Result<PersonDetails> {
+        let person = self
+            .get::<ComicVinePerson>(&format!("person/{PERSON_PREFIX}-{identifier}"), &[])
+            .await?
+            .results;
+
+        let related_metadata = person
+            .issue_credits
+            .unwrap_or_default()
+            .into_iter()
+            .map(|issue| MetadataPersonRelated {
+                role: "Creator".to_owned(),
+                metadata: PartialMetadataWithoutId {
+                    lot: MediaLot::Comic,
+                    source: MediaSource::ComicVine,
+                    identifier: issue.id.to_string(),
+                    title: issue.name.unwrap_or_default(),
+                    ..Default::default()
+                },
+                ..Default::default()
+            })
+            .collect();
+
+        let related_metadata_groups = person
+            .volume_credits
+            .unwrap_or_default()
+            .into_iter()
+            .map(|volume| MetadataGroupPersonRelated {
+                role: "Creator".to_owned(),
+                metadata_group: MetadataGroupWithoutId {
+                    lot: MediaLot::Comic,
+                    source: MediaSource::ComicVine,
+                    identifier: volume.id.to_string(),
+                    title: volume.name.unwrap_or_default(),
+                    ..Default::default()
+                },
+            })
+            .collect();
+
+        let alternate_names = person
+            .aliases
+            .map(|a| {
+                a.lines()
+                    .map(str::trim)
+                    .filter(|a| !a.is_empty())
+                    .map(String::from)
+                    .collect_vec()
+            })
+            .filter(|a| !a.is_empty());
+
+        Ok(PersonDetails {
+            related_metadata,
+            alternate_names,
+            related_metadata_groups,
+            name: person.name,
+            website: person.website,
+            source_url: person.site_detail_url,
+            birth_date: parse_date(&person.birth),
+            place: person.hometown.or(person.country),
+            death_date: parse_date(&person.death.and_then(|d| d.date)),
+            description: combine_description(person.deck, person.description),
+            gender: match person.gender {
+                Some(1) => Some("Male".to_owned()),
+                Some(2) => Some("Female".to_owned()),
+                _ => None,
+            },
+            assets: EntityAssets {
+                remote_images: get_prioritized_images(person.image),
+                ..Default::default()
+            },
+            ..Default::default()
+        })
+    }
+}
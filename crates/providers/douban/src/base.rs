@@ -0,0 +1,413 @@
+use std::{collections::HashMap, time::Duration};
+
+use anyhow::{Result, bail};
+use common_models::{EntityAssets, SearchDetails, TrendingMediaList};
+use common_utils::{RequestThrottle, convert_string_to_date, get_base_http_client, ryot_log};
+use dependent_models::SearchResults;
+use enum_models::{MediaLot, MediaSource};
+use itertools::Itertools;
+use media_models::{
+    MetadataDetails, MetadataSearchItem, PartialMetadataPerson, PartialMetadataWithoutId,
+};
+use reqwest::{
+    Client,
+    header::{ACCEPT_LANGUAGE, REFERER, USER_AGENT},
+};
+use rust_decimal::{Decimal, dec};
+use scraper::{ElementRef, Html, Node, Selector};
+use serde::de::DeserializeOwned;
+use tokio::time::sleep;
+
+use crate::models::*;
+
+const MAX_RETRIES: u32 = 3;
+
+/// Douban temporarily blocks clients that make more than one request every two seconds.
+static THROTTLE: RequestThrottle = RequestThrottle::new(Duration::from_secs(2));
+
+pub struct DoubanService {
+    client: Client,
+}
+
+impl DoubanService {
+    pub async fn new() -> Result<Self> {
+        Ok(Self {
+            client: get_base_http_client(None),
+        })
+    }
+
+    async fn fetch(&self, url: &str, query: &[(&str, &str)]) -> Result<String> {
+        for attempt in 0..MAX_RETRIES {
+            THROTTLE.wait().await;
+            let user_agent = USER_AGENTS[attempt as usize % USER_AGENTS.len()];
+            let response = self
+                .client
+                .get(url)
+                .query(query)
+                .header(REFERER, URL)
+                .header(USER_AGENT, user_agent)
+                .header(ACCEPT_LANGUAGE, "zh-CN,zh;q=0.9,en;q=0.8")
+                .send()
+                .await;
+            match response {
+                Ok(r) if r.status().is_success() => {
+                    let text = r.text().await?;
+                    if !text.contains("检测到有异常请求") {
+                        return Ok(text);
+                    }
+                    ryot_log!(debug, "Douban rate limited the request to {url}");
+                }
+                Ok(r) => ryot_log!(debug, "Douban returned {} for {url}", r.status()),
+                Err(e) => ryot_log!(debug, "Request to {url} failed: {e}"),
+            }
+            sleep(THROTTLE.interval() * 2_u32.pow(attempt + 1)).await;
+        }
+        bail!("Could not fetch {url} from Douban after {MAX_RETRIES} attempts")
+    }
+
+    async fn fetch_json<T: DeserializeOwned>(
+        &self,
+        url: &str,
+        query: &[(&str, &str)],
+    ) -> Result<T> {
+        Ok(serde_json::from_str(&self.fetch(url, query).await?)?)
+    }
+
+    async fn suggest(&self, query: &str) -> Result<Vec<DoubanSuggestItem>> {
+        self.fetch_json(&format!("{URL}/j/subject_suggest"), &[("q", query)])
+            .await
+    }
+
+    /// Douban only exposes a suggestion endpoint without pagination, so all the results
+    /// are returned as a single page.
+    pub async fn metadata_search(
+        &self,
+        query: &str,
+        lot: MediaLot,
+    ) -> Result<SearchResults<MetadataSearchItem>> {
+        let items = self
+            .suggest(query)
+            .await?
+            .into_iter()
+            .filter(|i| i.lot() == Some(lot))
+            .map(|i| MetadataSearchItem {
+                title: i.title,
+                identifier: i.id,
+                image: i.img.map(|i| get_large_image(&i)),
+                publish_year: i.year.and_then(|y| y.parse().ok()),
+            })
+            .collect_vec();
+        Ok(SearchResults {
+            details: SearchDetails {
+                next_page: None,
+                total_items: items.len().try_into().unwrap(),
+            },
+            items,
+        })
+    }
+
+    pub async fn people_search(&self, query: &str) -> Result<Vec<DoubanSuggestItem>> {
+        let items = self.suggest(query).await?;
+        Ok(items
+            .into_iter()
+            .filter(|i| i.item_type == "celebrity")
+            .collect())
+    }
+
+    /// Fetches the subjects listed under one of the tags of the explore page, which are
+    /// 热门 (trending), 豆瓣高分 (highly rated) and, for movies only, 最新 (latest).
+    pub async fn get_trending_media(
+        &self,
+        lot: MediaLot,
+        list: TrendingMediaList,
+    ) -> Result<Vec<PartialMetadataWithoutId>> {
+        let subject_type = match lot {
+            MediaLot::Show => "tv",
+            _ => "movie",
+        };
+        let tag = match (list, lot) {
+            (TrendingMediaList::Trending, _) => "热门",
+            (TrendingMediaList::Popular, _) => "豆瓣高分",
+            (TrendingMediaList::Upcoming, MediaLot::Movie) => "最新",
+            (TrendingMediaList::Upcoming, _) => bail!("Douban does not list upcoming shows"),
+        };
+        let response: DoubanTrendingResponse = self
+            .fetch_json(
+                &format!("{URL}/j/search_subjects"),
+                &[
+                    ("tag", tag),
+                    ("page_start", "0"),
+                    ("page_limit", "50"),
+                    ("type", subject_type),
+                ],
+            )
+            .await?;
+        Ok(response
+            .subjects
+            .into_iter()
+            .map(|s| PartialMetadataWithoutId {
+                lot,
+                title: s.title,
+                identifier: s.id,
+                source: MediaSource::Douban,
+                image: s.cover.map(|c| get_large_image(&c)),
+                ..Default::default()
+            })
+            .collect())
+    }
+
+    pub async fn subject_details(&self, identifier: &str) -> Result<DoubanSubject> {
+        let html = self
+            .fetch(&format!("{URL}/subject/{identifier}/"), &[])
+            .await?;
+        let subject = parse_subject(identifier, &html);
+        if subject.title.is_empty() {
+            bail!("Could not find a Douban subject with identifier {identifier}");
+        }
+        Ok(subject)
+    }
+
+    /// Fetches the short summary of a subject, which is the only place that says whether
+    /// it is a movie or a show.
+    pub async fn subject_lot(&self, identifier: &str) -> Result<MediaLot> {
+        let response: DoubanSubjectAbstractResponse = self
+            .fetch_json(
+                &format!("{URL}/j/subject_abstract"),
+                &[("subject_id", identifier)],
+            )
+            .await?;
+        Ok(match response.subject.is_tv {
+            true => MediaLot::Show,
+            false => MediaLot::Movie,
+        })
+    }
+
+    pub async fn celebrity_page(&self, identifier: &str) -> Result<String> {
+        self.fetch(&format!("{URL}/celebrity/{identifier}/"), &[])
+            .await
+    }
+}
+
+/// Maps the fields common to movies and shows, the specifics are filled by the callers.
+pub fn metadata_details_from_subject(subject: DoubanSubject, lot: MediaLot) -> MetadataDetails {
+    MetadataDetails {
+        title: subject.title,
+        genres: subject.genres,
+        people: subject.people,
+        publish_year: subject.publish_year,
+        publish_date: subject.publish_date,
+        provider_rating: subject.rating,
+        description: subject.description,
+        original_language: subject.language,
+        source_url: Some(format!("{URL}/subject/{}/", subject.id)),
+        assets: EntityAssets {
+            remote_images: Vec::from_iter(subject.image),
+            ..Default::default()
+        },
+        suggestions: subject
+            .suggestions
+            .into_iter()
+            .map(|s| PartialMetadataWithoutId {
+                lot,
+                title: s.title,
+                image: s.image,
+                identifier: s.id,
+                source: MediaSource::Douban,
+                ..Default::default()
+            })
+            .collect(),
+        ..Default::default()
+    }
+}
+
+/// Douban serves small posters by default, the same image is available in a larger size.
+pub fn get_large_image(url: &str) -> String {
+    url.replace("s_ratio_poster", "l_ratio_poster")
+        .replace("/s_ratio_celebrity/", "/l/")
+}
+
+pub fn selector(selector: &str) -> Selector {
+    Selector::parse(selector).unwrap()
+}
+
+pub fn element_text(element: &ElementRef) -> String {
+    element
+        .text()
+        .map(|t| t.trim())
+        .filter(|t| !t.is_empty())
+        .join("\n")
+}
+
+pub fn first_text(document: &Html, query: &str) -> Option<String> {
+    document
+        .select(&selector(query))
+        .next()
+        .map(|e| element_text(&e))
+        .filter(|t| !t.is_empty())
+}
+
+/// Extracts the id from links like `https://movie.douban.com/celebrity/1047973/`.
+pub fn extract_id(href: &str, segment: &str) -> Option<String> {
+    let (_, rest) = href.split_once(&format!("/{segment}/"))?;
+    let id = rest.split(['/', '?']).next()?;
+    (!id.is_empty() && id.chars().all(|c| c.is_ascii_digit())).then(|| id.to_owned())
+}
+
+pub fn leading_number(text: &str) -> Option<i32> {
+    text.trim()
+        .chars()
+        .take_while(|c| c.is_ascii_digit())
+        .collect::<String>()
+        .parse()
+        .ok()
+}
+
+/// Parses dates like `1994-09-10(多伦多电影节)` into the date part.
+pub fn parse_date(text: &str) -> Option<chrono::NaiveDate> {
+    text.trim().get(..10).and_then(convert_string_to_date)
+}
+
+/// Converts the `key: value` lines of an information block into a map.
+pub fn parse_info_lines(element: &ElementRef) -> HashMap<String, String> {
+    let mut text = String::new();
+    for node in element.descendants() {
+        match node.value() {
+            Node::Text(t) => text.push_str(t),
+            Node::Element(e) if matches!(e.name(), "br" | "li") => text.push('\n'),
+            _ => {}
+        }
+    }
+    text.lines()
+        .filter_map(|l| {
+            let (key, value) = l.split_once(':').or_else(|| l.split_once('：'))?;
+            let value = value.split_whitespace().join(" ");
+            (!value.is_empty()).then(|| (key.trim().to_owned(), value))
+        })
+        .collect()
+}
+
+pub(crate) fn parse_people(document: &Html) -> Vec<PartialMetadataPerson> {
+    let mut people = vec![];
+    for label in document.select(&selector("#info span.pl")) {
+        let role = match element_text(&label).as_str() {
+            "导演" => "Director",
+            "编剧" => "Writer",
+            "主演" => "Actor",
+            _ => continue,
+        };
+        let Some(parent) = label.parent().and_then(ElementRef::wrap) else {
+            continue;
+        };
+        for link in parent.select(&selector("a")) {
+            let Some(identifier) = link
+                .value()
+                .attr("href")
+                .and_then(|h| extract_id(h, "celebrity"))
+            else {
+                continue;
+            };
+            people.push(PartialMetadataPerson {
+                identifier,
+                role: role.to_owned(),
+                name: element_text(&link),
+                source: MediaSource::Douban,
+                ..Default::default()
+            });
+        }
+    }
+    people.into_iter().unique().collect()
+}
+
+fn parse_suggestions(document: &Html) -> Vec<DoubanRelatedSubject> {
+    document
+        .select(&selector("#recommendations dl"))
+        .filter_map(|item| {
+            let link = item.select(&selector("dd a")).next()?;
+            let id = extract_id(link.value().attr("href")?, "subject")?;
+            let image = item
+                .select(&selector("dt img"))
+                .next()
+                .and_then(|i| i.value().attr("src"))
+                .map(get_large_image);
+            Some(DoubanRelatedSubject {
+                id,
+                image,
+                title: element_text(&link),
+            })
+        })
+        .collect()
+}
+
+pub fn parse_subject(identifier: &str, html: &str) -> DoubanSubject {
+    let document = Html::parse_document(html);
+    let info = document
+        .select(&selector("#info"))
+        .next()
+        .map(|i| parse_info_lines(&i))
+        .unwrap_or_default();
+    let publish_date = document
+        .select(&selector("span[property='v:initialReleaseDate']"))
+        .filter_map(|e| parse_date(e.value().attr("content").unwrap_or(&element_text(&e))))
+        .min()
+        .or_else(|| info.get("首播").and_then(|d| parse_date(d)));
+    let publish_year = first_text(&document, "#content h1 span.year")
+        .and_then(|y| y.trim_matches(['(', ')']).parse().ok())
+        .or_else(|| publish_date.map(|d| d.format("%Y").to_string().parse().unwrap()));
+    let runtime = document
+        .select(&selector("span[property='v:runtime']"))
+        .next()
+        .and_then(|e| leading_number(e.value().attr("content").unwrap_or(&element_text(&e))))
+        .or_else(|| info.get("单集片长").and_then(|r| leading_number(r)));
+    let mut seasons = document
+        .select(&selector("select#season option"))
+        .filter_map(|o| {
+            Some((
+                leading_number(&element_text(&o))?,
+                o.value().attr("value")?.to_owned(),
+            ))
+        })
+        .collect_vec();
+    let season_number = seasons
+        .iter()
+        .find(|(_, id)| id == identifier)
+        .map(|(n, _)| *n)
+        .or_else(|| info.get("季数").and_then(|s| leading_number(s)));
+    if seasons.is_empty() {
+        seasons.push((season_number.unwrap_or(1), identifier.to_owned()));
+    }
+    let description = first_text(
+        &document,
+        "#link-report-intra span.all, #link-report span.all",
+    )
+    .or_else(|| first_text(&document, "span[property='v:summary']"));
+    DoubanSubject {
+        runtime,
+        seasons,
+        description,
+        publish_date,
+        publish_year,
+        id: identifier.to_owned(),
+        people: parse_people(&document),
+        suggestions: parse_suggestions(&document),
+        episodes: info.get("集数").and_then(|e| leading_number(e)),
+        title: first_text(&document, "span[property='v:itemreviewed']").unwrap_or_default(),
+        language: info
+            .get("语言")
+            .and_then(|l| l.split('/').next())
+            .map(|l| l.trim().to_owned()),
+        image: document
+            .select(&selector("#mainpic img"))
+            .next()
+            .and_then(|i| i.value().attr("src"))
+            .map(get_large_image),
+        genres: document
+            .select(&selector("span[property='v:genre']"))
+            .map(|g| element_text(&g))
+            .unique()
+            .collect(),
+        rating: first_text(&document, "strong[property='v:average']")
+            .and_then(|r| r.parse::<Decimal>().ok())
+            .filter(|r| *r != dec!(0))
+            .map(|r| r * dec!(10)),
+    }
+}
@@ -0,0 +1,16 @@
+mod base;
+pub use base::DoubanService;
+
+mod models;
+
+mod movies;
+pub use movies::DoubanMovieService;
+
+mod non_metadata;
+pub use non_metadata::NonMediaDoubanService;
+
+mod shows;
+pub use shows::DoubanShowService;
+
+#[cfg(test)]
+mod tests;
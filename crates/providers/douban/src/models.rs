@@ -0,0 +1,89 @@
+use chrono::NaiveDate;
+use enum_models::MediaLot;
+use media_models::PartialMetadataPerson;
+use rust_decimal::Decimal;
+use serde::Deserialize;
+
+pub static URL: &str = "https://movie.douban.com";
+
+/// Browser user agents that are rotated between requests since Douban blocks
+/// clients that do not look like a browser.
+pub static USER_AGENTS: [&str; 4] = [
+    "Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/120.0.0.0 Safari/537.36",
+    "Mozilla/5.0 (Macintosh; Intel Mac OS X 10_15_7) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/120.0.0.0 Safari/537.36",
+    "Mozilla/5.0 (Windows NT 10.0; Win64; x64; rv:121.0) Gecko/20100101 Firefox/121.0",
+    "Mozilla/5.0 (Macintosh; Intel Mac OS X 10_15_7) AppleWebKit/605.1.15 (KHTML, like Gecko) Version/17.1 Safari/605.1.15",
+];
+
+#[derive(Debug, Deserialize)]
+pub struct DoubanSuggestItem {
+    pub id: String,
+    pub title: String,
+    pub img: Option<String>,
+    pub year: Option<String>,
+    pub episode: Option<String>,
+    #[serde(rename = "type")]
+    pub item_type: String,
+}
+
+impl DoubanSuggestItem {
+    /// Douban reports every subject as a movie, shows are the ones with an episode count.
+    pub fn lot(&self) -> Option<MediaLot> {
+        match self.item_type.as_str() {
+            "movie" | "tv" => match self.episode.as_deref().unwrap_or_default().is_empty() {
+                true => Some(MediaLot::Movie),
+                false => Some(MediaLot::Show),
+            },
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct DoubanTrendingSubject {
+    pub id: String,
+    pub title: String,
+    pub cover: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct DoubanTrendingResponse {
+    pub subjects: Vec<DoubanTrendingSubject>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct DoubanSubjectAbstract {
+    pub is_tv: bool,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct DoubanSubjectAbstractResponse {
+    pub subject: DoubanSubjectAbstract,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DoubanRelatedSubject {
+    pub id: String,
+    pub title: String,
+    pub image: Option<String>,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct DoubanSubject {
+    pub id: String,
+    pub title: String,
+    pub image: Option<String>,
+    pub genres: Vec<String>,
+    pub runtime: Option<i32>,
+    pub episodes: Option<i32>,
+    pub language: Option<String>,
+    pub publish_year: Option<i32>,
+    pub rating: Option<Decimal>,
+    pub description: Option<String>,
+    pub publish_date: Option<NaiveDate>,
+    pub people: Vec<PartialMetadataPerson>,
+    /// The season numbers and subject ids of all the seasons of a show, since Douban
+    /// lists every season as a separate subject.
+    pub seasons: Vec<(i32, String)>,
+    pub suggestions: Vec<DoubanRelatedSubject>,
+}
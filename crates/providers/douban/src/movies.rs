@@ -0,0 +1,46 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use common_models::TrendingMediaList;
+use dependent_models::{MetadataSearchSourceSpecifics, SearchResults};
+use enum_models::MediaLot;
+use media_models::{MetadataDetails, MetadataSearchItem, MovieSpecifics, PartialMetadataWithoutId};
+use traits::MediaProvider;
+
+use crate::base::{DoubanService, metadata_details_from_subject};
+
+pub struct DoubanMovieService(DoubanService);
+
+impl DoubanMovieService {
+    pub async fn new() -> Result<Self> {
+        Ok(Self(DoubanService::new().await?))
+    }
+}
+
+#[async_trait]
+impl MediaProvider for DoubanMovieService {
+    async fn metadata_details(&self, identifier: &str) -> Result<MetadataDetails> {
+        let subject = self.0.subject_details(identifier).await?;
+        let runtime = subject.runtime;
+        Ok(MetadataDetails {
+            movie_specifics: Some(MovieSpecifics { runtime }),
+            ..metadata_details_from_subject(subject, MediaLot::Movie)
+        })
+    }
+
+    async fn metadata_search(
+        &self,
+        _page: u64,
+        query: &str,
+        _display_nsfw: bool,
+        _source_specifics: &Option<MetadataSearchSourceSpecifics>,
+    ) -> Result<SearchResults<MetadataSearchItem>> {
+        self.0.metadata_search(query, MediaLot::Movie).await
+    }
+
+    async fn get_trending_media(
+        &self,
+        list: TrendingMediaList,
+    ) -> Result<Vec<PartialMetadataWithoutId>> {
+        self.0.get_trending_media(MediaLot::Movie, list).await
+    }
+}
@@ -0,0 +1,185 @@
+use anyhow::{Result, bail};
+use async_trait::async_trait;
+use common_models::{EntityAssets, PersonSourceSpecifics, SearchDetails};
+use common_utils::ryot_log;
+use dependent_models::{MetadataPersonRelated, PersonDetails, SearchResults};
+use enum_models::MediaSource;
+use itertools::Itertools;
+use media_models::{PartialMetadataWithoutId, PeopleSearchItem};
+use scraper::Html;
+use traits::MediaProvider;
+
+use crate::{
+    base::{
+        DoubanService, element_text, extract_id, first_text, get_large_image, parse_date,
+        parse_info_lines, selector,
+    },
+    models::{DoubanRelatedSubject, URL},
+};
+
+pub struct NonMediaDoubanService(DoubanService);
+
+impl NonMediaDoubanService {
+    pub async fn new() -> Result<Self> {
+        Ok(Self(DoubanService::new().await?))
+    }
+}
+
+/// The works of a celebrity do not say whether they are movies or shows, which is looked up
+/// separately for each of them.
+pub(crate) fn parse_works(document: &Html) -> Vec<DoubanRelatedSubject> {
+    document
+        .select(&selector("#best_movies li, #recent_movies li"))
+        .filter_map(|item| {
+            let link = item.select(&selector("a")).next()?;
+            let id = extract_id(link.value().attr("href")?, "subject")?;
+            let image = item.select(&selector("img")).next();
+            let title = image
+                .and_then(|i| i.value().attr("alt"))
+                .map(String::from)
+                .or_else(|| {
+                    item.select(&selector(".info a"))
+                        .next()
+                        .map(|a| element_text(&a))
+                })
+                .filter(|t| !t.is_empty())?;
+            Some(DoubanRelatedSubject {
+                id,
+                title,
+                image: image
+                    .and_then(|i| i.value().attr("src"))
+                    .map(get_large_image),
+            })
+        })
+        .unique_by(|w| w.id.clone())
+        .collect()
+}
+
+/// Reads the details of a celebrity along with the role they are known for and their works,
+/// whose types still need to be looked up.
+pub(crate) fn parse_celebrity(
+    identifier: &str,
+    html: &str,
+) -> Result<(PersonDetails, String, Vec<DoubanRelatedSubject>)> {
+    let document = Html::parse_document(html);
+    let Some(name) = first_text(&document, "#content h1") else {
+        bail!("Could not find a Douban celebrity with identifier {identifier}");
+    };
+    let info = document
+        .select(&selector("#headline .info"))
+        .next()
+        .map(|i| parse_info_lines(&i))
+        .unwrap_or_default();
+    let (birth_date, death_date) = match info.get("生卒日期") {
+        Some(dates) => {
+            let (birth, death) = dates.split_once('至').unwrap_or((dates, ""));
+            (parse_date(birth), parse_date(death))
+        }
+        None => (info.get("出生日期").and_then(|d| parse_date(d)), None),
+    };
+    let alternate_names = ["更多中文名", "更多外文名"]
+        .iter()
+        .filter_map(|k| info.get(*k))
+        .flat_map(|n| n.split('/').map(|n| n.trim().to_owned()))
+        .filter(|n| !n.is_empty())
+        .collect_vec();
+    let role = info
+        .get("职业")
+        .and_then(|p| p.split('/').next())
+        .map(|p| p.trim().to_owned())
+        .unwrap_or_else(|| "Actor".to_owned());
+    let image = document
+        .select(&selector("#headline .pic img, #headline .nbg img"))
+        .next()
+        .and_then(|i| i.value().attr("src"))
+        .map(get_large_image);
+    let details = PersonDetails {
+        name: name.split_whitespace().join(" "),
+        birth_date,
+        death_date,
+        place: info.get("出生地").cloned(),
+        website: info.get("官方网站").cloned(),
+        source_url: Some(format!("{URL}/celebrity/{identifier}/")),
+        alternate_names: (!alternate_names.is_empty()).then_some(alternate_names),
+        description: first_text(&document, "#intro .all")
+            .or_else(|| first_text(&document, "#intro .bd")),
+        gender: info.get("性别").and_then(|g| match g.as_str() {
+            "男" => Some("Male".to_owned()),
+            "女" => Some("Female".to_owned()),
+            _ => None,
+        }),
+        assets: EntityAssets {
+            remote_images: Vec::from_iter(image),
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    Ok((details, role, parse_works(&document)))
+}
+
+#[async_trait]
+impl MediaProvider for NonMediaDoubanService {
+    async fn people_search(
+        &self,
+        _page: u64,
+        query: &str,
+        _display_nsfw: bool,
+        _source_specifics: &Option<PersonSourceSpecifics>,
+    ) -> Result<SearchResults<PeopleSearchItem>> {
+        let items = self
+            .0
+            .people_search(query)
+            .await?
+            .into_iter()
+            .map(|i| PeopleSearchItem {
+                name: i.title,
+                identifier: i.id,
+                image: i.img.map(|i| get_large_image(&i)),
+                ..Default::default()
+            })
+            .collect_vec();
+        Ok(SearchResults {
+            details: SearchDetails {
+                next_page: None,
+                total_items: items.len().try_into().unwrap(),
+            },
+            items,
+        })
+    }
+
+    async fn person_details(
+        &self,
+        identifier: &str,
+        source_specifics: &Option<PersonSourceSpecifics>,
+    ) -> Result<PersonDetails> {
+        let html = self.0.celebrity_page(identifier).await?;
+        let (mut details, role, works) = parse_celebrity(identifier, &html)?;
+        details.source_specifics = source_specifics.to_owned();
+        for work in works {
+            let lot = match self.0.subject_lot(&work.id).await {
+                Ok(lot) => lot,
+                Err(e) => {
+                    ryot_log!(
+                        debug,
+                        "Could not find the type of subject {}: {e:?}",
+                        work.id
+                    );
+                    continue;
+                }
+            };
+            details.related_metadata.push(MetadataPersonRelated {
+                role: role.clone(),
+                metadata: PartialMetadataWithoutId {
+                    lot,
+                    title: work.title,
+                    image: work.image,
+                    identifier: work.id,
+                    source: MediaSource::Douban,
+                    ..Default::default()
+                },
+                ..Default::default()
+            });
+        }
+        Ok(details)
+    }
+}
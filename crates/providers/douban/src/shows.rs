@@ -0,0 +1,112 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use common_models::TrendingMediaList;
+use common_utils::ryot_log;
+use dependent_models::{MetadataSearchSourceSpecifics, SearchResults};
+use enum_models::MediaLot;
+use media_models::{
+    MetadataDetails, MetadataSearchItem, PartialMetadataWithoutId, ShowEpisode, ShowSeason,
+    ShowSpecifics,
+};
+use traits::MediaProvider;
+
+use crate::base::{DoubanService, metadata_details_from_subject};
+
+pub struct DoubanShowService(DoubanService);
+
+impl DoubanShowService {
+    pub async fn new() -> Result<Self> {
+        Ok(Self(DoubanService::new().await?))
+    }
+}
+
+/// Removes the season from titles like `权力的游戏 第一季 Game of Thrones Season 1`.
+pub(crate) fn get_show_title(title: &str) -> String {
+    match title.find(" 第") {
+        Some(idx) if title[idx..].contains('季') => title[..idx].to_owned(),
+        _ => title.to_owned(),
+    }
+}
+
+#[async_trait]
+impl MediaProvider for DoubanShowService {
+    /// Douban lists every season of a show as a separate subject, so the other seasons
+    /// are fetched from the season selector of the requested subject.
+    async fn metadata_details(&self, identifier: &str) -> Result<MetadataDetails> {
+        let subject = self.0.subject_details(identifier).await?;
+        let mut seasons = vec![];
+        for (season_number, season_id) in subject.seasons.clone() {
+            let season = match season_id == subject.id {
+                true => subject.clone(),
+                false => match self.0.subject_details(&season_id).await {
+                    Ok(season) => season,
+                    Err(e) => {
+                        ryot_log!(debug, "Could not fetch season {season_id}: {e:?}");
+                        continue;
+                    }
+                },
+            };
+            let episodes = (1..=season.episodes.unwrap_or_default())
+                .map(|episode_number| ShowEpisode {
+                    episode_number,
+                    id: episode_number,
+                    runtime: season.runtime,
+                    name: format!("Episode {episode_number}"),
+                    publish_date: match episode_number {
+                        1 => season.publish_date,
+                        _ => None,
+                    },
+                    ..Default::default()
+                })
+                .collect();
+            seasons.push(ShowSeason {
+                episodes,
+                season_number,
+                overview: season.description,
+                publish_date: season.publish_date,
+                name: format!("Season {season_number}"),
+                id: season_id.parse().unwrap_or_default(),
+                poster_images: Vec::from_iter(season.image),
+                ..Default::default()
+            });
+        }
+        seasons.sort_by_key(|s| s.season_number);
+        let total_episodes = seasons.iter().map(|s| s.episodes.len()).sum();
+        let total_runtime = seasons
+            .iter()
+            .flat_map(|s| s.episodes.iter())
+            .map(|e| e.runtime.unwrap_or_default())
+            .sum();
+        let title = match seasons.len() > 1 {
+            true => get_show_title(&subject.title),
+            false => subject.title.clone(),
+        };
+        Ok(MetadataDetails {
+            title,
+            show_specifics: Some(ShowSpecifics {
+                total_seasons: Some(seasons.len()),
+                runtime: (total_runtime != 0).then_some(total_runtime),
+                total_episodes: (total_episodes != 0).then_some(total_episodes),
+                seasons,
+            }),
+            ..metadata_details_from_subject(subject, MediaLot::Show)
+        })
+    }
+
+    async fn metadata_search(
+        &self,
+        _page: u64,
+        query: &str,
+        _display_nsfw: bool,
+        _source_specifics: &Option<MetadataSearchSourceSpecifics>,
+    ) -> Result<SearchResults<MetadataSearchItem>> {
+        self.0.metadata_search(query, MediaLot::Show).await
+    }
+
+    async fn get_trending_media(
+        &self,
+        list: TrendingMediaList,
+    ) -> Result<Vec<PartialMetadataWithoutId>> {
+        self.0.get_trending_media(MediaLot::Show, list).await
+    }
+}
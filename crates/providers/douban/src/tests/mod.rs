@@ -0,0 +1,16 @@
+use enum_models::MediaSource;
+use rust_decimal::dec;
+use scraper::Html;
+
+use crate::{
+    base::{parse_people, parse_subject},
+    non_metadata::{parse_celebrity, parse_works},
+    shows::get_show_title,
+};
+
+mod people;
+mod subjects;
+
+const MOVIE_SUBJECT: &str = include_str!("fixtures/movie_subject.html");
+const SHOW_SUBJECT: &str = include_str!("fixtures/show_subject.html");
+const CELEBRITY: &str = include_str!("fixtures/celebrity.html");
@@ -0,0 +1,70 @@
+use chrono::NaiveDate;
+
+use super::*;
+
+#[test]
+fn test_parse_people_reads_roles_of_credits() {
+    let people = parse_people(&Html::parse_document(MOVIE_SUBJECT));
+    let credits = people
+        .iter()
+        .map(|p| (p.identifier.as_str(), p.role.as_str(), p.name.as_str()))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        credits,
+        vec![
+            ("1047973", "Director", "弗兰克·德拉邦特"),
+            ("1047973", "Writer", "弗兰克·德拉邦特"),
+            ("1049547", "Writer", "斯蒂芬·金"),
+            ("1054521", "Actor", "蒂姆·罗宾斯"),
+            ("1054534", "Actor", "摩根·弗里曼"),
+        ]
+    );
+    assert!(people.iter().all(|p| p.source == MediaSource::Douban));
+}
+
+#[test]
+fn test_parse_works_skips_duplicates_and_non_subjects() {
+    let works = parse_works(&Html::parse_document(CELEBRITY));
+    let ids = works.iter().map(|w| w.id.as_str()).collect::<Vec<_>>();
+    assert_eq!(ids, vec!["1292052", "1297518", "35207723"]);
+}
+
+#[test]
+fn test_parse_works_reads_title_and_large_image() {
+    let works = parse_works(&Html::parse_document(CELEBRITY));
+    assert_eq!(works[0].title, "肖申克的救赎");
+    assert_eq!(
+        works[0].image.as_deref(),
+        Some("https://img.doubanio.com/view/photo/l_ratio_poster/public/p480747492.jpg")
+    );
+    assert_eq!(works[1].title, "神秘河");
+    assert_eq!(works[1].image, None);
+}
+
+#[test]
+fn test_parse_celebrity_reads_details_and_role() {
+    let (details, role, works) = parse_celebrity("1054521", CELEBRITY).unwrap();
+    assert_eq!(details.name, "蒂姆·罗宾斯 Tim Robbins");
+    assert_eq!(details.gender.as_deref(), Some("Male"));
+    assert_eq!(details.birth_date, NaiveDate::from_ymd_opt(1958, 10, 16));
+    assert_eq!(details.place.as_deref(), Some("美国,加利福尼亚州,西柯汶纳"));
+    assert_eq!(
+        details.alternate_names,
+        Some(vec!["Timothy Francis Robbins".to_owned(), "Tim".to_owned()])
+    );
+    assert_eq!(
+        details.description.as_deref(),
+        Some("蒂姆·罗宾斯，美国演员、导演。")
+    );
+    assert_eq!(
+        details.assets.remote_images,
+        vec!["https://img.doubanio.com/view/celebrity/l/public/p17525.jpg"]
+    );
+    assert_eq!(role, "演员");
+    assert_eq!(works.len(), 3);
+}
+
+#[test]
+fn test_parse_celebrity_of_missing_page_fails() {
+    assert!(parse_celebrity("1", "<html><body></body></html>").is_err());
+}
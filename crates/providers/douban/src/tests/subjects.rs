@@ -0,0 +1,93 @@
+use chrono::NaiveDate;
+
+use super::*;
+
+#[test]
+fn test_parse_subject_reads_movie_details() {
+    let subject = parse_subject("1292052", MOVIE_SUBJECT);
+    assert_eq!(subject.id, "1292052");
+    assert_eq!(subject.title, "肖申克的救赎 The Shawshank Redemption");
+    assert_eq!(subject.publish_year, Some(1994));
+    assert_eq!(subject.runtime, Some(142));
+    assert_eq!(subject.episodes, None);
+    assert_eq!(subject.rating, Some(dec!(97.0)));
+    assert_eq!(subject.language.as_deref(), Some("英语"));
+    assert_eq!(subject.genres, vec!["剧情", "犯罪"]);
+    assert_eq!(
+        subject.image.as_deref(),
+        Some("https://img.doubanio.com/view/photo/l_ratio_poster/public/p480747492.jpg")
+    );
+    assert_eq!(
+        subject.description.as_deref(),
+        Some("一场谋杀案使银行家安迪蒙冤入狱，谋杀妻子及其情人的指控将囚禁他终生。")
+    );
+}
+
+#[test]
+fn test_parse_subject_uses_earliest_release_date() {
+    let subject = parse_subject("1292052", MOVIE_SUBJECT);
+    assert_eq!(subject.publish_date, NaiveDate::from_ymd_opt(1994, 9, 10));
+}
+
+#[test]
+fn test_parse_subject_reads_suggestions() {
+    let subject = parse_subject("1292052", MOVIE_SUBJECT);
+    let ids = subject
+        .suggestions
+        .iter()
+        .map(|s| s.id.as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(ids, vec!["1292720", "1291546"]);
+    assert_eq!(subject.suggestions[0].title, "阿甘正传");
+}
+
+#[test]
+fn test_parse_subject_without_seasons_is_its_own_first_season() {
+    let subject = parse_subject("1292052", MOVIE_SUBJECT);
+    assert_eq!(subject.seasons, vec![(1, "1292052".to_owned())]);
+}
+
+#[test]
+fn test_parse_subject_reads_show_seasons() {
+    let subject = parse_subject("6952149", SHOW_SUBJECT);
+    assert_eq!(
+        subject.seasons,
+        vec![
+            (1, "3016187".to_owned()),
+            (2, "6952149".to_owned()),
+            (3, "10527209".to_owned()),
+        ]
+    );
+    assert_eq!(subject.episodes, Some(10));
+    assert_eq!(subject.runtime, Some(55));
+    assert_eq!(subject.publish_date, NaiveDate::from_ymd_opt(2012, 4, 1));
+    assert_eq!(
+        subject.description.as_deref(),
+        Some("史塔克家族的当家人艾德·史塔克被处死后……")
+    );
+}
+
+#[test]
+fn test_parse_subject_ignores_missing_rating() {
+    let subject = parse_subject("6952149", SHOW_SUBJECT);
+    assert_eq!(subject.rating, None);
+}
+
+#[test]
+fn test_parse_subject_of_missing_page_has_no_title() {
+    let subject = parse_subject("1", "<html><body>页面不存在</body></html>");
+    assert!(subject.title.is_empty());
+}
+
+#[test]
+fn test_get_show_title_removes_season() {
+    assert_eq!(
+        get_show_title("权力的游戏 第二季 Game of Thrones Season 2"),
+        "权力的游戏"
+    );
+    assert_eq!(get_show_title("请回答1988"), "请回答1988");
+    assert_eq!(
+        get_show_title("第一炉香 Love After Love"),
+        "第一炉香 Love After Love"
+    );
+}
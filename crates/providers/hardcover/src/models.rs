@@ -32,6 +32,17 @@ pub struct Editions {
     pub editions: Vec<nest! { pub book_id: i64 }>,
 }
 
+#[nest_struct]
+#[derive(Debug, Deserialize)]
+pub struct BooksTrending {
+    pub books_trending: nest! { pub ids: Vec<i64> },
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Books {
+    pub books: Vec<Item<i64>>,
+}
+
 #[derive(Debug, Deserialize)]
 pub struct BooksByPk {
     pub books_by_pk: Item<i64>,
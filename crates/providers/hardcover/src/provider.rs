@@ -1,6 +1,7 @@
 use anyhow::Result;
 use async_trait::async_trait;
-use common_models::{EntityAssets, PersonSourceSpecifics, SearchDetails};
+use chrono::{Duration, Utc};
+use common_models::{EntityAssets, PersonSourceSpecifics, SearchDetails, TrendingMediaList};
 use common_utils::PAGE_SIZE;
 use database_models::metadata_group::MetadataGroupWithoutId;
 use dependent_models::{
@@ -16,11 +17,14 @@ use traits::MediaProvider;
 use crate::{
     base::HardcoverService,
     models::{
-        AuthorsByPk, BooksByPk, PublishersByPk, Response, SeriesByPk, URL, get_search_response,
-        query_type_from_specifics,
+        AuthorsByPk, Books, BooksByPk, BooksTrending, PublishersByPk, Response, SeriesByPk, URL,
+        get_search_response, query_type_from_specifics,
     },
 };
 
+/// Books that the most users have added to their library come first.
+const POPULAR_ORDER: &str = "order_by: { users_count: desc }, ";
+
 #[async_trait]
 impl MediaProvider for HardcoverService {
     async fn metadata_details(&self, identifier: &str) -> Result<MetadataDetails> {
@@ -178,6 +182,91 @@ query {{
         Ok(resp)
     }
 
+    async fn get_trending_media(
+        &self,
+        list: TrendingMediaList,
+    ) -> Result<Vec<PartialMetadataWithoutId>> {
+        let today = Utc::now().date_naive();
+        let (filter, order_by, ranked_ids) = match list {
+            TrendingMediaList::Trending => {
+                let from = today - Duration::days(30);
+                let body = format!(
+                    r#"
+query {{
+  books_trending(from: "{from}", to: "{today}", limit: 50, offset: 0) {{
+    ids
+  }}
+}}
+    "#
+                );
+                let trending = self
+                    .client
+                    .post(URL)
+                    .json(&serde_json::json!({"query": body}))
+                    .send()
+                    .await?
+                    .json::<Response<BooksTrending>>()
+                    .await?;
+                let ids = trending.data.books_trending.ids;
+                if ids.is_empty() {
+                    return Ok(vec![]);
+                }
+                let filter = format!(
+                    "id: {{ _in: [{}] }}",
+                    ids.iter()
+                        .map(|i| i.to_string())
+                        .collect::<Vec<_>>()
+                        .join(",")
+                );
+                (filter, "", Some(ids))
+            }
+            TrendingMediaList::Popular => (String::new(), POPULAR_ORDER, None),
+            TrendingMediaList::Upcoming => (
+                format!(r#"release_date: {{ _gt: "{today}" }}"#),
+                POPULAR_ORDER,
+                None,
+            ),
+        };
+        let body = format!(
+            r#"
+query {{
+  books(where: {{ {filter} }}, {order_by}limit: 50) {{
+    id
+    title
+    image {{ url }}
+  }}
+}}
+    "#
+        );
+        let mut books = self
+            .client
+            .post(URL)
+            .json(&serde_json::json!({"query": body}))
+            .send()
+            .await?
+            .json::<Response<Books>>()
+            .await?
+            .data
+            .books;
+        // the trending ids are ordered by rank but the books are not returned in that order
+        if let Some(ids) = ranked_ids {
+            books.sort_by_key(|b| ids.iter().position(|i| *i == b.id));
+        }
+        Ok(books
+            .into_iter()
+            .filter_map(|b| {
+                Some(PartialMetadataWithoutId {
+                    title: b.title?,
+                    lot: MediaLot::Book,
+                    identifier: b.id.to_string(),
+                    source: MediaSource::Hardcover,
+                    image: b.image.and_then(|i| i.url),
+                    ..Default::default()
+                })
+            })
+            .collect())
+    }
+
     async fn metadata_group_details(
         &self,
         identifier: &str,
@@ -2,10 +2,10 @@ use std::{collections::HashMap, sync::Arc};
 
 use anyhow::{Result, anyhow};
 use async_trait::async_trait;
-use chrono::Datelike;
+use chrono::{Datelike, Utc};
 use common_models::{
     EntityAssets, EntityRemoteVideo, EntityRemoteVideoSource, IdAndNamedObject, IdObject,
-    NamedObject, PersonSourceSpecifics, SearchDetails,
+    NamedObject, PersonSourceSpecifics, SearchDetails, TrendingMediaList,
 };
 use common_utils::get_base_http_client;
 use common_utils::{PAGE_SIZE, compute_next_page};
@@ -139,6 +139,11 @@ struct IgdbCompany {
     published: Option<Vec<IgdbItemResponse>>,
 }
 
+#[derive(Serialize, Clone, Deserialize, Debug)]
+struct IgdbPopularityPrimitive {
+    game_id: i32,
+}
+
 #[derive(Serialize, Clone, Deserialize, Debug)]
 struct IgdbVideo {
     video_id: String,
@@ -598,9 +603,78 @@ offset: {offset};
             },
         })
     }
+
+    async fn get_trending_media(
+        &self,
+        list: TrendingMediaList,
+    ) -> Result<Vec<PartialMetadataWithoutId>> {
+        let client = self.get_client_config().await?;
+        // popularity type 1 corresponds to the number of visits on the IGDB page of a game
+        // and type 4 to the number of users who have played it
+        let popularity_type = match list {
+            TrendingMediaList::Trending => 1,
+            TrendingMediaList::Popular => 4,
+            TrendingMediaList::Upcoming => {
+                let now = Utc::now().timestamp();
+                let games: Vec<IgdbItemResponse> = client
+                    .post(format!("{URL}/games"))
+                    .body(format!(
+                        "fields name, cover.image_id; where first_release_date > {now} & version_parent = null & hypes != null; sort hypes desc; limit 50;"
+                    ))
+                    .send()
+                    .await?
+                    .json()
+                    .await?;
+                return Ok(games
+                    .into_iter()
+                    .filter_map(|g| self.get_trending_item(g))
+                    .collect());
+            }
+        };
+        let popular: Vec<IgdbPopularityPrimitive> = client
+            .post(format!("{URL}/popularity_primitives"))
+            .body(format!(
+                "fields game_id; where popularity_type = {popularity_type}; sort value desc; limit 50;"
+            ))
+            .send()
+            .await?
+            .json()
+            .await?;
+        if popular.is_empty() {
+            return Ok(vec![]);
+        }
+        let ids = popular.iter().map(|p| p.game_id).join(",");
+        let games: Vec<IgdbItemResponse> = client
+            .post(format!("{URL}/games"))
+            .body(format!(
+                "fields name, cover.image_id; where id = ({ids}) & version_parent = null; limit 50;"
+            ))
+            .send()
+            .await?
+            .json()
+            .await?;
+        let mut games: HashMap<i32, IgdbItemResponse> =
+            games.into_iter().map(|g| (g.id, g)).collect();
+        Ok(popular
+            .into_iter()
+            .filter_map(|p| games.remove(&p.game_id))
+            .filter_map(|g| self.get_trending_item(g))
+            .collect())
+    }
 }
 
 impl IgdbService {
+    fn get_trending_item(&self, game: IgdbItemResponse) -> Option<PartialMetadataWithoutId> {
+        Some(PartialMetadataWithoutId {
+            title: game.name?,
+            lot: MediaLot::VideoGame,
+            source: MediaSource::Igdb,
+            identifier: game.id.to_string(),
+            image: game.cover.map(|c| self.get_cover_image_url(c.image_id)),
+            ..Default::default()
+        })
+    }
+
     fn get_cover_image_url(&self, hash: String) -> String {
         format!("{}/{}/{}.jpg", self.image_url, self.image_size, hash)
     }
@@ -1,13 +1,15 @@
-use anyhow::Result;
+use anyhow::{Result, anyhow, bail};
 use async_trait::async_trait;
 use chrono::Datelike;
-use common_models::{EntityAssets, NamedObject, SearchDetails};
+use common_models::{EntityAssets, NamedObject, SearchDetails, TrendingMediaList};
 use common_utils::PAGE_SIZE;
 use common_utils::get_base_http_client;
 use dependent_models::{MetadataSearchSourceSpecifics, SearchResults};
+use enum_models::{MediaLot, MediaSource};
 use itertools::Itertools;
 use media_models::{
-    MetadataDetails, MetadataFreeCreator, MetadataSearchItem, PodcastEpisode, PodcastSpecifics,
+    MetadataDetails, MetadataFreeCreator, MetadataSearchItem, MetadataTranslationDetails,
+    PartialMetadataWithoutId, PodcastEpisode, PodcastSpecifics,
 };
 use reqwest::Client;
 use sea_orm::prelude::ChronoDateTimeUtc;
@@ -15,6 +17,7 @@ use serde::{Deserialize, Serialize};
 use traits::MediaProvider;
 
 static URL: &str = "https://itunes.apple.com";
+static CHARTS_URL: &str = "https://rss.applemarketingtools.com/api/v2";
 
 #[derive(Debug, Clone)]
 pub struct ITunesService {
@@ -72,6 +75,24 @@ struct SearchResponse {
     results: Option<Vec<ITunesItem>>,
 }
 
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+struct ChartItem {
+    id: String,
+    name: String,
+    artwork_url_100: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+struct ChartResponse {
+    feed: ChartFeed,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+struct ChartFeed {
+    results: Vec<ChartItem>,
+}
+
 #[async_trait]
 impl MediaProvider for ITunesService {
     async fn metadata_details(&self, identifier: &str) -> Result<MetadataDetails> {
@@ -166,6 +187,42 @@ impl MediaProvider for ITunesService {
         })
     }
 
+    /// iTunes only has metadata in English and Japanese, other languages get an empty
+    /// translation.
+    async fn metadata_translation(
+        &self,
+        identifier: &str,
+        language: &str,
+    ) -> Result<MetadataTranslationDetails> {
+        let lang = match language.split('-').next().unwrap_or_default() {
+            l if l.eq_ignore_ascii_case("en") => "en_us",
+            l if l.eq_ignore_ascii_case("ja") => "ja_jp",
+            _ => return Ok(MetadataTranslationDetails::default()),
+        };
+        let rsp = self
+            .client
+            .get(format!("{URL}/lookup"))
+            .query(&[
+                ("id", identifier),
+                ("media", "podcast"),
+                ("entity", "podcast"),
+                ("lang", lang),
+            ])
+            .send()
+            .await?;
+        let details: SearchResponse = rsp.json().await?;
+        let item = details
+            .results
+            .unwrap_or_default()
+            .into_iter()
+            .next()
+            .ok_or_else(|| anyhow!("No podcast found for the given id"))?;
+        Ok(MetadataTranslationDetails {
+            title: Some(item.collection_name),
+            description: item.description,
+        })
+    }
+
     async fn metadata_search(
         &self,
         page: u64,
@@ -208,6 +265,42 @@ impl MediaProvider for ITunesService {
             },
         })
     }
+
+    async fn get_trending_media(
+        &self,
+        list: TrendingMediaList,
+    ) -> Result<Vec<PartialMetadataWithoutId>> {
+        if list != TrendingMediaList::Popular {
+            bail!("iTunes only has charts of the most popular podcasts");
+        }
+        let country = self
+            .language
+            .split('_')
+            .nth(1)
+            .unwrap_or("us")
+            .to_lowercase();
+        let rsp = self
+            .client
+            .get(format!(
+                "{CHARTS_URL}/{country}/podcasts/top/50/podcasts.json"
+            ))
+            .send()
+            .await?;
+        let chart: ChartResponse = rsp.json().await?;
+        Ok(chart
+            .feed
+            .results
+            .into_iter()
+            .map(|p| PartialMetadataWithoutId {
+                title: p.name,
+                identifier: p.id,
+                lot: MediaLot::Podcast,
+                source: MediaSource::Itunes,
+                image: p.artwork_url_100,
+                ..Default::default()
+            })
+            .collect())
+    }
 }
 
 fn get_search_response(item: ITunesItem) -> MetadataSearchItem {
@@ -0,0 +1,72 @@
+use std::time::Duration;
+
+use anyhow::{Result, bail};
+use common_utils::{PAGE_SIZE, RequestThrottle, get_base_http_client};
+use reqwest::{Client, StatusCode};
+use serde::de::DeserializeOwned;
+
+use crate::models::API_URL;
+
+/// MusicBrainz allows a single request per second from each client, so requests are
+/// spaced out across all instances of the service.
+static THROTTLE: RequestThrottle = RequestThrottle::new(Duration::from_secs(1));
+
+pub struct MusicBrainzService {
+    client: Client,
+}
+
+impl MusicBrainzService {
+    pub async fn new() -> Result<Self> {
+        Ok(Self {
+            client: get_base_http_client(None),
+        })
+    }
+
+    pub(crate) async fn get_optional<T: DeserializeOwned>(
+        &self,
+        path: &str,
+        query: &[(&str, &str)],
+    ) -> Result<Option<T>> {
+        THROTTLE.wait().await;
+        let response = self
+            .client
+            .get(format!("{API_URL}/{path}"))
+            .query(&[("fmt", "json")])
+            .query(query)
+            .send()
+            .await?;
+        if response.status() == StatusCode::NOT_FOUND {
+            return Ok(None);
+        }
+        Ok(Some(response.error_for_status()?.json().await?))
+    }
+
+    pub(crate) async fn get<T: DeserializeOwned>(
+        &self,
+        path: &str,
+        query: &[(&str, &str)],
+    ) -> Result<T> {
+        match self.get_optional(path, query).await? {
+            Some(response) => Ok(response),
+            None => bail!("Could not find {path} on MusicBrainz"),
+        }
+    }
+
+    pub(crate) async fn search<T: DeserializeOwned>(
+        &self,
+        entity: &str,
+        query: &str,
+        page: u64,
+    ) -> Result<T> {
+        let offset = (page.saturating_sub(1) * PAGE_SIZE).to_string();
+        self.get(
+            entity,
+            &[
+                ("query", query),
+                ("offset", &offset),
+                ("limit", &PAGE_SIZE.to_string()),
+            ],
+        )
+        .await
+    }
+}
@@ -0,0 +1,8 @@
+mod base;
+pub use base::MusicBrainzService;
+
+mod models;
+mod provider;
+
+#[cfg(test)]
+mod tests;
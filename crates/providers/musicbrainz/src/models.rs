@@ -0,0 +1,289 @@
+use chrono::NaiveDate;
+use common_models::EntityAssets;
+use common_utils::convert_string_to_date;
+use database_models::metadata_group::MetadataGroupWithoutId;
+use enum_models::{MediaLot, MediaSource};
+use itertools::Itertools;
+use media_models::{PartialMetadataPerson, PartialMetadataWithoutId};
+use rust_decimal::Decimal;
+use serde::Deserialize;
+
+pub static URL: &str = "https://musicbrainz.org";
+pub static API_URL: &str = "https://musicbrainz.org/ws/2";
+pub static COVER_ART_URL: &str = "https://coverartarchive.org";
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct MusicBrainzNamed {
+    pub name: String,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct MusicBrainzArtistCredit {
+    pub artist: MusicBrainzArtist,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(rename_all = "kebab-case")]
+pub struct MusicBrainzLifeSpan {
+    pub end: Option<String>,
+    pub begin: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct MusicBrainzUrl {
+    pub resource: String,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct MusicBrainzRelation {
+    #[serde(rename = "type")]
+    pub relation_type: String,
+    pub url: Option<MusicBrainzUrl>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(rename_all = "kebab-case")]
+pub struct MusicBrainzArtist {
+    pub id: String,
+    pub name: String,
+    pub gender: Option<String>,
+    pub country: Option<String>,
+    pub area: Option<MusicBrainzNamed>,
+    pub disambiguation: Option<String>,
+    pub life_span: Option<MusicBrainzLifeSpan>,
+    pub begin_area: Option<MusicBrainzNamed>,
+    #[serde(default)]
+    pub genres: Vec<MusicBrainzNamed>,
+    #[serde(default)]
+    pub aliases: Vec<MusicBrainzNamed>,
+    #[serde(default)]
+    pub relations: Vec<MusicBrainzRelation>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(rename_all = "kebab-case")]
+pub struct MusicBrainzRating {
+    pub value: Option<Decimal>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(rename_all = "kebab-case")]
+pub struct MusicBrainzCoverArtArchive {
+    pub front: bool,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(rename_all = "kebab-case")]
+pub struct MusicBrainzTrack {
+    pub position: Option<i32>,
+    pub recording: Option<MusicBrainzRecording>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(rename_all = "kebab-case")]
+pub struct MusicBrainzMedium {
+    pub position: Option<i32>,
+    #[serde(default, alias = "track")]
+    pub tracks: Vec<MusicBrainzTrack>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(rename_all = "kebab-case")]
+pub struct MusicBrainzReleaseGroup {
+    pub id: String,
+    pub title: String,
+    pub primary_type: Option<String>,
+    pub disambiguation: Option<String>,
+    #[serde(default)]
+    pub genres: Vec<MusicBrainzNamed>,
+    #[serde(default)]
+    pub releases: Vec<MusicBrainzRelease>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(rename_all = "kebab-case")]
+pub struct MusicBrainzRelease {
+    pub id: String,
+    pub title: String,
+    pub date: Option<String>,
+    pub status: Option<String>,
+    pub disambiguation: Option<String>,
+    #[serde(default)]
+    pub media: Vec<MusicBrainzMedium>,
+    #[serde(default)]
+    pub genres: Vec<MusicBrainzNamed>,
+    pub release_group: Option<Box<MusicBrainzReleaseGroup>>,
+    pub cover_art_archive: Option<MusicBrainzCoverArtArchive>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(rename_all = "kebab-case")]
+pub struct MusicBrainzRecording {
+    pub id: String,
+    pub title: String,
+    pub length: Option<i32>,
+    pub video: Option<bool>,
+    pub disambiguation: Option<String>,
+    pub first_release_date: Option<String>,
+    pub rating: Option<MusicBrainzRating>,
+    #[serde(default)]
+    pub genres: Vec<MusicBrainzNamed>,
+    #[serde(default)]
+    pub releases: Vec<MusicBrainzRelease>,
+    #[serde(default)]
+    pub artist_credit: Vec<MusicBrainzArtistCredit>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct MusicBrainzRecordingSearchResponse {
+    pub count: u64,
+    pub recordings: Vec<MusicBrainzRecording>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(rename_all = "kebab-case")]
+pub struct MusicBrainzReleaseGroupSearchResponse {
+    pub count: u64,
+    pub release_groups: Vec<MusicBrainzReleaseGroup>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct MusicBrainzArtistSearchResponse {
+    pub count: u64,
+    pub artists: Vec<MusicBrainzArtist>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(rename_all = "kebab-case")]
+pub struct MusicBrainzReleaseGroupBrowseResponse {
+    pub release_groups: Vec<MusicBrainzReleaseGroup>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct MusicBrainzRecordingBrowseResponse {
+    pub recordings: Vec<MusicBrainzRecording>,
+}
+
+pub fn get_year(date: &Option<String>) -> Option<i32> {
+    date.as_ref()
+        .and_then(|d| d.get(..4))
+        .and_then(|y| y.parse().ok())
+}
+
+pub fn get_date(date: &Option<String>) -> Option<NaiveDate> {
+    date.as_deref().and_then(convert_string_to_date)
+}
+
+pub fn get_release_group_image(identifier: &str) -> String {
+    format!("{COVER_ART_URL}/release-group/{identifier}/front-500")
+}
+
+/// Releases report whether they have artwork, in which case that is preferred over the
+/// artwork of the release group.
+pub fn get_release_images(release: &MusicBrainzRelease) -> Vec<String> {
+    let mut images = vec![];
+    if release.cover_art_archive.as_ref().is_some_and(|c| c.front) {
+        images.push(format!("{COVER_ART_URL}/release/{}/front", release.id));
+    }
+    if let Some(group) = &release.release_group {
+        images.push(format!("{COVER_ART_URL}/release-group/{}/front", group.id));
+    }
+    images
+}
+
+pub fn get_recording_image(recording: &MusicBrainzRecording) -> Option<String> {
+    recording
+        .releases
+        .iter()
+        .find_map(|r| r.release_group.as_ref())
+        .map(|g| get_release_group_image(&g.id))
+}
+
+pub fn get_title_with_disambiguation(title: &str, disambiguation: &Option<String>) -> String {
+    match disambiguation.as_deref().filter(|d| !d.is_empty()) {
+        Some(disambiguation) => format!("{title} ({disambiguation})"),
+        None => title.to_owned(),
+    }
+}
+
+pub fn get_people(artist_credit: &[MusicBrainzArtistCredit]) -> Vec<PartialMetadataPerson> {
+    artist_credit
+        .iter()
+        .map(|credit| PartialMetadataPerson {
+            role: "Artist".to_owned(),
+            name: credit.artist.name.clone(),
+            source: MediaSource::MusicBrainz,
+            identifier: credit.artist.id.clone(),
+            ..Default::default()
+        })
+        .collect()
+}
+
+/// The tracks of a release group are taken from its earliest official release.
+pub fn get_main_release(releases: &[MusicBrainzRelease]) -> Option<&MusicBrainzRelease> {
+    releases
+        .iter()
+        .sorted_by_key(|r| {
+            (
+                r.status.as_deref() != Some("Official"),
+                get_date(&r.date).is_none(),
+                r.date.clone(),
+            )
+        })
+        .next()
+}
+
+pub fn release_to_group(
+    release: &MusicBrainzRelease,
+    release_group: Option<&MusicBrainzReleaseGroup>,
+) -> (MetadataGroupWithoutId, Vec<PartialMetadataWithoutId>) {
+    let publish_year = get_year(&release.date);
+    let image = get_release_images(release).into_iter().next();
+    let items = release
+        .media
+        .iter()
+        .flat_map(|m| &m.tracks)
+        .filter_map(|t| t.recording.as_ref())
+        .unique_by(|r| r.id.clone())
+        .map(|recording| PartialMetadataWithoutId {
+            image: image.clone(),
+            lot: MediaLot::Music,
+            identifier: recording.id.clone(),
+            title: recording.title.clone(),
+            source: MediaSource::MusicBrainz,
+            publish_year: get_year(&recording.first_release_date).or(publish_year),
+        })
+        .collect_vec();
+    let (identifier, title, source_url) = match release_group {
+        Some(group) => (
+            group.id.clone(),
+            get_title_with_disambiguation(&group.title, &group.disambiguation),
+            format!("{URL}/release-group/{}", group.id),
+        ),
+        None => (
+            release.id.clone(),
+            get_title_with_disambiguation(&release.title, &release.disambiguation),
+            format!("{URL}/release/{}", release.id),
+        ),
+    };
+    let genres = release_group
+        .map(|g| &g.genres)
+        .unwrap_or(&release.genres)
+        .iter()
+        .map(|g| g.name.clone())
+        .collect_vec();
+    let group = MetadataGroupWithoutId {
+        title,
+        identifier,
+        lot: MediaLot::Music,
+        parts: items.len() as i32,
+        source_url: Some(source_url),
+        source: MediaSource::MusicBrainz,
+        description: (!genres.is_empty()).then(|| format!("Genres: {}", genres.join(", "))),
+        assets: EntityAssets {
+            remote_images: get_release_images(release),
+            ..Default::default()
+        },
+    };
+    (group, items)
+}
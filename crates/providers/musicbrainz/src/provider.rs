@@ -0,0 +1,331 @@
+use anyhow::{Result, bail};
+use async_trait::async_trait;
+use common_models::{EntityAssets, PersonSourceSpecifics, SearchDetails};
+use common_utils::{PAGE_SIZE, compute_next_page};
+use database_models::metadata_group::MetadataGroupWithoutId;
+use dependent_models::{
+    MetadataGroupPersonRelated, MetadataPersonRelated, MetadataSearchSourceSpecifics,
+    PersonDetails, SearchResults,
+};
+use enum_models::{MediaLot, MediaSource};
+use futures::try_join;
+use itertools::Itertools;
+use media_models::{
+    CommitMetadataGroupInput, MetadataDetails, MetadataGroupSearchItem, MetadataSearchItem,
+    MusicSpecifics, PartialMetadataWithoutId, PeopleSearchItem, UniqueMediaIdentifier,
+};
+use rust_decimal::dec;
+use traits::MediaProvider;
+
+use crate::{
+    base::MusicBrainzService,
+    models::{
+        MusicBrainzArtist, MusicBrainzArtistSearchResponse, MusicBrainzRecording,
+        MusicBrainzRecordingBrowseResponse, MusicBrainzRecordingSearchResponse, MusicBrainzRelease,
+        MusicBrainzReleaseGroup, MusicBrainzReleaseGroupBrowseResponse,
+        MusicBrainzReleaseGroupSearchResponse, URL, get_date, get_main_release, get_people,
+        get_recording_image, get_release_group_image, get_release_images,
+        get_title_with_disambiguation, get_year, release_to_group,
+    },
+};
+
+#[async_trait]
+impl MediaProvider for MusicBrainzService {
+    async fn metadata_details(&self, identifier: &str) -> Result<MetadataDetails> {
+        let recording: MusicBrainzRecording = self
+            .get(
+                &format!("recording/{identifier}"),
+                &[(
+                    "inc",
+                    "artist-credits+releases+release-groups+media+genres+ratings",
+                )],
+            )
+            .await?;
+
+        let groups = recording
+            .releases
+            .iter()
+            .filter_map(|r| r.release_group.as_ref())
+            .unique_by(|g| g.id.clone())
+            .map(|group| CommitMetadataGroupInput {
+                name: get_title_with_disambiguation(&group.title, &group.disambiguation),
+                image: Some(get_release_group_image(&group.id)),
+                unique: UniqueMediaIdentifier {
+                    lot: MediaLot::Music,
+                    identifier: group.id.clone(),
+                    source: MediaSource::MusicBrainz,
+                },
+                ..Default::default()
+            })
+            .collect_vec();
+
+        let main_release = get_main_release(&recording.releases);
+        let medium = main_release.and_then(|r| r.media.first());
+        let music_specifics = MusicSpecifics {
+            disc_number: medium.and_then(|m| m.position),
+            duration: recording.length.map(|ms| ms / 1000),
+            by_various_artists: Some(recording.artist_credit.len() > 1),
+            track_number: medium
+                .and_then(|m| m.tracks.first())
+                .and_then(|t| t.position),
+            ..Default::default()
+        };
+
+        let mut genres = recording
+            .genres
+            .iter()
+            .map(|g| g.name.clone())
+            .collect_vec();
+        if genres.is_empty() {
+            genres = recording
+                .releases
+                .iter()
+                .filter_map(|r| r.release_group.as_ref())
+                .flat_map(|g| g.genres.iter().map(|g| g.name.clone()))
+                .unique()
+                .collect();
+        }
+
+        Ok(MetadataDetails {
+            groups,
+            genres,
+            is_nsfw: None,
+            music_specifics: Some(music_specifics),
+            people: get_people(&recording.artist_credit),
+            publish_date: get_date(&recording.first_release_date),
+            publish_year: get_year(&recording.first_release_date),
+            source_url: Some(format!("{URL}/recording/{}", recording.id)),
+            title: get_title_with_disambiguation(&recording.title, &recording.disambiguation),
+            provider_rating: recording.rating.and_then(|r| r.value).map(|v| v * dec!(20)),
+            assets: EntityAssets {
+                remote_images: main_release.map(get_release_images).unwrap_or_default(),
+                ..Default::default()
+            },
+            ..Default::default()
+        })
+    }
+
+    async fn metadata_search(
+        &self,
+        page: u64,
+        query: &str,
+        _display_nsfw: bool,
+        _source_specifics: &Option<MetadataSearchSourceSpecifics>,
+    ) -> Result<SearchResults<MetadataSearchItem>> {
+        let response: MusicBrainzRecordingSearchResponse =
+            self.search("recording", query, page).await?;
+        let items = response
+            .recordings
+            .into_iter()
+            .map(|recording| {
+                let artists = recording
+                    .artist_credit
+                    .iter()
+                    .map(|c| c.artist.name.as_str())
+                    .join(", ");
+                MetadataSearchItem {
+                    image: get_recording_image(&recording),
+                    publish_year: get_year(&recording.first_release_date),
+                    title: match artists.is_empty() {
+                        true => recording.title.clone(),
+                        false => format!("{} - {artists}", recording.title),
+                    },
+                    identifier: recording.id,
+                }
+            })
+            .collect();
+        Ok(SearchResults {
+            items,
+            details: SearchDetails {
+                total_items: response.count,
+                next_page: compute_next_page(page, PAGE_SIZE, response.count),
+            },
+        })
+    }
+
+    /// Groups can either be release groups or individual releases, both of which are
+    /// identified by a MBID.
+    async fn metadata_group_details(
+        &self,
+        identifier: &str,
+    ) -> Result<(MetadataGroupWithoutId, Vec<PartialMetadataWithoutId>)> {
+        let release_group: Option<MusicBrainzReleaseGroup> = self
+            .get_optional(
+                &format!("release-group/{identifier}"),
+                &[("inc", "releases+genres")],
+            )
+            .await?;
+        let inc = [("inc", "recordings+release-groups+genres")];
+        let Some(release_group) = release_group else {
+            let release: MusicBrainzRelease =
+                self.get(&format!("release/{identifier}"), &inc).await?;
+            return Ok(release_to_group(&release, None));
+        };
+        let Some(main_release) = get_main_release(&release_group.releases) else {
+            bail!("Release group {identifier} does not have any releases");
+        };
+        let release: MusicBrainzRelease = self
+            .get(&format!("release/{}", main_release.id), &inc)
+            .await?;
+        let (mut group, items) = release_to_group(&release, Some(&release_group));
+        group
+            .assets
+            .remote_images
+            .insert(0, get_release_group_image(&release_group.id));
+        group.assets.remote_images = group.assets.remote_images.into_iter().unique().collect();
+        Ok((group, items))
+    }
+
+    async fn metadata_group_search(
+        &self,
+        page: u64,
+        query: &str,
+        _display_nsfw: bool,
+    ) -> Result<SearchResults<MetadataGroupSearchItem>> {
+        let response: MusicBrainzReleaseGroupSearchResponse =
+            self.search("release-group", query, page).await?;
+        let items = response
+            .release_groups
+            .into_iter()
+            .map(|group| MetadataGroupSearchItem {
+                parts: None,
+                image: Some(get_release_group_image(&group.id)),
+                name: get_title_with_disambiguation(&group.title, &group.disambiguation),
+                identifier: group.id,
+            })
+            .collect();
+        Ok(SearchResults {
+            items,
+            details: SearchDetails {
+                total_items: response.count,
+                next_page: compute_next_page(page, PAGE_SIZE, response.count),
+            },
+        })
+    }
+
+    async fn people_search(
+        &self,
+        page: u64,
+        query: &str,
+        _display_nsfw: bool,
+        _source_specifics: &Option<PersonSourceSpecifics>,
+    ) -> Result<SearchResults<PeopleSearchItem>> {
+        let response: MusicBrainzArtistSearchResponse = self.search("artist", query, page).await?;
+        let items = response
+            .artists
+            .into_iter()
+            .map(|artist| PeopleSearchItem {
+                image: None,
+                identifier: artist.id,
+                name: get_title_with_disambiguation(&artist.name, &artist.disambiguation),
+                birth_year: get_year(&artist.life_span.and_then(|l| l.begin)),
+            })
+            .collect();
+        Ok(SearchResults {
+            items,
+            details: SearchDetails {
+                total_items: response.count,
+                next_page: compute_next_page(page, PAGE_SIZE, response.count),
+            },
+        })
+    }
+
+    async fn person_details(
+        &self,
+        identifier: &str,
+        _source_specifics: &Option<PersonSourceSpecifics>,
+    ) -> Result<PersonDetails> {
+        let artist_path = format!("artist/{identifier}");
+        let browse_query = [("artist", identifier), ("limit", "100")];
+        let (artist, release_groups, recordings) = try_join!(
+            self.get::<MusicBrainzArtist>(&artist_path, &[("inc", "aliases+genres+url-rels")]),
+            self.get::<MusicBrainzReleaseGroupBrowseResponse>("release-group", &browse_query),
+            self.get::<MusicBrainzRecordingBrowseResponse>("recording", &browse_query),
+        )?;
+
+        let related_metadata_groups = release_groups
+            .release_groups
+            .into_iter()
+            .map(|group| MetadataGroupPersonRelated {
+                role: "Artist".to_owned(),
+                metadata_group: MetadataGroupWithoutId {
+                    lot: MediaLot::Music,
+                    source: MediaSource::MusicBrainz,
+                    title: get_title_with_disambiguation(&group.title, &group.disambiguation),
+                    source_url: Some(format!("{URL}/release-group/{}", group.id)),
+                    description: group.primary_type.clone(),
+                    assets: EntityAssets {
+                        remote_images: vec![get_release_group_image(&group.id)],
+                        ..Default::default()
+                    },
+                    identifier: group.id,
+                    ..Default::default()
+                },
+            })
+            .collect();
+
+        let related_metadata = recordings
+            .recordings
+            .into_iter()
+            .filter(|r| !r.video.unwrap_or_default())
+            .map(|recording| MetadataPersonRelated {
+                role: "Artist".to_owned(),
+                metadata: PartialMetadataWithoutId {
+                    image: None,
+                    lot: MediaLot::Music,
+                    source: MediaSource::MusicBrainz,
+                    publish_year: get_year(&recording.first_release_date),
+                    title: get_title_with_disambiguation(
+                        &recording.title,
+                        &recording.disambiguation,
+                    ),
+                    identifier: recording.id,
+                },
+                ..Default::default()
+            })
+            .collect();
+
+        let genres = artist.genres.iter().map(|g| g.name.as_str()).join(", ");
+        let description = [
+            artist.disambiguation.clone().filter(|d| !d.is_empty()),
+            (!genres.is_empty()).then(|| format!("Genres: {genres}")),
+        ]
+        .into_iter()
+        .flatten()
+        .join("\n\n");
+        let alternate_names = artist
+            .aliases
+            .iter()
+            .map(|a| a.name.clone())
+            .filter(|a| a != &artist.name)
+            .unique()
+            .collect_vec();
+        let website = artist
+            .relations
+            .iter()
+            .find(|r| r.relation_type == "official homepage")
+            .and_then(|r| r.url.as_ref())
+            .map(|u| u.resource.clone());
+        let place = artist
+            .begin_area
+            .as_ref()
+            .or(artist.area.as_ref())
+            .map(|a| a.name.clone())
+            .or(artist.country.clone());
+
+        Ok(PersonDetails {
+            place,
+            website,
+            related_metadata,
+            related_metadata_groups,
+            gender: artist.gender.clone(),
+            source_url: Some(format!("{URL}/artist/{}", artist.id)),
+            description: (!description.is_empty()).then_some(description),
+            alternate_names: (!alternate_names.is_empty()).then_some(alternate_names),
+            birth_date: get_date(&artist.life_span.as_ref().and_then(|l| l.begin.clone())),
+            death_date: get_date(&artist.life_span.as_ref().and_then(|l| l.end.clone())),
+            name: artist.name,
+            ..Default::default()
+        })
+    }
+}
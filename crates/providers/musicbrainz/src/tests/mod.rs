@@ -0,0 +1,21 @@
+use enum_models::{MediaLot, MediaSource};
+
+use crate::models::{
+    MusicBrainzRecording, MusicBrainzRelease, get_date, get_main_release, get_people,
+    get_recording_image, get_release_images, get_title_with_disambiguation, get_year,
+    release_to_group,
+};
+
+mod recordings;
+mod releases;
+
+const RELEASE: &str = include_str!("fixtures/release.json");
+const RECORDING: &str = include_str!("fixtures/recording.json");
+
+fn release() -> MusicBrainzRelease {
+    serde_json::from_str(RELEASE).unwrap()
+}
+
+fn recording() -> MusicBrainzRecording {
+    serde_json::from_str(RECORDING).unwrap()
+}
@@ -0,0 +1,48 @@
+use super::*;
+
+#[test]
+fn test_get_people_credits_artists() {
+    let people = get_people(&recording().artist_credit);
+    assert_eq!(people.len(), 1);
+    assert_eq!(people[0].name, "Radiohead");
+    assert_eq!(people[0].role, "Artist");
+    assert_eq!(people[0].identifier, "a74b1b7f-71a5-4011-9441-d0b5e4122711");
+    assert_eq!(people[0].source, MediaSource::MusicBrainz);
+}
+
+#[test]
+fn test_get_recording_image_uses_first_release_group() {
+    assert_eq!(
+        get_recording_image(&recording()).as_deref(),
+        Some(
+            "https://coverartarchive.org/release-group/9e7e7d1c-5b2a-3f2c-8c8e-4b6c3b8a7f00/front-500"
+        )
+    );
+}
+
+#[test]
+fn test_get_title_with_disambiguation() {
+    let recording = recording();
+    assert_eq!(
+        get_title_with_disambiguation(&recording.title, &recording.disambiguation),
+        "Paranoid Android (album version)"
+    );
+    assert_eq!(
+        get_title_with_disambiguation("Airbag", &Some(String::new())),
+        "Airbag"
+    );
+    assert_eq!(get_title_with_disambiguation("Airbag", &None), "Airbag");
+}
+
+#[test]
+fn test_get_year_and_date_handle_partial_dates() {
+    assert_eq!(get_year(&Some("1997-05-21".to_owned())), Some(1997));
+    assert_eq!(get_year(&Some("1997".to_owned())), Some(1997));
+    assert_eq!(get_year(&Some("19".to_owned())), None);
+    assert_eq!(get_year(&None), None);
+    assert_eq!(
+        get_date(&Some("1997-05-21".to_owned())),
+        chrono::NaiveDate::from_ymd_opt(1997, 5, 21)
+    );
+    assert_eq!(get_date(&None), None);
+}
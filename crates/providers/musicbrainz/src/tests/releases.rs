@@ -0,0 +1,53 @@
+use super::*;
+
+#[test]
+fn test_release_to_group_uses_release_group_and_skips_repeated_tracks() {
+    let release = release();
+    let (group, items) = release_to_group(&release, release.release_group.as_deref());
+    assert_eq!(group.identifier, "b1392450-e666-3926-a536-22c65f834433");
+    assert_eq!(group.title, "OK Computer");
+    assert_eq!(group.lot, MediaLot::Music);
+    assert_eq!(group.parts, 2);
+    assert_eq!(
+        group.source_url.as_deref(),
+        Some("https://musicbrainz.org/release-group/b1392450-e666-3926-a536-22c65f834433")
+    );
+    let titles = items.iter().map(|i| i.title.as_str()).collect::<Vec<_>>();
+    assert_eq!(titles, vec!["Airbag", "Paranoid Android"]);
+    assert!(items.iter().all(|i| i.source == MediaSource::MusicBrainz));
+    assert_eq!(items[1].publish_year, Some(1997));
+}
+
+#[test]
+fn test_release_to_group_falls_back_to_release() {
+    let release = release();
+    let (group, _) = release_to_group(&release, None);
+    assert_eq!(group.identifier, "b84ee12a-09ef-421b-82de-0441a926375b");
+    assert_eq!(
+        group.description.as_deref(),
+        Some("Genres: alternative rock")
+    );
+}
+
+#[test]
+fn test_get_release_images_prefers_release_artwork() {
+    let mut release = release();
+    assert_eq!(
+        get_release_images(&release),
+        vec![
+            "https://coverartarchive.org/release/b84ee12a-09ef-421b-82de-0441a926375b/front",
+            "https://coverartarchive.org/release-group/b1392450-e666-3926-a536-22c65f834433/front",
+        ]
+    );
+    release.cover_art_archive = None;
+    release.release_group = None;
+    assert!(get_release_images(&release).is_empty());
+}
+
+#[test]
+fn test_get_main_release_prefers_earliest_official_release() {
+    let recording = recording();
+    let main_release = get_main_release(&recording.releases).unwrap();
+    assert_eq!(main_release.id, "b84ee12a-09ef-421b-82de-0441a926375b");
+    assert!(get_main_release(&[]).is_none());
+}
@@ -2,7 +2,7 @@ use std::sync::Arc;
 
 use anyhow::{Result, bail};
 use async_trait::async_trait;
-use common_models::{EntityAssets, PersonSourceSpecifics, SearchDetails};
+use common_models::{EntityAssets, PersonSourceSpecifics, SearchDetails, TrendingMediaList};
 use common_utils::get_base_http_client;
 use common_utils::{PAGE_SIZE, compute_next_page, convert_date_to_year, convert_string_to_date};
 use data_encoding::BASE64;
@@ -104,6 +104,11 @@ struct SpotifyArtistSearchResponse {
     artists: SpotifyResponse<SpotifyArtist>,
 }
 
+#[derive(Debug, Serialize, Deserialize, Clone)]
+struct SpotifyAlbumsResponse {
+    albums: Vec<Option<SpotifyAlbum>>,
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone)]
 struct SpotifyArtistTopTracksResponse {
     tracks: Vec<SpotifyTrack>,
@@ -362,6 +367,58 @@ impl MediaProvider for SpotifyService {
         })
     }
 
+    async fn get_trending_media(
+        &self,
+        list: TrendingMediaList,
+    ) -> Result<Vec<PartialMetadataWithoutId>> {
+        if list != TrendingMediaList::Upcoming {
+            bail!("Spotify only lists new releases");
+        }
+        let new_releases: SpotifyAlbumSearchResponse = self
+            .client
+            .get(format!("{SPOTIFY_API_URL}/browse/new-releases"))
+            .query(&[("limit", "20")])
+            .send()
+            .await?
+            .json()
+            .await?;
+        let album_ids = new_releases
+            .albums
+            .items
+            .into_iter()
+            .filter_map(|a| a.id)
+            .collect::<Vec<_>>();
+        if album_ids.is_empty() {
+            return Ok(vec![]);
+        }
+        // the new releases endpoint only lists albums, so the lead track of each stands in for it
+        let albums: SpotifyAlbumsResponse = self
+            .client
+            .get(format!("{SPOTIFY_API_URL}/albums"))
+            .query(&[("ids", album_ids.join(","))])
+            .send()
+            .await?
+            .json()
+            .await?;
+        Ok(albums
+            .albums
+            .into_iter()
+            .flatten()
+            .filter_map(|album| {
+                let image = get_first_image(&album.images);
+                let track = album.tracks?.items.into_iter().next()?;
+                Some(PartialMetadataWithoutId {
+                    image,
+                    title: track.name,
+                    identifier: track.id,
+                    lot: MediaLot::Music,
+                    source: MediaSource::Spotify,
+                    ..Default::default()
+                })
+            })
+            .collect())
+    }
+
     async fn metadata_group_details(
         &self,
         identifier: &str,
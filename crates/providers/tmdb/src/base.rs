@@ -1,7 +1,7 @@
 use std::{collections::HashSet, future::Future, sync::Arc};
 
 use anyhow::{Result, bail};
-use common_models::MetadataLookupCacheInput;
+use common_models::{MetadataLookupCacheInput, TrendingMediaList};
 use common_utils::{convert_date_to_year, get_base_http_client, ryot_log};
 use dependent_models::{ApplicationCacheKey, ApplicationCacheValue, TmdbLanguage, TmdbSettings};
 use enum_models::{MediaLot, MediaSource};
@@ -9,8 +9,10 @@ use futures::{
     stream::{self, StreamExt},
     try_join,
 };
+use itertools::Itertools;
 use media_models::{
-    MetadataExternalIdentifiers, PartialMetadataWithoutId, TmdbMetadataLookupResult, WatchProvider,
+    MetadataExternalIdentifiers, MetadataTranslationDetails, PartialMetadataWithoutId,
+    TmdbMetadataLookupResult, WatchProvider,
 };
 use reqwest::{
     Client,
@@ -189,6 +191,43 @@ impl TmdbService {
         }
     }
 
+    /// Get the translation for a language like `zh` or, to pick a region, `zh-TW`. TMDB
+    /// returns empty strings for fields that have not been translated.
+    pub async fn get_translation(
+        &self,
+        media_type: &str,
+        identifier: &str,
+        language: &str,
+    ) -> Result<MetadataTranslationDetails> {
+        let rsp: TmdbTranslationsResponse = self
+            .client
+            .get(format!("{URL}/{media_type}/{identifier}/translations"))
+            .send()
+            .await?
+            .json()
+            .await?;
+        let (iso_639_1, iso_3166_1) = match language.split_once('-') {
+            Some((language, region)) => (language, Some(region)),
+            None => (language, None),
+        };
+        let candidates = rsp
+            .translations
+            .into_iter()
+            .filter(|t| t.iso_639_1.eq_ignore_ascii_case(iso_639_1))
+            .collect_vec();
+        let translation = candidates
+            .iter()
+            .find(|t| iso_3166_1.is_some_and(|r| t.iso_3166_1.eq_ignore_ascii_case(r)))
+            .or(candidates.first())
+            .map(|t| t.data.clone())
+            .unwrap_or_default();
+        let non_empty = |s: Option<String>| s.filter(|s| !s.trim().is_empty());
+        Ok(MetadataTranslationDetails {
+            title: non_empty(translation.title),
+            description: non_empty(translation.overview),
+        })
+    }
+
     pub async fn get_external_identifiers(
         &self,
         media_type: &str,
@@ -283,15 +322,22 @@ impl TmdbService {
     pub async fn get_trending_media(
         &self,
         media_type: &str,
+        list: TrendingMediaList,
     ) -> Result<Vec<PartialMetadataWithoutId>> {
         let media_lot = match media_type {
             "movie" => MediaLot::Movie,
             "tv" => MediaLot::Show,
             _ => bail!("Invalid media type"),
         };
+        let path = match (list, media_lot) {
+            (TrendingMediaList::Trending, _) => format!("trending/{media_type}/day"),
+            (TrendingMediaList::Popular, _) => format!("{media_type}/popular"),
+            (TrendingMediaList::Upcoming, MediaLot::Movie) => "movie/upcoming".to_owned(),
+            (TrendingMediaList::Upcoming, _) => "tv/on_the_air".to_owned(),
+        };
 
         self.fetch_paginated_data(
-            format!("{URL}/trending/{media_type}/day"),
+            format!("{URL}/{path}"),
             &[("page", "1"), ("language", self.language.as_str())],
             Some(3),
             |entry| async move {
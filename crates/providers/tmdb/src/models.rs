@@ -188,6 +188,25 @@ pub struct TmdbConfiguration {
     pub images: TmdbImageConfiguration,
 }
 
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct TmdbTranslationData {
+    #[serde(alias = "name")]
+    pub title: Option<String>,
+    pub overview: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct TmdbTranslation {
+    pub iso_639_1: String,
+    pub iso_3166_1: String,
+    pub data: TmdbTranslationData,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct TmdbTranslationsResponse {
+    pub translations: Vec<TmdbTranslation>,
+}
+
 pub async fn fetch_company_media_by_type(
     media_type: String,
     identifier: &str,
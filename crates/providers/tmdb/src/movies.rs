@@ -4,6 +4,7 @@ use anyhow::Result;
 use async_trait::async_trait;
 use common_models::{
     EntityAssets, EntityRemoteVideo, EntityRemoteVideoSource, PersonSourceSpecifics, SearchDetails,
+    TrendingMediaList,
 };
 use common_utils::{convert_date_to_year, convert_string_to_date};
 use database_models::metadata_group::MetadataGroupWithoutId;
@@ -13,7 +14,8 @@ use futures::try_join;
 use itertools::Itertools;
 use media_models::{
     CommitMetadataGroupInput, MetadataDetails, MetadataGroupSearchItem, MetadataSearchItem,
-    MovieSpecifics, PartialMetadataPerson, PartialMetadataWithoutId, UniqueMediaIdentifier,
+    MetadataTranslationDetails, MovieSpecifics, PartialMetadataPerson, PartialMetadataWithoutId,
+    UniqueMediaIdentifier,
 };
 use rust_decimal::dec;
 use supporting_service::SupportingService;
@@ -227,6 +229,14 @@ impl MediaProvider for TmdbMovieService {
         })
     }
 
+    async fn metadata_translation(
+        &self,
+        identifier: &str,
+        language: &str,
+    ) -> Result<MetadataTranslationDetails> {
+        self.0.get_translation("movie", identifier, language).await
+    }
+
     async fn metadata_group_search(
         &self,
         page: u64,
@@ -322,8 +332,11 @@ impl MediaProvider for TmdbMovieService {
         ))
     }
 
-    async fn get_trending_media(&self) -> Result<Vec<PartialMetadataWithoutId>> {
-        self.0.get_trending_media("movie").await
+    async fn get_trending_media(
+        &self,
+        list: TrendingMediaList,
+    ) -> Result<Vec<PartialMetadataWithoutId>> {
+        self.0.get_trending_media("movie", list).await
     }
 }
 
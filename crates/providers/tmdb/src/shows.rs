@@ -4,6 +4,7 @@ use anyhow::Result;
 use async_trait::async_trait;
 use common_models::{
     EntityAssets, EntityRemoteVideo, EntityRemoteVideoSource, PersonSourceSpecifics, SearchDetails,
+    TrendingMediaList,
 };
 use common_utils::{SHOW_SPECIAL_SEASON_NAMES, convert_date_to_year, convert_string_to_date};
 use dependent_models::{MetadataSearchSourceSpecifics, SearchResults};
@@ -15,8 +16,8 @@ use futures::{
 use hashbag::HashBag;
 use itertools::Itertools;
 use media_models::{
-    MetadataDetails, MetadataSearchItem, PartialMetadataPerson, PartialMetadataWithoutId,
-    ShowEpisode, ShowSeason, ShowSpecifics,
+    MetadataDetails, MetadataSearchItem, MetadataTranslationDetails, PartialMetadataPerson,
+    PartialMetadataWithoutId, ShowEpisode, ShowSeason, ShowSpecifics,
 };
 use rust_decimal::dec;
 use supporting_service::SupportingService;
@@ -247,6 +248,14 @@ impl MediaProvider for TmdbShowService {
         })
     }
 
+    async fn metadata_translation(
+        &self,
+        identifier: &str,
+        language: &str,
+    ) -> Result<MetadataTranslationDetails> {
+        self.0.get_translation("tv", identifier, language).await
+    }
+
     async fn metadata_search(
         &self,
         page: u64,
@@ -287,8 +296,11 @@ impl MediaProvider for TmdbShowService {
         })
     }
 
-    async fn get_trending_media(&self) -> Result<Vec<PartialMetadataWithoutId>> {
-        self.0.get_trending_media("tv").await
+    async fn get_trending_media(
+        &self,
+        list: TrendingMediaList,
+    ) -> Result<Vec<PartialMetadataWithoutId>> {
+        self.0.get_trending_media("tv", list).await
     }
 }
 
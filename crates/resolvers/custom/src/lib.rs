@@ -16,6 +16,10 @@ impl AuthProvider for CustomMutationResolver {
     fn is_mutation(&self) -> bool {
         true
     }
+
+    fn is_media_resolver(&self) -> bool {
+        true
+    }
 }
 
 impl GraphqlResolverSvc<CustomService> for CustomMutationResolver {}
@@ -6,8 +6,9 @@ use dependent_models::{
     UserWorkoutsListResponse, UserWorkoutsTemplatesListResponse,
 };
 use fitness_models::{
-    UpdateUserExerciseSettings, UpdateUserWorkoutAttributesInput, UserExercisesListInput,
-    UserMeasurementsListInput, UserWorkoutInput,
+    ExerciseProgressionSuggestion, UpdateUserExerciseSettings, UpdateUserWorkoutAttributesInput,
+    UserExercisesListInput, UserMeasurementsListInput, UserWorkoutInput,
+    UserWorkoutProgressionSuggestionsInput,
 };
 use fitness_service::FitnessService;
 use sea_orm::prelude::DateTimeUtc;
@@ -94,6 +95,18 @@ impl FitnessQueryResolver {
         Ok(service.user_exercise_details(user_id, exercise_id).await?)
     }
 
+    /// Get the suggested targets for the next session of each exercise in a workout or template.
+    async fn user_workout_progression_suggestions(
+        &self,
+        gql_ctx: &Context<'_>,
+        input: UserWorkoutProgressionSuggestionsInput,
+    ) -> Result<Vec<ExerciseProgressionSuggestion>> {
+        let (service, user_id) = self.svc_and_user(gql_ctx).await?;
+        Ok(service
+            .user_workout_progression_suggestions(user_id, input)
+            .await?)
+    }
+
     /// Get all the measurements for a user.
     async fn user_measurements_list(
         &self,
@@ -12,7 +12,11 @@ use traits::{AuthProvider, GraphqlResolverSvc};
 #[derive(Default)]
 pub struct MiscellaneousGroupingQueryResolver;
 
-impl AuthProvider for MiscellaneousGroupingQueryResolver {}
+impl AuthProvider for MiscellaneousGroupingQueryResolver {
+    fn is_media_resolver(&self) -> bool {
+        true
+    }
+}
 impl GraphqlResolverSvc<MiscellaneousService> for MiscellaneousGroupingQueryResolver {}
 
 #[Object]
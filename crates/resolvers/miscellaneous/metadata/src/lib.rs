@@ -10,7 +10,11 @@ use traits::{AuthProvider, GraphqlResolverSvc};
 #[derive(Default)]
 pub struct MiscellaneousMetadataQueryResolver;
 
-impl AuthProvider for MiscellaneousMetadataQueryResolver {}
+impl AuthProvider for MiscellaneousMetadataQueryResolver {
+    fn is_media_resolver(&self) -> bool {
+        true
+    }
+}
 
 impl GraphqlResolverSvc<MiscellaneousService> for MiscellaneousMetadataQueryResolver {}
 
@@ -67,6 +71,10 @@ impl AuthProvider for MiscellaneousMetadataMutationResolver {
     fn is_mutation(&self) -> bool {
         true
     }
+
+    fn is_media_resolver(&self) -> bool {
+        true
+    }
 }
 
 impl GraphqlResolverSvc<MiscellaneousService> for MiscellaneousMetadataMutationResolver {}
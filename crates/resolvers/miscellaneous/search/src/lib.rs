@@ -11,7 +11,11 @@ use traits::{AuthProvider, GraphqlResolverSvc};
 #[derive(Default)]
 pub struct MiscellaneousSearchQueryResolver;
 
-impl AuthProvider for MiscellaneousSearchQueryResolver {}
+impl AuthProvider for MiscellaneousSearchQueryResolver {
+    fn is_media_resolver(&self) -> bool {
+        true
+    }
+}
 impl GraphqlResolverSvc<MiscellaneousService> for MiscellaneousSearchQueryResolver {}
 
 #[Object]
@@ -51,8 +55,8 @@ impl MiscellaneousSearchQueryResolver {
         &self,
         gql_ctx: &Context<'_>,
     ) -> Result<TrendingMetadataIdsResponse> {
-        let service = self.svc(gql_ctx);
-        Ok(service.trending_metadata().await?)
+        let (service, user_id) = self.svc_and_user(gql_ctx).await?;
+        Ok(service.trending_metadata(&user_id).await?)
     }
 
     /// Lookup metadata by title.
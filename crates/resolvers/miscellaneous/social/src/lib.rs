@@ -11,7 +11,11 @@ use traits::{AuthProvider, GraphqlResolverSvc};
 #[derive(Default)]
 pub struct MiscellaneousSocialQueryResolver;
 
-impl AuthProvider for MiscellaneousSocialQueryResolver {}
+impl AuthProvider for MiscellaneousSocialQueryResolver {
+    fn is_media_resolver(&self) -> bool {
+        true
+    }
+}
 
 impl GraphqlResolverSvc<MiscellaneousService> for MiscellaneousSocialQueryResolver {}
 
@@ -55,6 +59,10 @@ impl AuthProvider for MiscellaneousSocialMutationResolver {
     fn is_mutation(&self) -> bool {
         true
     }
+
+    fn is_media_resolver(&self) -> bool {
+        true
+    }
 }
 
 impl GraphqlResolverSvc<MiscellaneousService> for MiscellaneousSocialMutationResolver {}
@@ -10,7 +10,11 @@ use traits::{AuthProvider, GraphqlResolverSvc};
 #[derive(Default)]
 pub struct MiscellaneousTrackingQueryResolver;
 
-impl AuthProvider for MiscellaneousTrackingQueryResolver {}
+impl AuthProvider for MiscellaneousTrackingQueryResolver {
+    fn is_media_resolver(&self) -> bool {
+        true
+    }
+}
 impl GraphqlResolverSvc<MiscellaneousService> for MiscellaneousTrackingQueryResolver {}
 
 #[Object]
@@ -45,6 +49,10 @@ impl AuthProvider for MiscellaneousTrackingMutationResolver {
     fn is_mutation(&self) -> bool {
         true
     }
+
+    fn is_media_resolver(&self) -> bool {
+        true
+    }
 }
 impl GraphqlResolverSvc<MiscellaneousService> for MiscellaneousTrackingMutationResolver {}
 
@@ -46,6 +46,10 @@ impl AuthProvider for UserAuthenticationMutationResolver {
     fn is_mutation(&self) -> bool {
         true
     }
+
+    fn is_allowed_for_guests(&self) -> bool {
+        true
+    }
 }
 
 impl GraphqlResolverSvc<UserService> for UserAuthenticationMutationResolver {}
@@ -1,13 +1,18 @@
 use async_graphql::{Context, Object, Result};
-use common_models::StringIdObject;
-use database_models::access_link;
-use dependent_models::{BasicUserDetails, UserDetailsResult};
+use common_models::{SearchInput, StringIdObject};
+use database_models::{access_link, share_link, user_invite};
+use dependent_models::{
+    BasicUserDetails, SharedEntityDetails, SharedEntityDetailsInput, UserActivityFeedResponse,
+    UserDetailsResult, UserFollowsResponse, UserWatchGroupDetails,
+};
 use media_models::{
-    CreateAccessLinkInput, GetPasswordChangeSessionInput, GetPasswordChangeSessionResponse,
-    ProcessAccessLinkInput, ProcessAccessLinkResult, SetPasswordViaSessionInput, UserResetResult,
+    CreateAccessLinkInput, CreateOrUpdateWatchGroupInput, CreateShareLinkInput,
+    GetPasswordChangeSessionInput, GetPasswordChangeSessionResponse, ProcessAccessLinkInput,
+    ProcessAccessLinkResult, SetPasswordViaSessionInput, UpdateWatchGroupExclusionInput,
+    UserResetResult,
 };
 use traits::{AuthProvider, GraphqlResolverSvc};
-use user_models::{UpdateUserInput, UserPreferences};
+use user_models::{CreateUserInviteInput, UpdateUserInput, UserPreferences};
 use user_service::UserService;
 
 #[derive(Default)]
@@ -40,6 +45,50 @@ impl UserManagementQueryResolver {
         let (service, user_id) = self.svc_and_user(gql_ctx).await?;
         Ok(service.user_access_links(&user_id).await?)
     }
+
+    /// Get all share links created by the currently logged in user.
+    async fn user_share_links(&self, gql_ctx: &Context<'_>) -> Result<Vec<share_link::Model>> {
+        let (service, user_id) = self.svc_and_user(gql_ctx).await?;
+        Ok(service.user_share_links(&user_id).await?)
+    }
+
+    /// Get all the invites created in the service. Only available to admins.
+    async fn user_invites(&self, gql_ctx: &Context<'_>) -> Result<Vec<user_invite::Model>> {
+        let (service, user_id) = self.svc_and_user(gql_ctx).await?;
+        Ok(service.user_invites(&user_id).await?)
+    }
+
+    /// Get the read-only details of an entity shared using a share link.
+    async fn shared_entity_details(
+        &self,
+        gql_ctx: &Context<'_>,
+        input: SharedEntityDetailsInput,
+    ) -> Result<SharedEntityDetails> {
+        let service = self.svc(gql_ctx);
+        Ok(service.shared_entity_details(input).await?)
+    }
+
+    /// Get the users following and followed by the currently logged in user.
+    async fn user_follows(&self, gql_ctx: &Context<'_>) -> Result<UserFollowsResponse> {
+        let (service, user_id) = self.svc_and_user(gql_ctx).await?;
+        Ok(service.user_follows(&user_id).await?)
+    }
+
+    /// Get the activity of the users followed by the currently logged in user.
+    async fn user_activity_feed(
+        &self,
+        gql_ctx: &Context<'_>,
+        input: SearchInput,
+    ) -> Result<UserActivityFeedResponse> {
+        let (service, user_id) = self.svc_and_user(gql_ctx).await?;
+        Ok(service.user_activity_feed(&user_id, input).await?)
+    }
+
+    /// Get all watch groups the currently logged in user is a member of.
+    async fn user_watch_groups(&self, gql_ctx: &Context<'_>) -> Result<Vec<UserWatchGroupDetails>> {
+        let (service, user_id) = self.svc_and_user(gql_ctx).await?;
+        Ok(service.user_watch_groups(&user_id).await?)
+    }
 }
 
 #[derive(Default)]
@@ -71,7 +120,8 @@ impl UserManagementMutationResolver {
         Ok(service.reset_user(user_id, to_reset_user_id).await?)
     }
 
-    /// Update a user's profile details.
+    /// Update a user's profile details. Changing the role or limits of a user is only
+    /// available to admins.
     async fn update_user(
         &self,
         gql_ctx: &Context<'_>,
@@ -142,4 +192,115 @@ impl UserManagementMutationResolver {
         let (service, _) = self.svc_and_user(gql_ctx).await?;
         Ok(service.revoke_access_link(access_link_id).await?)
     }
+
+    /// Create a public link to a single entity belonging to the currently logged in user.
+    async fn create_share_link(
+        &self,
+        gql_ctx: &Context<'_>,
+        input: CreateShareLinkInput,
+    ) -> Result<StringIdObject> {
+        let (service, user_id) = self.svc_and_user(gql_ctx).await?;
+        Ok(service.create_share_link(&user_id, input).await?)
+    }
+
+    /// Revoke a share link.
+    async fn revoke_share_link(
+        &self,
+        gql_ctx: &Context<'_>,
+        share_link_id: String,
+    ) -> Result<bool> {
+        let (service, user_id) = self.svc_and_user(gql_ctx).await?;
+        Ok(service.revoke_share_link(&user_id, share_link_id).await?)
+    }
+
+    /// Create an invite which allows registering a user with a predefined role, limits and
+    /// preferences. Only available to admins.
+    async fn create_user_invite(
+        &self,
+        gql_ctx: &Context<'_>,
+        input: CreateUserInviteInput,
+    ) -> Result<StringIdObject> {
+        let (service, user_id) = self.svc_and_user(gql_ctx).await?;
+        Ok(service.create_user_invite(&user_id, input).await?)
+    }
+
+    /// Delete an invite. Only available to admins.
+    async fn delete_user_invite(&self, gql_ctx: &Context<'_>, invite_id: String) -> Result<bool> {
+        let (service, user_id) = self.svc_and_user(gql_ctx).await?;
+        Ok(service.delete_user_invite(&user_id, invite_id).await?)
+    }
+
+    /// Follow another user to see their public activity in your feed.
+    async fn follow_user(&self, gql_ctx: &Context<'_>, to_follow_user_id: String) -> Result<bool> {
+        let (service, user_id) = self.svc_and_user(gql_ctx).await?;
+        Ok(service.follow_user(&user_id, to_follow_user_id).await?)
+    }
+
+    /// Stop following a user.
+    async fn unfollow_user(
+        &self,
+        gql_ctx: &Context<'_>,
+        to_unfollow_user_id: String,
+    ) -> Result<bool> {
+        let (service, user_id) = self.svc_and_user(gql_ctx).await?;
+        Ok(service.unfollow_user(&user_id, to_unfollow_user_id).await?)
+    }
+
+    /// Create or update a watch group whose members share progress on media they watch
+    /// together.
+    async fn create_or_update_watch_group(
+        &self,
+        gql_ctx: &Context<'_>,
+        input: CreateOrUpdateWatchGroupInput,
+    ) -> Result<StringIdObject> {
+        let (service, user_id) = self.svc_and_user(gql_ctx).await?;
+        Ok(service
+            .create_or_update_watch_group(&user_id, input)
+            .await?)
+    }
+
+    /// Delete a watch group created by the currently logged in user.
+    async fn delete_watch_group(
+        &self,
+        gql_ctx: &Context<'_>,
+        watch_group_id: String,
+    ) -> Result<bool> {
+        let (service, user_id) = self.svc_and_user(gql_ctx).await?;
+        Ok(service.delete_watch_group(&user_id, watch_group_id).await?)
+    }
+
+    /// Accept an invitation to a watch group, after which progress is shared with its
+    /// members.
+    async fn accept_watch_group_invitation(
+        &self,
+        gql_ctx: &Context<'_>,
+        watch_group_id: String,
+    ) -> Result<bool> {
+        let (service, user_id) = self.svc_and_user(gql_ctx).await?;
+        Ok(service
+            .accept_watch_group_invitation(&user_id, watch_group_id)
+            .await?)
+    }
+
+    /// Leave a watch group or decline an invitation to it.
+    async fn leave_watch_group(
+        &self,
+        gql_ctx: &Context<'_>,
+        watch_group_id: String,
+    ) -> Result<bool> {
+        let (service, user_id) = self.svc_and_user(gql_ctx).await?;
+        Ok(service.leave_watch_group(&user_id, watch_group_id).await?)
+    }
+
+    /// Stop or resume sharing progress on a media within a watch group.
+    async fn update_watch_group_exclusion(
+        &self,
+        gql_ctx: &Context<'_>,
+        input: UpdateWatchGroupExclusionInput,
+    ) -> Result<bool> {
+        let (service, user_id) = self.svc_and_user(gql_ctx).await?;
+        Ok(service
+            .update_watch_group_exclusion(&user_id, input)
+            .await?)
+    }
 }
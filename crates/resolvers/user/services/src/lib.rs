@@ -1,11 +1,16 @@
 use async_graphql::{Context, Object, Result};
-use database_models::{integration, notification_platform};
-use dependent_models::{CachedResponse, UserMetadataRecommendationsResponse};
+use common_models::StringIdObject;
+use database_models::{automation_rule, integration, notification_delivery, notification_platform};
+use dependent_models::{
+    CachedResponse, UserMetadataRecommendationsResponse, UserNotificationsListResponse,
+};
 use media_models::{
-    CreateOrUpdateUserIntegrationInput, CreateUserNotificationPlatformInput,
-    UpdateUserNotificationPlatformInput,
+    CreateOrUpdateUserAutomationRuleInput, CreateOrUpdateUserIntegrationInput,
+    MarkUserNotificationsAsReadInput, UpdateUserNotificationPlatformInput,
+    UserNotificationsListInput, VerifyUserNotificationPlatformInput,
 };
 use traits::{AuthProvider, GraphqlResolverSvc};
+use user_models::CreateUserNotificationPlatformInput;
 use user_service::UserService;
 
 #[derive(Default)]
@@ -32,6 +37,15 @@ impl UserServicesQueryResolver {
         Ok(service.user_integrations(&user_id).await?)
     }
 
+    /// Get all the automation rules for the currently logged in user.
+    async fn user_automation_rules(
+        &self,
+        gql_ctx: &Context<'_>,
+    ) -> Result<Vec<automation_rule::Model>> {
+        let (service, user_id) = self.svc_and_user(gql_ctx).await?;
+        Ok(service.user_automation_rules(&user_id).await?)
+    }
+
     /// Get all the notification platforms for the currently logged in user.
     async fn user_notification_platforms(
         &self,
@@ -40,6 +54,29 @@ impl UserServicesQueryResolver {
         let (service, user_id) = self.svc_and_user(gql_ctx).await?;
         Ok(service.user_notification_platforms(&user_id).await?)
     }
+
+    /// Get the notifications in the inbox of the currently logged in user.
+    async fn user_notifications_list(
+        &self,
+        gql_ctx: &Context<'_>,
+        input: UserNotificationsListInput,
+    ) -> Result<UserNotificationsListResponse> {
+        let (service, user_id) = self.svc_and_user(gql_ctx).await?;
+        Ok(service.user_notifications_list(&user_id, input).await?)
+    }
+
+    /// Get the most recent deliveries of a notification platform for the currently logged
+    /// in user.
+    async fn user_notification_deliveries(
+        &self,
+        gql_ctx: &Context<'_>,
+        notification_id: String,
+    ) -> Result<Vec<notification_delivery::Model>> {
+        let (service, user_id) = self.svc_and_user(gql_ctx).await?;
+        Ok(service
+            .user_notification_deliveries(&user_id, notification_id)
+            .await?)
+    }
 }
 
 #[derive(Default)]
@@ -79,6 +116,30 @@ impl UserServicesMutationResolver {
             .await?)
     }
 
+    /// Create or update an automation rule for the currently logged in user.
+    async fn create_or_update_user_automation_rule(
+        &self,
+        gql_ctx: &Context<'_>,
+        input: CreateOrUpdateUserAutomationRuleInput,
+    ) -> Result<StringIdObject> {
+        let (service, user_id) = self.svc_and_user(gql_ctx).await?;
+        Ok(service
+            .create_or_update_user_automation_rule(user_id, input)
+            .await?)
+    }
+
+    /// Delete an automation rule for the currently logged in user.
+    async fn delete_user_automation_rule(
+        &self,
+        gql_ctx: &Context<'_>,
+        rule_id: String,
+    ) -> Result<bool> {
+        let (service, user_id) = self.svc_and_user(gql_ctx).await?;
+        Ok(service
+            .delete_user_automation_rule(user_id, rule_id)
+            .await?)
+    }
+
     /// Add a notification platform for the currently logged in user.
     async fn create_user_notification_platform(
         &self,
@@ -115,6 +176,44 @@ impl UserServicesMutationResolver {
             .await?)
     }
 
+    /// Verify the address of an email notification platform using the code sent to it, and
+    /// enable the platform.
+    async fn verify_user_notification_platform(
+        &self,
+        gql_ctx: &Context<'_>,
+        input: VerifyUserNotificationPlatformInput,
+    ) -> Result<bool> {
+        let (service, user_id) = self.svc_and_user(gql_ctx).await?;
+        Ok(service
+            .verify_user_notification_platform(user_id, input)
+            .await?)
+    }
+
+    /// Send a new verification code to an email notification platform that has not been
+    /// verified yet.
+    async fn resend_user_notification_platform_verification(
+        &self,
+        gql_ctx: &Context<'_>,
+        notification_id: String,
+    ) -> Result<bool> {
+        let (service, user_id) = self.svc_and_user(gql_ctx).await?;
+        Ok(service
+            .resend_user_notification_platform_verification(user_id, notification_id)
+            .await?)
+    }
+
+    /// Mark notifications in the inbox of the currently logged in user as read.
+    async fn mark_user_notifications_as_read(
+        &self,
+        gql_ctx: &Context<'_>,
+        input: MarkUserNotificationsAsReadInput,
+    ) -> Result<bool> {
+        let (service, user_id) = self.svc_and_user(gql_ctx).await?;
+        Ok(service
+            .mark_user_notifications_as_read(&user_id, input)
+            .await?)
+    }
+
     /// Test all notification platforms for the currently logged in user.
     async fn test_user_notification_platforms(&self, gql_ctx: &Context<'_>) -> Result<bool> {
         let (service, user_id) = self.svc_and_user(gql_ctx).await?;
@@ -38,6 +38,7 @@ fn get_expiry_for_key(ss: &Arc<SupportingService>, key: &ApplicationCacheKey) ->
         | ApplicationCacheKey::UserCollectionContents { .. }
         | ApplicationCacheKey::UserWorkoutTemplatesList { .. }
         | ApplicationCacheKey::EntityRecentlyConsumed { .. }
+        | ApplicationCacheKey::NotificationPlatformVerification { .. }
         | ApplicationCacheKey::UserMetadataRecommendations { .. } => Duration::hours(1),
 
         ApplicationCacheKey::MetadataProgressUpdateCompletedCache { .. } => {
@@ -56,8 +57,8 @@ fn get_expiry_for_key(ss: &Arc<SupportingService>, key: &ApplicationCacheKey) ->
         | ApplicationCacheKey::UserMetadataGroupDetails { .. }
         | ApplicationCacheKey::UserWorkoutTemplateDetails { .. } => Duration::hours(8),
 
-        ApplicationCacheKey::TrendingMetadataIds
-        | ApplicationCacheKey::MetadataLookup { .. }
+        ApplicationCacheKey::MetadataLookup { .. }
+        | ApplicationCacheKey::TrendingMetadataIds { .. }
         | ApplicationCacheKey::TmdbMultiSearch { .. }
         | ApplicationCacheKey::YoutubeMusicSongListened { .. }
         | ApplicationCacheKey::CollectionRecommendations { .. }
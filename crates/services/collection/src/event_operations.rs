@@ -1,6 +1,7 @@
 use std::{collections::HashSet, sync::Arc};
 
 use anyhow::{Result, anyhow};
+use background_models::{ApplicationJob, LpApplicationJob};
 use chrono::Utc;
 use common_models::CollectionExtraInformationLot;
 use database_models::{
@@ -8,8 +9,10 @@ use database_models::{
     user_to_entity,
 };
 use dependent_utility_utils::expire_user_collections_list_cache;
+use enum_models::AutomationRuleTrigger;
 use futures::future::try_join_all;
 use itertools::Itertools;
+use media_models::AutomationRuleEvent;
 use sea_orm::{
     ActiveModelTrait, ActiveValue, ColumnTrait, EntityTrait, IntoActiveModel, QueryFilter,
     QuerySelect,
@@ -19,6 +22,7 @@ use uuid::Uuid;
 
 pub async fn handle_entity_added_to_collection_event(
     collection_to_entity_id: Uuid,
+    triggered_by_automation: bool,
     ss: &Arc<SupportingService>,
 ) -> Result<()> {
     let (cte, collection) = CollectionToEntity::find_by_id(collection_to_entity_id)
@@ -27,6 +31,26 @@ pub async fn handle_entity_added_to_collection_event(
         .await?
         .ok_or_else(|| anyhow!("Collection to entity does not exist"))?;
     let collection = collection.ok_or_else(|| anyhow!("Collection does not exist"))?;
+    let users = UserToEntity::find()
+        .select_only()
+        .column(user_to_entity::Column::UserId)
+        .filter(user_to_entity::Column::CollectionId.eq(&cte.collection_id))
+        .into_tuple::<String>()
+        .all(&ss.db)
+        .await?;
+    for user_id in users.iter() {
+        ss.perform_application_job(ApplicationJob::Lp(
+            LpApplicationJob::HandleAutomationRuleEvent(AutomationRuleEvent {
+                user_id: user_id.to_owned(),
+                entity_lot: cte.entity_lot,
+                entity_id: cte.entity_id.clone(),
+                triggered_by_automation,
+                collection_name: Some(collection.name.clone()),
+                trigger: AutomationRuleTrigger::EntityAddedToCollection,
+            }),
+        ))
+        .await?;
+    }
     let mut fields = collection.clone().information_template.unwrap_or_default();
     if !fields
         .iter()
@@ -67,13 +91,6 @@ pub async fn handle_entity_added_to_collection_event(
     col.information_template = ActiveValue::Set(Some(fields));
     col.last_updated_on = ActiveValue::Set(Utc::now());
     col.update(&ss.db).await?;
-    let users = UserToEntity::find()
-        .select_only()
-        .column(user_to_entity::Column::UserId)
-        .filter(user_to_entity::Column::CollectionId.eq(&cte.collection_id))
-        .into_tuple::<String>()
-        .all(&ss.db)
-        .await?;
     try_join_all(
         users
             .into_iter()
@@ -6,8 +6,8 @@ use common_models::{
     ChangeCollectionToEntitiesInput, ReorderCollectionEntityInput, StringIdObject,
 };
 use dependent_collection_utils::{
-    add_entities_to_collection, create_or_update_collection, remove_entities_from_collection,
-    reorder_collection_entity,
+    add_entities_to_collection, collection_contents, create_or_update_collection,
+    remove_entities_from_collection, reorder_collection_entity, sync_user_smart_collections,
 };
 use dependent_entity_list_utils::user_collections_list;
 use dependent_models::{
@@ -18,7 +18,6 @@ use media_models::CreateOrUpdateCollectionInput;
 use supporting_service::SupportingService;
 use uuid::Uuid;
 
-mod content_operations;
 mod event_operations;
 mod management_operations;
 mod recommendation_operations;
@@ -38,7 +37,7 @@ impl CollectionService {
         user_id: &String,
         input: CollectionContentsInput,
     ) -> Result<CachedResponse<CollectionContentsResponse>> {
-        content_operations::collection_contents(user_id, input, &self.0).await
+        collection_contents(user_id, input, &self.0).await
     }
 
     pub async fn collection_recommendations(
@@ -114,8 +113,21 @@ impl CollectionService {
     pub async fn handle_entity_added_to_collection_event(
         &self,
         collection_to_entity_id: Uuid,
+        triggered_by_automation: bool,
     ) -> Result<()> {
-        event_operations::handle_entity_added_to_collection_event(collection_to_entity_id, &self.0)
-            .await
+        event_operations::handle_entity_added_to_collection_event(
+            collection_to_entity_id,
+            triggered_by_automation,
+            &self.0,
+        )
+        .await
+    }
+
+    pub async fn sync_user_smart_collections(
+        &self,
+        user_id: &String,
+        metadata_id: Option<&String>,
+    ) -> Result<()> {
+        sync_user_smart_collections(user_id, metadata_id, &self.0).await
     }
 }
@@ -0,0 +1,126 @@
+use std::{collections::HashSet, fs::File as StdFile, io::Write, sync::Arc};
+
+use anyhow::Result;
+use common_models::EntityAssets;
+use common_utils::ryot_log;
+use database_models::{
+    exercise, integration, notification_platform,
+    prelude::{Exercise, Integration, NotificationPlatform, Workout},
+    workout,
+};
+use dependent_models::{
+    ImportOrExportAssetItem, ImportOrExportIntegrationItem, ImportOrExportNotificationPlatformItem,
+};
+use sea_orm::{ColumnTrait, EntityTrait, QueryFilter};
+use struson::writer::{JsonStreamWriter, JsonWriter};
+use supporting_service::SupportingService;
+use zip::{CompressionMethod, ZipWriter, write::SimpleFileOptions};
+
+pub async fn export_integrations(
+    ss: &Arc<SupportingService>,
+    user_id: &String,
+    writer: &mut JsonStreamWriter<StdFile>,
+) -> Result<()> {
+    let integrations = Integration::find()
+        .filter(integration::Column::UserId.eq(user_id))
+        .all(&ss.db)
+        .await?;
+    for integration in integrations {
+        let exp = ImportOrExportIntegrationItem {
+            lot: integration.lot,
+            name: integration.name,
+            provider: integration.provider,
+            is_disabled: integration.is_disabled,
+            extra_settings: integration.extra_settings,
+            minimum_progress: integration.minimum_progress,
+            maximum_progress: integration.maximum_progress,
+            provider_specifics: integration.provider_specifics,
+            sync_to_owned_collection: integration.sync_to_owned_collection,
+        };
+        writer.serialize_value(&exp)?;
+    }
+    Ok(())
+}
+
+pub async fn export_notification_platforms(
+    ss: &Arc<SupportingService>,
+    user_id: &String,
+    writer: &mut JsonStreamWriter<StdFile>,
+) -> Result<()> {
+    let platforms = NotificationPlatform::find()
+        .filter(notification_platform::Column::UserId.eq(user_id))
+        .all(&ss.db)
+        .await?;
+    for platform in platforms {
+        let exp = ImportOrExportNotificationPlatformItem {
+            lot: platform.lot,
+            is_disabled: platform.is_disabled,
+            description: platform.description,
+            quiet_hours: platform.quiet_hours,
+            delivery_mode: platform.delivery_mode,
+            configured_events: platform.configured_events,
+            platform_specifics: platform.platform_specifics,
+        };
+        writer.serialize_value(&exp)?;
+    }
+    Ok(())
+}
+
+fn extend_with_asset_keys(keys: &mut HashSet<String>, assets: &EntityAssets) {
+    keys.extend(assets.s3_images.iter().cloned());
+    keys.extend(assets.s3_videos.iter().cloned());
+    keys.extend(assets.s3_files.iter().cloned());
+}
+
+/// Export the files in the file storage which are attached to the custom exercises and
+/// workouts of the user, so that they can be restored on another instance. Each file is
+/// written to the archive as its own entry and the JSON only references its path.
+pub async fn export_assets(
+    ss: &Arc<SupportingService>,
+    user_id: &String,
+    writer: &mut JsonStreamWriter<StdFile>,
+    archive: &mut ZipWriter<StdFile>,
+) -> Result<()> {
+    let mut keys = HashSet::new();
+    let exercises = Exercise::find()
+        .filter(exercise::Column::CreatedByUserId.eq(user_id))
+        .all(&ss.db)
+        .await?;
+    for exercise in exercises {
+        extend_with_asset_keys(&mut keys, &exercise.assets);
+    }
+    let workouts = Workout::find()
+        .filter(workout::Column::UserId.eq(user_id))
+        .all(&ss.db)
+        .await?;
+    for workout in workouts {
+        if let Some(assets) = &workout.information.assets {
+            extend_with_asset_keys(&mut keys, assets);
+        }
+        for exercise in workout.information.exercises {
+            if let Some(assets) = &exercise.assets {
+                extend_with_asset_keys(&mut keys, assets);
+            }
+        }
+    }
+    ryot_log!(debug, "Exporting {} assets", keys.len());
+    // Most assets are images and videos which are already compressed.
+    let options = SimpleFileOptions::default().compression_method(CompressionMethod::Stored);
+    for (idx, key) in keys.into_iter().enumerate() {
+        let Ok((data, content_type)) = file_storage_service::get_object(ss, key.clone()).await
+        else {
+            ryot_log!(debug, "Could not download asset with key {key}, skipping");
+            continue;
+        };
+        let path = format!("assets/{idx}");
+        archive.start_file(path.as_str(), options)?;
+        archive.write_all(&data)?;
+        let exp = ImportOrExportAssetItem {
+            key,
+            path,
+            content_type,
+        };
+        writer.serialize_value(&exp)?;
+    }
+    Ok(())
+}
@@ -1,10 +1,12 @@
-use std::{collections::HashMap, path::PathBuf, sync::Arc};
+use std::{collections::HashMap, fs::File as StdFile, io, path::PathBuf, sync::Arc};
 
 use anyhow::{Result, bail};
 use background_models::{ApplicationJob, MpApplicationJob};
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, Duration, Utc};
 use common_models::ExportJob;
-use common_utils::{get_temporary_directory, ryot_log};
+use common_utils::{EXPORT_ARCHIVE_DATA_FILE_NAME, get_temporary_directory, ryot_log};
+use database_utils::user_by_id;
+use dependent_models::ImportOrExportAccountItem;
 use nanoid::nanoid;
 use reqwest::{
     Body, Client,
@@ -15,17 +17,35 @@ use struson::writer::{JsonStreamWriter, JsonWriter};
 use supporting_service::SupportingService;
 use tokio::fs::File;
 use tokio_util::codec::{BytesCodec, FramedRead};
+use zip::{ZipWriter, write::SimpleFileOptions};
 
 use crate::{
+    account_exports::{export_assets, export_integrations, export_notification_platforms},
     collection_exports::export_collections,
     export_utilities::ExportItem,
     fitness_exports::{
-        export_exercises, export_measurements, export_workout_templates, export_workouts,
+        export_custom_exercises, export_exercises, export_measurements, export_workout_templates,
+        export_workouts,
     },
     media_exports::{export_media, export_media_group, export_people},
 };
 
 pub async fn deploy_export_job(ss: &Arc<SupportingService>, user_id: String) -> Result<bool> {
+    let limits = user_by_id(&user_id, ss).await?.limits.unwrap_or_default();
+    if limits.export_interval_hours.is_some() || limits.max_export_storage_bytes.is_some() {
+        let exports = user_exports(ss, user_id.clone()).await?;
+        if let Some(hours) = limits.export_interval_hours
+            && let Some(latest) = exports.first()
+            && latest.ended_at + Duration::hours(hours) > Utc::now()
+        {
+            bail!("You can only perform an export once every {hours} hours");
+        }
+        if let Some(max) = limits.max_export_storage_bytes
+            && exports.iter().map(|e| e.size).sum::<i64>() >= max
+        {
+            bail!("Your exports exceed the storage limit, delete some of them first");
+        }
+    }
     ss.perform_application_job(ApplicationJob::Mp(MpApplicationJob::PerformExport(user_id)))
         .await?;
     Ok(true)
@@ -62,12 +82,22 @@ pub async fn perform_export(ss: &Arc<SupportingService>, user_id: String) -> Res
         bail!("File storage needs to be enabled to perform an export.");
     }
     let started_at = Utc::now();
-    let export_path =
-        PathBuf::from(get_temporary_directory()).join(format!("ryot-export-{}.json", nanoid!()));
-    let file = std::fs::File::create(&export_path)?;
-    let mut writer = JsonStreamWriter::new(file);
+    let export_id = nanoid!();
+    let temporary_directory = PathBuf::from(get_temporary_directory());
+    let data_path = temporary_directory.join(format!("ryot-export-{export_id}.json"));
+    let export_path = temporary_directory.join(format!("ryot-export-{export_id}.zip"));
+    let mut archive = ZipWriter::new(StdFile::create(&export_path)?);
+    let mut writer = JsonStreamWriter::new(StdFile::create(&data_path)?);
     writer.begin_object()?;
 
+    let user = user_by_id(&user_id, ss).await?;
+    writer.name("account")?;
+    writer.serialize_value(&ImportOrExportAccountItem {
+        id: user.id,
+        name: user.name,
+        preferences: user.preferences,
+    })?;
+
     for export in ExportItem::iter() {
         ryot_log!(debug, "Exporting {export}");
         writer.name(&export.to_string())?;
@@ -80,14 +110,26 @@ pub async fn perform_export(ss: &Arc<SupportingService>, user_id: String) -> Res
             ExportItem::Collections => export_collections(ss, &user_id, &mut writer).await?,
             ExportItem::Measurements => export_measurements(ss, &user_id, &mut writer).await?,
             ExportItem::MetadataGroups => export_media_group(ss, &user_id, &mut writer).await?,
+            ExportItem::Assets => export_assets(ss, &user_id, &mut writer, &mut archive).await?,
+            ExportItem::Integrations => export_integrations(ss, &user_id, &mut writer).await?,
+            ExportItem::CustomExercises => {
+                export_custom_exercises(ss, &user_id, &mut writer).await?
+            }
             ExportItem::WorkoutTemplates => {
                 export_workout_templates(ss, &user_id, &mut writer).await?
             }
+            ExportItem::NotificationPlatforms => {
+                export_notification_platforms(ss, &user_id, &mut writer).await?
+            }
         };
         writer.end_array()?;
     }
     writer.end_object()?;
     writer.finish_document()?;
+    archive.start_file(EXPORT_ARCHIVE_DATA_FILE_NAME, SimpleFileOptions::default())?;
+    io::copy(&mut StdFile::open(&data_path)?, &mut archive)?;
+    archive.finish()?;
+    std::fs::remove_file(&data_path)?;
     ryot_log!(debug, "Exporting completed");
     let ended_at = Utc::now();
     let (_key, url) = file_storage_service::get_presigned_put_url(
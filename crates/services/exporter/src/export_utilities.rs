@@ -5,13 +5,17 @@ use sea_orm::{EnumIter, strum::Display};
 #[strum(serialize_all = "snake_case")]
 pub enum ExportItem {
     People,
+    Assets,
     Workouts,
     Metadata,
     Exercises,
     Collections,
+    Integrations,
     Measurements,
     MetadataGroups,
+    CustomExercises,
     WorkoutTemplates,
+    NotificationPlatforms,
 }
 
 pub fn get_review_export_item(rev: ReviewItem) -> ImportOrExportItemRating {
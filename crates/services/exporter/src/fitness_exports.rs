@@ -3,7 +3,7 @@ use std::{fs::File as StdFile, sync::Arc};
 use anyhow::{Result, anyhow};
 use common_models::SearchInput;
 use common_utils::ryot_log;
-use database_models::prelude::Exercise;
+use database_models::{exercise, prelude::Exercise};
 use database_utils::{
     entity_in_collections_with_details, item_reviews, user_workout_details,
     user_workout_template_details,
@@ -18,7 +18,7 @@ use dependent_models::{
 use enum_models::EntityLot;
 use fitness_models::{UserExercisesListInput, UserMeasurementsListInput};
 use itertools::Itertools;
-use sea_orm::EntityTrait;
+use sea_orm::{ColumnTrait, EntityTrait, QueryFilter};
 use struson::writer::{JsonStreamWriter, JsonWriter};
 use supporting_service::SupportingService;
 
@@ -74,7 +74,8 @@ pub async fn export_measurements(
 ) -> Result<()> {
     let measurements =
         user_measurements_list(user_id, ss, UserMeasurementsListInput::default()).await?;
-    for measurement in measurements.response {
+    for mut measurement in measurements.response {
+        measurement.information.computed_statistics.clear();
         writer.serialize_value(&measurement)?;
     }
     Ok(())
@@ -136,6 +137,22 @@ pub async fn export_exercises(
     Ok(())
 }
 
+pub async fn export_custom_exercises(
+    ss: &Arc<SupportingService>,
+    user_id: &String,
+    writer: &mut JsonStreamWriter<StdFile>,
+) -> Result<()> {
+    let exercises = Exercise::find()
+        .filter(exercise::Column::CreatedByUserId.eq(user_id))
+        .all(&ss.db)
+        .await?;
+    ryot_log!(debug, "Exporting {} custom exercises", exercises.len());
+    for exercise in exercises {
+        writer.serialize_value(&exercise)?;
+    }
+    Ok(())
+}
+
 pub async fn export_workout_templates(
     ss: &Arc<SupportingService>,
     user_id: &String,
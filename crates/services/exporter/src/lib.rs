@@ -4,6 +4,7 @@ use anyhow::Result;
 use common_models::ExportJob;
 use supporting_service::SupportingService;
 
+mod account_exports;
 mod collection_exports;
 mod export_operations;
 mod export_utilities;
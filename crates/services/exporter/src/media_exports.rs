@@ -62,6 +62,7 @@ pub async fn export_media(
                     let manga_chapter_number =
                         s.manga_extra_information.clone().and_then(|d| d.chapter);
                     let manga_volume_number = s.manga_extra_information.and_then(|d| d.volume);
+                    let board_game = s.board_game_extra_information;
                     ImportOrExportMetadataItemSeen {
                         show_season_number,
                         show_episode_number,
@@ -75,6 +76,9 @@ pub async fn export_media(
                         progress: Some(s.progress),
                         manual_time_spent: s.manual_time_spent,
                         providers_consumed_on: Some(s.providers_consumed_on),
+                        board_game_winner: board_game.clone().and_then(|d| d.winner),
+                        board_game_duration: board_game.clone().and_then(|d| d.duration),
+                        board_game_player_count: board_game.and_then(|d| d.player_count),
                     }
                 })
                 .collect();
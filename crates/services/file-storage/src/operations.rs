@@ -73,6 +73,52 @@ pub async fn delete_object(ss: &Arc<SupportingService>, key: String) -> Result<b
     Ok(response)
 }
 
+fn generate_key(prefix: String, with_uploads: bool) -> String {
+    let first = if with_uploads { "uploads/" } else { "" };
+    let id = nanoid!(10);
+    if prefix.is_empty() {
+        format!("{}{}", first, id)
+    } else {
+        format!("{}{}/{}", first, prefix, id)
+    }
+}
+
+pub async fn upload_object(
+    ss: &Arc<SupportingService>,
+    prefix: String,
+    body: Vec<u8>,
+    content_type: &str,
+) -> Result<String> {
+    let (s3_client, bucket_name) = get_client_and_bucket_name(&ss.config);
+    let key = generate_key(prefix, false);
+    s3_client
+        .put_object()
+        .bucket(bucket_name)
+        .key(&key)
+        .content_type(content_type)
+        .body(body.into())
+        .send()
+        .await?;
+    Ok(key)
+}
+
+/// Download an object, returning its contents and content type.
+pub async fn get_object(ss: &Arc<SupportingService>, key: String) -> Result<(Vec<u8>, String)> {
+    let (s3_client, bucket_name) = get_client_and_bucket_name(&ss.config);
+    let object = s3_client
+        .get_object()
+        .bucket(bucket_name)
+        .key(key)
+        .send()
+        .await?;
+    let content_type = object
+        .content_type
+        .clone()
+        .unwrap_or_else(|| "application/octet-stream".to_string());
+    let body = object.body.collect().await?.into_bytes().to_vec();
+    Ok((body, content_type))
+}
+
 pub async fn get_presigned_put_url(
     ss: &Arc<SupportingService>,
     prefix: String,
@@ -80,13 +126,7 @@ pub async fn get_presigned_put_url(
     metadata: Option<HashMap<String, String>>,
 ) -> Result<(String, String)> {
     let (s3_client, bucket_name) = get_client_and_bucket_name(&ss.config);
-    let first = if with_uploads { "uploads/" } else { "" };
-    let id = nanoid!(10);
-    let key = if prefix.is_empty() {
-        format!("{}{}", first, id)
-    } else {
-        format!("{}{}/{}", first, prefix, id)
-    };
+    let key = generate_key(prefix, with_uploads);
     let url = s3_client
         .put_object()
         .bucket(bucket_name)
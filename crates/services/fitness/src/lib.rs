@@ -13,8 +13,9 @@ use dependent_models::{
     UserWorkoutsListResponse, UserWorkoutsTemplatesListResponse,
 };
 use fitness_models::{
-    UpdateUserExerciseSettings, UpdateUserWorkoutAttributesInput, UserExercisesListInput,
-    UserMeasurementsListInput, UserWorkoutInput,
+    ExerciseProgressionSuggestion, UpdateUserExerciseSettings, UpdateUserWorkoutAttributesInput,
+    UserExercisesListInput, UserMeasurementsListInput, UserWorkoutInput,
+    UserWorkoutProgressionSuggestionsInput,
 };
 use sea_orm::prelude::DateTimeUtc;
 use supporting_service::SupportingService;
@@ -25,6 +26,9 @@ pub use exercise_management::*;
 mod measurement_operations;
 pub use measurement_operations::*;
 
+mod progression_operations;
+pub use progression_operations::*;
+
 mod system_operations;
 pub use system_operations::*;
 
@@ -154,6 +158,15 @@ impl FitnessService {
         workout_operations::revise_user_workouts(&self.0, user_id).await
     }
 
+    // Progression operations methods delegated to progression_operations module
+    pub async fn user_workout_progression_suggestions(
+        &self,
+        user_id: String,
+        input: UserWorkoutProgressionSuggestionsInput,
+    ) -> Result<Vec<ExerciseProgressionSuggestion>> {
+        progression_operations::user_workout_progression_suggestions(&self.0, user_id, input).await
+    }
+
     // Measurement operations methods delegated to measurement_operations module
     pub async fn user_measurements_list(
         &self,
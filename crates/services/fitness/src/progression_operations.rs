@@ -0,0 +1,131 @@
+use std::sync::Arc;
+
+use anyhow::{Result, bail};
+use database_models::{
+    prelude::{UserToEntity, Workout, WorkoutTemplate},
+    user_to_entity, workout, workout_template,
+};
+use database_utils::user_by_id;
+use dependent_fitness_utils::get_exercise_progression;
+use fitness_models::{
+    ExerciseProgressionSuggestion, SetLot, UserWorkoutProgressionSuggestionsInput,
+};
+use itertools::Itertools;
+use sea_orm::{ColumnTrait, EntityTrait, QueryFilter};
+use supporting_service::SupportingService;
+
+pub async fn user_workout_progression_suggestions(
+    ss: &Arc<SupportingService>,
+    user_id: String,
+    input: UserWorkoutProgressionSuggestionsInput,
+) -> Result<Vec<ExerciseProgressionSuggestion>> {
+    let exercises = match (input.workout_id, input.workout_template_id) {
+        (Some(workout_id), _) => {
+            let Some(workout) = Workout::find_by_id(workout_id)
+                .filter(workout::Column::UserId.eq(&user_id))
+                .one(&ss.db)
+                .await?
+            else {
+                bail!("Workout does not exist for user");
+            };
+            workout.information.exercises
+        }
+        (None, Some(workout_template_id)) => {
+            let Some(template) = WorkoutTemplate::find_by_id(workout_template_id)
+                .filter(workout_template::Column::UserId.eq(&user_id))
+                .one(&ss.db)
+                .await?
+            else {
+                bail!("Workout template does not exist for user");
+            };
+            template.information.exercises
+        }
+        (None, None) => bail!("Either a workout or a workout template must be provided"),
+    };
+    let preferences = user_by_id(&user_id, ss)
+        .await?
+        .preferences
+        .fitness
+        .exercises;
+    let settings = preferences.progression;
+    let sessions_to_consider = usize::from(settings.deload_after_failed_sessions.max(1));
+    let associations = UserToEntity::find()
+        .filter(user_to_entity::Column::UserId.eq(&user_id))
+        .filter(user_to_entity::Column::ExerciseId.is_in(exercises.iter().map(|e| &e.id)))
+        .all(&ss.db)
+        .await?;
+    let recent_histories = exercises
+        .iter()
+        .map(|exercise| {
+            associations
+                .iter()
+                .find(|a| a.exercise_id.as_ref() == Some(&exercise.id))
+                .and_then(|a| a.exercise_extra_information.clone())
+                .map(|e| {
+                    e.history
+                        .into_iter()
+                        .take(sessions_to_consider)
+                        .collect_vec()
+                })
+                .unwrap_or_default()
+        })
+        .collect_vec();
+    let workouts = Workout::find()
+        .filter(
+            workout::Column::Id.is_in(
+                recent_histories
+                    .iter()
+                    .flatten()
+                    .map(|h| &h.workout_id)
+                    .unique(),
+            ),
+        )
+        .all(&ss.db)
+        .await?;
+    let mut suggestions = vec![];
+    for ((exercise_idx, exercise), recent) in
+        exercises.into_iter().enumerate().zip(recent_histories)
+    {
+        let Some(based_on) = recent.first() else {
+            continue;
+        };
+        let sessions = recent
+            .iter()
+            .filter_map(|h| {
+                workouts
+                    .iter()
+                    .find(|w| w.id == h.workout_id)
+                    .and_then(|w| w.information.exercises.get(h.idx))
+            })
+            .map(|e| {
+                e.sets
+                    .iter()
+                    .filter(|s| s.lot != SetLot::WarmUp)
+                    .cloned()
+                    .collect_vec()
+            })
+            .collect_vec();
+        let num_sets = exercise
+            .sets
+            .iter()
+            .filter(|s| s.lot != SetLot::WarmUp)
+            .count();
+        let Some((action, sets)) = get_exercise_progression(
+            exercise.lot,
+            num_sets,
+            &sessions,
+            &settings,
+            preferences.one_rm_formula,
+        ) else {
+            continue;
+        };
+        suggestions.push(ExerciseProgressionSuggestion {
+            sets,
+            action,
+            exercise_idx,
+            exercise_id: exercise.id,
+            based_on_workout_id: based_on.workout_id.clone(),
+        });
+    }
+    Ok(suggestions)
+}
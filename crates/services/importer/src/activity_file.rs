@@ -0,0 +1,480 @@
+use std::{
+    collections::HashMap,
+    fs::{self, File},
+    io::{BufReader, Read},
+    path::Path,
+    sync::Arc,
+};
+
+use anyhow::{Result, bail};
+use chrono::{DateTime, Duration, Utc};
+use common_models::EntityAssets;
+use common_utils::ryot_log;
+use convert_case::{Case, Casing};
+use database_models::exercise;
+use dependent_models::{ImportCompletedItem, ImportResult};
+use enum_models::ExerciseLot;
+use fitness_models::{
+    SetLot, UserExerciseInput, UserWorkoutInput, UserWorkoutSetRecord, WorkoutSetStatistic,
+};
+use fitparser::{FitDataRecord, Value, profile::MesgNum};
+use flate2::read::GzDecoder;
+use importer_models::{ImportFailStep, ImportFailedItem};
+use itertools::Itertools;
+use media_models::DeployPathImportInput;
+use roxmltree::{Document, Node};
+use rust_decimal::{Decimal, prelude::FromPrimitive};
+use serde::Serialize;
+use serde_with::skip_serializing_none;
+use supporting_service::SupportingService;
+use zip::ZipArchive;
+
+use crate::utils;
+
+/// The distance (in meters) of each split when the activity does not have laps.
+const SPLIT_DISTANCE: f64 = 1000.0;
+const EARTH_RADIUS: f64 = 6_371_000.0;
+const SEMICIRCLES_TO_DEGREES: f64 = 180.0 / 2_147_483_648.0;
+
+/// The numeric activity types Strava writes in the `type` element of its GPX exports.
+const STRAVA_RIDE_TYPE: &str = "1";
+const STRAVA_HIKE_TYPE: &str = "4";
+const STRAVA_RUN_TYPE: &str = "9";
+const STRAVA_WALK_TYPE: &str = "10";
+
+#[skip_serializing_none]
+#[derive(Debug, Default, Clone, Serialize)]
+struct ActivityPoint {
+    time: Option<DateTime<Utc>>,
+    latitude: Option<f64>,
+    longitude: Option<f64>,
+    elevation: Option<f64>,
+    heart_rate: Option<f64>,
+    /// The distance (in meters) covered until this point.
+    distance: Option<f64>,
+}
+
+#[derive(Debug, Default)]
+struct ActivityLap {
+    /// In seconds.
+    duration: f64,
+    /// In meters.
+    distance: f64,
+    calories: Option<f64>,
+}
+
+#[derive(Debug, Default)]
+pub(crate) struct Activity {
+    name: Option<String>,
+    sport: Option<String>,
+    laps: Vec<ActivityLap>,
+    points: Vec<ActivityPoint>,
+    start_time: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Serialize)]
+struct ActivityStreams<'a> {
+    sport: &'a str,
+    points: &'a [ActivityPoint],
+}
+
+pub async fn import(
+    input: DeployPathImportInput,
+    ss: &Arc<SupportingService>,
+    user_id: &str,
+) -> Result<ImportResult> {
+    let mut completed = vec![];
+    let mut failed = vec![];
+    let mut unique_exercises: HashMap<String, exercise::Model> = HashMap::new();
+    let can_store_streams = file_storage_service::is_enabled(ss).await;
+    for (file_name, contents) in read_activity_files(&input.export_path)? {
+        let activity = match parse_activity(&file_name, &contents) {
+            Ok(activity) => activity,
+            Err(e) => {
+                failed.push(ImportFailedItem {
+                    identifier: file_name,
+                    error: Some(e.to_string()),
+                    step: ImportFailStep::InputTransformation,
+                    ..Default::default()
+                });
+                continue;
+            }
+        };
+        let Some((start_time, end_time, sets)) = get_activity_sets(&activity) else {
+            failed.push(ImportFailedItem {
+                identifier: file_name,
+                step: ImportFailStep::InputTransformation,
+                error: Some("Activity does not have any timed laps or track points".to_owned()),
+                ..Default::default()
+            });
+            continue;
+        };
+        let exercise_name = get_exercise_name(activity.sport.as_deref());
+        let exercise_id = utils::associate_with_existing_or_new_exercise(
+            user_id,
+            &exercise_name,
+            ExerciseLot::DistanceAndDuration,
+            ss,
+            &mut unique_exercises,
+        )
+        .await?;
+        let mut assets = None;
+        if can_store_streams && !activity.points.is_empty() {
+            let streams = serde_json::to_vec(&ActivityStreams {
+                sport: &exercise_name,
+                points: &activity.points,
+            })?;
+            match file_storage_service::upload_object(
+                ss,
+                format!("activities/{user_id}"),
+                streams,
+                "application/json",
+            )
+            .await
+            {
+                Ok(key) => {
+                    assets = Some(EntityAssets {
+                        s3_files: vec![key],
+                        ..Default::default()
+                    })
+                }
+                Err(e) => ryot_log!(debug, "Could not store streams of {file_name}: {e:?}"),
+            }
+        }
+        let calories_burnt = activity
+            .laps
+            .iter()
+            .filter_map(|l| l.calories)
+            .reduce(|a, b| a + b)
+            .and_then(Decimal::from_f64)
+            .map(|c| c.round_dp(0));
+        ryot_log!(debug, "Importing activity {file_name} as {exercise_name}");
+        completed.push(ImportCompletedItem::Workout(UserWorkoutInput {
+            assets,
+            end_time,
+            start_time,
+            calories_burnt,
+            name: activity.name.unwrap_or_else(|| exercise_name.clone()),
+            exercises: vec![UserExerciseInput {
+                sets,
+                exercise_id,
+                ..Default::default()
+            }],
+            ..Default::default()
+        }));
+    }
+    completed.extend(
+        unique_exercises
+            .into_values()
+            .map(ImportCompletedItem::Exercise),
+    );
+    Ok(ImportResult { failed, completed })
+}
+
+/// Returns the name and contents of every supported file at the path, which can be a
+/// single activity or a zip archive of them. Gzipped files are decompressed.
+fn read_activity_files(path: &str) -> Result<Vec<(String, Vec<u8>)>> {
+    let mut files = vec![];
+    if path.to_lowercase().ends_with(".zip") {
+        let mut archive = ZipArchive::new(BufReader::new(File::open(path)?))?;
+        for i in 0..archive.len() {
+            let mut file = archive.by_index(i)?;
+            if file.is_dir() {
+                continue;
+            }
+            let mut contents = vec![];
+            file.read_to_end(&mut contents)?;
+            files.push((file.name().to_owned(), contents));
+        }
+    } else {
+        let file_name = Path::new(path)
+            .file_name()
+            .map(|f| f.to_string_lossy().to_string())
+            .unwrap_or_else(|| path.to_owned());
+        files.push((file_name, fs::read(path)?));
+    }
+    let mut activity_files = vec![];
+    for (file_name, contents) in files {
+        let lowercase = file_name.to_lowercase();
+        let (stripped, contents) = match lowercase.strip_suffix(".gz") {
+            None => (lowercase.as_str(), contents),
+            Some(stripped) => {
+                let mut decompressed = vec![];
+                GzDecoder::new(contents.as_slice()).read_to_end(&mut decompressed)?;
+                (stripped, decompressed)
+            }
+        };
+        if [".gpx", ".tcx", ".fit"]
+            .iter()
+            .any(|e| stripped.ends_with(e))
+        {
+            activity_files.push((file_name, contents));
+        }
+    }
+    Ok(activity_files)
+}
+
+pub(crate) fn parse_activity(file_name: &str, contents: &[u8]) -> Result<Activity> {
+    let lowercase = file_name.to_lowercase();
+    let extension = lowercase.trim_end_matches(".gz").rsplit('.').next();
+    let mut activity = match extension {
+        Some("fit") => parse_fit(contents)?,
+        Some("gpx") => parse_gpx(std::str::from_utf8(contents)?.trim_start())?,
+        Some("tcx") => parse_tcx(std::str::from_utf8(contents)?.trim_start())?,
+        _ => bail!("Unsupported activity file"),
+    };
+    fill_point_distances(&mut activity.points);
+    Ok(activity)
+}
+
+fn child<'a, 'input>(node: Node<'a, 'input>, name: &str) -> Option<Node<'a, 'input>> {
+    node.children().find(|n| n.tag_name().name() == name)
+}
+
+fn child_text<'a>(node: Node<'a, '_>, name: &str) -> Option<&'a str> {
+    child(node, name).and_then(|n| n.text()).map(str::trim)
+}
+
+fn child_number(node: Node, name: &str) -> Option<f64> {
+    child_text(node, name).and_then(|t| t.parse().ok())
+}
+
+fn parse_time(text: &str) -> Option<DateTime<Utc>> {
+    DateTime::parse_from_rfc3339(text)
+        .ok()
+        .map(|d| d.with_timezone(&Utc))
+}
+
+fn parse_gpx(text: &str) -> Result<Activity> {
+    let document = Document::parse(text)?;
+    let root = document.root_element();
+    let track = root.descendants().find(|n| n.tag_name().name() == "trk");
+    let points = root
+        .descendants()
+        .filter(|n| n.tag_name().name() == "trkpt")
+        .map(|point| ActivityPoint {
+            latitude: point.attribute("lat").and_then(|l| l.parse().ok()),
+            longitude: point.attribute("lon").and_then(|l| l.parse().ok()),
+            elevation: child_number(point, "ele"),
+            time: child_text(point, "time").and_then(parse_time),
+            heart_rate: point
+                .descendants()
+                .find(|n| n.tag_name().name() == "hr")
+                .and_then(|n| n.text())
+                .and_then(|t| t.trim().parse().ok()),
+            ..Default::default()
+        })
+        .collect_vec();
+    let metadata_time = child(root, "metadata").and_then(|m| child_text(m, "time"));
+    Ok(Activity {
+        name: track.and_then(|t| child_text(t, "name")).map(String::from),
+        sport: track.and_then(|t| child_text(t, "type")).map(String::from),
+        start_time: metadata_time.and_then(parse_time),
+        points,
+        ..Default::default()
+    })
+}
+
+fn parse_tcx(text: &str) -> Result<Activity> {
+    let document = Document::parse(text)?;
+    let Some(activity) = document
+        .descendants()
+        .find(|n| n.tag_name().name() == "Activity")
+    else {
+        bail!("No activity found in file");
+    };
+    let laps = activity
+        .children()
+        .filter(|n| n.tag_name().name() == "Lap")
+        .collect_vec();
+    let points = laps
+        .iter()
+        .flat_map(|lap| lap.descendants())
+        .filter(|n| n.tag_name().name() == "Trackpoint")
+        .map(|point| {
+            let position = child(point, "Position");
+            ActivityPoint {
+                time: child_text(point, "Time").and_then(parse_time),
+                latitude: position.and_then(|p| child_number(p, "LatitudeDegrees")),
+                longitude: position.and_then(|p| child_number(p, "LongitudeDegrees")),
+                elevation: child_number(point, "AltitudeMeters"),
+                distance: child_number(point, "DistanceMeters"),
+                heart_rate: child(point, "HeartRateBpm").and_then(|h| child_number(h, "Value")),
+            }
+        })
+        .collect_vec();
+    Ok(Activity {
+        points,
+        name: child_text(activity, "Notes").map(String::from),
+        sport: activity.attribute("Sport").map(String::from),
+        start_time: child_text(activity, "Id").and_then(parse_time),
+        laps: laps
+            .iter()
+            .map(|lap| ActivityLap {
+                calories: child_number(*lap, "Calories"),
+                duration: child_number(*lap, "TotalTimeSeconds").unwrap_or_default(),
+                distance: child_number(*lap, "DistanceMeters").unwrap_or_default(),
+            })
+            .collect(),
+    })
+}
+
+fn fit_value<'a>(record: &'a FitDataRecord, name: &str) -> Option<&'a Value> {
+    record
+        .fields()
+        .iter()
+        .find(|f| f.name() == name)
+        .map(|f| f.value())
+}
+
+fn fit_number(record: &FitDataRecord, name: &str) -> Option<f64> {
+    fit_value(record, name)
+        .cloned()
+        .and_then(|v| TryInto::<f64>::try_into(v).ok())
+}
+
+fn fit_time(record: &FitDataRecord, name: &str) -> Option<DateTime<Utc>> {
+    match fit_value(record, name) {
+        Some(Value::Timestamp(time)) => Some(time.with_timezone(&Utc)),
+        _ => None,
+    }
+}
+
+fn parse_fit(contents: &[u8]) -> Result<Activity> {
+    let mut activity = Activity::default();
+    for record in fitparser::from_bytes(contents)? {
+        match record.kind() {
+            MesgNum::Record => activity.points.push(ActivityPoint {
+                time: fit_time(&record, "timestamp"),
+                latitude: fit_number(&record, "position_lat").map(|l| l * SEMICIRCLES_TO_DEGREES),
+                longitude: fit_number(&record, "position_long").map(|l| l * SEMICIRCLES_TO_DEGREES),
+                elevation: fit_number(&record, "enhanced_altitude")
+                    .or_else(|| fit_number(&record, "altitude")),
+                heart_rate: fit_number(&record, "heart_rate"),
+                distance: fit_number(&record, "distance"),
+            }),
+            MesgNum::Lap => activity.laps.push(ActivityLap {
+                calories: fit_number(&record, "total_calories"),
+                distance: fit_number(&record, "total_distance").unwrap_or_default(),
+                duration: fit_number(&record, "total_timer_time")
+                    .or_else(|| fit_number(&record, "total_elapsed_time"))
+                    .unwrap_or_default(),
+            }),
+            MesgNum::Session => {
+                activity.start_time = fit_time(&record, "start_time");
+                if let Some(Value::String(sport)) = fit_value(&record, "sport") {
+                    activity.sport = Some(sport.to_owned());
+                }
+            }
+            _ => {}
+        }
+    }
+    Ok(activity)
+}
+
+fn haversine_distance(from: (f64, f64), to: (f64, f64)) -> f64 {
+    let (lat1, lat2) = (from.0.to_radians(), to.0.to_radians());
+    let d_lat = lat2 - lat1;
+    let d_lon = (to.1 - from.1).to_radians();
+    let a = (d_lat / 2.0).sin().powi(2) + lat1.cos() * lat2.cos() * (d_lon / 2.0).sin().powi(2);
+    2.0 * EARTH_RADIUS * a.sqrt().asin()
+}
+
+/// Files without recorded distances (like most GPX files) get them computed from the
+/// coordinates of consecutive points.
+fn fill_point_distances(points: &mut [ActivityPoint]) {
+    if points.iter().any(|p| p.distance.is_some()) {
+        return;
+    }
+    let mut total = 0.0;
+    let mut previous = None;
+    for point in points.iter_mut() {
+        if let (Some(latitude), Some(longitude)) = (point.latitude, point.longitude) {
+            if let Some(previous) = previous {
+                total += haversine_distance(previous, (latitude, longitude));
+            }
+            previous = Some((latitude, longitude));
+            point.distance = Some(total);
+        }
+    }
+}
+
+/// Splits the track points into sets of `SPLIT_DISTANCE` meters each.
+fn get_splits(points: &[ActivityPoint]) -> Vec<ActivityLap> {
+    let timed = points
+        .iter()
+        .filter_map(|p| Some((p.time?, p.distance?)))
+        .collect_vec();
+    let Some(&(mut split_time, mut split_distance)) = timed.first() else {
+        return vec![];
+    };
+    let mut splits = vec![];
+    for (idx, &(time, distance)) in timed.iter().enumerate() {
+        let is_last = idx == timed.len() - 1;
+        if distance - split_distance >= SPLIT_DISTANCE || (is_last && time > split_time) {
+            splits.push(ActivityLap {
+                calories: None,
+                distance: distance - split_distance,
+                duration: (time - split_time).num_milliseconds() as f64 / 1000.0,
+            });
+            split_time = time;
+            split_distance = distance;
+        }
+    }
+    splits
+}
+
+pub(crate) fn get_activity_sets(
+    activity: &Activity,
+) -> Option<(DateTime<Utc>, DateTime<Utc>, Vec<UserWorkoutSetRecord>)> {
+    let splits;
+    let laps = match activity.laps.is_empty() {
+        false => &activity.laps,
+        true => {
+            splits = get_splits(&activity.points);
+            &splits
+        }
+    };
+    let sets = laps
+        .iter()
+        .filter(|l| l.duration > 0.0)
+        .map(|lap| UserWorkoutSetRecord {
+            lot: SetLot::Normal,
+            statistic: WorkoutSetStatistic {
+                duration: Decimal::from_f64(lap.duration / 60.0).map(|d| d.round_dp(2)),
+                distance: Decimal::from_f64(lap.distance / 1000.0).map(|d| d.round_dp(3)),
+                ..Default::default()
+            },
+            ..Default::default()
+        })
+        .collect_vec();
+    let first_point_time = activity.points.iter().find_map(|p| p.time);
+    let start_time = activity.start_time.or(first_point_time)?;
+    if sets.is_empty() {
+        return None;
+    }
+    let total_duration = laps.iter().map(|l| l.duration).sum::<f64>();
+    let end_time = activity
+        .points
+        .iter()
+        .rev()
+        .find_map(|p| p.time)
+        .filter(|t| *t > start_time)
+        .unwrap_or_else(|| start_time + Duration::milliseconds((total_duration * 1000.0) as i64));
+    Some((start_time, end_time, sets))
+}
+
+pub(crate) fn get_exercise_name(sport: Option<&str>) -> String {
+    let Some(sport) = sport.map(|s| s.trim().to_lowercase()) else {
+        return "Cardio".to_owned();
+    };
+    match sport.as_str() {
+        "running" | "run" | STRAVA_RUN_TYPE => "Running".to_owned(),
+        "biking" | "cycling" | "ride" | STRAVA_RIDE_TYPE => "Cycling".to_owned(),
+        "walking" | "walk" | STRAVA_WALK_TYPE => "Walking".to_owned(),
+        "hiking" | "hike" | STRAVA_HIKE_TYPE => "Hiking".to_owned(),
+        "swimming" | "swim" => "Swimming".to_owned(),
+        "" | "other" | "generic" => "Cardio".to_owned(),
+        _ => sport.to_case(Case::Title),
+    }
+}
@@ -0,0 +1,388 @@
+use std::{
+    collections::HashMap,
+    fs::File,
+    io::{BufRead, BufReader},
+    sync::Arc,
+};
+
+use anyhow::{Result, bail};
+use chrono::{DateTime, Utc};
+use common_utils::ryot_log;
+use convert_case::{Case, Casing};
+use database_models::{exercise, user_measurement};
+use dependent_models::{ImportCompletedItem, ImportResult};
+use enum_models::ExerciseLot;
+use fitness_models::{
+    SetLot, UserExerciseInput, UserMeasurementInformation, UserMeasurementStatistic,
+    UserWorkoutInput, UserWorkoutSetRecord, WorkoutSetStatistic,
+};
+use importer_models::{ImportFailStep, ImportFailedItem};
+use indexmap::IndexMap;
+use media_models::DeployPathImportInput;
+use quick_xml::{
+    Reader,
+    events::{BytesStart, Event},
+};
+use rust_decimal::{Decimal, dec};
+use supporting_service::SupportingService;
+use user_models::UserStatisticsMeasurement;
+use zip::ZipArchive;
+
+use crate::utils;
+
+const DATE_FORMAT: &str = "%Y-%m-%d %H:%M:%S %z";
+const WORKOUT_TYPE_PREFIX: &str = "HKWorkoutActivityType";
+
+/// The Apple Health record types that are imported, along with the name of the
+/// statistic they are stored as and the factor their value is multiplied by.
+const RECORD_TYPES: [(&str, &str, Decimal); 14] = [
+    ("HKQuantityTypeIdentifierBodyMass", "weight", dec!(1)),
+    (
+        "HKQuantityTypeIdentifierBodyFatPercentage",
+        "body_fat",
+        dec!(100),
+    ),
+    (
+        "HKQuantityTypeIdentifierLeanBodyMass",
+        "lean_body_mass",
+        dec!(1),
+    ),
+    (
+        "HKQuantityTypeIdentifierBodyMassIndex",
+        "body_mass_index",
+        dec!(1),
+    ),
+    ("HKQuantityTypeIdentifierHeight", "height", dec!(1)),
+    (
+        "HKQuantityTypeIdentifierWaistCircumference",
+        "waist_circumference",
+        dec!(1),
+    ),
+    (
+        "HKQuantityTypeIdentifierRestingHeartRate",
+        "resting_heart_rate",
+        dec!(1),
+    ),
+    (
+        "HKQuantityTypeIdentifierHeartRateVariabilitySDNN",
+        "heart_rate_variability",
+        dec!(1),
+    ),
+    (
+        "HKQuantityTypeIdentifierBloodGlucose",
+        "sugar_level",
+        dec!(1),
+    ),
+    (
+        "HKQuantityTypeIdentifierBloodPressureSystolic",
+        "blood_pressure_systolic",
+        dec!(1),
+    ),
+    (
+        "HKQuantityTypeIdentifierBloodPressureDiastolic",
+        "blood_pressure_diastolic",
+        dec!(1),
+    ),
+    (
+        "HKQuantityTypeIdentifierOxygenSaturation",
+        "oxygen_saturation",
+        dec!(100),
+    ),
+    (
+        "HKQuantityTypeIdentifierBodyTemperature",
+        "body_temperature",
+        dec!(1),
+    ),
+    ("HKQuantityTypeIdentifierVO2Max", "vo2_max", dec!(1)),
+];
+
+#[derive(Debug, Default)]
+pub struct HealthWorkout {
+    pub activity_type: String,
+    pub end_date: String,
+    pub start_date: String,
+    pub source_name: Option<String>,
+    /// In minutes.
+    pub duration: Option<Decimal>,
+    /// In kilometers.
+    pub distance: Option<Decimal>,
+    /// In kilocalories.
+    pub energy_burned: Option<Decimal>,
+}
+
+#[derive(Debug, Default)]
+pub struct HealthExport {
+    pub workouts: Vec<HealthWorkout>,
+    pub units: IndexMap<&'static str, String>,
+    pub measurements: IndexMap<String, Vec<UserMeasurementStatistic>>,
+}
+
+pub async fn import(
+    input: DeployPathImportInput,
+    ss: &Arc<SupportingService>,
+    user_id: &str,
+) -> Result<ImportResult> {
+    let export = if input.export_path.to_lowercase().ends_with(".zip") {
+        let mut archive = ZipArchive::new(BufReader::new(File::open(&input.export_path)?))?;
+        let Some(name) = archive
+            .file_names()
+            .find(|n| n.ends_with("/export.xml") || *n == "export.xml")
+            .map(String::from)
+        else {
+            bail!("No export.xml found in the archive");
+        };
+        parse_export(BufReader::new(archive.by_name(&name)?))?
+    } else {
+        parse_export(BufReader::new(File::open(&input.export_path)?))?
+    };
+    let mut completed = vec![];
+    let mut failed = vec![];
+    for (date, statistics) in export.measurements {
+        let Ok(timestamp) = DateTime::parse_from_str(&date, DATE_FORMAT) else {
+            failed.push(ImportFailedItem {
+                identifier: date,
+                step: ImportFailStep::InputTransformation,
+                error: Some("Could not parse the date of the record".to_owned()),
+                ..Default::default()
+            });
+            continue;
+        };
+        completed.push(ImportCompletedItem::Measurement(user_measurement::Model {
+            timestamp: timestamp.with_timezone(&Utc),
+            information: UserMeasurementInformation {
+                statistics,
+                ..Default::default()
+            },
+            ..Default::default()
+        }));
+    }
+    utils::add_missing_measurement_statistics(
+        user_id,
+        export
+            .units
+            .into_iter()
+            .map(|(name, unit)| UserStatisticsMeasurement {
+                name: name.to_owned(),
+                unit: Some(unit).filter(|u| !u.is_empty()),
+            })
+            .collect(),
+        ss,
+    )
+    .await?;
+    let mut unique_exercises: HashMap<String, exercise::Model> = HashMap::new();
+    for workout in export.workouts {
+        let (Ok(start_time), Ok(end_time)) = (
+            DateTime::parse_from_str(&workout.start_date, DATE_FORMAT),
+            DateTime::parse_from_str(&workout.end_date, DATE_FORMAT),
+        ) else {
+            failed.push(ImportFailedItem {
+                identifier: workout.activity_type,
+                step: ImportFailStep::InputTransformation,
+                error: Some("Could not parse the dates of the workout".to_owned()),
+                ..Default::default()
+            });
+            continue;
+        };
+        let exercise_name = workout
+            .activity_type
+            .trim_start_matches(WORKOUT_TYPE_PREFIX)
+            .to_case(Case::Title);
+        let exercise_lot = match workout.distance {
+            Some(_) => ExerciseLot::DistanceAndDuration,
+            None => ExerciseLot::Duration,
+        };
+        let exercise_id = utils::associate_with_existing_or_new_exercise(
+            user_id,
+            &exercise_name,
+            exercise_lot,
+            ss,
+            &mut unique_exercises,
+        )
+        .await?;
+        ryot_log!(debug, "Importing workout with exercise {exercise_name}");
+        completed.push(ImportCompletedItem::Workout(UserWorkoutInput {
+            name: exercise_name,
+            comment: workout.source_name,
+            calories_burnt: workout.energy_burned,
+            end_time: end_time.with_timezone(&Utc),
+            start_time: start_time.with_timezone(&Utc),
+            exercises: vec![UserExerciseInput {
+                exercise_id,
+                sets: vec![UserWorkoutSetRecord {
+                    lot: SetLot::Normal,
+                    statistic: WorkoutSetStatistic {
+                        distance: workout.distance,
+                        duration: workout.duration,
+                        ..Default::default()
+                    },
+                    ..Default::default()
+                }],
+                ..Default::default()
+            }],
+            ..Default::default()
+        }));
+    }
+    completed.extend(
+        unique_exercises
+            .into_values()
+            .map(ImportCompletedItem::Exercise),
+    );
+    Ok(ImportResult { failed, completed })
+}
+
+fn get_attributes(element: &BytesStart) -> HashMap<String, String> {
+    element
+        .attributes()
+        .flatten()
+        .filter_map(|a| {
+            let key = String::from_utf8_lossy(a.key.as_ref()).to_string();
+            a.unescape_value().ok().map(|v| (key, v.to_string()))
+        })
+        .collect()
+}
+
+fn get_decimal(attributes: &HashMap<String, String>, key: &str) -> Option<Decimal> {
+    attributes.get(key).and_then(|v| v.parse().ok())
+}
+
+fn to_minutes(value: Decimal, unit: &str) -> Decimal {
+    match unit {
+        "s" => value / dec!(60),
+        "hr" | "h" => value * dec!(60),
+        _ => value,
+    }
+}
+
+fn to_kilometers(value: Decimal, unit: &str) -> Decimal {
+    match unit {
+        "m" => value / dec!(1000),
+        "mi" => value * dec!(1.609344),
+        "ft" => value * dec!(0.0003048),
+        "yd" => value * dec!(0.0009144),
+        _ => value,
+    }
+}
+
+fn to_kilocalories(value: Decimal, unit: &str) -> Decimal {
+    match unit {
+        "kJ" => value / dec!(4.184),
+        _ => value,
+    }
+}
+
+/// Converts imperial record values to the metric unit the statistic is stored in.
+fn to_metric(value: Decimal, unit: &str) -> (Decimal, &str) {
+    match unit {
+        "lb" => ((value * dec!(0.45359237)).round_dp(2), "kg"),
+        "st" => ((value * dec!(6.35029318)).round_dp(2), "kg"),
+        "g" => (value / dec!(1000), "kg"),
+        "in" => ((value * dec!(2.54)).round_dp(2), "cm"),
+        "ft" => ((value * dec!(30.48)).round_dp(2), "cm"),
+        "m" => (value * dec!(100), "cm"),
+        "mm" => (value / dec!(10), "cm"),
+        "degF" => (((value - dec!(32)) * dec!(5) / dec!(9)).round_dp(2), "degC"),
+        "count/min" => (value, "bpm"),
+        _ => (value, unit),
+    }
+}
+
+/// Reads the export as a stream since it routinely grows to several gigabytes.
+pub fn parse_export<R: BufRead>(source: R) -> Result<HealthExport> {
+    let mut reader = Reader::from_reader(source);
+    reader.config_mut().trim_text(true);
+    let mut buffer = vec![];
+    let mut export = HealthExport::default();
+    let mut current_workout: Option<HealthWorkout> = None;
+    loop {
+        let (element, is_empty) = match reader.read_event_into(&mut buffer)? {
+            Event::Eof => break,
+            Event::Start(e) => (e, false),
+            Event::Empty(e) => (e, true),
+            Event::End(e) => {
+                if e.name().as_ref() == b"Workout"
+                    && let Some(workout) = current_workout.take()
+                {
+                    export.workouts.push(workout);
+                }
+                buffer.clear();
+                continue;
+            }
+            _ => {
+                buffer.clear();
+                continue;
+            }
+        };
+        match element.name().as_ref() {
+            b"Record" => {
+                let attributes = get_attributes(&element);
+                let record_type = attributes.get("type").map(String::as_str);
+                if let Some((_, name, factor)) =
+                    RECORD_TYPES.iter().find(|(t, ..)| Some(*t) == record_type)
+                    && let Some(value) = get_decimal(&attributes, "value")
+                    && let Some(date) = attributes.get("startDate")
+                {
+                    let unit = attributes.get("unit").map(String::as_str).unwrap_or("");
+                    let (value, unit) = to_metric(value, unit);
+                    export.units.entry(name).or_insert(unit.to_owned());
+                    let statistics = export.measurements.entry(date.to_owned()).or_default();
+                    if !statistics.iter().any(|s| s.name == *name) {
+                        statistics.push(UserMeasurementStatistic {
+                            name: name.to_string(),
+                            value: value * factor,
+                        });
+                    }
+                }
+            }
+            b"Workout" => {
+                let attributes = get_attributes(&element);
+                let unit = |key: &str| attributes.get(key).cloned().unwrap_or_default();
+                let workout = HealthWorkout {
+                    source_name: attributes.get("sourceName").cloned(),
+                    end_date: attributes.get("endDate").cloned().unwrap_or_default(),
+                    start_date: attributes.get("startDate").cloned().unwrap_or_default(),
+                    activity_type: attributes
+                        .get("workoutActivityType")
+                        .cloned()
+                        .unwrap_or_default(),
+                    duration: get_decimal(&attributes, "duration")
+                        .map(|d| to_minutes(d, &unit("durationUnit")).round_dp(2)),
+                    distance: get_decimal(&attributes, "totalDistance")
+                        .map(|d| to_kilometers(d, &unit("totalDistanceUnit")).round_dp(3)),
+                    energy_burned: get_decimal(&attributes, "totalEnergyBurned")
+                        .map(|e| to_kilocalories(e, &unit("totalEnergyBurnedUnit")).round_dp(0)),
+                };
+                match is_empty {
+                    true => export.workouts.push(workout),
+                    false => current_workout = Some(workout),
+                }
+            }
+            // Newer exports only record the totals of a workout in its statistics.
+            b"WorkoutStatistics" => {
+                if let Some(workout) = current_workout.as_mut() {
+                    let attributes = get_attributes(&element);
+                    let unit = attributes.get("unit").cloned().unwrap_or_default();
+                    let sum = get_decimal(&attributes, "sum");
+                    match attributes.get("type").map(String::as_str) {
+                        Some("HKQuantityTypeIdentifierActiveEnergyBurned")
+                            if workout.energy_burned.is_none() =>
+                        {
+                            workout.energy_burned =
+                                sum.map(|s| to_kilocalories(s, &unit).round_dp(0));
+                        }
+                        Some(
+                            "HKQuantityTypeIdentifierDistanceWalkingRunning"
+                            | "HKQuantityTypeIdentifierDistanceCycling"
+                            | "HKQuantityTypeIdentifierDistanceSwimming",
+                        ) if workout.distance.is_none() => {
+                            workout.distance = sum.map(|s| to_kilometers(s, &unit).round_dp(3));
+                        }
+                        _ => {}
+                    }
+                }
+            }
+            _ => {}
+        }
+        buffer.clear();
+    }
+    Ok(export)
+}
@@ -0,0 +1,148 @@
+use std::fs;
+
+use anyhow::Result;
+use bangumi_provider::get_lot_from_subject_type;
+use chrono::{DateTime, Utc};
+use common_models::DefaultCollection;
+use dependent_models::{
+    CollectionToEntityDetails, ImportCompletedItem, ImportOrExportMetadataItem, ImportResult,
+};
+use enum_models::{ImportSource, MediaLot, MediaSource, Visibility};
+use importer_models::{ImportFailStep, ImportFailedItem};
+use media_models::{
+    DeployPathImportInput, ImportOrExportItemRating, ImportOrExportItemReview,
+    ImportOrExportMetadataItemSeen,
+};
+use rust_decimal::{Decimal, dec};
+use serde::Deserialize;
+
+#[derive(Debug, Deserialize)]
+struct BangumiTag {
+    name: String,
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct BangumiCollectionSubject {
+    #[serde(default)]
+    name: String,
+    #[serde(default)]
+    tags: Vec<BangumiTag>,
+}
+
+/// A collection entry as returned by `/v0/users/{username}/collections`.
+#[derive(Debug, Deserialize)]
+struct BangumiCollection {
+    subject_id: i64,
+    subject_type: u8,
+    #[serde(rename = "type")]
+    collection_type: u8,
+    rate: u8,
+    ep_status: i32,
+    vol_status: i32,
+    private: bool,
+    comment: Option<String>,
+    updated_at: DateTime<Utc>,
+    #[serde(default)]
+    subject: Option<BangumiCollectionSubject>,
+}
+
+/// The export is either the list of collections or the paginated API response.
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum BangumiExport {
+    List(Vec<BangumiCollection>),
+    Paginated { data: Vec<BangumiCollection> },
+}
+
+pub async fn import(input: DeployPathImportInput) -> Result<ImportResult> {
+    let export = fs::read_to_string(input.export_path)?;
+    let collections = match serde_json::from_str::<BangumiExport>(&export)? {
+        BangumiExport::List(data) | BangumiExport::Paginated { data } => data,
+    };
+    let mut completed = vec![];
+    let mut failed = vec![];
+    for item in collections {
+        let subject = item.subject.unwrap_or_default();
+        let tags = subject.tags.into_iter().map(|t| t.name).collect::<Vec<_>>();
+        let Some(lot) = get_lot_from_subject_type(item.subject_type, &tags) else {
+            failed.push(ImportFailedItem {
+                identifier: subject.name,
+                step: ImportFailStep::InputTransformation,
+                error: Some(format!(
+                    "Subject type {} is not supported",
+                    item.subject_type
+                )),
+                ..Default::default()
+            });
+            continue;
+        };
+        let seen = ImportOrExportMetadataItemSeen {
+            ended_on: Some(item.updated_at),
+            providers_consumed_on: Some(vec![ImportSource::Bangumi.to_string()]),
+            ..Default::default()
+        };
+        // 1: wish, 2: done, 3: doing, 4: on hold, 5: dropped
+        let mut seen_history = match lot {
+            MediaLot::Anime => (1..=item.ep_status)
+                .map(|episode| ImportOrExportMetadataItemSeen {
+                    anime_episode_number: Some(episode),
+                    ..seen.clone()
+                })
+                .collect(),
+            MediaLot::Manga if item.ep_status > 0 => (1..=item.ep_status)
+                .map(|chapter| ImportOrExportMetadataItemSeen {
+                    manga_chapter_number: Some(Decimal::from(chapter)),
+                    ..seen.clone()
+                })
+                .collect(),
+            MediaLot::Manga => (1..=item.vol_status)
+                .map(|volume| ImportOrExportMetadataItemSeen {
+                    manga_volume_number: Some(volume),
+                    ..seen.clone()
+                })
+                .collect(),
+            _ => vec![],
+        };
+        if seen_history.is_empty() && item.collection_type == 2 {
+            seen_history.push(seen);
+        }
+        let collection_name = match item.collection_type {
+            1 => Some(DefaultCollection::Watchlist.to_string()),
+            3 => Some(DefaultCollection::InProgress.to_string()),
+            4 => Some("On Hold".to_owned()),
+            5 => Some("Dropped".to_owned()),
+            _ => None,
+        };
+        let mut review = ImportOrExportItemRating {
+            rating: (item.rate > 0).then(|| Decimal::from(item.rate) * dec!(10)),
+            ..Default::default()
+        };
+        if let Some(comment) = item.comment.filter(|c| !c.is_empty()) {
+            review.review = Some(ImportOrExportItemReview {
+                text: Some(comment),
+                date: Some(item.updated_at),
+                visibility: Some(match item.private {
+                    true => Visibility::Private,
+                    false => Visibility::Public,
+                }),
+                ..Default::default()
+            });
+        }
+        completed.push(ImportCompletedItem::Metadata(ImportOrExportMetadataItem {
+            lot,
+            seen_history,
+            source_id: subject.name,
+            source: MediaSource::Bangumi,
+            identifier: item.subject_id.to_string(),
+            reviews: vec![review],
+            collections: collection_name
+                .map(|collection_name| CollectionToEntityDetails {
+                    collection_name,
+                    ..Default::default()
+                })
+                .into_iter()
+                .collect(),
+        }));
+    }
+    Ok(ImportResult { completed, failed })
+}
@@ -0,0 +1,102 @@
+use std::fs;
+
+use anyhow::Result;
+use board_game_geek_provider::child;
+use chrono::NaiveDate;
+use common_utils::convert_naive_to_utc;
+use dependent_models::{ImportCompletedItem, ImportOrExportMetadataItem, ImportResult};
+use enum_models::{ImportSource, MediaLot, MediaSource};
+use importer_models::{ImportFailStep, ImportFailedItem};
+use indexmap::IndexMap;
+use itertools::Itertools;
+use media_models::{DeployPathImportInput, ImportOrExportMetadataItemSeen};
+use roxmltree::Document;
+
+/// Imports the play history as returned by `/xmlapi2/plays?username=<name>`. Every
+/// play is logged as a separate seen entry of the game that was played.
+pub async fn import(input: DeployPathImportInput) -> Result<ImportResult> {
+    let export = fs::read_to_string(input.export_path)?;
+    parse_plays(&export)
+}
+
+pub(crate) fn parse_plays(export: &str) -> Result<ImportResult> {
+    let document = Document::parse(export)?;
+    let mut failed = vec![];
+    let mut games: IndexMap<String, ImportOrExportMetadataItem> = IndexMap::new();
+    for play in document
+        .root_element()
+        .children()
+        .filter(|n| n.tag_name().name() == "play")
+    {
+        let Some(item) = child(play, "item") else {
+            continue;
+        };
+        let name = item.attribute("name").unwrap_or_default().to_owned();
+        let (Some(identifier), Some("thing")) =
+            (item.attribute("objectid"), item.attribute("objecttype"))
+        else {
+            failed.push(ImportFailedItem {
+                identifier: name,
+                lot: Some(MediaLot::BoardGame),
+                step: ImportFailStep::InputTransformation,
+                error: Some("Only plays of board games can be imported".to_owned()),
+            });
+            continue;
+        };
+        let players = child(play, "players")
+            .map(|p| {
+                p.children()
+                    .filter(|n| n.tag_name().name() == "player")
+                    .collect_vec()
+            })
+            .unwrap_or_default();
+        let winner = players
+            .iter()
+            .filter(|p| p.attribute("win") == Some("1"))
+            .filter_map(|p| {
+                [p.attribute("name"), p.attribute("username")]
+                    .into_iter()
+                    .flatten()
+                    .find(|n| !n.is_empty())
+            })
+            .join(", ");
+        let seen = ImportOrExportMetadataItemSeen {
+            ended_on: play
+                .attribute("date")
+                .and_then(|d| NaiveDate::parse_from_str(d, "%Y-%m-%d").ok())
+                .map(convert_naive_to_utc),
+            board_game_winner: (!winner.is_empty()).then_some(winner),
+            board_game_player_count: (!players.is_empty()).then_some(players.len() as i32),
+            board_game_duration: play
+                .attribute("length")
+                .and_then(|l| l.parse().ok())
+                .filter(|l| *l > 0),
+            providers_consumed_on: Some(vec![ImportSource::BoardGameGeek.to_string()]),
+            ..Default::default()
+        };
+        // A single entry can log the same play multiple times.
+        let quantity = play
+            .attribute("quantity")
+            .and_then(|q| q.parse::<usize>().ok())
+            .unwrap_or(1)
+            .max(1);
+        games
+            .entry(identifier.to_owned())
+            .or_insert_with(|| ImportOrExportMetadataItem {
+                source_id: name,
+                lot: MediaLot::BoardGame,
+                identifier: identifier.to_owned(),
+                source: MediaSource::BoardGameGeek,
+                ..Default::default()
+            })
+            .seen_history
+            .extend(std::iter::repeat_n(seen, quantity));
+    }
+    Ok(ImportResult {
+        failed,
+        completed: games
+            .into_values()
+            .map(ImportCompletedItem::Metadata)
+            .collect(),
+    })
+}
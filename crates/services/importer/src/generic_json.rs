@@ -1,14 +1,154 @@
-use std::fs::read_to_string;
+use std::{
+    collections::HashMap,
+    fs::File,
+    io::{BufReader, Read},
+    path::Path,
+    sync::Arc,
+};
 
-use anyhow::Result;
+use anyhow::{Result, anyhow};
+use common_models::EntityAssets;
+use common_utils::{EXPORT_ARCHIVE_DATA_FILE_NAME, ryot_log};
+use dependent_fitness_utils::generate_exercise_id;
 use dependent_models::{CompleteExport, ImportCompletedItem, ImportResult};
 use enum_models::ImportSource;
+use importer_models::{ImportFailStep, ImportFailedItem};
 use itertools::Itertools;
-use media_models::{CreateOrUpdateCollectionInput, DeployPathImportInput};
+use media_models::{
+    CreateOrUpdateCollectionInput, DeployPathImportInput, ImportOrExportItemRating,
+};
+use nanoid::nanoid;
+use supporting_service::SupportingService;
+use zip::ZipArchive;
 
-pub async fn import(input: DeployPathImportInput) -> Result<ImportResult> {
-    let export = read_to_string(input.export_path)?;
-    let complete_data = serde_json::from_str::<CompleteExport>(&export).unwrap();
+/// Read an export which is either an archive created by the exporter or a plain JSON file
+/// from older versions. The archive is returned so that the assets can be read from it.
+pub(crate) fn read_export(
+    path: impl AsRef<Path>,
+) -> Result<(CompleteExport, Option<ZipArchive<BufReader<File>>>)> {
+    let file = BufReader::new(File::open(path.as_ref())?);
+    let Ok(mut archive) = ZipArchive::new(file) else {
+        let file = BufReader::new(File::open(path.as_ref())?);
+        return Ok((serde_json::from_reader(file)?, None));
+    };
+    let data = serde_json::from_reader(BufReader::new(
+        archive.by_name(EXPORT_ARCHIVE_DATA_FILE_NAME)?,
+    ))?;
+    Ok((data, Some(archive)))
+}
+
+pub(crate) fn read_export_asset(
+    archive: &mut ZipArchive<BufReader<File>>,
+    path: &str,
+) -> Result<Vec<u8>> {
+    let mut entry = archive.by_name(path)?;
+    let mut data = Vec::with_capacity(entry.size() as usize);
+    entry.read_to_end(&mut data)?;
+    Ok(data)
+}
+
+pub(crate) fn remap_asset_keys(assets: &mut EntityAssets, keys: &HashMap<String, String>) {
+    for key in assets
+        .s3_images
+        .iter_mut()
+        .chain(assets.s3_videos.iter_mut())
+        .chain(assets.s3_files.iter_mut())
+    {
+        if let Some(new_key) = keys.get(key) {
+            *key = new_key.to_owned();
+        }
+    }
+}
+
+pub(crate) fn remap_review_user_ids(
+    reviews: &mut [ImportOrExportItemRating],
+    old_id: &str,
+    new_id: &str,
+) {
+    for comment in reviews
+        .iter_mut()
+        .flat_map(|r| r.comments.iter_mut().flatten())
+    {
+        if comment.user.id == old_id {
+            comment.user.id = new_id.to_owned();
+        }
+        if comment.liked_by.remove(old_id) {
+            comment.liked_by.insert(new_id.to_owned());
+        }
+    }
+}
+
+pub async fn import(
+    input: DeployPathImportInput,
+    ss: &Arc<SupportingService>,
+    user_id: &str,
+) -> Result<ImportResult> {
+    let (complete_data, mut archive) = read_export(input.export_path)?;
+
+    let mut failed = vec![];
+    let mut completed = vec![];
+
+    let mut asset_keys = HashMap::new();
+    for asset in complete_data.assets.unwrap_or_default() {
+        let data = match archive.as_mut() {
+            Some(archive) => read_export_asset(archive, &asset.path),
+            None => Err(anyhow!(
+                "Assets can only be imported from an export archive"
+            )),
+        };
+        let uploaded = match data {
+            Ok(data) => {
+                file_storage_service::upload_object(
+                    ss,
+                    format!("uploads/imports/{user_id}"),
+                    data,
+                    &asset.content_type,
+                )
+                .await
+            }
+            Err(e) => Err(e),
+        };
+        match uploaded {
+            Ok(new_key) => {
+                asset_keys.insert(asset.key, new_key);
+            }
+            Err(e) => failed.push(ImportFailedItem {
+                identifier: asset.key,
+                error: Some(e.to_string()),
+                step: ImportFailStep::DatabaseCommit,
+                ..Default::default()
+            }),
+        }
+    }
+    ryot_log!(debug, "Uploaded {} assets", asset_keys.len());
+
+    let mut exercise_ids = HashMap::new();
+    for mut exercise in complete_data.custom_exercises.unwrap_or_default() {
+        exercise_ids.insert(
+            exercise.id.clone(),
+            generate_exercise_id(&exercise.name, exercise.lot, user_id),
+        );
+        remap_asset_keys(&mut exercise.assets, &asset_keys);
+        completed.push(ImportCompletedItem::Exercise(exercise));
+    }
+    let remap_exercise_id = |id: &mut String| {
+        if let Some(new_id) = exercise_ids.get(id) {
+            *id = new_id.to_owned();
+        }
+    };
+
+    let old_user_id = complete_data.account.as_ref().map(|a| a.id.clone());
+    let remap_reviews = |reviews: &mut Vec<ImportOrExportItemRating>| {
+        if let Some(old_id) = &old_user_id {
+            remap_review_user_ids(reviews, old_id, user_id);
+        }
+    };
+
+    if let Some(account) = complete_data.account.clone() {
+        completed.push(ImportCompletedItem::Preferences(Box::new(
+            account.preferences,
+        )));
+    }
 
     let media = complete_data
         .metadata
@@ -20,37 +160,86 @@ pub async fn import(input: DeployPathImportInput) -> Result<ImportResult> {
                     s.providers_consumed_on = Some(vec![ImportSource::GenericJson.to_string()]);
                 }
             });
+            remap_reviews(&mut m.reviews);
             m.to_owned()
         })
         .collect_vec();
 
-    let mut completed = vec![];
     for media in media {
         completed.push(ImportCompletedItem::Metadata(media));
     }
-    for people in complete_data.people.unwrap_or_default() {
+    for mut people in complete_data.people.unwrap_or_default() {
+        remap_reviews(&mut people.reviews);
         completed.push(ImportCompletedItem::Person(people));
     }
     for measurement in complete_data.measurements.unwrap_or_default() {
         completed.push(ImportCompletedItem::Measurement(measurement));
     }
-    for workout in complete_data.workouts.unwrap_or_default() {
+    for mut exercise in complete_data.exercises.unwrap_or_default() {
+        remap_exercise_id(&mut exercise.id);
+        remap_reviews(&mut exercise.reviews);
+        completed.push(ImportCompletedItem::ApplicationExercise(exercise));
+    }
+    let mut template_ids = HashMap::new();
+    for mut template in complete_data.workout_templates.unwrap_or_default() {
+        let new_id = format!("wktpl_{}", nanoid!(12));
+        template_ids.insert(template.details.id.clone(), new_id.clone());
+        template.details.id = new_id;
+        for exercise in template.details.information.exercises.iter_mut() {
+            remap_exercise_id(&mut exercise.id);
+        }
+        for exercise in template.details.summary.exercises.iter_mut() {
+            remap_exercise_id(&mut exercise.id);
+        }
+        completed.push(ImportCompletedItem::ApplicationWorkoutTemplate(Box::new(
+            template,
+        )));
+    }
+    for mut workout in complete_data.workouts.unwrap_or_default() {
+        if let Some(template_id) = workout.details.template_id.as_mut()
+            && let Some(new_id) = template_ids.get(template_id)
+        {
+            *template_id = new_id.to_owned();
+        }
+        let information = &mut workout.details.information;
+        if let Some(assets) = information.assets.as_mut() {
+            remap_asset_keys(assets, &asset_keys);
+        }
+        for exercise in information.exercises.iter_mut() {
+            remap_exercise_id(&mut exercise.id);
+            if let Some(assets) = exercise.assets.as_mut() {
+                remap_asset_keys(assets, &asset_keys);
+            }
+        }
         completed.push(ImportCompletedItem::ApplicationWorkout(Box::new(workout)));
     }
-    for media_group in complete_data.metadata_groups.unwrap_or_default() {
+    for mut media_group in complete_data.metadata_groups.unwrap_or_default() {
+        remap_reviews(&mut media_group.reviews);
         completed.push(ImportCompletedItem::MetadataGroup(media_group));
     }
     for collection in complete_data.collections.unwrap_or_default() {
+        let extra_information = collection
+            .collaborators
+            .iter()
+            .find(|c| Some(&c.collaborator.id) == old_user_id.as_ref())
+            .and_then(|c| c.extra_information.clone());
         let collection_input = CreateOrUpdateCollectionInput {
+            extra_information,
             name: collection.name,
             description: collection.description,
+            smart_filter: collection.smart_filter,
             information_template: collection.information_template,
             ..Default::default()
         };
         completed.push(ImportCompletedItem::Collection(collection_input));
     }
-    Ok(ImportResult {
-        completed,
-        ..Default::default()
-    })
+    for integration in complete_data.integrations.unwrap_or_default() {
+        completed.push(ImportCompletedItem::Integration(Box::new(integration)));
+    }
+    for platform in complete_data.notification_platforms.unwrap_or_default() {
+        completed.push(ImportCompletedItem::NotificationPlatform(Box::new(
+            platform,
+        )));
+    }
+    Ok(ImportResult { failed, completed })
 }
@@ -0,0 +1,362 @@
+use std::{
+    collections::HashMap,
+    fs::{self, File},
+    io::{self, BufReader},
+    path::PathBuf,
+    sync::Arc,
+};
+
+use anyhow::{Result, bail};
+use chrono::{DateTime, Utc};
+use common_utils::ryot_log;
+use database_models::{exercise, user_measurement};
+use dependent_models::{ImportCompletedItem, ImportResult};
+use enum_models::ExerciseLot;
+use fitness_models::{
+    SetLot, UserExerciseInput, UserMeasurementInformation, UserMeasurementStatistic,
+    UserWorkoutInput, UserWorkoutSetRecord, WorkoutSetStatistic,
+};
+use importer_models::{ImportFailStep, ImportFailedItem};
+use indexmap::IndexMap;
+use media_models::DeployPathImportInput;
+use rust_decimal::{Decimal, dec, prelude::FromPrimitive};
+use sea_orm::{ConnectionTrait, Database, DatabaseConnection, DbBackend, Statement};
+use supporting_service::SupportingService;
+use tempfile::TempDir;
+use user_models::UserStatisticsMeasurement;
+use zip::ZipArchive;
+
+use crate::utils;
+
+/// The Health Connect records that are imported as measurements, along with the column
+/// holding the value, the name and unit of the statistic and the factor the value is
+/// multiplied by. Masses are stored in grams by Health Connect.
+const MEASUREMENT_RECORDS: [(&str, &str, &str, &str, Decimal); 12] = [
+    ("weight", "weight", "weight", "kg", dec!(0.001)),
+    ("body_fat", "percentage", "body_fat", "%", dec!(1)),
+    (
+        "lean_body_mass",
+        "mass",
+        "lean_body_mass",
+        "kg",
+        dec!(0.001),
+    ),
+    ("bone_mass", "mass", "bone_mass", "kg", dec!(0.001)),
+    ("height", "height", "height", "cm", dec!(100)),
+    (
+        "resting_heart_rate",
+        "beats_per_minute",
+        "resting_heart_rate",
+        "bpm",
+        dec!(1),
+    ),
+    ("blood_glucose", "level", "sugar_level", "mmol/L", dec!(1)),
+    (
+        "blood_pressure",
+        "systolic",
+        "blood_pressure_systolic",
+        "mmHg",
+        dec!(1),
+    ),
+    (
+        "blood_pressure",
+        "diastolic",
+        "blood_pressure_diastolic",
+        "mmHg",
+        dec!(1),
+    ),
+    (
+        "oxygen_saturation",
+        "percentage",
+        "oxygen_saturation",
+        "%",
+        dec!(1),
+    ),
+    (
+        "body_temperature",
+        "temperature",
+        "body_temperature",
+        "°C",
+        dec!(1),
+    ),
+    (
+        "vo2_max",
+        "vo2_milliliters_per_minute_kilogram",
+        "vo2_max",
+        "mL/kg/min",
+        dec!(1),
+    ),
+];
+
+#[derive(Debug)]
+struct ExerciseSession {
+    title: Option<String>,
+    exercise_type: i64,
+    end_time: DateTime<Utc>,
+    start_time: DateTime<Utc>,
+}
+
+pub async fn import(
+    input: DeployPathImportInput,
+    ss: &Arc<SupportingService>,
+    user_id: &str,
+) -> Result<ImportResult> {
+    let (_temp_dir, database_path) = get_database_path(&input.export_path)?;
+    let db = Database::connect(format!("sqlite://{}?mode=ro", database_path.display())).await?;
+    let tables = query_all(
+        &db,
+        "SELECT name FROM sqlite_master WHERE type = 'table'",
+        [],
+    )
+    .await?
+    .into_iter()
+    .filter_map(|r| r.try_get::<String>("", "name").ok())
+    .collect::<Vec<_>>();
+    let mut completed = vec![];
+    let mut failed = vec![];
+    let mut statistics_to_track = vec![];
+    let mut measurements: IndexMap<i64, Vec<UserMeasurementStatistic>> = IndexMap::new();
+    for (record, column, name, unit, factor) in MEASUREMENT_RECORDS {
+        let table = format!("{record}_record_table");
+        if !tables.contains(&table) {
+            continue;
+        }
+        let sql = format!(
+            "SELECT CAST(time AS INTEGER) AS time, CAST({column} AS REAL) AS value FROM {table}"
+        );
+        let rows = query_all(&db, &sql, []).await?;
+        ryot_log!(debug, "Found {} records in {table}", rows.len());
+        if rows.is_empty() {
+            continue;
+        }
+        statistics_to_track.push(UserStatisticsMeasurement {
+            name: name.to_owned(),
+            unit: Some(unit.to_owned()),
+        });
+        for row in rows {
+            let (Ok(time), Ok(value)) = (
+                row.try_get::<i64>("", "time"),
+                row.try_get::<f64>("", "value"),
+            ) else {
+                continue;
+            };
+            let Some(value) = Decimal::from_f64(value) else {
+                continue;
+            };
+            let statistics = measurements.entry(time).or_default();
+            if !statistics.iter().any(|s| s.name == name) {
+                statistics.push(UserMeasurementStatistic {
+                    name: name.to_owned(),
+                    value: (value * factor).round_dp(2),
+                });
+            }
+        }
+    }
+    for (time, statistics) in measurements {
+        let Some(timestamp) = DateTime::from_timestamp_millis(time) else {
+            failed.push(ImportFailedItem {
+                identifier: time.to_string(),
+                step: ImportFailStep::InputTransformation,
+                error: Some("Invalid timestamp of record".to_owned()),
+                ..Default::default()
+            });
+            continue;
+        };
+        completed.push(ImportCompletedItem::Measurement(user_measurement::Model {
+            timestamp,
+            information: UserMeasurementInformation {
+                statistics,
+                ..Default::default()
+            },
+            ..Default::default()
+        }));
+    }
+    utils::add_missing_measurement_statistics(user_id, statistics_to_track, ss).await?;
+
+    let mut unique_exercises: HashMap<String, exercise::Model> = HashMap::new();
+    let sessions = match tables.iter().any(|t| t == "exercise_session_record_table") {
+        false => vec![],
+        true => get_exercise_sessions(&db).await?,
+    };
+    for session in sessions {
+        let start = session.start_time.timestamp_millis();
+        let end = session.end_time.timestamp_millis();
+        // Energy is stored in calories and distance in meters by Health Connect.
+        let calories_burnt = match tables
+            .iter()
+            .any(|t| t == "total_calories_burned_record_table")
+        {
+            false => None,
+            true => sum_in_session(
+                &db,
+                "total_calories_burned_record_table",
+                "energy",
+                start,
+                end,
+            )
+            .await?
+            .map(|c| (c / dec!(1000)).round_dp(0)),
+        };
+        let distance = match tables.iter().any(|t| t == "distance_record_table") {
+            false => None,
+            true => sum_in_session(&db, "distance_record_table", "distance", start, end)
+                .await?
+                .map(|d| (d / dec!(1000)).round_dp(3)),
+        };
+        let exercise_name = get_exercise_name(session.exercise_type);
+        let exercise_lot = match distance {
+            Some(_) => ExerciseLot::DistanceAndDuration,
+            None => ExerciseLot::Duration,
+        };
+        let exercise_id = utils::associate_with_existing_or_new_exercise(
+            user_id,
+            &exercise_name,
+            exercise_lot,
+            ss,
+            &mut unique_exercises,
+        )
+        .await?;
+        let duration = Decimal::from_i64((end - start) / 1000).map(|d| (d / dec!(60)).round_dp(2));
+        completed.push(ImportCompletedItem::Workout(UserWorkoutInput {
+            calories_burnt,
+            end_time: session.end_time,
+            start_time: session.start_time,
+            name: session.title.unwrap_or_else(|| exercise_name.clone()),
+            exercises: vec![UserExerciseInput {
+                exercise_id,
+                sets: vec![UserWorkoutSetRecord {
+                    lot: SetLot::Normal,
+                    statistic: WorkoutSetStatistic {
+                        distance,
+                        duration,
+                        ..Default::default()
+                    },
+                    ..Default::default()
+                }],
+                ..Default::default()
+            }],
+            ..Default::default()
+        }));
+    }
+    db.close().await?;
+    completed.extend(
+        unique_exercises
+            .into_values()
+            .map(ImportCompletedItem::Exercise),
+    );
+    Ok(ImportResult { failed, completed })
+}
+
+/// The export is a zip archive containing a SQLite database, which is extracted to a
+/// temporary directory that lives as long as the returned handle.
+fn get_database_path(export_path: &str) -> Result<(Option<TempDir>, PathBuf)> {
+    if !export_path.to_lowercase().ends_with(".zip") {
+        return Ok((None, PathBuf::from(export_path)));
+    }
+    let mut archive = ZipArchive::new(BufReader::new(File::open(export_path)?))?;
+    let Some(name) = archive
+        .file_names()
+        .find(|n| n.ends_with(".db"))
+        .map(String::from)
+    else {
+        bail!("No database found in the archive");
+    };
+    let temp_dir = TempDir::new()?;
+    let database_path = temp_dir.path().join("health_connect_export.db");
+    io::copy(
+        &mut archive.by_name(&name)?,
+        &mut fs::File::create(&database_path)?,
+    )?;
+    Ok((Some(temp_dir), database_path))
+}
+
+async fn query_all<const N: usize>(
+    db: &DatabaseConnection,
+    sql: &str,
+    values: [sea_orm::Value; N],
+) -> Result<Vec<sea_orm::QueryResult>> {
+    let statement = Statement::from_sql_and_values(DbBackend::Sqlite, sql, values);
+    Ok(db.query_all(statement).await?)
+}
+
+async fn get_exercise_sessions(db: &DatabaseConnection) -> Result<Vec<ExerciseSession>> {
+    let rows = query_all(
+        db,
+        "SELECT CAST(start_time AS INTEGER) AS start_time, CAST(end_time AS INTEGER) AS end_time, \
+         CAST(exercise_type AS INTEGER) AS exercise_type, title FROM exercise_session_record_table",
+        [],
+    )
+    .await?;
+    let sessions = rows
+        .into_iter()
+        .filter_map(|row| {
+            let start_time = row.try_get::<i64>("", "start_time").ok()?;
+            let end_time = row.try_get::<i64>("", "end_time").ok()?;
+            Some(ExerciseSession {
+                title: row
+                    .try_get::<Option<String>>("", "title")
+                    .ok()
+                    .flatten()
+                    .filter(|t| !t.is_empty()),
+                exercise_type: row.try_get("", "exercise_type").unwrap_or_default(),
+                end_time: DateTime::from_timestamp_millis(end_time)?,
+                start_time: DateTime::from_timestamp_millis(start_time)?,
+            })
+        })
+        .collect();
+    Ok(sessions)
+}
+
+async fn sum_in_session(
+    db: &DatabaseConnection,
+    table: &str,
+    column: &str,
+    start: i64,
+    end: i64,
+) -> Result<Option<Decimal>> {
+    let sql = format!(
+        "SELECT CAST(SUM({column}) AS REAL) AS total FROM {table} \
+         WHERE start_time >= ? AND end_time <= ?"
+    );
+    let rows = query_all(db, &sql, [start.into(), end.into()]).await?;
+    Ok(rows
+        .first()
+        .and_then(|r| r.try_get::<Option<f64>>("", "total").ok().flatten())
+        .and_then(Decimal::from_f64))
+}
+
+/// Maps the exercise types defined by Health Connect to the name of the exercise.
+fn get_exercise_name(exercise_type: i64) -> String {
+    match exercise_type {
+        2 => "Badminton",
+        4 => "Baseball",
+        5 => "Basketball",
+        8 => "Cycling",
+        9 => "Cycling, Stationary",
+        14 => "Cricket",
+        16 => "Dancing",
+        25 => "Elliptical",
+        26 => "Exercise Class",
+        29 => "Football",
+        35 => "High Intensity Interval Training",
+        37 => "Hiking",
+        46 => "Paddling",
+        48 => "Pilates",
+        53 => "Rowing Machine",
+        56 => "Running",
+        57 => "Running, Treadmill",
+        61 => "Skiing",
+        64 => "Soccer",
+        68 => "Stair Climbing",
+        70 => "Strength Training",
+        73 => "Swimming, Open Water",
+        74 => "Swimming, Pool",
+        75 => "Table Tennis",
+        76 => "Tennis",
+        79 => "Walking",
+        81 => "Weightlifting",
+        83 => "Yoga",
+        _ => "Workout",
+    }
+    .to_owned()
+}
@@ -1,14 +1,15 @@
 use std::{collections::HashMap, sync::Arc};
 
-use anyhow::Result;
+use anyhow::{Result, bail};
 use background_models::{ApplicationJob, MpApplicationJob};
 use chrono::{Duration, NaiveDateTime, Offset, TimeZone, Utc};
-use common_models::BackgroundJob;
+use common_models::{BackendError, BackgroundJob};
 use common_utils::{MAX_IMPORT_RETRIES_FOR_PARTIAL_STATE, ryot_log};
 use database_models::{
     exercise, import_report,
     prelude::{Exercise, ImportReport},
 };
+use database_utils::user_by_id;
 use dependent_fitness_utils::generate_exercise_id;
 use dependent_import_utils::process_import;
 use dependent_jobs_utils::deploy_background_job;
@@ -18,8 +19,9 @@ use dependent_provider_utils::{
     get_tmdb_non_media_service,
 };
 use enum_models::ImportSource;
-use enum_models::{ExerciseLot, ExerciseSource};
+use enum_models::{ExerciseLot, ExerciseSource, UserLot};
 use importer_models::{ImportFailStep, ImportFailedItem};
+use itertools::Itertools;
 use media_models::DeployImportJobInput;
 use rust_decimal::dec;
 use sea_orm::{
@@ -28,13 +30,19 @@ use sea_orm::{
 };
 use supporting_service::SupportingService;
 use traits::TraceOk;
+use user_models::UserStatisticsMeasurement;
 
+mod activity_file;
 mod anilist;
+mod apple_health;
 mod audiobookshelf;
+mod bangumi;
+mod board_game_geek;
 mod generic_json;
 mod goodreads;
 mod grouvee;
 mod hardcover;
+mod health_connect;
 mod hevy;
 mod igdb;
 mod imdb;
@@ -49,6 +57,23 @@ mod storygraph;
 mod strong_app;
 mod trakt;
 
+#[cfg(test)]
+mod tests;
+
+/// Whether the source only holds fitness data, which is all fitness-only users can import.
+/// Generic JSON exports are not included since they can contain media.
+fn is_fitness_import_source(source: ImportSource) -> bool {
+    matches!(
+        source,
+        ImportSource::Hevy
+            | ImportSource::OpenScale
+            | ImportSource::StrongApp
+            | ImportSource::AppleHealth
+            | ImportSource::ActivityFile
+            | ImportSource::HealthConnect
+    )
+}
+
 pub struct ImporterService(pub Arc<SupportingService>);
 
 impl ImporterService {
@@ -57,6 +82,10 @@ impl ImporterService {
         user_id: String,
         input: DeployImportJobInput,
     ) -> Result<bool> {
+        let user = user_by_id(&user_id, &self.0).await?;
+        if user.lot == UserLot::FitnessOnly && !is_fitness_import_source(input.source) {
+            bail!(BackendError::RoleNotAllowed.to_string());
+        }
         let job = MpApplicationJob::ImportFromExternalSource(user_id, Box::new(input));
         self.0
             .perform_application_job(ApplicationJob::Mp(job))
@@ -97,12 +126,25 @@ impl ImporterService {
             ImportSource::Jellyfin => jellyfin::import(input.jellyfin.unwrap()).await,
             ImportSource::Myanimelist => myanimelist::import(input.mal.unwrap()).await,
             ImportSource::Grouvee => grouvee::import(input.generic_csv.unwrap()).await,
-            ImportSource::GenericJson => generic_json::import(input.path.unwrap()).await,
+            ImportSource::GenericJson => {
+                generic_json::import(input.path.unwrap(), &self.0, &user_id).await
+            }
             ImportSource::Hardcover => hardcover::import(input.generic_csv.unwrap()).await,
             ImportSource::Netflix => netflix::import(input.netflix.unwrap(), &self.0).await,
             ImportSource::Anilist => anilist::import(input.path.unwrap(), &self.0).await,
+            ImportSource::Bangumi => bangumi::import(input.path.unwrap()).await,
+            ImportSource::BoardGameGeek => board_game_geek::import(input.path.unwrap()).await,
             ImportSource::Mediatracker => mediatracker::import(input.url_and_key.unwrap()).await,
             ImportSource::Hevy => hevy::import(input.generic_csv.unwrap(), &self.0, &user_id).await,
+            ImportSource::AppleHealth => {
+                apple_health::import(input.path.unwrap(), &self.0, &user_id).await
+            }
+            ImportSource::HealthConnect => {
+                health_connect::import(input.path.unwrap(), &self.0, &user_id).await
+            }
+            ImportSource::ActivityFile => {
+                activity_file::import(input.path.unwrap(), &self.0, &user_id).await
+            }
             ImportSource::OpenScale => {
                 open_scale::import(input.generic_csv.unwrap(), &self.0.timezone).await
             }
@@ -257,4 +299,28 @@ pub mod utils {
         };
         Ok(exercise_id)
     }
+
+    /// Adds the statistics that are not already tracked to the measurement preferences of
+    /// the user, so that imported values are visible without any manual setup.
+    pub async fn add_missing_measurement_statistics(
+        user_id: &str,
+        statistics: Vec<UserStatisticsMeasurement>,
+        ss: &Arc<SupportingService>,
+    ) -> Result<()> {
+        let user = user_by_id(&user_id.to_owned(), ss).await?;
+        let mut preferences = user.preferences.clone();
+        let existing = &mut preferences.fitness.measurements.statistics;
+        let missing = statistics
+            .into_iter()
+            .filter(|s| !existing.iter().any(|e| e.name == s.name))
+            .collect_vec();
+        if missing.is_empty() {
+            return Ok(());
+        }
+        existing.extend(missing);
+        let mut user = user.into_active_model();
+        user.preferences = ActiveValue::Set(preferences);
+        user.update(&ss.db).await?;
+        Ok(())
+    }
 }
@@ -0,0 +1,71 @@
+use rust_decimal::Decimal;
+
+use super::*;
+use crate::activity_file::{get_activity_sets, get_exercise_name, parse_activity};
+
+const GPX_ACTIVITY: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<gpx version="1.1" creator="StravaGPX">
+  <metadata><time>2024-05-01T06:00:00Z</time></metadata>
+  <trk>
+    <name>Morning Run</name>
+    <type>9</type>
+    <trkseg>
+      <trkpt lat="0.000" lon="0.0"><ele>10</ele><time>2024-05-01T06:00:00Z</time></trkpt>
+      <trkpt lat="0.005" lon="0.0"><ele>11</ele><time>2024-05-01T06:03:00Z</time></trkpt>
+      <trkpt lat="0.010" lon="0.0"><ele>12</ele><time>2024-05-01T06:06:00Z</time></trkpt>
+      <trkpt lat="0.015" lon="0.0"><ele>13</ele><time>2024-05-01T06:09:00Z</time></trkpt>
+    </trkseg>
+  </trk>
+</gpx>"#;
+
+const TCX_ACTIVITY: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<TrainingCenterDatabase xmlns="http://www.garmin.com/xmlschemas/TrainingCenterDatabase/v2">
+  <Activities>
+    <Activity Sport="Biking">
+      <Id>2024-05-02T07:00:00Z</Id>
+      <Lap StartTime="2024-05-02T07:00:00Z">
+        <TotalTimeSeconds>600</TotalTimeSeconds>
+        <DistanceMeters>4000</DistanceMeters>
+        <Calories>120</Calories>
+        <Track>
+          <Trackpoint><Time>2024-05-02T07:00:00Z</Time><DistanceMeters>0</DistanceMeters></Trackpoint>
+          <Trackpoint><Time>2024-05-02T07:10:00Z</Time><DistanceMeters>4000</DistanceMeters></Trackpoint>
+        </Track>
+      </Lap>
+    </Activity>
+  </Activities>
+</TrainingCenterDatabase>"#;
+
+fn set_values(sets: &[fitness_models::UserWorkoutSetRecord]) -> Vec<(Decimal, Decimal)> {
+    sets.iter()
+        .map(|s| (s.statistic.duration.unwrap(), s.statistic.distance.unwrap()))
+        .collect_vec()
+}
+
+#[test]
+fn test_gpx_distances_are_split_by_kilometer() {
+    let activity = parse_activity("run.gpx", GPX_ACTIVITY.as_bytes()).unwrap();
+    let (start_time, end_time, sets) = get_activity_sets(&activity).unwrap();
+    assert_eq!(start_time.to_rfc3339(), "2024-05-01T06:00:00+00:00");
+    assert_eq!(end_time.to_rfc3339(), "2024-05-01T06:09:00+00:00");
+    assert_eq!(
+        set_values(&sets),
+        vec![(dec!(6), dec!(1.112)), (dec!(3), dec!(0.556))]
+    );
+}
+
+#[test]
+fn test_tcx_laps_become_sets() {
+    let activity = parse_activity("ride.tcx", TCX_ACTIVITY.as_bytes()).unwrap();
+    let (_, end_time, sets) = get_activity_sets(&activity).unwrap();
+    assert_eq!(end_time.to_rfc3339(), "2024-05-02T07:10:00+00:00");
+    assert_eq!(set_values(&sets), vec![(dec!(10), dec!(4))]);
+}
+
+#[test]
+fn test_exercise_names_from_sports() {
+    assert_eq!(get_exercise_name(Some("9")), "Running");
+    assert_eq!(get_exercise_name(Some("Biking")), "Cycling");
+    assert_eq!(get_exercise_name(Some("open_water")), "Open Water");
+    assert_eq!(get_exercise_name(None), "Cardio");
+}
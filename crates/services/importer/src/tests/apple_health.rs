@@ -0,0 +1,37 @@
+use std::io::Cursor;
+
+use crate::apple_health::parse_export;
+
+use super::*;
+
+const IMPERIAL_EXPORT: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<HealthData locale="en_US">
+  <Record type="HKQuantityTypeIdentifierBodyMass" unit="lb" value="200" startDate="2024-03-01 08:00:00 -0500"/>
+  <Record type="HKQuantityTypeIdentifierHeight" unit="ft" value="6" startDate="2024-03-01 08:00:00 -0500"/>
+  <Record type="HKQuantityTypeIdentifierBodyTemperature" unit="degF" value="98.6" startDate="2024-03-01 08:00:00 -0500"/>
+  <Workout workoutActivityType="HKWorkoutActivityTypeRunning" duration="30" durationUnit="min" totalDistance="3" totalDistanceUnit="mi" startDate="2024-03-01 09:00:00 -0500" endDate="2024-03-01 09:30:00 -0500"/>
+</HealthData>"#;
+
+#[test]
+fn test_imperial_records_are_converted_to_metric() {
+    let export = parse_export(Cursor::new(IMPERIAL_EXPORT)).unwrap();
+    let statistics = export
+        .measurements
+        .get("2024-03-01 08:00:00 -0500")
+        .unwrap()
+        .iter()
+        .map(|s| (s.name.as_str(), s.value))
+        .collect_vec();
+    assert_eq!(
+        statistics,
+        vec![
+            ("weight", dec!(90.72)),
+            ("height", dec!(182.88)),
+            ("body_temperature", dec!(37)),
+        ]
+    );
+    assert_eq!(export.units.get("weight").unwrap(), "kg");
+    assert_eq!(export.units.get("height").unwrap(), "cm");
+    assert_eq!(export.units.get("body_temperature").unwrap(), "degC");
+    assert_eq!(export.workouts[0].distance, Some(dec!(4.828)));
+}
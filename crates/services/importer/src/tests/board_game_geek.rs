@@ -0,0 +1,98 @@
+use dependent_models::ImportCompletedItem;
+use enum_models::{MediaLot, MediaSource};
+
+use crate::board_game_geek::parse_plays;
+
+use super::*;
+
+const PLAYS: &str = r#"<?xml version="1.0" encoding="utf-8"?>
+<plays username="alice" userid="1" total="4" page="1">
+  <play id="1" date="2024-03-01" quantity="2" length="45" incomplete="0" nowinstats="0" location="">
+    <item name="Catan" objecttype="thing" objectid="13"/>
+    <players>
+      <player username="alice" name="Alice" win="1"/>
+      <player username="bob" name="" win="0"/>
+      <player username="" name="Carol" win="1"/>
+    </players>
+  </play>
+  <play id="2" date="2024-03-05" quantity="0" length="0">
+    <item name="Catan" objecttype="thing" objectid="13"/>
+  </play>
+  <play id="3" date="2024-03-06" quantity="1" length="30">
+    <item name="Some Family" objecttype="family" objectid="99"/>
+  </play>
+  <play id="4" date="2024-03-07">
+    <item name="Azul" objecttype="thing" objectid="230802"/>
+    <players>
+      <player username="bob" name="" win="1"/>
+    </players>
+  </play>
+</plays>"#;
+
+#[test]
+fn test_parse_plays_groups_plays_by_game() {
+    let result = parse_plays(PLAYS).unwrap();
+    let games = result
+        .completed
+        .iter()
+        .map(|item| match item {
+            ImportCompletedItem::Metadata(m) => m,
+            _ => panic!("Expected only metadata items"),
+        })
+        .collect_vec();
+    let summary = games
+        .iter()
+        .map(|g| {
+            (
+                g.identifier.as_str(),
+                g.source_id.as_str(),
+                g.seen_history.len(),
+            )
+        })
+        .collect_vec();
+    assert_eq!(summary, vec![("13", "Catan", 3), ("230802", "Azul", 1)]);
+    assert!(
+        games
+            .iter()
+            .all(|g| g.lot == MediaLot::BoardGame && g.source == MediaSource::BoardGameGeek)
+    );
+}
+
+#[test]
+fn test_parse_plays_reads_play_details() {
+    let result = parse_plays(PLAYS).unwrap();
+    let ImportCompletedItem::Metadata(catan) = &result.completed[0] else {
+        panic!("Expected a metadata item");
+    };
+    let first = &catan.seen_history[0];
+    assert_eq!(first.board_game_winner.as_deref(), Some("Alice, Carol"));
+    assert_eq!(first.board_game_player_count, Some(3));
+    assert_eq!(first.board_game_duration, Some(45));
+    assert_eq!(
+        first
+            .ended_on
+            .map(|d| d.date_naive().to_string())
+            .as_deref(),
+        Some("2024-03-01")
+    );
+    let last = &catan.seen_history[2];
+    assert_eq!(last.board_game_winner, None);
+    assert_eq!(last.board_game_player_count, None);
+    assert_eq!(last.board_game_duration, None);
+
+    let ImportCompletedItem::Metadata(azul) = &result.completed[1] else {
+        panic!("Expected a metadata item");
+    };
+    assert_eq!(
+        azul.seen_history[0].board_game_winner.as_deref(),
+        Some("bob")
+    );
+}
+
+#[test]
+fn test_parse_plays_fails_plays_of_non_games() {
+    let result = parse_plays(PLAYS).unwrap();
+    assert_eq!(result.failed.len(), 1);
+    assert_eq!(result.failed[0].identifier, "Some Family");
+    assert_eq!(result.failed[0].step, ImportFailStep::InputTransformation);
+}
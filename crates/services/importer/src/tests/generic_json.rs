@@ -0,0 +1,139 @@
+use std::{
+    collections::{HashMap, HashSet},
+    fs::File,
+    io::Write,
+};
+
+use common_models::{EntityAssets, StringIdAndNamedObject};
+use common_utils::EXPORT_ARCHIVE_DATA_FILE_NAME;
+use media_models::{ImportOrExportItemRating, ImportOrExportItemReviewComment};
+use tempfile::TempDir;
+use zip::{ZipWriter, write::SimpleFileOptions};
+
+use crate::generic_json::{
+    read_export, read_export_asset, remap_asset_keys, remap_review_user_ids,
+};
+
+const EXPORT_DATA: &str = r#"{
+    "account": null,
+    "assets": [{ "key": "uploads/abc", "path": "assets/0", "content_type": "image/png" }]
+}"#;
+
+fn comment(user_id: &str, liked_by: &[&str]) -> ImportOrExportItemReviewComment {
+    ImportOrExportItemReviewComment {
+        user: StringIdAndNamedObject {
+            id: user_id.to_owned(),
+            name: "Someone".to_owned(),
+        },
+        liked_by: liked_by.iter().map(|l| l.to_string()).collect(),
+        ..Default::default()
+    }
+}
+
+#[test]
+fn test_remap_asset_keys_replaces_uploaded_keys() {
+    let mut assets = EntityAssets {
+        s3_images: vec!["old/image".to_owned()],
+        s3_videos: vec!["old/video".to_owned()],
+        s3_files: vec!["old/file".to_owned()],
+        ..Default::default()
+    };
+    let keys = HashMap::from([
+        ("old/image".to_owned(), "new/image".to_owned()),
+        ("old/video".to_owned(), "new/video".to_owned()),
+        ("old/file".to_owned(), "new/file".to_owned()),
+    ]);
+    remap_asset_keys(&mut assets, &keys);
+    assert_eq!(assets.s3_images, vec!["new/image"]);
+    assert_eq!(assets.s3_videos, vec!["new/video"]);
+    assert_eq!(assets.s3_files, vec!["new/file"]);
+}
+
+#[test]
+fn test_remap_asset_keys_keeps_keys_that_were_not_uploaded() {
+    let mut assets = EntityAssets {
+        s3_images: vec!["old/image".to_owned(), "missing/image".to_owned()],
+        remote_images: vec!["https://example.com/image.png".to_owned()],
+        ..Default::default()
+    };
+    let keys = HashMap::from([("old/image".to_owned(), "new/image".to_owned())]);
+    remap_asset_keys(&mut assets, &keys);
+    assert_eq!(assets.s3_images, vec!["new/image", "missing/image"]);
+    assert_eq!(assets.remote_images, vec!["https://example.com/image.png"]);
+}
+
+#[test]
+fn test_remap_review_user_ids_updates_authors_and_likes() {
+    let mut reviews = vec![ImportOrExportItemRating {
+        comments: Some(vec![
+            comment("usr_old", &["usr_friend"]),
+            comment("usr_friend", &["usr_old", "usr_other"]),
+        ]),
+        ..Default::default()
+    }];
+    remap_review_user_ids(&mut reviews, "usr_old", "usr_new");
+    let comments = reviews[0].comments.as_ref().unwrap();
+    assert_eq!(comments[0].user.id, "usr_new");
+    assert_eq!(
+        comments[0].liked_by,
+        HashSet::from(["usr_friend".to_owned()])
+    );
+    assert_eq!(comments[1].user.id, "usr_friend");
+    assert_eq!(
+        comments[1].liked_by,
+        HashSet::from(["usr_new".to_owned(), "usr_other".to_owned()])
+    );
+}
+
+#[test]
+fn test_remap_review_user_ids_skips_reviews_without_comments() {
+    let mut reviews = vec![ImportOrExportItemRating::default()];
+    remap_review_user_ids(&mut reviews, "usr_old", "usr_new");
+    assert!(reviews[0].comments.is_none());
+}
+
+#[test]
+fn test_read_export_reads_data_and_assets_from_archive() {
+    let dir = TempDir::new().unwrap();
+    let path = dir.path().join("export.zip");
+    let mut archive = ZipWriter::new(File::create(&path).unwrap());
+    archive
+        .start_file("assets/0", SimpleFileOptions::default())
+        .unwrap();
+    archive.write_all(b"image-bytes").unwrap();
+    archive
+        .start_file(EXPORT_ARCHIVE_DATA_FILE_NAME, SimpleFileOptions::default())
+        .unwrap();
+    archive.write_all(EXPORT_DATA.as_bytes()).unwrap();
+    archive.finish().unwrap();
+
+    let (data, archive) = read_export(&path).unwrap();
+    let assets = data.assets.unwrap();
+    assert_eq!(assets.len(), 1);
+    assert_eq!(assets[0].key, "uploads/abc");
+    let mut archive = archive.unwrap();
+    assert_eq!(
+        read_export_asset(&mut archive, &assets[0].path).unwrap(),
+        b"image-bytes"
+    );
+    assert!(read_export_asset(&mut archive, "assets/1").is_err());
+}
+
+#[test]
+fn test_read_export_accepts_plain_json_exports() {
+    let dir = TempDir::new().unwrap();
+    let path = dir.path().join("export.json");
+    std::fs::write(&path, r#"{ "metadata": [] }"#).unwrap();
+    let (data, archive) = read_export(&path).unwrap();
+    assert!(data.metadata.unwrap().is_empty());
+    assert!(data.assets.is_none());
+    assert!(archive.is_none());
+}
+
+#[test]
+fn test_read_export_fails_for_invalid_files() {
+    let dir = TempDir::new().unwrap();
+    let path = dir.path().join("export.json");
+    std::fs::write(&path, "not an export").unwrap();
+    assert!(read_export(&path).is_err());
+}
@@ -0,0 +1,6 @@
+use super::*;
+
+mod activity_file;
+mod apple_health;
+mod board_game_geek;
+mod generic_json;
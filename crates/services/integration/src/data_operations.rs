@@ -61,6 +61,15 @@ impl IntegrationService {
                     )
                     .await
                 }
+                IntegrationProvider::Kavita => {
+                    yank::kavita::yank_progress(
+                        specifics.kavita_base_url.unwrap(),
+                        specifics.kavita_api_key.unwrap(),
+                        specifics.kavita_provider.unwrap(),
+                        &self.0,
+                    )
+                    .await
+                }
                 IntegrationProvider::YoutubeMusic => {
                     server_key_validation_guard(is_server_key_validated(&self.0).await?).await?;
                     yank::youtube_music::yank_progress(
@@ -152,6 +161,15 @@ impl IntegrationService {
                     )
                     .await
                 }
+                IntegrationProvider::Kavita => {
+                    yank::kavita::sync_to_owned_collection(
+                        specifics.kavita_base_url.unwrap(),
+                        specifics.kavita_api_key.unwrap(),
+                        specifics.kavita_provider.unwrap(),
+                        &self.0,
+                    )
+                    .await
+                }
                 IntegrationProvider::PlexYank => {
                     yank::plex::sync_to_owned_collection(
                         specifics.plex_yank_base_url.unwrap(),
@@ -1,7 +1,9 @@
-use anyhow::{Result, anyhow};
+use std::sync::Arc;
+
+use anyhow::{Result, anyhow, bail};
 use database_models::{
-    metadata,
-    prelude::{CollectionToEntity, Metadata, Seen, UserToEntity},
+    integration, metadata,
+    prelude::{CollectionToEntity, Integration, Metadata, Seen, UserToEntity},
     seen, user_to_entity,
 };
 use database_utils::server_key_validation_guard;
@@ -10,6 +12,7 @@ use dependent_details_utils::metadata_details;
 use enum_models::{EntityLot, IntegrationLot, IntegrationProvider, MediaLot};
 use media_models::SeenShowExtraInformation;
 use sea_orm::{ColumnTrait, EntityTrait, QueryFilter, QuerySelect};
+use supporting_service::SupportingService;
 use uuid::Uuid;
 
 use crate::{
@@ -19,7 +22,79 @@ use crate::{
     utils::{ArrPushConfig, ArrPushConfigExternalId},
 };
 
+async fn push_metadata_to_arr_integration(
+    ss: &Arc<SupportingService>,
+    integration: &integration::Model,
+    metadata_id: &String,
+) -> Result<()> {
+    let Some(specifics) = integration.provider_specifics.clone() else {
+        bail!("Integration is missing its provider settings");
+    };
+    let metadata = metadata_details(ss, metadata_id).await?.response;
+    let maybe_entity_id = match metadata.lot {
+        MediaLot::Show => metadata
+            .external_identifiers
+            .and_then(|ei| ei.tvdb_id.map(|i| i.to_string())),
+        _ => Some(metadata.identifier.clone()),
+    };
+    let Some(entity_id) = maybe_entity_id else {
+        return Ok(());
+    };
+    match integration.provider {
+        IntegrationProvider::Radarr => {
+            push::radarr::push_progress(ArrPushConfig {
+                api_key: specifics.radarr_api_key.unwrap(),
+                profile_id: specifics.radarr_profile_id.unwrap(),
+                external_id: ArrPushConfigExternalId::Tmdb(entity_id),
+                base_url: specifics.radarr_base_url.unwrap(),
+                metadata_lot: metadata.lot,
+                metadata_title: metadata.title,
+                root_folder_path: specifics.radarr_root_folder_path.unwrap(),
+                tag_ids: specifics.radarr_tag_ids.clone(),
+            })
+            .await
+        }
+        IntegrationProvider::Sonarr => {
+            push::sonarr::push_progress(ArrPushConfig {
+                api_key: specifics.sonarr_api_key.unwrap(),
+                profile_id: specifics.sonarr_profile_id.unwrap(),
+                external_id: ArrPushConfigExternalId::Tvdb(entity_id),
+                base_url: specifics.sonarr_base_url.unwrap(),
+                metadata_lot: metadata.lot,
+                metadata_title: metadata.title,
+                root_folder_path: specifics.sonarr_root_folder_path.unwrap(),
+                tag_ids: specifics.sonarr_tag_ids.clone(),
+            })
+            .await
+        }
+        _ => bail!("Only Radarr and Sonarr integrations can be pushed to"),
+    }
+}
+
 impl IntegrationService {
+    pub async fn push_metadata_to_integration(
+        &self,
+        integration_id: String,
+        metadata_id: String,
+    ) -> Result<()> {
+        let integration = Integration::find_by_id(integration_id)
+            .one(&self.0.db)
+            .await?
+            .ok_or(anyhow!("Integration does not exist"))?;
+        if integration.is_disabled.unwrap_or_default() {
+            return Ok(());
+        }
+        let push_result =
+            push_metadata_to_arr_integration(&self.0, &integration, &metadata_id).await;
+        set_trigger_result(
+            &self.0,
+            push_result.err().map(|e| e.to_string()),
+            &integration,
+        )
+        .await?;
+        Ok(())
+    }
+
     pub async fn handle_entity_added_to_collection_event(
         &self,
         collection_to_entity_id: Uuid,
@@ -58,46 +133,8 @@ impl IntegrationService {
                 if !possible_collection_ids.contains(&cte.collection_id) {
                     continue;
                 }
-                let specifics = integration.provider_specifics.clone().unwrap();
-                let metadata = metadata_details(&self.0, &cte.entity_id).await?.response;
-                let maybe_entity_id = match metadata.lot {
-                    MediaLot::Show => metadata
-                        .external_identifiers
-                        .and_then(|ei| ei.tvdb_id.map(|i| i.to_string())),
-                    _ => Some(metadata.identifier.clone()),
-                };
-                let Some(entity_id) = maybe_entity_id else {
-                    continue;
-                };
-                let push_result = match integration.provider {
-                    IntegrationProvider::Radarr => {
-                        push::radarr::push_progress(ArrPushConfig {
-                            api_key: specifics.radarr_api_key.unwrap(),
-                            profile_id: specifics.radarr_profile_id.unwrap(),
-                            external_id: ArrPushConfigExternalId::Tmdb(entity_id),
-                            base_url: specifics.radarr_base_url.unwrap(),
-                            metadata_lot: metadata.lot,
-                            metadata_title: metadata.title,
-                            root_folder_path: specifics.radarr_root_folder_path.unwrap(),
-                            tag_ids: specifics.radarr_tag_ids.clone(),
-                        })
-                        .await
-                    }
-                    IntegrationProvider::Sonarr => {
-                        push::sonarr::push_progress(ArrPushConfig {
-                            api_key: specifics.sonarr_api_key.unwrap(),
-                            profile_id: specifics.sonarr_profile_id.unwrap(),
-                            external_id: ArrPushConfigExternalId::Tvdb(entity_id),
-                            base_url: specifics.sonarr_base_url.unwrap(),
-                            metadata_lot: metadata.lot,
-                            metadata_title: metadata.title,
-                            root_folder_path: specifics.sonarr_root_folder_path.unwrap(),
-                            tag_ids: specifics.sonarr_tag_ids.clone(),
-                        })
-                        .await
-                    }
-                    _ => unreachable!(),
-                };
+                let push_result =
+                    push_metadata_to_arr_integration(&self.0, &integration, &cte.entity_id).await;
                 set_trigger_result(
                     &self.0,
                     push_result.err().map(|e| e.to_string()),
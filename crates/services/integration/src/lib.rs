@@ -7,6 +7,8 @@ mod event_handlers;
 mod integration_operations;
 mod push;
 mod sink;
+#[cfg(test)]
+mod tests;
 mod utils;
 mod webhook_handler;
 mod yank;
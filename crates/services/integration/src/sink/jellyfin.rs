@@ -22,6 +22,8 @@ mod models {
     #[serde(rename_all = "PascalCase")]
     pub struct JellyfinWebhookItemProviderIdsPayload {
         pub tmdb: Option<String>,
+        #[serde(rename = "MusicBrainzRecording")]
+        pub musicbrainz_recording: Option<String>,
     }
     #[derive(Serialize, Deserialize, Debug, Clone)]
     #[serde(rename_all = "PascalCase")]
@@ -47,26 +49,35 @@ mod models {
 
 pub async fn sink_progress(payload: String) -> Result<Option<ImportResult>> {
     let payload = serde_json::from_str::<models::JellyfinWebhookPayload>(&payload)?;
-    let identifier = payload
-        .item
-        .provider_ids
-        .tmdb
-        .as_ref()
-        .or_else(|| {
-            payload
-                .series
-                .as_ref()
-                .and_then(|s| s.provider_ids.tmdb.as_ref())
-        })
-        .ok_or_else(|| anyhow!("No TMDb ID associated with this media"))?
-        .clone();
-
-    let lot = match payload.item.item_type.as_str() {
-        "Episode" => MediaLot::Show,
-        "Movie" => MediaLot::Movie,
+    let (lot, source) = match payload.item.item_type.as_str() {
+        "Episode" => (MediaLot::Show, MediaSource::Tmdb),
+        "Movie" => (MediaLot::Movie, MediaSource::Tmdb),
+        "Audio" => (MediaLot::Music, MediaSource::MusicBrainz),
         _ => return Ok(None),
     };
 
+    let identifier = match source {
+        MediaSource::MusicBrainz => payload
+            .item
+            .provider_ids
+            .musicbrainz_recording
+            .clone()
+            .ok_or_else(|| anyhow!("No MusicBrainz recording ID associated with this media"))?,
+        _ => payload
+            .item
+            .provider_ids
+            .tmdb
+            .as_ref()
+            .or_else(|| {
+                payload
+                    .series
+                    .as_ref()
+                    .and_then(|s| s.provider_ids.tmdb.as_ref())
+            })
+            .ok_or_else(|| anyhow!("No TMDb ID associated with this media"))?
+            .clone(),
+    };
+
     let mut seen_item = ImportOrExportMetadataItemSeen {
         show_season_number: payload.item.season_number,
         show_episode_number: payload.item.episode_number,
@@ -90,8 +101,8 @@ pub async fn sink_progress(payload: String) -> Result<Option<ImportResult>> {
     Ok(Some(ImportResult {
         completed: vec![ImportCompletedItem::Metadata(ImportOrExportMetadataItem {
             lot,
+            source,
             identifier,
-            source: MediaSource::Tmdb,
             seen_history: vec![seen_item],
             ..Default::default()
         })],
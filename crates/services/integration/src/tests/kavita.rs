@@ -0,0 +1,68 @@
+use super::*;
+
+fn series_detail() -> models::SeriesDetail {
+    serde_json::from_value(serde_json::json!({
+        "chapters": [
+            { "minNumber": 1, "pages": 20, "pagesRead": 20 },
+            { "minNumber": 2, "pages": 20, "pagesRead": 0 },
+            { "minNumber": -100000, "pages": 10, "pagesRead": 10 }
+        ],
+        "volumes": [
+            { "chapters": [{ "minNumber": 3.5, "pages": 40, "pagesRead": 10 }] },
+            { "chapters": [{ "pages": 12, "pagesRead": 12 }] }
+        ]
+    }))
+    .unwrap()
+}
+
+#[test]
+fn test_get_provider_from_link_reads_known_hosts() {
+    assert_eq!(
+        get_provider_from_link("https://anilist.co/manga/30013/One-Piece"),
+        Some((MediaSource::Anilist, "30013".to_owned()))
+    );
+    assert_eq!(
+        get_provider_from_link("https://www.myanimelist.net/manga/13/One_Piece"),
+        Some((MediaSource::Myanimelist, "13".to_owned()))
+    );
+    assert_eq!(
+        get_provider_from_link("https://comicvine.gamespot.com/batman/4050-796/"),
+        Some((MediaSource::ComicVine, "796".to_owned()))
+    );
+}
+
+#[test]
+fn test_get_provider_from_link_ignores_unknown_links() {
+    assert_eq!(
+        get_provider_from_link("https://mangadex.org/title/abc"),
+        None
+    );
+    assert_eq!(get_provider_from_link("https://anilist.co"), None);
+    assert_eq!(get_provider_from_link("not a link"), None);
+}
+
+#[test]
+fn test_get_read_chapters_skips_unread_and_special_chapters() {
+    let numbers = get_read_chapters(series_detail())
+        .iter()
+        .map(|c| c.min_number)
+        .collect::<Vec<_>>();
+    assert_eq!(numbers, vec![Some(dec!(1)), Some(dec!(3.5))]);
+}
+
+#[test]
+fn test_calculate_percentage_handles_empty_chapters() {
+    let chapters = get_read_chapters(series_detail());
+    assert_eq!(calculate_percentage(&chapters[0]), dec!(100));
+    assert_eq!(calculate_percentage(&chapters[1]), dec!(25));
+    let empty: models::Chapter =
+        serde_json::from_value(serde_json::json!({ "minNumber": 1 })).unwrap();
+    assert_eq!(calculate_percentage(&empty), dec!(0));
+}
+
+#[test]
+fn test_get_issue_number_matches_comic_vine_format() {
+    let chapters = get_read_chapters(series_detail());
+    assert_eq!(get_issue_number(&chapters[0]), "1");
+    assert_eq!(get_issue_number(&chapters[1]), "3.5");
+}
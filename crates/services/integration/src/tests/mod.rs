@@ -0,0 +1,8 @@
+use enum_models::MediaSource;
+use rust_decimal::dec;
+
+use crate::yank::kavita::{
+    calculate_percentage, get_issue_number, get_provider_from_link, get_read_chapters, models,
+};
+
+mod kavita;
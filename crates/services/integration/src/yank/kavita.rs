@@ -0,0 +1,346 @@
+use std::{collections::HashMap, sync::Arc};
+
+use anyhow::{Error, Result};
+use comic_vine_provider::{ComicVineService, extract_volume_id};
+use common_models::DefaultCollection;
+use common_utils::{get_base_http_client, ryot_log};
+use database_models::{
+    metadata, metadata_group,
+    prelude::{Metadata, MetadataGroup},
+};
+use dependent_models::{
+    CollectionToEntityDetails, ImportCompletedItem, ImportOrExportMetadataGroupItem,
+    ImportOrExportMetadataItem, ImportResult,
+};
+use enum_models::{MediaLot, MediaSource};
+use importer_models::{ImportFailStep, ImportFailedItem};
+use itertools::Itertools;
+use media_models::ImportOrExportMetadataItemSeen;
+use reqwest::{
+    Client,
+    header::{AUTHORIZATION, HeaderValue},
+};
+use rust_decimal::{Decimal, dec};
+use sea_orm::{ColumnTrait, EntityTrait, QueryFilter};
+use serde::Deserialize;
+use serde_json::json;
+use supporting_service::SupportingService;
+use url::Url;
+
+pub(crate) mod models {
+    use super::*;
+
+    #[derive(Debug, Deserialize)]
+    pub struct AuthenticateResponse {
+        pub token: String,
+    }
+
+    #[derive(Debug, Deserialize)]
+    pub struct Series {
+        pub id: i64,
+        pub name: String,
+    }
+
+    #[derive(Debug, Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    pub struct Chapter {
+        pub min_number: Option<Decimal>,
+        #[serde(default)]
+        pub pages: i32,
+        #[serde(default)]
+        pub pages_read: i32,
+    }
+
+    #[derive(Debug, Deserialize)]
+    pub struct Volume {
+        #[serde(default)]
+        pub chapters: Vec<Chapter>,
+    }
+
+    #[derive(Debug, Deserialize)]
+    pub struct SeriesDetail {
+        #[serde(default)]
+        pub chapters: Vec<Chapter>,
+        #[serde(default)]
+        pub volumes: Vec<Volume>,
+    }
+
+    #[derive(Debug, Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    pub struct SeriesMetadata {
+        pub web_links: Option<String>,
+    }
+}
+
+async fn get_client(base_url: &str, api_key: &str) -> Result<Client> {
+    let auth: models::AuthenticateResponse = get_base_http_client(None)
+        .post(format!("{base_url}/api/Plugin/authenticate"))
+        .query(&[("apiKey", api_key), ("pluginName", "Ryot")])
+        .send()
+        .await?
+        .error_for_status()?
+        .json()
+        .await?;
+    Ok(get_base_http_client(Some(vec![(
+        AUTHORIZATION,
+        HeaderValue::from_str(&format!("Bearer {}", auth.token))?,
+    )])))
+}
+
+/// Kavita stores links without a label, so the provider is inferred from the host.
+pub(crate) fn get_provider_from_link(link: &str) -> Option<(MediaSource, String)> {
+    let url = Url::parse(link).ok()?;
+    let host = url.host_str()?;
+    let source = match host.trim_start_matches("www.") {
+        "comicvine.gamespot.com" => {
+            return extract_volume_id(link).map(|id| (MediaSource::ComicVine, id));
+        }
+        "anilist.co" => MediaSource::Anilist,
+        "myanimelist.net" => MediaSource::Myanimelist,
+        _ => return None,
+    };
+    url.path_segments()?
+        .nth(1)
+        .map(|id| (source, id.to_owned()))
+}
+
+async fn get_series_provider(
+    client: &Client,
+    base_url: &str,
+    source: MediaSource,
+    series: &models::Series,
+    ss: &Arc<SupportingService>,
+) -> Result<Option<(MediaSource, String)>> {
+    let metadata: models::SeriesMetadata = client
+        .get(format!("{base_url}/api/Series/metadata"))
+        .query(&[("seriesId", series.id)])
+        .send()
+        .await?
+        .error_for_status()?
+        .json()
+        .await?;
+    let links = metadata
+        .web_links
+        .unwrap_or_default()
+        .split(',')
+        .map(|l| l.trim().to_owned())
+        .filter(|l| !l.is_empty())
+        .collect::<Vec<_>>();
+    let providers = links
+        .iter()
+        .filter_map(|l| get_provider_from_link(l))
+        .collect_vec();
+    if let Some(provider) = providers
+        .iter()
+        .find(|p| p.0 == source)
+        .or(providers.first())
+    {
+        return Ok(Some(provider.clone()));
+    }
+    let provider = match source {
+        MediaSource::ComicVine => MetadataGroup::find()
+            .filter(metadata_group::Column::Lot.eq(MediaLot::Comic))
+            .filter(metadata_group::Column::Title.eq(&series.name))
+            .one(&ss.db)
+            .await?
+            .map(|g| (g.source, g.identifier)),
+        _ => Metadata::find()
+            .filter(metadata::Column::Lot.eq(MediaLot::Manga))
+            .filter(metadata::Column::Title.eq(&series.name))
+            .one(&ss.db)
+            .await?
+            .map(|m| (m.source, m.identifier)),
+    };
+    Ok(provider)
+}
+
+pub(crate) fn calculate_percentage(chapter: &models::Chapter) -> Decimal {
+    if chapter.pages == 0 {
+        return dec!(0);
+    }
+    Decimal::from(chapter.pages_read) / Decimal::from(chapter.pages) * dec!(100)
+}
+
+/// Chapters can be listed directly on the series or nested in its volumes. Special
+/// chapters have a negative number and are not tracked.
+pub(crate) fn get_read_chapters(detail: models::SeriesDetail) -> Vec<models::Chapter> {
+    detail
+        .chapters
+        .into_iter()
+        .chain(detail.volumes.into_iter().flat_map(|v| v.chapters))
+        .filter(|c| c.pages_read > 0 && c.min_number.is_some_and(|n| n >= dec!(0)))
+        .collect()
+}
+
+pub(crate) fn get_issue_number(chapter: &models::Chapter) -> String {
+    chapter
+        .min_number
+        .unwrap_or_default()
+        .normalize()
+        .to_string()
+}
+
+fn series_failed_item(series: &models::Series, error: Error) -> ImportFailedItem {
+    ryot_log!(
+        debug,
+        "Could not get metadata of Kavita series {}: {error}",
+        series.name
+    );
+    ImportFailedItem {
+        identifier: series.name.clone(),
+        error: Some(error.to_string()),
+        step: ImportFailStep::ItemDetailsFromSource,
+        ..Default::default()
+    }
+}
+
+pub async fn yank_progress(
+    base_url: String,
+    api_key: String,
+    source: MediaSource,
+    ss: &Arc<SupportingService>,
+) -> Result<ImportResult> {
+    let client = get_client(&base_url, &api_key).await?;
+    let series: Vec<models::Series> = client
+        .post(format!("{base_url}/api/Series/on-deck"))
+        .query(&[("libraryId", "0"), ("PageNumber", "1"), ("PageSize", "50")])
+        .send()
+        .await?
+        .error_for_status()?
+        .json()
+        .await?;
+    ryot_log!(debug, "Got {} series in progress from Kavita", series.len());
+
+    let comic_vine_service = match source {
+        MediaSource::ComicVine => Some(ComicVineService::new(&ss.config.comics.comic_vine).await?),
+        _ => None,
+    };
+    let mut result = ImportResult::default();
+    for item in series {
+        let (source, identifier) =
+            match get_series_provider(&client, &base_url, source, &item, ss).await {
+                Ok(Some(provider)) => provider,
+                Ok(None) => {
+                    ryot_log!(debug, "No provider found for Kavita series: {}", item.name);
+                    continue;
+                }
+                Err(e) => {
+                    result.failed.push(series_failed_item(&item, e));
+                    continue;
+                }
+            };
+        let detail: models::SeriesDetail = client
+            .get(format!("{base_url}/api/Series/series-detail"))
+            .query(&[("seriesId", item.id)])
+            .send()
+            .await?
+            .error_for_status()?
+            .json()
+            .await?;
+        let issues = match (&comic_vine_service, source) {
+            (Some(service), MediaSource::ComicVine) => {
+                match service.volume_issue_identifiers(&identifier).await {
+                    Ok(issues) => Some(issues),
+                    Err(e) => {
+                        result.failed.push(series_failed_item(&item, e));
+                        continue;
+                    }
+                }
+            }
+            _ => None,
+        };
+        for chapter in get_read_chapters(detail) {
+            let seen = ImportOrExportMetadataItemSeen {
+                progress: Some(calculate_percentage(&chapter)),
+                providers_consumed_on: Some(vec!["Kavita".to_string()]),
+                ..Default::default()
+            };
+            let (lot, identifier, seen) = match &issues {
+                Some(issues) => {
+                    let number = get_issue_number(&chapter);
+                    let Some(issue) = issues.get(&number) else {
+                        ryot_log!(debug, "No issue {number} found for volume {identifier}");
+                        continue;
+                    };
+                    (MediaLot::Comic, issue.clone(), seen)
+                }
+                None => (
+                    MediaLot::Manga,
+                    identifier.clone(),
+                    ImportOrExportMetadataItemSeen {
+                        manga_chapter_number: chapter.min_number,
+                        ..seen
+                    },
+                ),
+            };
+            result
+                .completed
+                .push(ImportCompletedItem::Metadata(ImportOrExportMetadataItem {
+                    lot,
+                    source,
+                    identifier,
+                    seen_history: vec![seen],
+                    ..Default::default()
+                }));
+        }
+    }
+    Ok(result)
+}
+
+pub async fn sync_to_owned_collection(
+    base_url: String,
+    api_key: String,
+    source: MediaSource,
+    ss: &Arc<SupportingService>,
+) -> Result<ImportResult> {
+    let client = get_client(&base_url, &api_key).await?;
+    let series: Vec<models::Series> = client
+        .post(format!("{base_url}/api/Series/all-v2"))
+        .json(&json!({ "statements": [], "combination": 1, "limitTo": 0 }))
+        .send()
+        .await?
+        .error_for_status()?
+        .json()
+        .await?;
+    let collections = vec![CollectionToEntityDetails {
+        collection_name: DefaultCollection::Owned.to_string(),
+        ..Default::default()
+    }];
+    let mut failed = vec![];
+    let mut unique_items = HashMap::new();
+    for item in series {
+        match get_series_provider(&client, &base_url, source, &item, ss).await {
+            Ok(Some((MediaSource::ComicVine, identifier))) => {
+                unique_items.insert(
+                    identifier.clone(),
+                    ImportCompletedItem::MetadataGroup(ImportOrExportMetadataGroupItem {
+                        identifier,
+                        title: item.name,
+                        lot: MediaLot::Comic,
+                        source: MediaSource::ComicVine,
+                        collections: collections.clone(),
+                        ..Default::default()
+                    }),
+                );
+            }
+            Ok(Some((source, identifier))) => {
+                unique_items.insert(
+                    identifier.clone(),
+                    ImportCompletedItem::Metadata(ImportOrExportMetadataItem {
+                        source,
+                        identifier,
+                        lot: MediaLot::Manga,
+                        collections: collections.clone(),
+                        ..Default::default()
+                    }),
+                );
+            }
+            Ok(None) => ryot_log!(debug, "No provider found for Kavita series: {}", item.name),
+            Err(e) => failed.push(series_failed_item(&item, e)),
+        }
+    }
+    Ok(ImportResult {
+        failed,
+        completed: unique_items.into_values().collect(),
+    })
+}
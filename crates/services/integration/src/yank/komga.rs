@@ -4,12 +4,17 @@ use std::{
 };
 
 use anyhow::{Context, Result, anyhow, bail};
+use comic_vine_provider::{ComicVineService, extract_issue_id, extract_volume_id};
 use common_models::DefaultCollection;
 use common_utils::get_base_http_client;
 use common_utils::{ryot_log, sleep_for_n_seconds};
-use database_models::{metadata, prelude::Metadata};
+use database_models::{
+    metadata, metadata_group,
+    prelude::{Metadata, MetadataGroup},
+};
 use dependent_models::{
-    CollectionToEntityDetails, ImportCompletedItem, ImportOrExportMetadataItem, ImportResult,
+    CollectionToEntityDetails, ImportCompletedItem, ImportOrExportMetadataGroupItem,
+    ImportOrExportMetadataItem, ImportResult,
 };
 use enum_models::{MediaLot, MediaSource};
 use eventsource_stream::Eventsource;
@@ -36,6 +41,8 @@ mod komga_book {
     #[serde(rename_all = "camelCase")]
     pub struct Metadata {
         pub number: String,
+        #[serde(default)]
+        pub links: Vec<komga_series::Link>,
     }
 
     #[derive(Debug, Serialize, Deserialize)]
@@ -97,11 +104,11 @@ mod komga_series {
 
         /// Extracts the list of providers with a MediaSource,ID Tuple
         ///
-        /// Currently only works for MAL and Anilist as MangaUpdates doesn't store the ID
-        /// in the url
+        /// Currently only works for MAL, Anilist and ComicVine as MangaUpdates doesn't
+        /// store the ID in the url
         ///
-        /// Requires that the metadata is stored with the label Anilist or MAL other
-        /// spellings wont work
+        /// Requires that the metadata is stored with the label Anilist, MAL or ComicVine
+        /// other spellings wont work
         ///
         /// returns: list of providers with a MediaSource, ID Tuple
         pub fn find_providers(&self) -> Vec<(MediaSource, Option<String>)> {
@@ -110,11 +117,15 @@ mod komga_series {
                 // NOTE: manga_updates doesn't work here because the ID isn't in the url
                 let source = match link.label.to_lowercase().as_str() {
                     "anilist" => MediaSource::Anilist,
+                    "comicvine" => MediaSource::ComicVine,
                     "myanimelist" => MediaSource::Myanimelist,
                     _ => continue,
                 };
 
-                let id = self.extract_id(link.url.clone());
+                let id = match source {
+                    MediaSource::ComicVine => extract_volume_id(&link.url),
+                    _ => self.extract_id(link.url.clone()),
+                };
                 provider_links.push((source, id));
             }
 
@@ -274,7 +285,7 @@ async fn fetch_api<T: DeserializeOwned>(
 /// * `series`: The series object from which we want to grab the provider from. There
 ///   should be a links section which is populated with urls from which we
 ///   can extract the series ID. If not a simple search of the db for a manga
-///   with the same title will be preformed
+///   (or a comic volume when using ComicVine) with the same title will be preformed
 ///
 /// returns: This contains the MediaSource and the ID of the series.
 async fn find_provider_and_id(
@@ -290,6 +301,16 @@ async fn find_provider_and_id(
             .cloned()
             .or_else(|| providers.first().cloned())
             .unwrap_or_default())
+    } else if source == MediaSource::ComicVine {
+        let db_volume = MetadataGroup::find()
+            .filter(metadata_group::Column::Lot.eq(MediaLot::Comic))
+            .filter(Expr::col(metadata_group::Column::Title).eq(&series.name))
+            .one(&ss.db)
+            .await?;
+
+        Ok(db_volume
+            .map(|volume| (volume.source, Some(volume.identifier)))
+            .unwrap_or_default())
     } else {
         let db_manga = Metadata::find()
             .filter(metadata::Column::Lot.eq(MediaLot::Manga))
@@ -350,6 +371,47 @@ async fn process_events(
         bail!(msg)
     };
 
+    let seen = ImportOrExportMetadataItemSeen {
+        progress: Some(calculate_percentage(
+            book.read_progress.page,
+            book.media.pages_count,
+        )),
+        providers_consumed_on: Some(vec!["Komga".to_string()]),
+        ..Default::default()
+    };
+
+    if source == MediaSource::ComicVine {
+        let issue_id = match book
+            .metadata
+            .links
+            .iter()
+            .find_map(|link| extract_issue_id(&link.url))
+        {
+            Some(issue_id) => Some(issue_id),
+            None => {
+                ComicVineService::new(&ss.config.comics.comic_vine)
+                    .await?
+                    .issue_identifier_by_number(&id, &book.metadata.number)
+                    .await?
+            }
+        };
+        let Some(issue_id) = issue_id else {
+            bail!(
+                "No ComicVine issue found for {} #{}",
+                series.name,
+                book.metadata.number
+            )
+        };
+        return Ok((
+            UniqueMediaIdentifier {
+                source,
+                identifier: issue_id,
+                lot: MediaLot::Comic,
+            },
+            seen,
+        ));
+    }
+
     Ok((
         UniqueMediaIdentifier {
             source,
@@ -357,13 +419,8 @@ async fn process_events(
             lot: MediaLot::Manga,
         },
         ImportOrExportMetadataItemSeen {
-            progress: Some(calculate_percentage(
-                book.read_progress.page,
-                book.media.pages_count,
-            )),
-            providers_consumed_on: Some(vec!["Komga".to_string()]),
             manga_chapter_number: Some(book.metadata.number.parse().unwrap_or_default()),
-            ..Default::default()
+            ..seen
         },
     ))
 }
@@ -488,17 +545,29 @@ pub async fn sync_to_owned_collection(
     // multiple times this prevents us from double committing an identifier
     let unique_collection_updates: HashMap<String, _> = stream::iter(series.content)
         .filter_map(|book| async move {
+            let collections = vec![CollectionToEntityDetails {
+                collection_name: DefaultCollection::Owned.to_string(),
+                ..Default::default()
+            }];
             match find_provider_and_id(source, ss, &book).await {
+                Ok((MediaSource::ComicVine, Some(id))) => Some((
+                    id.clone(),
+                    ImportCompletedItem::MetadataGroup(ImportOrExportMetadataGroupItem {
+                        collections,
+                        identifier: id,
+                        title: book.name,
+                        lot: MediaLot::Comic,
+                        source: MediaSource::ComicVine,
+                        ..Default::default()
+                    }),
+                )),
                 Ok((source, Some(id))) => Some((
                     id.clone(),
                     ImportCompletedItem::Metadata(ImportOrExportMetadataItem {
                         identifier: id,
                         lot: MediaLot::Manga,
                         source,
-                        collections: vec![CollectionToEntityDetails {
-                            collection_name: DefaultCollection::Owned.to_string(),
-                            ..Default::default()
-                        }],
+                        collections,
                         ..Default::default()
                     }),
                 )),
@@ -1,4 +1,5 @@
 pub mod audiobookshelf;
+pub mod kavita;
 pub mod komga;
 pub mod plex;
 pub mod youtube_music;
@@ -31,9 +31,10 @@ pub async fn expire_cache_keys(ss: &Arc<SupportingService>) -> Result<()> {
             }),
         )));
     }
-    all_keys.push(ExpireCacheKeyInput::ByKey(Box::new(
-        ApplicationCacheKey::TrendingMetadataIds,
-    )));
+    all_keys.push(ExpireCacheKeyInput::BySanitizedKey {
+        user_id: None,
+        key: ApplicationCacheKeyDiscriminants::TrendingMetadataIds,
+    });
 
     for key in all_keys {
         cache_service::expire_key(ss, key).await?;
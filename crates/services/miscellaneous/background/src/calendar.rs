@@ -1,9 +1,10 @@
-use std::sync::Arc;
+use std::{collections::HashMap, sync::Arc};
 
 use anyhow::Result;
 use application_utils::{
     get_current_date, get_podcast_episode_by_number, get_show_episode_by_numbers,
 };
+use background_models::{ApplicationJob, LpApplicationJob};
 use chrono::NaiveDate;
 use common_models::{ChangeCollectionToEntitiesInput, DefaultCollection, EntityToCollectionInput};
 use common_utils::{SHOW_SPECIAL_SEASON_NAMES, ryot_log};
@@ -12,13 +13,15 @@ use database_models::{
     collection_entity_membership::{self, Entity as CollectionEntityMembership},
     metadata::{self, Entity as Metadata},
 };
+use database_utils::user_timezone_by_id;
 use dependent_collection_utils::remove_entities_from_collection;
 use dependent_notification_utils::{get_users_monitoring_entity, send_notification_for_user};
-use enum_models::{EntityLot, UserNotificationContent};
+use enum_models::{AutomationRuleTrigger, EntityLot, UserNotificationContent};
 use futures::TryStreamExt;
 use itertools::Itertools;
 use media_models::{
-    SeenAnimeExtraInformation, SeenPodcastExtraInformation, SeenShowExtraInformation,
+    AutomationRuleEvent, SeenAnimeExtraInformation, SeenPodcastExtraInformation,
+    SeenShowExtraInformation,
 };
 use sea_orm::{ActiveModelTrait, ActiveValue, ColumnTrait, EntityTrait, ModelTrait, QueryFilter};
 use serde::{Deserialize, Serialize};
@@ -199,6 +202,17 @@ pub async fn notify_users_for_released_media(ss: &Arc<SupportingService>) -> Res
             get_users_monitoring_entity(&metadata_id, EntityLot::Metadata, ss).await?;
         for user in users_to_notify {
             send_notification_for_user(&user, ss, notification.clone()).await?;
+            ss.perform_application_job(ApplicationJob::Lp(
+                LpApplicationJob::HandleAutomationRuleEvent(AutomationRuleEvent {
+                    user_id: user,
+                    collection_name: None,
+                    entity_lot: EntityLot::Metadata,
+                    entity_id: metadata_id.clone(),
+                    trigger: AutomationRuleTrigger::ReleaseDateReached,
+                    triggered_by_automation: false,
+                }),
+            ))
+            .await?;
         }
     }
     Ok(())
@@ -210,6 +224,7 @@ pub async fn notify_users_for_pending_reminders(ss: &Arc<SupportingService>) ->
         text: String,
         reminder: NaiveDate,
     }
+    let mut user_timezones = HashMap::new();
     for membership in CollectionEntityMembership::find()
         .filter(
             collection_entity_membership::Column::CollectionName
@@ -221,7 +236,15 @@ pub async fn notify_users_for_pending_reminders(ss: &Arc<SupportingService>) ->
         if let Some(reminder) = membership.collection_to_entity_information {
             let reminder: UserMediaReminder =
                 serde_json::from_str(&serde_json::to_string(&reminder)?)?;
-            if get_current_date(&ss.timezone) == reminder.reminder {
+            let timezone = match user_timezones.get(&membership.user_id) {
+                Some(timezone) => *timezone,
+                None => {
+                    let timezone = user_timezone_by_id(&membership.user_id, ss).await?;
+                    user_timezones.insert(membership.user_id.clone(), timezone);
+                    timezone
+                }
+            };
+            if get_current_date(&timezone) == reminder.reminder {
                 send_notification_for_user(
                     &membership.user_id,
                     ss,
@@ -1,20 +1,28 @@
 use std::sync::Arc;
 
 use anyhow::Result;
-use chrono::Utc;
+use chrono::{Duration, Utc};
 use common_utils::{BULK_DATABASE_UPDATE_OR_DELETE_CHUNK_SIZE, ryot_log};
 use database_models::{
-    access_link, application_cache, genre, metadata, metadata_group, metadata_to_genre, person,
+    access_link, application_cache, collection, genre, metadata, metadata_group, metadata_to_genre,
+    person,
     prelude::{
-        AccessLink, ApplicationCache, Genre, Metadata, MetadataGroup, MetadataToGenre, Person,
-        UserToEntity,
+        AccessLink, ApplicationCache, Collection, Genre, Metadata, MetadataGroup, MetadataToGenre,
+        Person, Review, Seen, ShareLink, UserFeedEvent, UserInvite, UserNotification, UserToEntity,
+        Workout,
     },
-    user_to_entity,
+    review, seen, share_link, user_feed_event, user_invite, user_notification, user_to_entity,
+    workout,
+};
+use enum_models::UserFeedEventLot;
+use sea_orm::{
+    ColumnTrait, EntityTrait, QueryFilter, QuerySelect, QueryTrait, UpdateMany, prelude::Expr,
 };
-use sea_orm::{ColumnTrait, EntityTrait, QueryFilter, QuerySelect, UpdateMany, prelude::Expr};
 use supporting_service::SupportingService;
 use traits::TraceOk;
 
+const USER_NOTIFICATION_RETENTION_DAYS: i64 = 90;
+
 pub async fn remove_useless_data(ss: &Arc<SupportingService>) -> Result<()> {
     let metadata_to_delete = Metadata::find()
         .select_only()
@@ -86,12 +94,72 @@ pub async fn remove_useless_data(ss: &Arc<SupportingService>) -> Result<()> {
         .exec(&ss.db)
         .await
         .trace_ok();
+    ryot_log!(debug, "Deleting revoked share links");
+    ShareLink::delete_many()
+        .filter(share_link::Column::IsRevoked.eq(true))
+        .exec(&ss.db)
+        .await
+        .trace_ok();
+    ryot_log!(debug, "Deleting expired user invites");
+    UserInvite::delete_many()
+        .filter(user_invite::Column::UsedOn.is_null())
+        .filter(user_invite::Column::ExpiresOn.lt(Utc::now()))
+        .exec(&ss.db)
+        .await
+        .trace_ok();
+    ryot_log!(debug, "Deleting feed events whose origin no longer exists");
+    for (lot, origin_ids) in [
+        (
+            UserFeedEventLot::Review,
+            Review::find()
+                .select_only()
+                .column(review::Column::Id)
+                .into_query(),
+        ),
+        (
+            UserFeedEventLot::SeenCompleted,
+            Seen::find()
+                .select_only()
+                .column(seen::Column::Id)
+                .into_query(),
+        ),
+        (
+            UserFeedEventLot::Workout,
+            Workout::find()
+                .select_only()
+                .column(workout::Column::Id)
+                .into_query(),
+        ),
+        (
+            UserFeedEventLot::CollectionCreated,
+            Collection::find()
+                .select_only()
+                .column(collection::Column::Id)
+                .into_query(),
+        ),
+    ] {
+        UserFeedEvent::delete_many()
+            .filter(user_feed_event::Column::Lot.eq(lot))
+            .filter(user_feed_event::Column::OriginId.not_in_subquery(origin_ids))
+            .exec(&ss.db)
+            .await
+            .trace_ok();
+    }
     ryot_log!(debug, "Deleting expired application caches");
     ApplicationCache::delete_many()
         .filter(application_cache::Column::ExpiresAt.lt(Utc::now()))
         .exec(&ss.db)
         .await
         .trace_ok();
+    ryot_log!(debug, "Deleting old inbox notifications");
+    UserNotification::delete_many()
+        .filter(
+            user_notification::Column::CreatedOn
+                .lt(Utc::now() - Duration::days(USER_NOTIFICATION_RETENTION_DAYS)),
+        )
+        .exec(&ss.db)
+        .await
+        .trace_ok();
     Ok(())
 }
 
@@ -4,6 +4,7 @@ use anyhow::Result;
 use chrono::Utc;
 use common_utils::ryot_log;
 use database_models::{import_report, prelude::ImportReport};
+use dependent_collection_utils::sync_all_smart_collections;
 use sea_orm::{ColumnTrait, EntityTrait, QueryFilter, prelude::Expr};
 use supporting_service::SupportingService;
 use traits::TraceOk;
@@ -22,7 +23,9 @@ use crate::{
         update_all_monitored_metadata_and_notify_users,
         update_all_monitored_people_and_notify_users,
     },
-    notifications::send_notifications_for_outdated_seen_entries,
+    notifications::{
+        remove_old_notification_deliveries, send_notifications_for_outdated_seen_entries,
+    },
     summaries::regenerate_user_summaries,
 };
 
@@ -60,10 +63,14 @@ pub async fn perform_background_jobs(ss: &Arc<SupportingService>) -> Result<()>
     sync_integrations_data_to_owned_collection(ss)
         .await
         .trace_ok();
+    ryot_log!(trace, "Syncing smart collections");
+    sync_all_smart_collections(ss).await.trace_ok();
     ryot_log!(trace, "Sending notifications for outdated seen entries");
     send_notifications_for_outdated_seen_entries(ss)
         .await
         .trace_ok();
+    ryot_log!(trace, "Removing old notification deliveries");
+    remove_old_notification_deliveries(ss).await.trace_ok();
     ryot_log!(trace, "Removing useless data");
     remove_useless_data(ss).await.trace_ok();
     ryot_log!(trace, "Removing cached metadata after metadata updates");
@@ -97,3 +104,7 @@ pub async fn invalidate_import_jobs(ss: &Arc<SupportingService>) -> Result<()> {
 pub async fn cleanup_user_and_metadata_association(ss: &Arc<SupportingService>) -> Result<()> {
     user::cleanup_user_and_metadata_association(ss).await
 }
+
+pub async fn process_pending_notification_deliveries(ss: &Arc<SupportingService>) -> Result<()> {
+    dependent_notification_utils::process_pending_notification_deliveries(ss).await
+}
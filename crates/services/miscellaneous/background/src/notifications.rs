@@ -2,19 +2,37 @@ use std::sync::Arc;
 
 use anyhow::Result;
 use chrono::{Duration, Utc};
+use common_utils::ryot_log;
 use convert_case::{Case, Casing};
 use database_models::{
-    prelude::{Metadata, Seen},
+    notification_delivery,
+    prelude::{Metadata, NotificationDelivery, Seen},
     seen,
 };
 use dependent_core_utils::is_server_key_validated;
 use dependent_notification_utils::send_notification_for_user;
-use enum_models::{EntityLot, SeenState, UserNotificationContent};
+use enum_models::{EntityLot, NotificationDeliveryStatus, SeenState, UserNotificationContent};
 use sea_orm::{ColumnTrait, EntityTrait, ModelTrait, QueryFilter};
 use supporting_service::SupportingService;
 
 const IN_PROGRESS_OUTDATED_THRESHOLD_DAYS: i64 = 7;
 const ON_A_HOLD_OUTDATED_THRESHOLD_DAYS: i64 = 14;
+const NOTIFICATION_DELIVERY_RETENTION_DAYS: i64 = 30;
+
+pub async fn remove_old_notification_deliveries(ss: &Arc<SupportingService>) -> Result<()> {
+    let threshold = Utc::now() - Duration::days(NOTIFICATION_DELIVERY_RETENTION_DAYS);
+    let result = NotificationDelivery::delete_many()
+        .filter(notification_delivery::Column::CreatedOn.lt(threshold))
+        .filter(notification_delivery::Column::Status.ne(NotificationDeliveryStatus::Pending))
+        .exec(&ss.db)
+        .await?;
+    ryot_log!(
+        debug,
+        "Removed {} old notification deliveries",
+        result.rows_affected
+    );
+    Ok(())
+}
 
 pub async fn send_notifications_for_outdated_seen_entries(
     ss: &Arc<SupportingService>,
@@ -49,6 +67,7 @@ pub async fn send_notifications_for_outdated_seen_entries(
                 UserNotificationContent::OutdatedSeenEntries {
                     seen_state: state,
                     days_threshold: days,
+                    entity_id: Some(metadata.id),
                     entity_title: metadata.title,
                     entity_lot: EntityLot::Metadata,
                     last_updated_on: seen_item.last_updated_on.date_naive(),
@@ -1,8 +1,11 @@
 use std::sync::Arc;
 
 use anyhow::{Result, anyhow};
-use application_utils::{get_podcast_episode_by_number, get_show_episode_by_numbers};
-use chrono::{Days, NaiveDate, Utc};
+use application_utils::{
+    get_current_date, get_podcast_episode_by_number, get_show_episode_by_numbers,
+};
+use chrono::{Days, NaiveDate};
+use chrono_tz::Tz;
 use common_models::EntityAssets;
 use common_utils::{get_db_stmt, get_first_and_last_day_of_month};
 use database_models::{
@@ -10,9 +13,8 @@ use database_models::{
     prelude::{CalendarEvent, Metadata, UserToEntity},
     user_to_entity,
 };
-use database_utils::user_by_id;
+use database_utils::{get_user_timezone, user_by_id};
 use enum_models::{MediaLot, UserToMediaReason};
-use futures::{TryFutureExt, try_join};
 use itertools::Itertools;
 use media_models::{
     GraphqlCalendarEvent, PodcastSpecifics, SeenAnimeExtraInformation, SeenPodcastExtraInformation,
@@ -20,9 +22,9 @@ use media_models::{
 };
 use media_models::{GroupedCalendarEvent, UserCalendarEventInput, UserUpcomingCalendarEventInput};
 use sea_orm::{
-    ColumnTrait, EntityTrait, FromQueryResult, JoinType, Order, QueryFilter, QueryOrder,
-    QuerySelect, QueryTrait,
-    sea_query::{Alias, Asterisk, Condition, Expr, PgFunc, Query},
+    EntityTrait, FromQueryResult, JoinType, Order, QueryFilter, QueryOrder, QuerySelect,
+    QueryTrait,
+    sea_query::{Alias, Asterisk, Condition, Expr, PgFunc, Query, SimpleExpr},
 };
 use supporting_service::SupportingService;
 use user_models::DashboardElementLot;
@@ -45,6 +47,7 @@ pub async fn user_calendar_events(
     .await?;
     let grouped_events = events
         .into_iter()
+        .sorted_by_key(|event| event.date)
         .chunk_by(|event| event.date)
         .into_iter()
         .map(|(date, events)| GroupedCalendarEvent {
@@ -60,15 +63,17 @@ pub async fn user_upcoming_calendar_events(
     user_id: String,
     input: UserUpcomingCalendarEventInput,
 ) -> Result<Vec<GraphqlCalendarEvent>> {
-    let start_date = Utc::now().date_naive();
+    let user = user_by_id(&user_id, ss).await?;
+    let start_date = get_current_date(&get_user_timezone(&user, ss));
     let (media_limit, end_date) = match input {
         UserUpcomingCalendarEventInput::NextMedia(l) => (Some(l), None),
         UserUpcomingCalendarEventInput::NextDays(d) => {
             (None, start_date.checked_add_days(Days::new(d)))
         }
     };
-    let preferences = user_by_id(&user_id, ss).await?.preferences.general;
-    let element = preferences
+    let element = user
+        .preferences
+        .general
         .dashboard
         .iter()
         .find(|e| matches!(e.section, DashboardElementLot::Upcoming));
@@ -85,6 +90,27 @@ pub async fn user_upcoming_calendar_events(
     Ok(events)
 }
 
+/// The date an event falls on for the user. Only anime airing times carry a time of day, so
+/// every other event keeps the date it was stored with.
+fn calendar_event_local_date(timezone: &Tz) -> SimpleExpr {
+    Expr::case(
+        Expr::col((
+            calendar_event::Entity,
+            calendar_event::Column::MetadataAnimeExtraInformation,
+        ))
+        .is_not_null(),
+        Expr::cust_with_values(
+            r#"CAST(("calendar_event"."timestamp" AT TIME ZONE 'UTC') AT TIME ZONE $1 AS DATE)"#,
+            [timezone.name()],
+        ),
+    )
+    .finally(Expr::col((
+        calendar_event::Entity,
+        calendar_event::Column::Date,
+    )))
+    .into()
+}
+
 async fn get_calendar_events(
     ss: &Arc<SupportingService>,
     user_id: String,
@@ -97,7 +123,7 @@ async fn get_calendar_events(
     #[derive(Debug, FromQueryResult, Clone)]
     struct CalEvent {
         id: String,
-        date: NaiveDate,
+        local_date: NaiveDate,
         m_lot: MediaLot,
         m_title: String,
         metadata_id: String,
@@ -109,6 +135,9 @@ async fn get_calendar_events(
         metadata_podcast_extra_information: Option<SeenPodcastExtraInformation>,
     }
 
+    let user = user_by_id(&user_id, ss).await?;
+    let timezone = get_user_timezone(&user, ss);
+    let local_date = calendar_event_local_date(&timezone);
     let stmt = Query::select()
         .column(Asterisk)
         .from_subquery(
@@ -121,6 +150,7 @@ async fn get_calendar_events(
                             calendar_event::Column::MetadataId,
                         )))
                 })
+                .column_as(local_date.clone(), "local_date")
                 .column_as(
                     Expr::col((metadata::Entity, metadata::Column::Lot)),
                     "m_lot",
@@ -166,30 +196,28 @@ async fn get_calendar_events(
                         })
                         .into(),
                 )
-                .order_by_asc(calendar_event::Column::Date)
+                .order_by_asc(local_date.clone())
                 .apply_if(start_date, |q, v| {
-                    q.filter(calendar_event::Column::Date.gte(v))
+                    q.filter(Expr::expr(local_date.clone()).gte(v))
                 })
                 .apply_if(end_date, |q, v| {
-                    q.filter(calendar_event::Column::Date.lte(v))
+                    q.filter(Expr::expr(local_date.clone()).lte(v))
                 })
                 .limit(media_limit)
                 .into_query(),
             Alias::new("sub_query"),
         )
-        .order_by(calendar_event::Column::Date, Order::Asc)
+        .order_by(Alias::new("local_date"), Order::Asc)
         .to_owned();
-    let (user, all_events) = try_join!(
-        user_by_id(&user_id, ss),
-        CalEvent::find_by_statement(get_db_stmt(stmt))
-            .all(&ss.db)
-            .map_err(|_| anyhow!("Failed to fetch calendar events"))
-    )?;
+    let all_events = CalEvent::find_by_statement(get_db_stmt(stmt))
+        .all(&ss.db)
+        .await
+        .map_err(|_| anyhow!("Failed to fetch calendar events"))?;
     let show_spoilers_in_calendar = user.preferences.general.show_spoilers_in_calendar;
     let mut events = vec![];
     for evt in all_events {
         let mut calc = GraphqlCalendarEvent {
-            date: evt.date,
+            date: evt.local_date,
             metadata_lot: evt.m_lot,
             calendar_event_id: evt.id,
             metadata_text: evt.m_title,
@@ -2,14 +2,16 @@ use std::sync::Arc;
 
 use anyhow::{Result, anyhow};
 use application_utils::calculate_average_rating_for_user;
+use background_models::{ApplicationJob, LpApplicationJob};
 use common_models::{EntityRecentlyConsumedCacheInput, UserLevelCacheKey};
 use database_models::{
     functions::get_user_to_entity_association,
-    prelude::{Metadata, Seen},
+    metadata_translation,
+    prelude::{Metadata, MetadataTranslation, Seen},
     seen,
 };
 use database_utils::{
-    entity_in_collections_with_details, item_reviews, server_key_validation_guard,
+    entity_in_collections_with_details, item_reviews, server_key_validation_guard, user_by_id,
 };
 use dependent_core_utils::is_server_key_validated;
 use dependent_entity_utils::generic_metadata;
@@ -25,9 +27,32 @@ use media_models::{
     UserMediaNextEntry, UserMetadataDetailsEpisodeProgress, UserMetadataDetailsShowSeasonProgress,
 };
 use rust_decimal::dec;
-use sea_orm::{ColumnTrait, EntityTrait, QuerySelect};
+use sea_orm::{ColumnTrait, EntityTrait, QueryFilter, QuerySelect};
 use supporting_service::SupportingService;
 
+#[cfg(test)]
+mod tests;
+
+/// The translations to show a media item with, in the order of the user's languages. A
+/// language with a region like `zh-TW` falls back to a translation in `zh`. The details from
+/// the provider are only used when none of these has the field.
+pub(crate) fn get_preferred_translations<'a>(
+    languages: &[String],
+    translations: &'a [metadata_translation::Model],
+) -> Vec<&'a metadata_translation::Model> {
+    languages
+        .iter()
+        .flat_map(|language| {
+            let base = language.split_once('-').map(|(base, _)| base);
+            let exact = translations.iter().find(|t| &t.language == language);
+            let fallback = base.and_then(|base| translations.iter().find(|t| t.language == base));
+            [exact, fallback]
+        })
+        .flatten()
+        .unique_by(|t| &t.id)
+        .collect_vec()
+}
+
 pub async fn user_metadata_details(
     ss: &Arc<SupportingService>,
     user_id: String,
@@ -48,6 +73,8 @@ pub async fn user_metadata_details(
                 (_, history),
                 seen_by,
                 user_to_meta,
+                user,
+                translations,
             ) = try_join!(
                 generic_metadata(&metadata_id, ss, None),
                 entity_in_collections_with_details(&user_id, &metadata_id, EntityLot::Metadata, ss),
@@ -60,9 +87,37 @@ pub async fn user_metadata_details(
                     .into_tuple::<(i64,)>()
                     .one(&ss.db)
                     .map_err(|_| anyhow!("Metadata not found")),
-                get_user_to_entity_association(&ss.db, &user_id, &metadata_id, EntityLot::Metadata)
+                get_user_to_entity_association(&ss.db, &user_id, &metadata_id, EntityLot::Metadata),
+                user_by_id(&user_id, ss),
+                MetadataTranslation::find()
+                    .filter(metadata_translation::Column::MetadataId.eq(&metadata_id))
+                    .all(&ss.db)
+                    .map_err(|_| anyhow!("Failed to fetch translations"))
             )?;
 
+            let languages = user.preferences.general.metadata_languages;
+            if languages
+                .iter()
+                .any(|l| !translations.iter().any(|t| &t.language == l))
+            {
+                ss.perform_application_job(ApplicationJob::Lp(
+                    LpApplicationJob::UpdateUserMetadataTranslations(
+                        user_id.clone(),
+                        metadata_id.clone(),
+                    ),
+                ))
+                .await?;
+            }
+            let preferred_translations = get_preferred_translations(&languages, &translations);
+            let title = preferred_translations
+                .iter()
+                .find_map(|t| t.title.clone())
+                .unwrap_or_else(|| media_details.model.title.clone());
+            let description = preferred_translations
+                .iter()
+                .find_map(|t| t.description.clone())
+                .or_else(|| media_details.model.description.clone());
+
             let in_progress = history
                 .iter()
                 .find(|h| h.state == SeenState::InProgress || h.state == SeenState::OnAHold)
@@ -182,10 +237,12 @@ pub async fn user_metadata_details(
                     None
                 };
             Ok(UserMetadataDetails {
+                title,
                 reviews,
                 history,
                 next_entry,
                 collections,
+                description,
                 in_progress,
                 show_progress,
                 average_rating,
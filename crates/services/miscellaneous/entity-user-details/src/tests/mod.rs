@@ -0,0 +1,15 @@
+use database_models::metadata_translation;
+use rstest::rstest;
+
+mod translations;
+
+fn translation(language: &str, title: Option<&str>) -> metadata_translation::Model {
+    metadata_translation::Model {
+        id: format!("mtr_{language}"),
+        language: language.to_owned(),
+        metadata_id: "met_1".to_owned(),
+        title: title.map(str::to_owned),
+        created_on: Default::default(),
+        description: None,
+    }
+}
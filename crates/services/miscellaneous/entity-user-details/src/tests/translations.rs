@@ -0,0 +1,42 @@
+use super::*;
+use crate::get_preferred_translations;
+
+fn preferred_languages(languages: &[&str], stored: &[&str]) -> Vec<String> {
+    let languages = languages.iter().map(|l| l.to_string()).collect::<Vec<_>>();
+    let translations = stored
+        .iter()
+        .map(|l| translation(l, Some(l)))
+        .collect::<Vec<_>>();
+    get_preferred_translations(&languages, &translations)
+        .into_iter()
+        .map(|t| t.language.clone())
+        .collect()
+}
+
+#[rstest]
+#[case(&["zh-TW", "en"], &["en", "zh-TW", "zh"], &["zh-TW", "zh", "en"])]
+#[case(&["zh-TW", "en"], &["en", "zh"], &["zh", "en"])]
+#[case(&["en", "zh-TW"], &["zh", "en"], &["en", "zh"])]
+#[case(&["zh", "zh-TW"], &["zh", "zh-TW"], &["zh", "zh-TW"])]
+#[case(&["ja"], &["en", "zh"], &[])]
+#[case(&[], &["en", "zh"], &[])]
+fn test_translations_follow_the_user_language_order(
+    #[case] languages: &[&str],
+    #[case] stored: &[&str],
+    #[case] expected: &[&str],
+) {
+    assert_eq!(preferred_languages(languages, stored), expected);
+}
+
+#[test]
+fn test_untranslated_fields_fall_through_to_the_next_language() {
+    let languages = ["zh-TW".to_owned(), "en".to_owned()];
+    let translations = [
+        translation("zh-TW", None),
+        translation("en", Some("Spirited Away")),
+    ];
+    let title = get_preferred_translations(&languages, &translations)
+        .iter()
+        .find_map(|t| t.title.clone());
+    assert_eq!(title.as_deref(), Some("Spirited Away"));
+}
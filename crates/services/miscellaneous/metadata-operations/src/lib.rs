@@ -16,7 +16,7 @@ use database_models::{
     },
     review, seen, user_to_entity,
 };
-use database_utils::entity_in_collections_with_collection_to_entity_ids;
+use database_utils::{entity_in_collections_with_collection_to_entity_ids, user_by_id};
 use dependent_collection_utils::{add_entities_to_collection, remove_entities_from_collection};
 use dependent_details_utils::metadata_details;
 use dependent_entity_utils::{
@@ -185,6 +185,20 @@ pub async fn create_custom_metadata(
     user_id: String,
     input: CreateCustomMetadataInput,
 ) -> Result<metadata::Model> {
+    if let Some(max) = user_by_id(&user_id, ss)
+        .await?
+        .limits
+        .and_then(|l| l.max_custom_metadata)
+    {
+        let created = Metadata::find()
+            .filter(metadata::Column::Source.eq(MediaSource::Custom))
+            .filter(metadata::Column::CreatedByUserId.eq(&user_id))
+            .count(&ss.db)
+            .await?;
+        if created >= max {
+            bail!("You can not create more than {max} custom media");
+        }
+    }
     let identifier = nanoid!(10);
     let metadata = get_data_for_custom_metadata(input.clone(), identifier, &user_id);
     let metadata = metadata.insert(&ss.db).await?;
@@ -492,6 +506,8 @@ fn get_data_for_custom_metadata(
     let is_partial = match input.lot {
         MediaLot::Show => input.show_specifics.is_none(),
         MediaLot::Book => input.book_specifics.is_none(),
+        MediaLot::Comic => input.comic_specifics.is_none(),
+        MediaLot::BoardGame => input.board_game_specifics.is_none(),
         MediaLot::Music => input.music_specifics.is_none(),
         MediaLot::Anime => input.anime_specifics.is_none(),
         MediaLot::Manga => input.manga_specifics.is_none(),
@@ -513,6 +529,8 @@ fn get_data_for_custom_metadata(
         publish_date: ActiveValue::Set(input.publish_date),
         show_specifics: ActiveValue::Set(input.show_specifics),
         book_specifics: ActiveValue::Set(input.book_specifics),
+        comic_specifics: ActiveValue::Set(input.comic_specifics),
+        board_game_specifics: ActiveValue::Set(input.board_game_specifics),
         manga_specifics: ActiveValue::Set(input.manga_specifics),
         anime_specifics: ActiveValue::Set(input.anime_specifics),
         movie_specifics: ActiveValue::Set(input.movie_specifics),
@@ -3,7 +3,9 @@ use std::sync::Arc;
 use anyhow::{Result, bail};
 use common_models::StringIdObject;
 use database_models::prelude::{Review, Seen};
-use dependent_progress_utils::metadata_progress_update;
+use dependent_progress_utils::{
+    deploy_job_to_mirror_metadata_progress_updates, metadata_progress_update,
+};
 use dependent_seen_utils::handle_after_metadata_seen_tasks;
 use dependent_utility_utils::associate_user_with_entity;
 use enum_models::EntityLot;
@@ -85,8 +87,15 @@ pub async fn bulk_metadata_progress_update(
     user_id: &String,
     input: Vec<MetadataProgressUpdateInput>,
 ) -> Result<()> {
+    let mut to_mirror = vec![];
     for seen in input {
-        metadata_progress_update(user_id, ss, seen).await.trace_ok();
+        if metadata_progress_update(user_id, ss, seen.clone())
+            .await
+            .trace_ok()
+            .is_some()
+        {
+            to_mirror.push(seen);
+        }
     }
-    Ok(())
+    deploy_job_to_mirror_metadata_progress_updates(user_id, to_mirror, ss).await
 }
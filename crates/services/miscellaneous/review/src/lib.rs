@@ -1,11 +1,13 @@
 use std::{collections::HashSet, sync::Arc};
 
 use anyhow::{Result, anyhow, bail};
+use background_models::{ApplicationJob, LpApplicationJob};
 use chrono::Utc;
 use common_models::StringIdAndNamedObject;
 use database_models::{prelude::Review, review};
 use database_utils::user_by_id;
 use dependent_utility_utils::associate_user_with_entity;
+use enum_models::EntityLot;
 use media_models::{CreateReviewCommentInput, ImportOrExportItemReviewComment};
 use nanoid::nanoid;
 use sea_orm::{
@@ -27,7 +29,14 @@ pub async fn delete_review(
         Some(r) => {
             if r.user_id == user_id {
                 associate_user_with_entity(&user_id, &r.entity_id, r.entity_lot, ss).await?;
+                let (entity_id, entity_lot) = (r.entity_id.clone(), r.entity_lot);
                 r.delete(&ss.db).await?;
+                if entity_lot == EntityLot::Metadata {
+                    ss.perform_application_job(ApplicationJob::Lp(
+                        LpApplicationJob::SyncUserSmartCollections(user_id, Some(entity_id)),
+                    ))
+                    .await?;
+                }
                 Ok(true)
             } else {
                 Err(anyhow!("This review does not belong to you"))
@@ -10,6 +10,7 @@ use dependent_core_utils::core_details;
 use dependent_entity_list_utils::{
     user_genres_list, user_metadata_groups_list, user_metadata_list, user_people_list,
 };
+use dependent_entity_utils::update_user_metadata_translations;
 use dependent_jobs_utils::{
     deploy_background_job, deploy_update_metadata_group_job, deploy_update_metadata_job,
     deploy_update_person_job,
@@ -25,6 +26,7 @@ use dependent_notification_utils::{
     update_metadata_and_notify_users, update_metadata_group_and_notify_users,
     update_person_and_notify_users,
 };
+use dependent_progress_utils::mirror_metadata_progress_updates;
 use dependent_review_utils::post_review;
 use enum_models::EntityLot;
 use media_models::{
@@ -64,6 +66,14 @@ impl MiscellaneousService {
         .await
     }
 
+    pub async fn update_user_metadata_translations(
+        &self,
+        user_id: &String,
+        metadata_id: &String,
+    ) -> Result<()> {
+        update_user_metadata_translations(user_id, metadata_id, &self.0).await
+    }
+
     pub async fn user_person_details(
         &self,
         user_id: String,
@@ -144,6 +154,14 @@ impl MiscellaneousService {
             .await
     }
 
+    pub async fn mirror_metadata_progress_updates(
+        &self,
+        user_id: String,
+        input: Vec<MetadataProgressUpdateInput>,
+    ) -> Result<()> {
+        mirror_metadata_progress_updates(&user_id, input, &self.0).await
+    }
+
     pub async fn expire_cache_key(&self, cache_id: Uuid) -> Result<bool> {
         miscellaneous_general_service::expire_cache_key(&self.0, cache_id).await
     }
@@ -334,8 +352,8 @@ impl MiscellaneousService {
         Ok(())
     }
 
-    pub async fn trending_metadata(&self) -> Result<TrendingMetadataIdsResponse> {
-        miscellaneous_trending_and_events_service::trending_metadata(&self.0).await
+    pub async fn trending_metadata(&self, user_id: &String) -> Result<TrendingMetadataIdsResponse> {
+        miscellaneous_trending_and_events_service::trending_metadata(&self.0, user_id).await
     }
 
     pub async fn handle_review_posted_event(&self, event: ReviewPostedEvent) -> Result<()> {
@@ -370,6 +388,10 @@ impl MiscellaneousService {
         miscellaneous_background_service::cleanup_user_and_metadata_association(&self.0).await
     }
 
+    pub async fn process_pending_notification_deliveries(&self) -> Result<()> {
+        miscellaneous_background_service::process_pending_notification_deliveries(&self.0).await
+    }
+
     pub async fn perform_background_jobs(&self) -> Result<()> {
         miscellaneous_background_service::perform_background_jobs(&self.0).await
     }
@@ -1,60 +1,113 @@
 use std::{collections::HashSet, sync::Arc};
 
 use anyhow::Result;
+use common_models::{TrendingMediaList, TrendingMetadataCacheInput};
 use database_models::{metadata, prelude::Metadata};
+use database_utils::user_by_id;
 use dependent_entity_utils::commit_metadata;
 use dependent_models::{ApplicationCacheKey, ApplicationCacheValue, TrendingMetadataIdsResponse};
 use dependent_notification_utils::{get_users_monitoring_entity, send_notification_for_user};
 use dependent_provider_utils::get_metadata_provider;
 use enum_meta::Meta;
-use enum_models::{MediaLot, UserNotificationContent};
+use enum_models::{MediaLot, MediaSource, UserNotificationContent};
 use itertools::Itertools;
 use media_models::ReviewPostedEvent;
-use sea_orm::{ColumnTrait, EntityTrait, Iterable, QueryFilter, QueryOrder, QuerySelect};
+use sea_orm::{ColumnTrait, Condition, EntityTrait, Iterable, QueryFilter, QuerySelect};
 use supporting_service::SupportingService;
+use traits::TraceOk;
 
-pub async fn trending_metadata(ss: &Arc<SupportingService>) -> Result<TrendingMetadataIdsResponse> {
-    let key = ApplicationCacheKey::TrendingMetadataIds;
+#[cfg(test)]
+mod tests;
+
+async fn provider_trending_metadata(
+    ss: &Arc<SupportingService>,
+    lot: MediaLot,
+    source: MediaSource,
+    list: TrendingMediaList,
+) -> Result<TrendingMetadataIdsResponse> {
     let cached_response = cache_service::get_or_set_with_callback(
         ss,
-        key,
+        ApplicationCacheKey::TrendingMetadataIds(TrendingMetadataCacheInput { lot, source, list }),
         ApplicationCacheValue::TrendingMetadataIds,
         || async {
-            let mut trending_ids = HashSet::new();
-            let provider_configs = MediaLot::iter()
-                .flat_map(|lot| lot.meta().into_iter().map(move |source| (lot, source)));
-
-            for (lot, source) in provider_configs {
-                let provider = match get_metadata_provider(lot, source, ss).await {
-                    Ok(p) => p,
-                    Err(_) => continue,
-                };
-                let media = match provider.get_trending_media().await {
-                    Ok(m) => m,
-                    Err(_) => continue,
-                };
-                for item in media {
-                    if let Ok((metadata, _)) = commit_metadata(item, ss, None).await {
-                        trending_ids.insert(metadata.id);
-                    }
+            // a provider that is not configured or does not support trending lists is cached
+            // as empty so that it is not queried again on every dashboard load
+            let Some(provider) = get_metadata_provider(lot, source, ss).await.trace_ok() else {
+                return Ok(vec![]);
+            };
+            let Some(media) = provider.get_trending_media(list).await.trace_ok() else {
+                return Ok(vec![]);
+            };
+            let mut trending_ids = vec![];
+            for item in media {
+                if let Ok((metadata, _)) = commit_metadata(item, ss, None).await {
+                    trending_ids.push(metadata.id);
                 }
             }
-
-            let vec = trending_ids.into_iter().collect_vec();
-            Ok(vec)
+            Ok(trending_ids.into_iter().unique().collect_vec())
         },
     )
     .await?;
-    let (_id, cached) = (cached_response.cache_id, cached_response.response);
-    let actually_in_db = Metadata::find()
+    Ok(cached_response.response)
+}
+
+/// Takes one item from each list in turn so that no single list dominates the result.
+pub(crate) fn round_robin(lists: Vec<Vec<String>>) -> Vec<String> {
+    let mut iterators = lists.into_iter().map(|l| l.into_iter()).collect_vec();
+    let mut merged = vec![];
+    loop {
+        let before = merged.len();
+        for iterator in iterators.iter_mut() {
+            merged.extend(iterator.next());
+        }
+        if merged.len() == before {
+            break;
+        }
+    }
+    merged.into_iter().unique().collect()
+}
+
+pub async fn trending_metadata(
+    ss: &Arc<SupportingService>,
+    user_id: &String,
+) -> Result<TrendingMetadataIdsResponse> {
+    let preferences = user_by_id(user_id, ss).await?.preferences;
+    let media_preferences = preferences.features_enabled.media;
+    if !media_preferences.enabled {
+        return Ok(vec![]);
+    }
+    let mut per_lot = vec![];
+    for lot in media_preferences.specific {
+        let mut per_list = vec![];
+        for source in lot.meta() {
+            for list in TrendingMediaList::iter() {
+                per_list.push(provider_trending_metadata(ss, lot, source, list).await?);
+            }
+        }
+        per_lot.push(round_robin(per_list));
+    }
+    let merged = round_robin(per_lot);
+    let mut query = Metadata::find()
         .select_only()
         .column(metadata::Column::Id)
-        .filter(metadata::Column::Id.is_in(cached))
-        .order_by_desc(metadata::Column::LastUpdatedOn)
+        .filter(metadata::Column::Id.is_in(merged.clone()));
+    if !preferences.general.display_nsfw {
+        query = query.filter(
+            Condition::any()
+                .add(metadata::Column::IsNsfw.eq(false))
+                .add(metadata::Column::IsNsfw.is_null()),
+        );
+    }
+    let actually_in_db: HashSet<String> = query
         .into_tuple::<String>()
         .all(&ss.db)
-        .await?;
-    Ok(actually_in_db)
+        .await?
+        .into_iter()
+        .collect();
+    Ok(merged
+        .into_iter()
+        .filter(|id| actually_in_db.contains(id))
+        .collect())
 }
 
 pub async fn handle_review_posted_event(
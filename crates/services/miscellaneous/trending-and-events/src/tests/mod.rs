@@ -0,0 +1,3 @@
+use crate::round_robin;
+
+mod round_robin;
@@ -0,0 +1,29 @@
+use super::*;
+
+fn ids(values: &[&str]) -> Vec<String> {
+    values.iter().map(|v| v.to_string()).collect()
+}
+
+#[test]
+fn test_round_robin_interleaves_lists() {
+    let merged = round_robin(vec![
+        ids(&["a1", "a2", "a3"]),
+        ids(&["b1", "b2"]),
+        ids(&["c1"]),
+    ]);
+    assert_eq!(merged, ids(&["a1", "b1", "c1", "a2", "b2", "a3"]));
+}
+
+#[test]
+fn test_round_robin_keeps_first_occurrence_of_duplicates() {
+    let merged = round_robin(vec![ids(&["x", "a"]), ids(&["b", "x"])]);
+    assert_eq!(merged, ids(&["x", "b", "a"]));
+}
+
+#[test]
+fn test_round_robin_skips_empty_lists() {
+    let merged = round_robin(vec![vec![], ids(&["a", "b"]), vec![]]);
+    assert_eq!(merged, ids(&["a", "b"]));
+    assert!(round_robin(vec![]).is_empty());
+    assert!(round_robin(vec![vec![], vec![]]).is_empty());
+}
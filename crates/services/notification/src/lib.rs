@@ -1,15 +1,124 @@
 use std::env;
 
-use anyhow::Result;
+use anyhow::{Result, anyhow, bail};
+use chrono::Utc;
 use common_utils::{APPLICATION_JSON_HEADER, AVATAR_URL, PROJECT_NAME, ryot_log};
+use config_definition::SmtpConfig;
 use convert_case::{Case, Casing};
+use enum_models::UserNotificationContent;
+use lettre::{
+    AsyncSmtpTransport, AsyncTransport, Message, Tokio1Executor, message::MultiPart,
+    transport::smtp::authentication::Credentials,
+};
+use nanoid::nanoid;
 use reqwest::{
-    Client,
+    Client, Url,
     header::{AUTHORIZATION, CONTENT_TYPE, HeaderValue},
 };
+use ring::hmac;
 use user_models::NotificationPlatformSpecifics;
 
-pub async fn send_notification(specifics: NotificationPlatformSpecifics, msg: &str) -> Result<()> {
+#[cfg(test)]
+mod tests;
+
+fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+/// Wraps a plain-text notification in a minimal HTML document, turning any URLs in it into
+/// links.
+pub(crate) fn render_email_html(subject: &str, text: &str) -> String {
+    let body = text
+        .split(' ')
+        .map(|word| {
+            let escaped = escape_html(word);
+            let url = word.trim_matches(|c| matches!(c, '(' | ')' | ',' | '.'));
+            if !url.starts_with("http://") && !url.starts_with("https://") {
+                return escaped;
+            }
+            let url = escape_html(url);
+            escaped.replacen(&url, &format!(r#"<a href="{url}">{url}</a>"#), 1)
+        })
+        .collect::<Vec<_>>()
+        .join(" ");
+    format!(
+        r#"<!DOCTYPE html>
+<html>
+  <body style="font-family: sans-serif; color: #1a1b1e;">
+    <img src="{AVATAR_URL}" alt="{project_name}" width="48" height="48" />
+    <h2>{subject}</h2>
+    <p>{body}</p>
+    <p style="color: #868e96; font-size: 12px;">Sent by {project_name}.</p>
+  </body>
+</html>"#,
+        subject = escape_html(subject),
+        project_name = PROJECT_NAME.to_case(Case::Title),
+    )
+}
+
+pub async fn send_email(smtp: &SmtpConfig, to: &str, subject: &str, text: &str) -> Result<()> {
+    if !smtp.is_enabled() {
+        bail!("SMTP is not configured on this server");
+    }
+    let message = Message::builder()
+        .from(smtp.mailbox.parse()?)
+        .to(to.parse()?)
+        .subject(subject)
+        .multipart(MultiPart::alternative_plain_html(
+            text.to_owned(),
+            render_email_html(subject, text),
+        ))?;
+    let mut transport = match smtp.disable_tls {
+        true => AsyncSmtpTransport::<Tokio1Executor>::builder_dangerous(&smtp.server),
+        false => AsyncSmtpTransport::<Tokio1Executor>::relay(&smtp.server)?,
+    };
+    if let Some(port) = smtp.port {
+        transport = transport.port(port);
+    }
+    if !smtp.user.is_empty() {
+        transport =
+            transport.credentials(Credentials::new(smtp.user.clone(), smtp.password.clone()));
+    }
+    transport.build().send(message).await?;
+    Ok(())
+}
+
+/// The JSON body posted by the webhook platform. `content` is the structured notification,
+/// and is absent for test notifications.
+pub(crate) fn get_webhook_payload(
+    subject: &str,
+    msg: &str,
+    content: Option<&UserNotificationContent>,
+) -> serde_json::Value {
+    let event = content
+        .map(|c| c.to_string().to_case(Case::Snake))
+        .unwrap_or_else(|| "test_notification".to_owned());
+    serde_json::json!({
+        "event": event,
+        "title": subject,
+        "message": msg,
+        "content": content,
+        "sent_at": Utc::now(),
+    })
+}
+
+/// The value of the signature header, which receivers recompute over the raw body with
+/// their copy of the secret.
+pub(crate) fn get_webhook_signature(secret: &str, body: &[u8]) -> String {
+    let key = hmac::Key::new(hmac::HMAC_SHA256, secret.as_bytes());
+    format!("sha256={}", hex::encode(hmac::sign(&key, body)))
+}
+
+pub async fn send_notification(
+    specifics: NotificationPlatformSpecifics,
+    smtp: &SmtpConfig,
+    subject: &str,
+    msg: &str,
+    content: Option<&UserNotificationContent>,
+) -> Result<()> {
     let project_name = PROJECT_NAME.to_case(Case::Title);
     let client = Client::new();
     if env::var("DISABLE_NOTIFICATIONS").is_ok() {
@@ -28,6 +137,9 @@ pub async fn send_notification(specifics: NotificationPlatformSpecifics, msg: &s
                 .send()
                 .await?;
         }
+        NotificationPlatformSpecifics::Email { email, .. } => {
+            send_email(smtp, &email, subject, msg).await?;
+        }
         NotificationPlatformSpecifics::Discord { url } => {
             client
                 .post(url)
@@ -60,6 +172,37 @@ pub async fn send_notification(specifics: NotificationPlatformSpecifics, msg: &s
                 .send()
                 .await?;
         }
+        NotificationPlatformSpecifics::Matrix {
+            url,
+            room_id,
+            access_token,
+        } => {
+            let mut endpoint = Url::parse(&url)?;
+            endpoint
+                .path_segments_mut()
+                .map_err(|_| anyhow!("Invalid Matrix homeserver URL"))?
+                .pop_if_empty()
+                .extend([
+                    "_matrix",
+                    "client",
+                    "v3",
+                    "rooms",
+                    &room_id,
+                    "send",
+                    "m.room.message",
+                    &nanoid!(),
+                ]);
+            client
+                .put(endpoint)
+                .bearer_auth(access_token)
+                .json(&serde_json::json!({
+                    "body": msg,
+                    "msgtype": "m.text",
+                }))
+                .send()
+                .await?
+                .error_for_status()?;
+        }
         NotificationPlatformSpecifics::Ntfy {
             url,
             priority,
@@ -122,6 +265,18 @@ pub async fn send_notification(specifics: NotificationPlatformSpecifics, msg: &s
                 .send()
                 .await?;
         }
+        NotificationPlatformSpecifics::Slack { url } => {
+            client
+                .post(url)
+                .json(&serde_json::json!({
+                    "text": msg,
+                    "icon_url": AVATAR_URL,
+                    "username": project_name,
+                }))
+                .send()
+                .await?
+                .error_for_status()?;
+        }
         NotificationPlatformSpecifics::Telegram { bot_token, chat_id } => {
             client
                 .post(format!(
@@ -135,6 +290,23 @@ pub async fn send_notification(specifics: NotificationPlatformSpecifics, msg: &s
                 .send()
                 .await?;
         }
+        NotificationPlatformSpecifics::Webhook {
+            url,
+            secret,
+            headers,
+        } => {
+            let body = serde_json::to_vec(&get_webhook_payload(subject, msg, content))?;
+            let mut request = client
+                .post(url)
+                .header(CONTENT_TYPE, APPLICATION_JSON_HEADER.clone());
+            for header in headers {
+                request = request.header(header.name, header.value);
+            }
+            if let Some(secret) = secret {
+                request = request.header("X-Ryot-Signature", get_webhook_signature(&secret, &body));
+            }
+            request.body(body).send().await?.error_for_status()?;
+        }
     }
     Ok(())
 }
@@ -0,0 +1,53 @@
+use super::*;
+
+#[test]
+fn test_render_email_html_escapes_text_and_links_urls() {
+    let html = render_email_html(
+        "New <episode>",
+        "Watch it at https://example.com/show?a=1&b=2, or not.",
+    );
+    assert!(html.contains("<h2>New &lt;episode&gt;</h2>"));
+    assert!(html.contains(
+        r#"<a href="https://example.com/show?a=1&amp;b=2">https://example.com/show?a=1&amp;b=2</a>,"#
+    ));
+    assert!(html.contains("or not."));
+    assert!(!html.contains("<episode>"));
+}
+
+#[test]
+fn test_render_email_html_leaves_plain_words_alone() {
+    let html = render_email_html("Subject", "http is not a link");
+    assert!(html.contains("<p>http is not a link</p>"));
+    assert!(!html.contains("<a href"));
+}
+
+#[tokio::test]
+async fn test_send_email_delivers_plain_and_html_parts() {
+    let (port, sink) = start_smtp_sink().await;
+    send_email(
+        &smtp_config(port),
+        "user@example.com",
+        "Verify your email address",
+        "Your verification code is 123456.",
+    )
+    .await
+    .unwrap();
+    let data = sink.await.unwrap();
+    assert!(data.contains("To: user@example.com"));
+    assert!(data.contains("Subject: Verify your email address"));
+    assert!(data.contains("Content-Type: text/plain"));
+    assert!(data.contains("Content-Type: text/html"));
+    assert!(data.contains("Your verification code is 123456."));
+}
+
+#[tokio::test]
+async fn test_send_email_requires_smtp_configuration() {
+    let config = SmtpConfig {
+        server: String::new(),
+        ..smtp_config(25)
+    };
+    let error = send_email(&config, "user@example.com", "Subject", "Text")
+        .await
+        .unwrap_err();
+    assert_eq!(error.to_string(), "SMTP is not configured on this server");
+}
@@ -0,0 +1,58 @@
+use config_definition::SmtpConfig;
+use enum_models::{EntityLot, UserNotificationContent};
+use tokio::{
+    io::{AsyncBufReadExt, AsyncWriteExt, BufReader},
+    net::TcpListener,
+    task::JoinHandle,
+};
+
+use crate::{get_webhook_payload, get_webhook_signature, render_email_html, send_email};
+
+mod email;
+mod webhook;
+
+/// A minimal SMTP server which accepts a single message and returns its raw data.
+async fn start_smtp_sink() -> (u16, JoinHandle<String>) {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let port = listener.local_addr().unwrap().port();
+    let handle = tokio::spawn(async move {
+        let (stream, _) = listener.accept().await.unwrap();
+        let (reader, mut writer) = stream.into_split();
+        let mut lines = BufReader::new(reader).lines();
+        writer.write_all(b"220 localhost ESMTP\r\n").await.unwrap();
+        let mut data = String::new();
+        while let Some(line) = lines.next_line().await.unwrap() {
+            let command = line.to_uppercase();
+            let reply: &[u8] = if command.starts_with("EHLO") {
+                b"250-localhost\r\n250 8BITMIME\r\n"
+            } else if command.starts_with("DATA") {
+                writer.write_all(b"354 Go ahead\r\n").await.unwrap();
+                while let Some(line) = lines.next_line().await.unwrap() {
+                    if line == "." {
+                        break;
+                    }
+                    data.push_str(&line);
+                    data.push('\n');
+                }
+                writer.write_all(b"250 Queued\r\n").await.unwrap();
+                break;
+            } else {
+                b"250 OK\r\n"
+            };
+            writer.write_all(reply).await.unwrap();
+        }
+        data
+    });
+    (port, handle)
+}
+
+fn smtp_config(port: u16) -> SmtpConfig {
+    SmtpConfig {
+        port: Some(port),
+        disable_tls: true,
+        user: String::new(),
+        password: String::new(),
+        server: "127.0.0.1".to_owned(),
+        mailbox: "Ryot <no-reply@ryot.io>".to_owned(),
+    }
+}
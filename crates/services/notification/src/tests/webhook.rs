@@ -0,0 +1,39 @@
+use super::*;
+
+#[test]
+fn test_webhook_payload_of_test_notification() {
+    let payload = get_webhook_payload("Test notification", "Hello", None);
+    assert_eq!(payload["event"], "test_notification");
+    assert_eq!(payload["title"], "Test notification");
+    assert_eq!(payload["message"], "Hello");
+    assert!(payload["content"].is_null());
+    assert!(payload["sent_at"].is_string());
+}
+
+#[test]
+fn test_webhook_payload_includes_structured_content() {
+    let content = UserNotificationContent::ReviewPosted {
+        entity_id: "met_1".to_owned(),
+        entity_title: "Dune".to_owned(),
+        entity_lot: EntityLot::Metadata,
+        triggered_by_username: "alice".to_owned(),
+    };
+    let payload = get_webhook_payload("Review posted", "alice reviewed Dune", Some(&content));
+    assert_eq!(payload["event"], "review_posted");
+    assert_eq!(payload["content"], serde_json::to_value(&content).unwrap());
+}
+
+#[test]
+fn test_webhook_signature_is_hex_encoded_hmac_sha256() {
+    assert_eq!(
+        get_webhook_signature("key", b"The quick brown fox jumps over the lazy dog"),
+        "sha256=f7bc83f430538424b13298e6aa6fb143ef4d59a14946175997479dbc2d1a3cd8"
+    );
+}
+
+#[test]
+fn test_webhook_signature_depends_on_secret_and_body() {
+    let signature = get_webhook_signature("secret", b"{}");
+    assert_ne!(signature, get_webhook_signature("other", b"{}"));
+    assert_ne!(signature, get_webhook_signature("secret", b"{ }"));
+}
@@ -3,14 +3,19 @@ use std::sync::Arc;
 use anyhow::Result;
 use chrono::Duration;
 use common_utils::generate_session_id;
+use database_models::{application_cache, prelude::ApplicationCache};
 use dependent_models::{
-    ApplicationCacheKey, ApplicationCacheValue, ExpireCacheKeyInput, UserSessionValue,
+    ApplicationCacheKey, ApplicationCacheKeyDiscriminants, ApplicationCacheValue,
+    ExpireCacheKeyInput, UserSessionValue,
 };
+use enum_models::UserLot;
+use sea_orm::{ColumnTrait, EntityTrait, QueryFilter, prelude::Expr};
 use supporting_service::SupportingService;
 
 pub async fn create_session(
     ss: &Arc<SupportingService>,
     user_id: String,
+    user_lot: UserLot,
     access_link_id: Option<String>,
     expiry_duration: Option<Duration>,
 ) -> Result<String> {
@@ -19,6 +24,7 @@ pub async fn create_session(
     let cache_value = ApplicationCacheValue::UserSession(UserSessionValue {
         user_id,
         access_link_id,
+        user_lot: Some(user_lot),
     });
     match expiry_duration {
         Some(duration) => {
@@ -47,3 +53,31 @@ pub async fn invalidate_session(ss: &Arc<SupportingService>, session_id: &str) -
     cache_service::expire_key(ss, ExpireCacheKeyInput::ByKey(Box::new(cache_key))).await?;
     Ok(())
 }
+
+/// Change the role stored in all sessions of a user, so that a new role applies without
+/// them having to log in again.
+pub async fn update_user_sessions_lot(
+    ss: &Arc<SupportingService>,
+    user_id: &String,
+    user_lot: UserLot,
+) -> Result<()> {
+    ApplicationCache::update_many()
+        .col_expr(
+            application_cache::Column::Value,
+            Expr::cust_with_values(
+                r#"jsonb_set("value", '{UserSession,user_lot}', $1)"#,
+                [serde_json::to_value(user_lot)?],
+            ),
+        )
+        .filter(
+            application_cache::Column::SanitizedKey
+                .eq(ApplicationCacheKeyDiscriminants::UserSession.to_string()),
+        )
+        .filter(Expr::cust_with_values(
+            r#""value" -> 'UserSession' ->> 'user_id' = $1"#,
+            [user_id],
+        ))
+        .exec(&ss.db)
+        .await?;
+    Ok(())
+}
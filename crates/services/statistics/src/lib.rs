@@ -6,12 +6,8 @@ use dependent_analytics_utils::calculate_user_activities_and_summary;
 use dependent_models::{CachedResponse, UserAnalytics};
 use supporting_service::SupportingService;
 
-mod analytics_operations;
-mod daily_activity_operations;
-
-pub use crate::{
-    analytics_operations::user_analytics,
-    daily_activity_operations::{get_daily_user_activities, user_analytics_parameters},
+pub use dependent_analytics_utils::{
+    get_daily_user_activities, user_analytics, user_analytics_parameters,
 };
 
 pub struct StatisticsService(pub Arc<SupportingService>);
@@ -4,7 +4,7 @@ use anyhow::Result;
 use chrono::Utc;
 use common_models::StringIdObject;
 use database_models::{access_link, prelude::AccessLink, user};
-use database_utils::{get_enabled_users_query, server_key_validation_guard};
+use database_utils::{get_enabled_users_query, server_key_validation_guard, user_by_id};
 use dependent_core_utils::is_server_key_validated;
 use media_models::{
     CreateAccessLinkInput, ProcessAccessLinkError, ProcessAccessLinkErrorVariant,
@@ -86,9 +86,11 @@ pub async fn process_access_link(
             error: ProcessAccessLinkErrorVariant::Revoked,
         }));
     }
+    let user_lot = user_by_id(&link.user_id, ss).await?.lot;
     let api_key = session_service::create_session(
         ss,
         link.user_id.clone(),
+        user_lot,
         Some(link.id.clone()),
         link.expires_on.map(|s| s - Utc::now()),
     )
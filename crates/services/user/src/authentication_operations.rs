@@ -17,7 +17,8 @@ use sea_orm::{
 use supporting_service::SupportingService;
 
 pub async fn generate_auth_token(ss: &Arc<SupportingService>, user_id: String) -> Result<String> {
-    let session_id = session_service::create_session(ss, user_id, None, None).await?;
+    let user_lot = user_by_id(&user_id, ss).await?.lot;
+    let session_id = session_service::create_session(ss, user_id, user_lot, None, None).await?;
     Ok(session_id)
 }
 
@@ -45,6 +46,7 @@ pub async fn user_details(
         id: user.id,
         lot: user.lot,
         name: user.name,
+        limits: user.limits,
         preferences: user.preferences,
         is_disabled: user.is_disabled,
         oidc_issuer_id: user.oidc_issuer_id,
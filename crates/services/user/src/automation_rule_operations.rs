@@ -0,0 +1,340 @@
+use std::sync::Arc;
+
+use anyhow::{Result, anyhow, bail};
+use background_models::{ApplicationJob, LpApplicationJob};
+use chrono::Utc;
+use common_models::{ChangeCollectionToEntitiesInput, EntityToCollectionInput, StringIdObject};
+use common_utils::ryot_log;
+use database_models::{
+    automation_rule, collection, collection_entity_membership, integration, metadata,
+    prelude::{
+        AutomationRule, Collection, CollectionEntityMembership, Integration, Metadata, UserToEntity,
+    },
+    user_to_entity,
+};
+use dependent_collection_utils::{
+    add_entities_to_collection_by_automation, remove_entities_from_collection,
+};
+use dependent_notification_utils::send_notification_for_user;
+use dependent_review_utils::get_entity_title_from_id_and_lot;
+use enum_models::{
+    AutomationRuleActionLot, EntityLot, IntegrationProvider, MediaLot, UserNotificationContent,
+};
+use media_models::{
+    AutomationRuleAction, AutomationRuleEvent, CreateOrUpdateUserAutomationRuleInput,
+};
+use sea_orm::{
+    ActiveModelTrait, ActiveValue, ColumnTrait, EntityTrait, IntoActiveModel, ModelTrait,
+    QueryFilter, QueryOrder, QuerySelect,
+};
+use supporting_service::SupportingService;
+
+async fn collection_exists(
+    ss: &Arc<SupportingService>,
+    user_id: &String,
+    collection_name: &String,
+) -> Result<bool> {
+    let collection = Collection::find()
+        .left_join(UserToEntity)
+        .filter(user_to_entity::Column::UserId.eq(user_id))
+        .filter(collection::Column::Name.eq(collection_name))
+        .one(&ss.db)
+        .await?;
+    Ok(collection.is_some())
+}
+
+async fn get_collection_membership(
+    ss: &Arc<SupportingService>,
+    event: &AutomationRuleEvent,
+    collection_name: &String,
+) -> Result<Option<collection_entity_membership::Model>> {
+    let membership = CollectionEntityMembership::find()
+        .filter(collection_entity_membership::Column::UserId.eq(&event.user_id))
+        .filter(collection_entity_membership::Column::EntityId.eq(&event.entity_id))
+        .filter(collection_entity_membership::Column::EntityLot.eq(event.entity_lot))
+        .filter(collection_entity_membership::Column::CollectionName.eq(collection_name))
+        .one(&ss.db)
+        .await?;
+    Ok(membership)
+}
+
+async fn validate_automation_rule_action(
+    ss: &Arc<SupportingService>,
+    user_id: &String,
+    action: &AutomationRuleAction,
+) -> Result<()> {
+    match action.lot {
+        AutomationRuleActionLot::AddToCollection
+        | AutomationRuleActionLot::RemoveFromCollection
+        | AutomationRuleActionLot::SetCollectionExtraInformation => {
+            let Some(collection_name) = &action.collection_name else {
+                bail!("Collection actions need a collection name");
+            };
+            if !collection_exists(ss, user_id, collection_name).await? {
+                bail!("Collection {} does not exist", collection_name);
+            }
+            if action.lot == AutomationRuleActionLot::SetCollectionExtraInformation
+                && action.information.is_none()
+            {
+                bail!("Setting collection extra information needs the information to set");
+            }
+        }
+        AutomationRuleActionLot::PushToIntegration => {
+            let Some(integration_id) = &action.integration_id else {
+                bail!("Pushing to an integration needs an integration id");
+            };
+            let integration = Integration::find_by_id(integration_id)
+                .one(&ss.db)
+                .await?
+                .filter(|i| &i.user_id == user_id)
+                .ok_or_else(|| anyhow!("Integration with the given id does not exist"))?;
+            if !matches!(
+                integration.provider,
+                IntegrationProvider::Radarr | IntegrationProvider::Sonarr
+            ) {
+                bail!("Only Radarr and Sonarr integrations can be pushed to");
+            }
+        }
+        AutomationRuleActionLot::SendNotification => {}
+    }
+    Ok(())
+}
+
+pub async fn create_or_update_user_automation_rule(
+    ss: &Arc<SupportingService>,
+    user_id: String,
+    input: CreateOrUpdateUserAutomationRuleInput,
+) -> Result<StringIdObject> {
+    if input.actions.is_empty() {
+        bail!("An automation rule needs at least one action");
+    }
+    for action in &input.actions {
+        validate_automation_rule_action(ss, &user_id, action).await?;
+    }
+    let id = match input.rule_id {
+        None => ActiveValue::NotSet,
+        Some(id) => {
+            let rule = AutomationRule::find_by_id(&id)
+                .one(&ss.db)
+                .await?
+                .ok_or_else(|| anyhow!("Automation rule with the given id does not exist"))?;
+            if rule.user_id != user_id {
+                bail!("Automation rule does not belong to the user");
+            }
+            ActiveValue::Unchanged(id)
+        }
+    };
+    let to_save = automation_rule::ActiveModel {
+        id,
+        name: ActiveValue::Set(input.name),
+        user_id: ActiveValue::Set(user_id),
+        trigger: ActiveValue::Set(input.trigger),
+        actions: ActiveValue::Set(input.actions),
+        last_error: ActiveValue::Set(None),
+        is_disabled: ActiveValue::Set(input.is_disabled),
+        conditions: ActiveValue::Set(input.conditions.unwrap_or_default()),
+        ..Default::default()
+    };
+    let saved = to_save.save(&ss.db).await?;
+    Ok(StringIdObject {
+        id: saved.id.unwrap(),
+    })
+}
+
+pub async fn delete_user_automation_rule(
+    ss: &Arc<SupportingService>,
+    user_id: String,
+    rule_id: String,
+) -> Result<bool> {
+    let rule = AutomationRule::find_by_id(rule_id)
+        .one(&ss.db)
+        .await?
+        .ok_or_else(|| anyhow!("Automation rule with the given id does not exist"))?;
+    if rule.user_id != user_id {
+        bail!("Automation rule does not belong to the user");
+    }
+    rule.delete(&ss.db).await?;
+    Ok(true)
+}
+
+pub async fn user_automation_rules(
+    ss: &Arc<SupportingService>,
+    user_id: &String,
+) -> Result<Vec<automation_rule::Model>> {
+    let rules = AutomationRule::find()
+        .filter(automation_rule::Column::UserId.eq(user_id))
+        .order_by_desc(automation_rule::Column::CreatedOn)
+        .all(&ss.db)
+        .await?;
+    Ok(rules)
+}
+
+/// Perform a single action of a rule. Collection actions that would not change anything
+/// are skipped. Entities added by an action are marked as such, so they do not set off
+/// any rules in turn.
+async fn perform_automation_rule_action(
+    ss: &Arc<SupportingService>,
+    rule: &automation_rule::Model,
+    action: &AutomationRuleAction,
+    event: &AutomationRuleEvent,
+) -> Result<()> {
+    let user_id = &event.user_id;
+    let collection_input =
+        |collection_name: &String, information| ChangeCollectionToEntitiesInput {
+            creator_user_id: user_id.to_owned(),
+            collection_name: collection_name.to_owned(),
+            entities: vec![EntityToCollectionInput {
+                information,
+                entity_id: event.entity_id.clone(),
+                entity_lot: event.entity_lot,
+            }],
+        };
+    match action.lot {
+        AutomationRuleActionLot::AddToCollection
+        | AutomationRuleActionLot::RemoveFromCollection
+        | AutomationRuleActionLot::SetCollectionExtraInformation => {
+            let collection_name = action
+                .collection_name
+                .as_ref()
+                .ok_or_else(|| anyhow!("Collection actions need a collection name"))?;
+            if !collection_exists(ss, user_id, collection_name).await? {
+                bail!("Collection {} does not exist", collection_name);
+            }
+            let membership = get_collection_membership(ss, event, collection_name).await?;
+            match action.lot {
+                AutomationRuleActionLot::AddToCollection if membership.is_none() => {
+                    add_entities_to_collection_by_automation(
+                        user_id,
+                        collection_input(collection_name, None),
+                        ss,
+                    )
+                    .await?;
+                }
+                AutomationRuleActionLot::RemoveFromCollection if membership.is_some() => {
+                    remove_entities_from_collection(
+                        user_id,
+                        collection_input(collection_name, None),
+                        ss,
+                    )
+                    .await?;
+                }
+                AutomationRuleActionLot::SetCollectionExtraInformation
+                    if membership
+                        .as_ref()
+                        .and_then(|m| m.collection_to_entity_information.as_ref())
+                        != action.information.as_ref() =>
+                {
+                    add_entities_to_collection_by_automation(
+                        user_id,
+                        collection_input(collection_name, action.information.clone()),
+                        ss,
+                    )
+                    .await?;
+                }
+                _ => {}
+            }
+        }
+        AutomationRuleActionLot::SendNotification => {
+            let entity_title =
+                get_entity_title_from_id_and_lot(&event.entity_id, event.entity_lot, ss).await?;
+            send_notification_for_user(
+                user_id,
+                ss,
+                UserNotificationContent::AutomationRuleTriggered {
+                    entity_title,
+                    rule_name: rule.name.clone(),
+                    message: action.message.clone(),
+                    entity_lot: event.entity_lot,
+                    entity_id: event.entity_id.clone(),
+                },
+            )
+            .await?;
+        }
+        AutomationRuleActionLot::PushToIntegration => {
+            if event.entity_lot != EntityLot::Metadata {
+                bail!("Only media can be pushed to an integration");
+            }
+            let integration_id = action
+                .integration_id
+                .clone()
+                .ok_or_else(|| anyhow!("Pushing to an integration needs an integration id"))?;
+            let belongs_to_user = Integration::find_by_id(&integration_id)
+                .filter(integration::Column::UserId.eq(user_id))
+                .one(&ss.db)
+                .await?
+                .is_some();
+            if !belongs_to_user {
+                bail!("Integration with the given id does not exist");
+            }
+            ss.perform_application_job(ApplicationJob::Lp(
+                LpApplicationJob::PushMetadataToIntegration(
+                    integration_id,
+                    event.entity_id.clone(),
+                ),
+            ))
+            .await?;
+        }
+    }
+    Ok(())
+}
+
+pub async fn handle_automation_rule_event(
+    ss: &Arc<SupportingService>,
+    event: AutomationRuleEvent,
+) -> Result<()> {
+    if event.triggered_by_automation {
+        ryot_log!(
+            debug,
+            "Ignoring event caused by an automation rule {:?}",
+            event
+        );
+        return Ok(());
+    }
+    let rules = AutomationRule::find()
+        .filter(automation_rule::Column::UserId.eq(&event.user_id))
+        .filter(automation_rule::Column::Trigger.eq(event.trigger))
+        .filter(
+            automation_rule::Column::IsDisabled
+                .is_null()
+                .or(automation_rule::Column::IsDisabled.eq(false)),
+        )
+        .order_by_asc(automation_rule::Column::CreatedOn)
+        .all(&ss.db)
+        .await?;
+    if rules.is_empty() {
+        return Ok(());
+    }
+    let metadata_lot = match event.entity_lot {
+        EntityLot::Metadata => {
+            Metadata::find_by_id(&event.entity_id)
+                .select_only()
+                .column(metadata::Column::Lot)
+                .into_tuple::<MediaLot>()
+                .one(&ss.db)
+                .await?
+        }
+        _ => None,
+    };
+    for rule in rules {
+        if !rule.conditions.matches(&event, metadata_lot) {
+            continue;
+        }
+        ryot_log!(
+            debug,
+            "Performing automation rule {} for {:?}",
+            rule.id,
+            event
+        );
+        let mut errors = vec![];
+        for action in rule.actions.iter() {
+            if let Err(e) = perform_automation_rule_action(ss, &rule, action, &event).await {
+                errors.push(e.to_string());
+            }
+        }
+        let mut to_update = rule.into_active_model();
+        to_update.last_triggered_on = ActiveValue::Set(Some(Utc::now()));
+        to_update.last_error = ActiveValue::Set((!errors.is_empty()).then(|| errors.join("; ")));
+        to_update.update(&ss.db).await?;
+    }
+    Ok(())
+}
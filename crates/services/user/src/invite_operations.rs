@@ -0,0 +1,105 @@
+use std::sync::Arc;
+
+use anyhow::{Result, bail};
+use chrono::{DateTime, Utc};
+use common_models::StringIdObject;
+use database_models::{prelude::UserInvite, user_invite};
+use database_utils::admin_account_guard;
+use enum_models::UserLot;
+use sea_orm::{
+    ActiveModelTrait, ActiveValue, ColumnTrait, EntityTrait, ModelTrait, QueryFilter, QueryOrder,
+    UpdateMany,
+};
+use supporting_service::SupportingService;
+use user_models::CreateUserInviteInput;
+
+pub async fn user_invites(
+    ss: &Arc<SupportingService>,
+    admin_user_id: &String,
+) -> Result<Vec<user_invite::Model>> {
+    admin_account_guard(admin_user_id, ss).await?;
+    let invites = UserInvite::find()
+        .order_by_desc(user_invite::Column::CreatedOn)
+        .all(&ss.db)
+        .await?;
+    Ok(invites)
+}
+
+pub async fn create_user_invite(
+    ss: &Arc<SupportingService>,
+    admin_user_id: &String,
+    input: CreateUserInviteInput,
+) -> Result<StringIdObject> {
+    admin_account_guard(admin_user_id, ss).await?;
+    if input.expires_on.is_some_and(|e| e <= Utc::now()) {
+        bail!("Expiry date must be in the future");
+    }
+    let invite = user_invite::ActiveModel {
+        limits: ActiveValue::Set(input.limits),
+        expires_on: ActiveValue::Set(input.expires_on),
+        preferences: ActiveValue::Set(input.preferences),
+        lot: ActiveValue::Set(input.lot.unwrap_or(UserLot::Normal)),
+        created_by_user_id: ActiveValue::Set(admin_user_id.to_owned()),
+        ..Default::default()
+    };
+    let invite = invite.insert(&ss.db).await?;
+    Ok(StringIdObject { id: invite.id })
+}
+
+pub async fn delete_user_invite(
+    ss: &Arc<SupportingService>,
+    admin_user_id: &String,
+    invite_id: String,
+) -> Result<bool> {
+    admin_account_guard(admin_user_id, ss).await?;
+    let Some(invite) = UserInvite::find_by_id(invite_id).one(&ss.db).await? else {
+        bail!("Invite does not exist");
+    };
+    invite.delete(&ss.db).await?;
+    Ok(true)
+}
+
+/// Whether an invite can still be used to register at `now`.
+pub(crate) fn is_user_invite_valid(invite: &user_invite::Model, now: DateTime<Utc>) -> bool {
+    invite.used_on.is_none() && invite.expires_on.is_none_or(|e| e > now)
+}
+
+/// Find an invite that has not been used and has not expired yet.
+pub(crate) async fn valid_user_invite(
+    ss: &Arc<SupportingService>,
+    invite_id: &String,
+) -> Result<Option<user_invite::Model>> {
+    let invite = UserInvite::find_by_id(invite_id)
+        .one(&ss.db)
+        .await?
+        .filter(|i| is_user_invite_valid(i, Utc::now()));
+    Ok(invite)
+}
+
+/// The update that marks an invite as used. It only matches invites that are still unused, so
+/// of several registrations racing for the same invite only one can redeem it.
+pub(crate) fn redeem_user_invite_query(
+    invite_id: &String,
+    user_id: &String,
+) -> UpdateMany<UserInvite> {
+    UserInvite::update_many()
+        .filter(user_invite::Column::Id.eq(invite_id))
+        .filter(user_invite::Column::UsedOn.is_null())
+        .set(user_invite::ActiveModel {
+            used_on: ActiveValue::Set(Some(Utc::now())),
+            used_by_user_id: ActiveValue::Set(Some(user_id.to_owned())),
+            ..Default::default()
+        })
+}
+
+/// Mark the invite as used by the given user. Returns `false` if someone else redeemed it first.
+pub(crate) async fn redeem_user_invite(
+    ss: &Arc<SupportingService>,
+    invite_id: &String,
+    user_id: &String,
+) -> Result<bool> {
+    let result = redeem_user_invite_query(invite_id, user_id)
+        .exec(&ss.db)
+        .await?;
+    Ok(result.rows_affected == 1)
+}
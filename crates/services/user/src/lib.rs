@@ -1,37 +1,53 @@
 use std::sync::Arc;
 
 use anyhow::Result;
-use common_models::StringIdObject;
-use database_models::{access_link, integration, notification_platform};
+use common_models::{SearchInput, StringIdObject};
+use database_models::{
+    access_link, automation_rule, integration, notification_delivery, notification_platform,
+    share_link, user_invite,
+};
 use database_utils::server_key_validation_guard;
 use dependent_core_utils::is_server_key_validated;
 use dependent_models::{
-    BasicUserDetails, CachedResponse, UserDetailsResult, UserMetadataRecommendationsResponse,
+    BasicUserDetails, CachedResponse, SharedEntityDetails, SharedEntityDetailsInput,
+    UserActivityFeedResponse, UserDetailsResult, UserFollowsResponse,
+    UserMetadataRecommendationsResponse, UserNotificationsListResponse, UserWatchGroupDetails,
 };
 use media_models::{
-    AuthUserInput, CreateAccessLinkInput, CreateOrUpdateUserIntegrationInput,
-    CreateUserNotificationPlatformInput, GetPasswordChangeSessionInput,
-    GetPasswordChangeSessionResponse, LoginResult, OidcTokenOutput, ProcessAccessLinkInput,
-    ProcessAccessLinkResult, RegisterResult, RegisterUserInput, SetPasswordViaSessionInput,
-    UpdateUserNotificationPlatformInput, UserResetResult, UserTwoFactorBackupCodesResponse,
-    UserTwoFactorInitiateResponse, UserTwoFactorSetupInput, UserTwoFactorVerifyInput,
-    VerifyTwoFactorResult,
+    AuthUserInput, AutomationRuleEvent, CreateAccessLinkInput,
+    CreateOrUpdateUserAutomationRuleInput, CreateOrUpdateUserIntegrationInput,
+    CreateOrUpdateWatchGroupInput, CreateShareLinkInput, GetPasswordChangeSessionInput,
+    GetPasswordChangeSessionResponse, LoginResult, MarkUserNotificationsAsReadInput,
+    OidcTokenOutput, ProcessAccessLinkInput, ProcessAccessLinkResult, RegisterResult,
+    RegisterUserInput, SetPasswordViaSessionInput, UpdateUserNotificationPlatformInput,
+    UpdateWatchGroupExclusionInput, UserNotificationsListInput, UserResetResult,
+    UserTwoFactorBackupCodesResponse, UserTwoFactorInitiateResponse, UserTwoFactorSetupInput,
+    UserTwoFactorVerifyInput, VerifyTwoFactorResult, VerifyUserNotificationPlatformInput,
 };
 use openidconnect::Nonce;
 use supporting_service::SupportingService;
-use user_models::{UpdateUserInput, UserPreferences};
+use user_models::{
+    CreateUserInviteInput, CreateUserNotificationPlatformInput, UpdateUserInput, UserPreferences,
+};
 
 mod access_link_operations;
 mod authentication_operations;
+mod automation_rule_operations;
 mod integration_operations;
+mod invite_operations;
 mod notification_operations;
 mod oidc_operations;
 mod password_change_operations;
 mod recommendation_operations;
+mod share_link_operations;
+mod social_operations;
+#[cfg(test)]
+mod tests;
 mod two_factor_operations;
 mod user_data_operations;
 mod user_management_operations;
 mod user_preferences_operations;
+mod watch_group_operations;
 
 fn empty_nonce_verifier(_nonce: Option<&Nonce>) -> Result<(), String> {
     Ok(())
@@ -71,6 +87,118 @@ impl UserService {
         authentication_operations::revoke_access_link(&self.0, access_link_id).await
     }
 
+    pub async fn user_share_links(&self, user_id: &String) -> Result<Vec<share_link::Model>> {
+        share_link_operations::user_share_links(&self.0, user_id).await
+    }
+
+    pub async fn create_share_link(
+        &self,
+        user_id: &String,
+        input: CreateShareLinkInput,
+    ) -> Result<StringIdObject> {
+        share_link_operations::create_share_link(&self.0, user_id, input).await
+    }
+
+    pub async fn revoke_share_link(&self, user_id: &String, share_link_id: String) -> Result<bool> {
+        share_link_operations::revoke_share_link(&self.0, user_id, share_link_id).await
+    }
+
+    pub async fn shared_entity_details(
+        &self,
+        input: SharedEntityDetailsInput,
+    ) -> Result<SharedEntityDetails> {
+        share_link_operations::shared_entity_details(&self.0, input).await
+    }
+
+    pub async fn user_invites(&self, admin_user_id: &String) -> Result<Vec<user_invite::Model>> {
+        invite_operations::user_invites(&self.0, admin_user_id).await
+    }
+
+    pub async fn create_user_invite(
+        &self,
+        admin_user_id: &String,
+        input: CreateUserInviteInput,
+    ) -> Result<StringIdObject> {
+        invite_operations::create_user_invite(&self.0, admin_user_id, input).await
+    }
+
+    pub async fn delete_user_invite(
+        &self,
+        admin_user_id: &String,
+        invite_id: String,
+    ) -> Result<bool> {
+        invite_operations::delete_user_invite(&self.0, admin_user_id, invite_id).await
+    }
+
+    pub async fn follow_user(&self, user_id: &String, to_follow_user_id: String) -> Result<bool> {
+        social_operations::follow_user(&self.0, user_id, to_follow_user_id).await
+    }
+
+    pub async fn unfollow_user(
+        &self,
+        user_id: &String,
+        to_unfollow_user_id: String,
+    ) -> Result<bool> {
+        social_operations::unfollow_user(&self.0, user_id, to_unfollow_user_id).await
+    }
+
+    pub async fn user_follows(&self, user_id: &String) -> Result<UserFollowsResponse> {
+        social_operations::user_follows(&self.0, user_id).await
+    }
+
+    pub async fn user_activity_feed(
+        &self,
+        user_id: &String,
+        input: SearchInput,
+    ) -> Result<UserActivityFeedResponse> {
+        social_operations::user_activity_feed(&self.0, user_id, input).await
+    }
+
+    pub async fn user_watch_groups(&self, user_id: &String) -> Result<Vec<UserWatchGroupDetails>> {
+        watch_group_operations::user_watch_groups(&self.0, user_id).await
+    }
+
+    pub async fn create_or_update_watch_group(
+        &self,
+        user_id: &String,
+        input: CreateOrUpdateWatchGroupInput,
+    ) -> Result<StringIdObject> {
+        watch_group_operations::create_or_update_watch_group(&self.0, user_id, input).await
+    }
+
+    pub async fn delete_watch_group(
+        &self,
+        user_id: &String,
+        watch_group_id: String,
+    ) -> Result<bool> {
+        watch_group_operations::delete_watch_group(&self.0, user_id, watch_group_id).await
+    }
+
+    pub async fn accept_watch_group_invitation(
+        &self,
+        user_id: &String,
+        watch_group_id: String,
+    ) -> Result<bool> {
+        watch_group_operations::accept_watch_group_invitation(&self.0, user_id, watch_group_id)
+            .await
+    }
+
+    pub async fn leave_watch_group(
+        &self,
+        user_id: &String,
+        watch_group_id: String,
+    ) -> Result<bool> {
+        watch_group_operations::leave_watch_group(&self.0, user_id, watch_group_id).await
+    }
+
+    pub async fn update_watch_group_exclusion(
+        &self,
+        user_id: &String,
+        input: UpdateWatchGroupExclusionInput,
+    ) -> Result<bool> {
+        watch_group_operations::update_watch_group_exclusion(&self.0, user_id, input).await
+    }
+
     pub async fn users_list(&self, query: Option<String>) -> Result<Vec<BasicUserDetails>> {
         user_data_operations::users_list(&self.0, query).await
     }
@@ -132,7 +260,7 @@ impl UserService {
         user_id: String,
         input: CreateOrUpdateUserIntegrationInput,
     ) -> Result<bool> {
-        integration_operations::create_or_update_user_integration(&self.0, user_id, input).await
+        dependent_utility_utils::create_or_update_user_integration(&self.0, user_id, input).await
     }
 
     pub async fn delete_user_integration(
@@ -143,6 +271,34 @@ impl UserService {
         integration_operations::delete_user_integration(&self.0, user_id, integration_id).await
     }
 
+    pub async fn create_or_update_user_automation_rule(
+        &self,
+        user_id: String,
+        input: CreateOrUpdateUserAutomationRuleInput,
+    ) -> Result<StringIdObject> {
+        automation_rule_operations::create_or_update_user_automation_rule(&self.0, user_id, input)
+            .await
+    }
+
+    pub async fn delete_user_automation_rule(
+        &self,
+        user_id: String,
+        rule_id: String,
+    ) -> Result<bool> {
+        automation_rule_operations::delete_user_automation_rule(&self.0, user_id, rule_id).await
+    }
+
+    pub async fn user_automation_rules(
+        &self,
+        user_id: &String,
+    ) -> Result<Vec<automation_rule::Model>> {
+        automation_rule_operations::user_automation_rules(&self.0, user_id).await
+    }
+
+    pub async fn handle_automation_rule_event(&self, event: AutomationRuleEvent) -> Result<()> {
+        automation_rule_operations::handle_automation_rule_event(&self.0, event).await
+    }
+
     pub async fn create_user_notification_platform(
         &self,
         user_id: String,
@@ -172,6 +328,27 @@ impl UserService {
         .await
     }
 
+    pub async fn verify_user_notification_platform(
+        &self,
+        user_id: String,
+        input: VerifyUserNotificationPlatformInput,
+    ) -> Result<bool> {
+        notification_operations::verify_user_notification_platform(&self.0, user_id, input).await
+    }
+
+    pub async fn resend_user_notification_platform_verification(
+        &self,
+        user_id: String,
+        notification_id: String,
+    ) -> Result<bool> {
+        notification_operations::resend_user_notification_platform_verification(
+            &self.0,
+            user_id,
+            notification_id,
+        )
+        .await
+    }
+
     pub async fn test_user_notification_platforms(&self, user_id: &String) -> Result<bool> {
         notification_operations::test_user_notification_platforms(&self.0, user_id).await
     }
@@ -184,6 +361,30 @@ impl UserService {
         user_data_operations::user_integrations(&self.0, user_id).await
     }
 
+    pub async fn user_notifications_list(
+        &self,
+        user_id: &String,
+        input: UserNotificationsListInput,
+    ) -> Result<UserNotificationsListResponse> {
+        notification_operations::user_notifications_list(&self.0, user_id, input).await
+    }
+
+    pub async fn mark_user_notifications_as_read(
+        &self,
+        user_id: &String,
+        input: MarkUserNotificationsAsReadInput,
+    ) -> Result<bool> {
+        notification_operations::mark_user_notifications_as_read(&self.0, user_id, input).await
+    }
+
+    pub async fn user_notification_deliveries(
+        &self,
+        user_id: &String,
+        notification_id: String,
+    ) -> Result<Vec<notification_delivery::Model>> {
+        user_data_operations::user_notification_deliveries(&self.0, user_id, notification_id).await
+    }
+
     pub async fn user_notification_platforms(
         &self,
         user_id: &String,
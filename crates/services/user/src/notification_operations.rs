@@ -1,16 +1,32 @@
 use std::sync::Arc;
 
 use anyhow::{Result, anyhow, bail};
-use database_models::{notification_platform, prelude::NotificationPlatform};
+use common_models::{SearchDetails, UserLevelCacheKey};
+use database_models::{
+    notification_platform,
+    prelude::{NotificationPlatform, UserNotification},
+    user_notification,
+};
+use database_utils::{apply_columns_search, extract_pagination_params};
+use dependent_models::{
+    ApplicationCacheKey, ApplicationCacheValue, ExpireCacheKeyInput,
+    NotificationPlatformVerificationCacheValue, UserNotificationsListResponse,
+};
+use dependent_notification_utils::{
+    MAX_VERIFICATION_ATTEMPTS, send_notification_platform_verification,
+};
 use enum_models::{NotificationPlatformLot, UserNotificationContentDiscriminants};
-use media_models::{CreateUserNotificationPlatformInput, UpdateUserNotificationPlatformInput};
+use media_models::{
+    MarkUserNotificationsAsReadInput, UpdateUserNotificationPlatformInput,
+    UserNotificationsListInput, VerifyUserNotificationPlatformInput,
+};
 use notification_service::send_notification;
 use sea_orm::{
-    ActiveModelTrait, ActiveValue, ColumnTrait, EntityTrait, IntoActiveModel, Iterable, ModelTrait,
-    QueryFilter,
+    ActiveModelTrait, ActiveValue, ColumnTrait, EntityTrait, IntoActiveModel, ItemsAndPagesNumber,
+    Iterable, ModelTrait, PaginatorTrait, QueryFilter, QueryOrder, QueryTrait, prelude::Expr,
 };
 use supporting_service::SupportingService;
-use user_models::NotificationPlatformSpecifics;
+use user_models::{CreateUserNotificationPlatformInput, NotificationPlatformSpecifics};
 
 pub async fn update_user_notification_platform(
     ss: &Arc<SupportingService>,
@@ -24,6 +40,13 @@ pub async fn update_user_notification_platform(
     if db_notification.user_id != user_id {
         bail!("Notification platform does not belong to the user");
     }
+    if input.is_disabled == Some(false)
+        && let NotificationPlatformSpecifics::Email {
+            is_verified: false, ..
+        } = db_notification.platform_specifics
+    {
+        bail!("The email address must be verified before the platform can be enabled");
+    }
     let mut db_notification = db_notification.into_active_model();
     if let Some(s) = input.is_disabled {
         db_notification.is_disabled = ActiveValue::Set(Some(s));
@@ -31,6 +54,14 @@ pub async fn update_user_notification_platform(
     if let Some(e) = input.configured_events {
         db_notification.configured_events = ActiveValue::Set(e);
     }
+    if let Some(m) = input.delivery_mode {
+        db_notification.delivery_mode = ActiveValue::Set(m);
+    }
+    if input.clear_quiet_hours == Some(true) {
+        db_notification.quiet_hours = ActiveValue::Set(None);
+    } else if let Some(q) = input.quiet_hours {
+        db_notification.quiet_hours = ActiveValue::Set(Some(q));
+    }
     db_notification.update(&ss.db).await?;
     Ok(true)
 }
@@ -66,8 +97,91 @@ pub async fn test_user_notification_platforms(
         .await?;
     for platform in notifications {
         let msg = format!("This is a test notification for platform: {}", platform.lot);
-        send_notification(platform.platform_specifics, &msg).await?;
+        send_notification(
+            platform.platform_specifics,
+            &ss.config.server.smtp,
+            "Test notification",
+            &msg,
+            None,
+        )
+        .await?;
+    }
+    Ok(true)
+}
+
+pub async fn resend_user_notification_platform_verification(
+    ss: &Arc<SupportingService>,
+    user_id: String,
+    notification_id: String,
+) -> Result<bool> {
+    let notification = NotificationPlatform::find_by_id(&notification_id)
+        .one(&ss.db)
+        .await?
+        .ok_or_else(|| anyhow!("Notification platform with the given id does not exist"))?;
+    if notification.user_id != user_id {
+        bail!("Notification platform does not belong to the user");
+    }
+    let NotificationPlatformSpecifics::Email {
+        email,
+        is_verified: false,
+    } = notification.platform_specifics
+    else {
+        bail!("Notification platform does not need verification");
+    };
+    send_notification_platform_verification(ss, &user_id, &notification_id, &email).await?;
+    Ok(true)
+}
+
+pub async fn verify_user_notification_platform(
+    ss: &Arc<SupportingService>,
+    user_id: String,
+    input: VerifyUserNotificationPlatformInput,
+) -> Result<bool> {
+    let notification = NotificationPlatform::find_by_id(&input.notification_id)
+        .one(&ss.db)
+        .await?
+        .ok_or_else(|| anyhow!("Notification platform with the given id does not exist"))?;
+    if notification.user_id != user_id {
+        bail!("Notification platform does not belong to the user");
     }
+    let NotificationPlatformSpecifics::Email { email, .. } =
+        notification.platform_specifics.clone()
+    else {
+        bail!("Notification platform does not need verification");
+    };
+    let cache_key = ApplicationCacheKey::NotificationPlatformVerification(UserLevelCacheKey {
+        user_id: user_id.clone(),
+        input: input.notification_id.clone(),
+    });
+    let Some((cache_id, mut value)) = cache_service::get_value::<
+        NotificationPlatformVerificationCacheValue,
+    >(ss, cache_key.clone())
+    .await
+    else {
+        bail!("Verification code has expired, please request a new one");
+    };
+    // the exhausted code is kept until it expires so that resending does not reset the count
+    if value.failed_attempts >= MAX_VERIFICATION_ATTEMPTS {
+        bail!("Too many invalid attempts, please try again later");
+    }
+    if value.code != input.code.trim() {
+        value.failed_attempts += 1;
+        cache_service::set_key(
+            ss,
+            cache_key,
+            ApplicationCacheValue::NotificationPlatformVerification(value),
+        )
+        .await?;
+        bail!("Invalid verification code");
+    }
+    let mut notification = notification.into_active_model();
+    notification.is_disabled = ActiveValue::Set(Some(false));
+    notification.platform_specifics = ActiveValue::Set(NotificationPlatformSpecifics::Email {
+        email,
+        is_verified: true,
+    });
+    notification.update(&ss.db).await?;
+    cache_service::expire_key(ss, ExpireCacheKeyInput::ById(cache_id)).await?;
     Ok(true)
 }
 
@@ -84,11 +198,25 @@ pub async fn create_user_notification_platform(
         NotificationPlatformLot::Discord => NotificationPlatformSpecifics::Discord {
             url: input.base_url.unwrap(),
         },
+        NotificationPlatformLot::Email => {
+            if !ss.config.server.smtp.is_enabled() {
+                bail!("SMTP is not configured on this server");
+            }
+            NotificationPlatformSpecifics::Email {
+                is_verified: false,
+                email: input.email.unwrap().trim().to_owned(),
+            }
+        }
         NotificationPlatformLot::Gotify => NotificationPlatformSpecifics::Gotify {
             url: input.base_url.unwrap(),
             token: input.api_token.unwrap(),
             priority: input.priority,
         },
+        NotificationPlatformLot::Matrix => NotificationPlatformSpecifics::Matrix {
+            url: input.base_url.unwrap(),
+            room_id: input.room_id.unwrap(),
+            access_token: input.api_token.unwrap(),
+        },
         NotificationPlatformLot::Ntfy => NotificationPlatformSpecifics::Ntfy {
             url: input.base_url,
             topic: input.api_token.unwrap(),
@@ -105,10 +233,18 @@ pub async fn create_user_notification_platform(
         NotificationPlatformLot::PushSafer => NotificationPlatformSpecifics::PushSafer {
             key: input.api_token.unwrap(),
         },
+        NotificationPlatformLot::Slack => NotificationPlatformSpecifics::Slack {
+            url: input.base_url.unwrap(),
+        },
         NotificationPlatformLot::Telegram => NotificationPlatformSpecifics::Telegram {
             bot_token: input.api_token.unwrap(),
             chat_id: input.chat_id.unwrap(),
         },
+        NotificationPlatformLot::Webhook => NotificationPlatformSpecifics::Webhook {
+            url: input.base_url.unwrap(),
+            secret: input.signing_secret,
+            headers: input.headers.unwrap_or_default(),
+        },
     };
     let description = match &specifics {
         NotificationPlatformSpecifics::Apprise { url, key } => {
@@ -117,9 +253,15 @@ pub async fn create_user_notification_platform(
         NotificationPlatformSpecifics::Discord { url } => {
             format!("Webhook: {url}")
         }
+        NotificationPlatformSpecifics::Email { email, .. } => {
+            format!("Email: {email}")
+        }
         NotificationPlatformSpecifics::Gotify { url, token, .. } => {
             format!("URL: {url}, Token: {token}")
         }
+        NotificationPlatformSpecifics::Matrix { url, room_id, .. } => {
+            format!("URL: {url}, Room ID: {room_id}")
+        }
         NotificationPlatformSpecifics::Ntfy { url, topic, .. } => {
             format!("URL: {url:?}, Topic: {topic}")
         }
@@ -132,18 +274,92 @@ pub async fn create_user_notification_platform(
         NotificationPlatformSpecifics::PushSafer { key } => {
             format!("Key: {key}")
         }
+        NotificationPlatformSpecifics::Slack { url } => {
+            format!("Webhook: {url}")
+        }
         NotificationPlatformSpecifics::Telegram { chat_id, .. } => {
             format!("Chat ID: {chat_id}")
         }
+        NotificationPlatformSpecifics::Webhook { url, headers, .. } => {
+            let names = headers.iter().map(|h| h.name.as_str()).collect::<Vec<_>>();
+            format!("URL: {url}, Headers: {names:?}")
+        }
+    };
+    let pending_verification = match &specifics {
+        NotificationPlatformSpecifics::Email { email, .. } => Some(email.clone()),
+        _ => None,
     };
     let notification = notification_platform::ActiveModel {
         lot: ActiveValue::Set(input.lot),
-        user_id: ActiveValue::Set(user_id),
+        user_id: ActiveValue::Set(user_id.clone()),
+        is_disabled: ActiveValue::Set(pending_verification.as_ref().map(|_| true)),
         description: ActiveValue::Set(description),
         platform_specifics: ActiveValue::Set(specifics),
         configured_events: ActiveValue::Set(UserNotificationContentDiscriminants::iter().collect()),
         ..Default::default()
     };
-    let new_notification_id = notification.insert(&ss.db).await?.id;
+    let notification = notification.insert(&ss.db).await?;
+    let new_notification_id = notification.id.clone();
+    if let Some(email) = pending_verification
+        && let Err(e) =
+            send_notification_platform_verification(ss, &user_id, &new_notification_id, &email)
+                .await
+    {
+        notification.delete(&ss.db).await?;
+        return Err(e);
+    }
     Ok(new_notification_id)
 }
+
+pub async fn user_notifications_list(
+    ss: &Arc<SupportingService>,
+    user_id: &String,
+    input: UserNotificationsListInput,
+) -> Result<UserNotificationsListResponse> {
+    let (take, page) = extract_pagination_params(input.search.clone(), user_id, ss).await?;
+    let paginator = UserNotification::find()
+        .filter(user_notification::Column::UserId.eq(user_id))
+        .apply_if(input.unread_only.filter(|u| *u), |query, _| {
+            query.filter(user_notification::Column::IsRead.eq(false))
+        })
+        .apply_if(input.search.and_then(|s| s.query), |query, v| {
+            apply_columns_search(&v, query, [Expr::col(user_notification::Column::Message)])
+        })
+        .order_by_desc(user_notification::Column::CreatedOn)
+        .paginate(&ss.db, take);
+    let ItemsAndPagesNumber {
+        number_of_items,
+        number_of_pages,
+    } = paginator.num_items_and_pages().await?;
+    let items = paginator.fetch_page(page - 1).await?;
+    let unread_count = UserNotification::find()
+        .filter(user_notification::Column::UserId.eq(user_id))
+        .filter(user_notification::Column::IsRead.eq(false))
+        .count(&ss.db)
+        .await?;
+    Ok(UserNotificationsListResponse {
+        items,
+        unread_count,
+        details: SearchDetails {
+            total_items: number_of_items,
+            next_page: (page < number_of_pages).then(|| page + 1),
+        },
+    })
+}
+
+pub async fn mark_user_notifications_as_read(
+    ss: &Arc<SupportingService>,
+    user_id: &String,
+    input: MarkUserNotificationsAsReadInput,
+) -> Result<bool> {
+    UserNotification::update_many()
+        .col_expr(user_notification::Column::IsRead, Expr::value(true))
+        .filter(user_notification::Column::UserId.eq(user_id))
+        .filter(user_notification::Column::IsRead.eq(false))
+        .apply_if(input.notification_ids, |query, ids| {
+            query.filter(user_notification::Column::Id.is_in(ids))
+        })
+        .exec(&ss.db)
+        .await?;
+    Ok(true)
+}
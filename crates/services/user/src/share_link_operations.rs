@@ -0,0 +1,204 @@
+use std::sync::Arc;
+
+use anyhow::{Result, bail};
+use chrono::{DateTime, NaiveDate, Utc};
+use common_models::{ApplicationDateRange, StringIdObject, UserAnalyticsInput};
+use database_models::{
+    prelude::{Collection, Review, ShareLink, User, Workout, WorkoutTemplate},
+    share_link,
+};
+use database_utils::item_reviews;
+use dependent_analytics_utils::user_analytics;
+use dependent_collection_utils::collection_contents;
+use dependent_models::{CollectionContentsInput, SharedEntityDetails, SharedEntityDetailsInput};
+use enum_models::{ShareLinkEntityLot, Visibility};
+use media_models::CreateShareLinkInput;
+use sea_orm::{
+    ActiveModelTrait, ActiveValue, ColumnTrait, EntityTrait, IntoActiveModel, QueryFilter,
+    QueryOrder,
+};
+use supporting_service::SupportingService;
+
+pub(crate) fn year_range(year: &str) -> Result<ApplicationDateRange> {
+    let Ok(year) = year.parse::<i32>() else {
+        bail!("Invalid year");
+    };
+    Ok(ApplicationDateRange {
+        start_date: NaiveDate::from_ymd_opt(year, 1, 1),
+        end_date: NaiveDate::from_ymd_opt(year, 12, 31),
+    })
+}
+
+/// Only the owner of an entity can share it. `owner_id` is `None` if the entity does not
+/// exist.
+pub(crate) fn check_share_link_owner(owner_id: Option<&String>, user_id: &String) -> Result<()> {
+    if owner_id != Some(user_id) {
+        bail!("Entity does not exist or does not belong to the user");
+    }
+    Ok(())
+}
+
+pub(crate) fn check_share_link_is_active(
+    link: &share_link::Model,
+    now: DateTime<Utc>,
+) -> Result<()> {
+    if link.is_revoked == Some(true) {
+        bail!("Share link has been revoked");
+    }
+    if let Some(expires_on) = link.expires_on
+        && expires_on < now
+    {
+        bail!("Share link has expired");
+    }
+    Ok(())
+}
+
+pub async fn user_share_links(
+    ss: &Arc<SupportingService>,
+    user_id: &String,
+) -> Result<Vec<share_link::Model>> {
+    let links = ShareLink::find()
+        .filter(share_link::Column::UserId.eq(user_id))
+        .order_by_desc(share_link::Column::CreatedOn)
+        .all(&ss.db)
+        .await?;
+    Ok(links)
+}
+
+pub async fn create_share_link(
+    ss: &Arc<SupportingService>,
+    user_id: &String,
+    input: CreateShareLinkInput,
+) -> Result<StringIdObject> {
+    let owner_id = match input.entity_lot {
+        ShareLinkEntityLot::YearSummary => {
+            year_range(&input.entity_id)?;
+            Some(user_id.to_owned())
+        }
+        ShareLinkEntityLot::Review => Review::find_by_id(&input.entity_id)
+            .one(&ss.db)
+            .await?
+            .map(|r| r.user_id),
+        ShareLinkEntityLot::Workout => Workout::find_by_id(&input.entity_id)
+            .one(&ss.db)
+            .await?
+            .map(|w| w.user_id),
+        ShareLinkEntityLot::Collection => Collection::find_by_id(&input.entity_id)
+            .one(&ss.db)
+            .await?
+            .map(|c| c.user_id),
+        ShareLinkEntityLot::WorkoutTemplate => WorkoutTemplate::find_by_id(&input.entity_id)
+            .one(&ss.db)
+            .await?
+            .map(|w| w.user_id),
+    };
+    check_share_link_owner(owner_id.as_ref(), user_id)?;
+    let link = share_link::ActiveModel {
+        name: ActiveValue::Set(input.name),
+        user_id: ActiveValue::Set(user_id.to_owned()),
+        entity_id: ActiveValue::Set(input.entity_id),
+        entity_lot: ActiveValue::Set(input.entity_lot),
+        expires_on: ActiveValue::Set(input.expires_on),
+        ..Default::default()
+    }
+    .insert(&ss.db)
+    .await?;
+    Ok(StringIdObject { id: link.id })
+}
+
+pub async fn revoke_share_link(
+    ss: &Arc<SupportingService>,
+    user_id: &String,
+    share_link_id: String,
+) -> Result<bool> {
+    let Some(link) = ShareLink::find_by_id(share_link_id)
+        .filter(share_link::Column::UserId.eq(user_id))
+        .one(&ss.db)
+        .await?
+    else {
+        bail!("Share link does not exist");
+    };
+    let mut link = link.into_active_model();
+    link.is_revoked = ActiveValue::Set(Some(true));
+    link.update(&ss.db).await?;
+    Ok(true)
+}
+
+pub async fn shared_entity_details(
+    ss: &Arc<SupportingService>,
+    input: SharedEntityDetailsInput,
+) -> Result<SharedEntityDetails> {
+    let Some(link) = ShareLink::find_by_id(input.share_link_id)
+        .one(&ss.db)
+        .await?
+    else {
+        bail!("Share link does not exist");
+    };
+    check_share_link_is_active(&link, Utc::now())?;
+    let Some(owner) = User::find_by_id(&link.user_id).one(&ss.db).await? else {
+        bail!("Share link does not exist");
+    };
+    let mut details = SharedEntityDetails {
+        owner_name: owner.name,
+        link: link.clone(),
+        review: None,
+        workout: None,
+        collection: None,
+        year_summary: None,
+        workout_template: None,
+    };
+    match link.entity_lot {
+        ShareLinkEntityLot::Collection => {
+            let mut contents = collection_contents(
+                &owner.id,
+                CollectionContentsInput {
+                    search: input.search,
+                    sort: None,
+                    filter: None,
+                    collection_id: link.entity_id.clone(),
+                },
+                ss,
+            )
+            .await?
+            .response;
+            contents
+                .reviews
+                .retain(|r| r.visibility != Visibility::Private);
+            details.collection = Some(contents);
+        }
+        ShareLinkEntityLot::Review => {
+            let Some(review) = Review::find_by_id(&link.entity_id).one(&ss.db).await? else {
+                bail!("Review does not exist");
+            };
+            details.review =
+                item_reviews(&owner.id, &review.entity_id, review.entity_lot, false, ss)
+                    .await?
+                    .into_iter()
+                    .find(|r| r.id == review.id);
+        }
+        ShareLinkEntityLot::Workout => {
+            details.workout = Workout::find_by_id(&link.entity_id).one(&ss.db).await?;
+        }
+        ShareLinkEntityLot::WorkoutTemplate => {
+            details.workout_template = WorkoutTemplate::find_by_id(&link.entity_id)
+                .one(&ss.db)
+                .await?;
+        }
+        ShareLinkEntityLot::YearSummary => {
+            let analytics = user_analytics(
+                ss,
+                &owner.id,
+                UserAnalyticsInput {
+                    group_by: None,
+                    date_range: year_range(&link.entity_id)?,
+                },
+            )
+            .await?;
+            details.year_summary = Some(analytics.response);
+        }
+    }
+    let mut link = link.into_active_model();
+    link.times_viewed = ActiveValue::Set(details.link.times_viewed + 1);
+    link.update(&ss.db).await?;
+    Ok(details)
+}
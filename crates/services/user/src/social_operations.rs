@@ -0,0 +1,208 @@
+use std::{collections::HashMap, sync::Arc};
+
+use anyhow::{Result, bail};
+use common_models::{SearchDetails, SearchInput, StringIdAndNamedObject};
+use database_models::{
+    prelude::{Review, User, UserFeedEvent, UserFollow},
+    review, user, user_feed_event, user_follow,
+};
+use database_utils::{extract_pagination_params, get_enabled_users_query};
+use dependent_models::{UserActivityFeedItem, UserActivityFeedResponse, UserFollowsResponse};
+use enum_models::{UserFeedEventLot, Visibility};
+use itertools::Itertools;
+use sea_orm::{
+    ActiveModelTrait, ActiveValue, ColumnTrait, Condition, EntityTrait, ItemsAndPagesNumber,
+    PaginatorTrait, QueryFilter, QueryOrder, QuerySelect, QueryTrait,
+};
+use supporting_service::SupportingService;
+use user_models::UserSocialPreferences;
+
+/// The kinds of events a user shares with their followers.
+pub(crate) fn shared_feed_event_lots(social: &UserSocialPreferences) -> Vec<UserFeedEventLot> {
+    if !social.allow_followers {
+        return vec![];
+    }
+    [
+        (social.share_reviews, UserFeedEventLot::Review),
+        (social.share_workouts, UserFeedEventLot::Workout),
+        (social.share_completions, UserFeedEventLot::SeenCompleted),
+        (
+            social.share_collections,
+            UserFeedEventLot::CollectionCreated,
+        ),
+    ]
+    .into_iter()
+    .filter_map(|(is_shared, lot)| is_shared.then_some(lot))
+    .collect()
+}
+
+pub async fn follow_user(
+    ss: &Arc<SupportingService>,
+    user_id: &String,
+    to_follow_user_id: String,
+) -> Result<bool> {
+    if user_id == &to_follow_user_id {
+        bail!("You can not follow yourself");
+    }
+    let Some(to_follow) = get_enabled_users_query()
+        .filter(user::Column::Id.eq(&to_follow_user_id))
+        .one(&ss.db)
+        .await?
+    else {
+        bail!("User does not exist");
+    };
+    if !to_follow.preferences.social.allow_followers {
+        bail!("This user does not allow followers");
+    }
+    let already_following = UserFollow::find()
+        .filter(user_follow::Column::FollowerId.eq(user_id))
+        .filter(user_follow::Column::FollowingId.eq(&to_follow_user_id))
+        .one(&ss.db)
+        .await?;
+    if already_following.is_none() {
+        user_follow::ActiveModel {
+            follower_id: ActiveValue::Set(user_id.to_owned()),
+            following_id: ActiveValue::Set(to_follow_user_id),
+            ..Default::default()
+        }
+        .insert(&ss.db)
+        .await?;
+    }
+    Ok(true)
+}
+
+pub async fn unfollow_user(
+    ss: &Arc<SupportingService>,
+    user_id: &String,
+    to_unfollow_user_id: String,
+) -> Result<bool> {
+    UserFollow::delete_many()
+        .filter(user_follow::Column::FollowerId.eq(user_id))
+        .filter(user_follow::Column::FollowingId.eq(to_unfollow_user_id))
+        .exec(&ss.db)
+        .await?;
+    Ok(true)
+}
+
+pub async fn user_follows(
+    ss: &Arc<SupportingService>,
+    user_id: &String,
+) -> Result<UserFollowsResponse> {
+    let follows = UserFollow::find()
+        .filter(
+            Condition::any()
+                .add(user_follow::Column::FollowerId.eq(user_id))
+                .add(user_follow::Column::FollowingId.eq(user_id)),
+        )
+        .order_by_desc(user_follow::Column::CreatedOn)
+        .all(&ss.db)
+        .await?;
+    let user_ids = follows
+        .iter()
+        .flat_map(|f| [f.follower_id.clone(), f.following_id.clone()])
+        .unique()
+        .collect_vec();
+    let names: HashMap<String, String> = User::find()
+        .select_only()
+        .columns([user::Column::Id, user::Column::Name])
+        .filter(user::Column::Id.is_in(user_ids))
+        .into_tuple::<(String, String)>()
+        .all(&ss.db)
+        .await?
+        .into_iter()
+        .collect();
+    let to_object = |id: &String| StringIdAndNamedObject {
+        id: id.to_owned(),
+        name: names.get(id).cloned().unwrap_or_default(),
+    };
+    let mut response = UserFollowsResponse {
+        followers: vec![],
+        following: vec![],
+    };
+    for follow in follows {
+        match &follow.follower_id == user_id {
+            true => response.following.push(to_object(&follow.following_id)),
+            false => response.followers.push(to_object(&follow.follower_id)),
+        }
+    }
+    Ok(response)
+}
+
+pub async fn user_activity_feed(
+    ss: &Arc<SupportingService>,
+    user_id: &String,
+    input: SearchInput,
+) -> Result<UserActivityFeedResponse> {
+    let (take, page) = extract_pagination_params(Some(input), user_id, ss).await?;
+    let following_ids = UserFollow::find()
+        .select_only()
+        .column(user_follow::Column::FollowingId)
+        .filter(user_follow::Column::FollowerId.eq(user_id))
+        .into_tuple::<String>()
+        .all(&ss.db)
+        .await?;
+    let following = get_enabled_users_query()
+        .filter(user::Column::Id.is_in(following_ids))
+        .all(&ss.db)
+        .await?;
+    let mut users_condition = Condition::any();
+    let mut names = HashMap::new();
+    for user in following {
+        let lots = shared_feed_event_lots(&user.preferences.social);
+        if lots.is_empty() {
+            continue;
+        }
+        users_condition = users_condition.add(
+            Condition::all()
+                .add(user_feed_event::Column::UserId.eq(&user.id))
+                .add(user_feed_event::Column::Lot.is_in(lots)),
+        );
+        names.insert(user.id, user.name);
+    }
+    if names.is_empty() {
+        return Ok(UserActivityFeedResponse {
+            items: vec![],
+            details: SearchDetails::default(),
+        });
+    }
+    let paginator = UserFeedEvent::find()
+        .filter(users_condition)
+        .filter(
+            Condition::any()
+                .add(user_feed_event::Column::Lot.ne(UserFeedEventLot::Review))
+                .add(
+                    user_feed_event::Column::OriginId.in_subquery(
+                        Review::find()
+                            .select_only()
+                            .column(review::Column::Id)
+                            .filter(review::Column::Visibility.eq(Visibility::Public))
+                            .into_query(),
+                    ),
+                ),
+        )
+        .order_by_desc(user_feed_event::Column::CreatedOn)
+        .paginate(&ss.db, take);
+    let ItemsAndPagesNumber {
+        number_of_items,
+        number_of_pages,
+    } = paginator.num_items_and_pages().await?;
+    let items = paginator
+        .fetch_page(page - 1)
+        .await?
+        .into_iter()
+        .map(|event| UserActivityFeedItem {
+            user: StringIdAndNamedObject {
+                id: event.user_id.clone(),
+                name: names.get(&event.user_id).cloned().unwrap_or_default(),
+            },
+            event,
+        })
+        .collect();
+    Ok(UserActivityFeedResponse {
+        items,
+        details: SearchDetails {
+            total_items: number_of_items,
+            next_page: (page < number_of_pages).then(|| page + 1),
+        },
+    })
+}
@@ -0,0 +1,120 @@
+use chrono::{DateTime, Duration, TimeZone, Utc};
+use database_models::user_invite;
+use user_models::UserPreferences;
+
+use super::*;
+use crate::{
+    invite_operations::is_user_invite_valid,
+    user_management_operations::{
+        get_registered_user_lot, get_registered_user_settings, is_open_registration_allowed,
+    },
+};
+
+fn now() -> DateTime<Utc> {
+    Utc.with_ymd_and_hms(2025, 10, 28, 12, 0, 0).unwrap()
+}
+
+fn invite(lot: UserLot) -> user_invite::Model {
+    user_invite::Model {
+        id: "inv_1".to_owned(),
+        lot,
+        created_on: now() - Duration::days(1),
+        limits: None,
+        used_on: None,
+        expires_on: None,
+        used_by_user_id: None,
+        created_by_user_id: "usr_admin".to_owned(),
+        preferences: None,
+    }
+}
+
+#[test]
+fn test_unused_invite_without_expiry_is_valid() {
+    assert!(is_user_invite_valid(&invite(UserLot::Normal), now()));
+}
+
+#[test]
+fn test_invite_is_valid_until_it_expires() {
+    let invite = user_invite::Model {
+        expires_on: Some(now() + Duration::hours(1)),
+        ..invite(UserLot::Normal)
+    };
+    assert!(is_user_invite_valid(&invite, now()));
+    assert!(!is_user_invite_valid(&invite, now() + Duration::hours(1)));
+    assert!(!is_user_invite_valid(&invite, now() + Duration::days(1)));
+}
+
+#[test]
+fn test_redeemed_invite_is_not_valid() {
+    let invite = user_invite::Model {
+        used_on: Some(now() - Duration::minutes(5)),
+        used_by_user_id: Some(user_id()),
+        ..invite(UserLot::Normal)
+    };
+    assert!(!is_user_invite_valid(&invite, now()));
+}
+
+#[test]
+fn test_invite_decides_the_role_of_the_new_user() {
+    assert_eq!(
+        get_registered_user_lot(Some(&invite(UserLot::Admin)), Some(UserLot::Normal), 5),
+        UserLot::Admin
+    );
+    assert_eq!(
+        get_registered_user_lot(Some(&invite(UserLot::Normal)), None, 0),
+        UserLot::Normal
+    );
+}
+
+#[test]
+fn test_first_user_without_invite_becomes_admin() {
+    assert_eq!(get_registered_user_lot(None, None, 0), UserLot::Admin);
+    assert_eq!(get_registered_user_lot(None, None, 1), UserLot::Normal);
+    assert_eq!(
+        get_registered_user_lot(None, Some(UserLot::Normal), 0),
+        UserLot::Normal
+    );
+}
+
+#[test]
+fn test_new_user_gets_the_limits_and_preferences_of_the_invite() {
+    let limits = UserLimits {
+        max_integrations: Some(2),
+        ..Default::default()
+    };
+    let mut preferences = UserPreferences::default();
+    preferences.general.list_page_size = 50;
+    let invite = user_invite::Model {
+        limits: Some(limits.clone()),
+        preferences: Some(preferences.clone()),
+        ..invite(UserLot::Normal)
+    };
+    assert_eq!(
+        get_registered_user_settings(Some(&invite)),
+        (Some(limits), preferences)
+    );
+    assert_eq!(
+        get_registered_user_settings(None),
+        (None, UserPreferences::default())
+    );
+}
+
+#[test]
+fn test_closed_registration_needs_admin_access_token() {
+    assert!(is_open_registration_allowed(true, None, ADMIN_ACCESS_TOKEN));
+    assert!(!is_open_registration_allowed(
+        false,
+        None,
+        ADMIN_ACCESS_TOKEN
+    ));
+    assert!(!is_open_registration_allowed(
+        false,
+        Some("wrong-token"),
+        ADMIN_ACCESS_TOKEN
+    ));
+    assert!(is_open_registration_allowed(
+        false,
+        Some(ADMIN_ACCESS_TOKEN),
+        ADMIN_ACCESS_TOKEN
+    ));
+}
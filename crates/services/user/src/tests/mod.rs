@@ -0,0 +1,25 @@
+use enum_models::UserLot;
+use user_models::{UpdateUserInput, UserLimits};
+
+mod invites;
+mod share_links;
+mod social;
+mod user_management;
+
+const ADMIN_ACCESS_TOKEN: &str = "secret-token";
+
+fn user_id() -> String {
+    "usr_target".to_owned()
+}
+
+fn update_input() -> UpdateUserInput {
+    UpdateUserInput {
+        lot: None,
+        limits: None,
+        username: None,
+        user_id: user_id(),
+        is_disabled: None,
+        admin_access_token: None,
+        is_onboarding_tour_completed: None,
+    }
+}
@@ -0,0 +1,71 @@
+use chrono::{DateTime, Duration, NaiveDate, TimeZone, Utc};
+use database_models::share_link;
+use enum_models::ShareLinkEntityLot;
+
+use super::*;
+use crate::share_link_operations::{
+    check_share_link_is_active, check_share_link_owner, year_range,
+};
+
+fn now() -> DateTime<Utc> {
+    Utc.with_ymd_and_hms(2025, 10, 28, 12, 0, 0).unwrap()
+}
+
+fn link(is_revoked: Option<bool>, expires_on: Option<DateTime<Utc>>) -> share_link::Model {
+    share_link::Model {
+        id: "shl_1".to_owned(),
+        name: None,
+        user_id: user_id(),
+        entity_id: "2024".to_owned(),
+        times_viewed: 0,
+        created_on: now() - Duration::days(7),
+        is_revoked,
+        entity_lot: ShareLinkEntityLot::YearSummary,
+        expires_on,
+    }
+}
+
+#[test]
+fn test_share_link_without_expiry_stays_active() {
+    assert!(check_share_link_is_active(&link(None, None), now()).is_ok());
+    assert!(check_share_link_is_active(&link(Some(false), None), now()).is_ok());
+}
+
+#[test]
+fn test_share_link_is_active_until_it_expires() {
+    let expires_on = now() + Duration::minutes(1);
+    assert!(check_share_link_is_active(&link(None, Some(expires_on)), now()).is_ok());
+    assert!(check_share_link_is_active(&link(None, Some(now())), now()).is_ok());
+    let err = check_share_link_is_active(&link(None, Some(expires_on)), now() + Duration::hours(1))
+        .unwrap_err();
+    assert_eq!(err.to_string(), "Share link has expired");
+}
+
+#[test]
+fn test_revoked_share_link_is_rejected_even_before_expiry() {
+    let expires_on = now() + Duration::days(1);
+    let err = check_share_link_is_active(&link(Some(true), Some(expires_on)), now()).unwrap_err();
+    assert_eq!(err.to_string(), "Share link has been revoked");
+}
+
+#[test]
+fn test_only_the_owner_can_share_an_entity() {
+    let owner = user_id();
+    let other = "usr_other".to_owned();
+    assert!(check_share_link_owner(Some(&owner), &owner).is_ok());
+    assert!(check_share_link_owner(Some(&other), &owner).is_err());
+    assert!(check_share_link_owner(None, &owner).is_err());
+}
+
+#[test]
+fn test_year_summary_is_scoped_to_the_shared_year() {
+    let range = year_range("2024").unwrap();
+    assert_eq!(range.start_date, NaiveDate::from_ymd_opt(2024, 1, 1));
+    assert_eq!(range.end_date, NaiveDate::from_ymd_opt(2024, 12, 31));
+}
+
+#[test]
+fn test_year_summary_needs_a_year() {
+    assert!(year_range("last-year").is_err());
+    assert!(year_range("").is_err());
+}
@@ -0,0 +1,61 @@
+use enum_models::UserFeedEventLot;
+use user_models::UserSocialPreferences;
+
+use crate::social_operations::shared_feed_event_lots;
+
+#[test]
+fn test_only_reviews_are_shared_by_default() {
+    assert_eq!(
+        shared_feed_event_lots(&UserSocialPreferences::default()),
+        vec![UserFeedEventLot::Review]
+    );
+}
+
+#[test]
+fn test_nothing_is_shared_without_followers() {
+    let social = UserSocialPreferences {
+        allow_followers: false,
+        share_reviews: true,
+        share_workouts: true,
+        share_completions: true,
+        share_collections: true,
+    };
+    assert!(shared_feed_event_lots(&social).is_empty());
+}
+
+#[test]
+fn test_each_kind_of_event_is_shared_on_its_own() {
+    let social = UserSocialPreferences {
+        share_reviews: false,
+        share_workouts: true,
+        share_completions: false,
+        share_collections: true,
+        ..Default::default()
+    };
+    assert_eq!(
+        shared_feed_event_lots(&social),
+        vec![
+            UserFeedEventLot::Workout,
+            UserFeedEventLot::CollectionCreated
+        ]
+    );
+}
+
+#[test]
+fn test_all_kinds_of_events_can_be_shared() {
+    let social = UserSocialPreferences {
+        share_workouts: true,
+        share_completions: true,
+        share_collections: true,
+        ..Default::default()
+    };
+    assert_eq!(
+        shared_feed_event_lots(&social),
+        vec![
+            UserFeedEventLot::Review,
+            UserFeedEventLot::Workout,
+            UserFeedEventLot::SeenCompleted,
+            UserFeedEventLot::CollectionCreated
+        ]
+    );
+}
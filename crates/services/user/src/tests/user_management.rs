@@ -0,0 +1,85 @@
+use super::*;
+use crate::user_management_operations::check_update_user_permission;
+
+fn check(input: &UpdateUserInput, requester: Option<&str>, is_admin: bool) -> bool {
+    let requester = requester.map(|r| r.to_owned());
+    check_update_user_permission(input, requester.as_ref(), is_admin, ADMIN_ACCESS_TOKEN).is_ok()
+}
+
+#[test]
+fn test_user_can_update_own_details() {
+    let input = UpdateUserInput {
+        username: Some("new-name".to_owned()),
+        ..update_input()
+    };
+    assert!(check(&input, Some("usr_target"), false));
+}
+
+#[test]
+fn test_user_cannot_update_another_user() {
+    assert!(!check(&update_input(), Some("usr_other"), false));
+}
+
+#[test]
+fn test_admin_can_update_another_user() {
+    assert!(check(&update_input(), Some("usr_admin"), true));
+}
+
+#[test]
+fn test_anonymous_request_needs_admin_access_token() {
+    assert!(!check(&update_input(), None, false));
+    let input = UpdateUserInput {
+        admin_access_token: Some(ADMIN_ACCESS_TOKEN.to_owned()),
+        ..update_input()
+    };
+    assert!(check(&input, None, false));
+}
+
+#[test]
+fn test_wrong_admin_access_token_is_rejected() {
+    let input = UpdateUserInput {
+        admin_access_token: Some("wrong-token".to_owned()),
+        ..update_input()
+    };
+    assert!(!check(&input, None, false));
+    assert!(!check(&input, Some("usr_other"), false));
+}
+
+#[test]
+fn test_user_cannot_change_own_lot() {
+    let input = UpdateUserInput {
+        lot: Some(UserLot::Admin),
+        ..update_input()
+    };
+    assert!(!check(&input, Some("usr_target"), false));
+}
+
+#[test]
+fn test_user_cannot_change_own_limits() {
+    let input = UpdateUserInput {
+        limits: Some(UserLimits::default()),
+        ..update_input()
+    };
+    assert!(!check(&input, Some("usr_target"), false));
+}
+
+#[test]
+fn test_admin_can_change_lot_and_limits() {
+    let input = UpdateUserInput {
+        lot: Some(UserLot::FitnessOnly),
+        limits: Some(UserLimits::default()),
+        ..update_input()
+    };
+    assert!(check(&input, Some("usr_admin"), true));
+}
+
+#[test]
+fn test_admin_access_token_can_change_lot() {
+    let input = UpdateUserInput {
+        lot: Some(UserLot::Normal),
+        admin_access_token: Some(ADMIN_ACCESS_TOKEN.to_owned()),
+        ..update_input()
+    };
+    assert!(check(&input, Some("usr_target"), false));
+    assert!(check(&input, None, false));
+}
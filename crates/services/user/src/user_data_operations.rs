@@ -2,15 +2,19 @@ use std::sync::Arc;
 
 use anyhow::Result;
 use database_models::{
-    access_link, integration, notification_platform,
-    prelude::{AccessLink, Integration, NotificationPlatform, User},
+    access_link, integration, notification_delivery, notification_platform,
+    prelude::{AccessLink, Integration, NotificationDelivery, NotificationPlatform, User},
     user,
 };
 use database_utils::{apply_columns_search, get_enabled_users_query};
 use dependent_models::BasicUserDetails;
-use sea_orm::{ColumnTrait, EntityTrait, QueryFilter, QueryOrder, QueryTrait, prelude::Expr};
+use sea_orm::{
+    ColumnTrait, EntityTrait, QueryFilter, QueryOrder, QuerySelect, QueryTrait, prelude::Expr,
+};
 use supporting_service::SupportingService;
 
+const USER_NOTIFICATION_DELIVERIES_LIMIT: u64 = 100;
+
 pub async fn user_access_links(
     ss: &Arc<SupportingService>,
     user_id: &String,
@@ -73,6 +77,21 @@ pub async fn user_notification_platforms(
     Ok(all_notifications)
 }
 
+pub async fn user_notification_deliveries(
+    ss: &Arc<SupportingService>,
+    user_id: &String,
+    notification_id: String,
+) -> Result<Vec<notification_delivery::Model>> {
+    let deliveries = NotificationDelivery::find()
+        .filter(notification_delivery::Column::UserId.eq(user_id))
+        .filter(notification_delivery::Column::PlatformId.eq(notification_id))
+        .order_by_desc(notification_delivery::Column::CreatedOn)
+        .limit(USER_NOTIFICATION_DELIVERIES_LIMIT)
+        .all(&ss.db)
+        .await?;
+    Ok(deliveries)
+}
+
 pub async fn user_by_oidc_issuer_id(
     ss: &Arc<SupportingService>,
     oidc_issuer_id: String,
@@ -1,9 +1,9 @@
 use std::sync::Arc;
 
 use anyhow::{Result, bail};
-use common_models::{DefaultCollection, StringIdObject};
+use common_models::{BackendError, DefaultCollection, StringIdObject};
 use common_utils::ryot_log;
-use database_models::{prelude::User, user};
+use database_models::{prelude::User, user, user_invite};
 use database_utils::{admin_account_guard, deploy_job_to_calculate_user_activities_and_summary};
 use dependent_collection_utils::create_or_update_collection;
 use dependent_models::ExpireCacheKeyInput;
@@ -21,29 +21,91 @@ use sea_orm::{
 };
 use sea_orm::{IntoActiveModel, Iterable};
 use supporting_service::SupportingService;
-use user_models::{UpdateUserInput, UserPreferences};
+use user_models::{UpdateUserInput, UserLimits, UserPreferences};
 
 use crate::{
+    invite_operations::{redeem_user_invite, valid_user_invite},
     password_change_operations::{build_password_change_url, generate_password_change_session},
     user_data_operations::users_list,
 };
 
+/// Users can update their own details, while updating other users needs an admin or the
+/// admin access token. Only those can change the role or limits of any user.
+pub(crate) fn check_update_user_permission(
+    input: &UpdateUserInput,
+    requester_user_id: Option<&String>,
+    is_requester_admin: bool,
+    admin_access_token: &str,
+) -> Result<()> {
+    let has_admin_access_token =
+        input.admin_access_token.as_deref().unwrap_or_default() == admin_access_token;
+    let is_allowed = match requester_user_id {
+        Some(uid) => uid == &input.user_id || is_requester_admin || has_admin_access_token,
+        None => has_admin_access_token,
+    };
+    if !is_allowed {
+        bail!("Admin access token required");
+    }
+    if (input.lot.is_some() || input.limits.is_some())
+        && !is_requester_admin
+        && !has_admin_access_token
+    {
+        bail!(BackendError::AdminOnlyAction.to_string());
+    }
+    Ok(())
+}
+
+/// Whether someone who is neither invited nor an admin can register.
+pub(crate) fn is_open_registration_allowed(
+    allow_registration: bool,
+    admin_access_token: Option<&str>,
+    server_admin_access_token: &str,
+) -> bool {
+    allow_registration || admin_access_token.unwrap_or_default() == server_admin_access_token
+}
+
+/// The role of a new user. An invite decides it, otherwise the first user becomes an admin.
+pub(crate) fn get_registered_user_lot(
+    invite: Option<&user_invite::Model>,
+    requested_lot: Option<UserLot>,
+    total_users: u64,
+) -> UserLot {
+    match invite.map(|i| i.lot).or(requested_lot) {
+        Some(specified_lot) => specified_lot,
+        None => match total_users == 0 {
+            true => UserLot::Admin,
+            false => UserLot::Normal,
+        },
+    }
+}
+
+/// The limits and preferences a new user starts with, which an invite can set up front.
+pub(crate) fn get_registered_user_settings(
+    invite: Option<&user_invite::Model>,
+) -> (Option<UserLimits>, UserPreferences) {
+    let limits = invite.and_then(|i| i.limits.clone());
+    let preferences = invite
+        .and_then(|i| i.preferences.clone())
+        .unwrap_or_default();
+    (limits, preferences)
+}
+
 pub async fn update_user(
     input: UpdateUserInput,
     ss: &Arc<SupportingService>,
     requester_user_id: Option<String>,
 ) -> Result<StringIdObject> {
-    if let Some(ref uid) = requester_user_id {
-        if uid != &input.user_id
-            && admin_account_guard(uid, ss).await.is_err()
-            && input.admin_access_token.unwrap_or_default() != ss.config.server.admin_access_token
-        {
-            bail!("Admin access token required");
-        }
-    } else if input.admin_access_token.unwrap_or_default() != ss.config.server.admin_access_token {
-        bail!("Admin access token required");
-    }
-    let db_user = User::find_by_id(input.user_id).one(&ss.db).await?.unwrap();
+    let is_requester_admin = match requester_user_id {
+        Some(ref uid) => admin_account_guard(uid, ss).await.is_ok(),
+        None => false,
+    };
+    check_update_user_permission(
+        &input,
+        requester_user_id.as_ref(),
+        is_requester_admin,
+        &ss.config.server.admin_access_token,
+    )?;
+    let db_user = User::find_by_id(&input.user_id).one(&ss.db).await?.unwrap();
     let mut extra_information = db_user.extra_information.clone().unwrap_or_default();
     let mut user_obj = db_user.into_active_model();
     if let Some(n) = input.username {
@@ -52,6 +114,9 @@ pub async fn update_user(
     if let Some(l) = input.lot {
         user_obj.lot = ActiveValue::Set(l);
     }
+    if let Some(l) = input.limits {
+        user_obj.limits = ActiveValue::Set(Some(l));
+    }
     if let Some(d) = input.is_disabled {
         user_obj.is_disabled = ActiveValue::Set(Some(d));
     }
@@ -60,6 +125,9 @@ pub async fn update_user(
         user_obj.extra_information = ActiveValue::Set(Some(extra_information));
     }
     let user_obj = user_obj.update(&ss.db).await?;
+    if let Some(l) = input.lot {
+        session_service::update_user_sessions_lot(ss, &user_obj.id, l).await?;
+    }
     ryot_log!(debug, "Updated user with id {:?}", user_obj.id);
     Ok(StringIdObject { id: user_obj.id })
 }
@@ -118,6 +186,7 @@ pub async fn reset_user(
     let register_input = RegisterUserInput {
         data: auth_input,
         lot: Some(original_lot),
+        invite_code: None,
         user_id: Some(original_id.clone()),
         admin_access_token: Some(ss.config.server.admin_access_token.clone()),
     };
@@ -152,14 +221,29 @@ pub async fn register_user(
     requester_user_id: Option<String>,
     input: RegisterUserInput,
 ) -> Result<RegisterResult> {
-    if let Some(ref uid) = requester_user_id {
-        admin_account_guard(uid, ss).await?;
-    } else if !ss.config.users.allow_registration
-        && input.admin_access_token.unwrap_or_default() != ss.config.server.admin_access_token
-    {
-        return Ok(RegisterResult::Error(RegisterError {
-            error: RegisterErrorVariant::Disabled,
-        }));
+    let invite = match input.invite_code {
+        None => None,
+        Some(ref code) => match valid_user_invite(ss, code).await? {
+            Some(invite) => Some(invite),
+            None => {
+                return Ok(RegisterResult::Error(RegisterError {
+                    error: RegisterErrorVariant::InvalidInviteCode,
+                }));
+            }
+        },
+    };
+    if invite.is_none() {
+        if let Some(ref uid) = requester_user_id {
+            admin_account_guard(uid, ss).await?;
+        } else if !is_open_registration_allowed(
+            ss.config.users.allow_registration,
+            input.admin_access_token.as_deref(),
+            &ss.config.server.admin_access_token,
+        ) {
+            return Ok(RegisterResult::Error(RegisterError {
+                error: RegisterErrorVariant::Disabled,
+            }));
+        }
     }
     let (filter, username, password) = match input.data.clone() {
         AuthUserInput::Oidc(data) => (
@@ -187,13 +271,8 @@ pub async fn register_user(
         AuthUserInput::Password(_) => None,
     };
     // TODO: https://github.com/SeaQL/sea-orm/discussions/730#discussioncomment-13440496
-    let lot = match input.lot {
-        Some(specified_lot) => specified_lot,
-        None => match total_users == 0 {
-            true => UserLot::Admin,
-            false => UserLot::Normal,
-        },
-    };
+    let lot = get_registered_user_lot(invite.as_ref(), input.lot, total_users);
+    let (limits, preferences) = get_registered_user_settings(invite.as_ref());
     let user_id = input
         .user_id
         .unwrap_or_else(|| format!("usr_{}", nanoid!(12)));
@@ -203,10 +282,19 @@ pub async fn register_user(
         name: ActiveValue::Set(username),
         password: ActiveValue::Set(password),
         oidc_issuer_id: ActiveValue::Set(oidc_issuer_id),
-        preferences: ActiveValue::Set(UserPreferences::default()),
+        limits: ActiveValue::Set(limits),
+        preferences: ActiveValue::Set(preferences),
         ..Default::default()
     };
     let user = user.insert(&ss.db).await?;
+    if let Some(invite) = invite
+        && !redeem_user_invite(ss, &invite.id, &user.id).await?
+    {
+        user.delete(&ss.db).await?;
+        return Ok(RegisterResult::Error(RegisterError {
+            error: RegisterErrorVariant::InvalidInviteCode,
+        }));
+    }
     ryot_log!(
         debug,
         "User {:?} registered with id {:?}",
@@ -1,7 +1,13 @@
 use std::sync::Arc;
 
-use anyhow::Result;
-use database_utils::user_by_id;
+use anyhow::{Result, bail};
+use chrono_tz::Tz;
+use database_utils::{
+    deploy_job_to_calculate_user_activities_and_summary, schedule_user_for_workout_revision,
+    user_by_id,
+};
+use dependent_models::{ApplicationCacheKeyDiscriminants, ExpireCacheKeyInput};
+use dependent_utility_utils::expire_user_measurements_list_cache;
 use sea_orm::{ActiveModelTrait, ActiveValue, IntoActiveModel};
 use supporting_service::SupportingService;
 use user_models::UserPreferences;
@@ -11,9 +17,40 @@ pub async fn update_user_preference(
     user_id: &String,
     input: UserPreferences,
 ) -> Result<bool> {
+    if let Some(timezone) = &input.general.timezone
+        && timezone.parse::<Tz>().is_err()
+    {
+        bail!("{} is not a valid timezone", timezone);
+    }
     let user_model = user_by_id(user_id, ss).await?;
+    let one_rm_formula_changed = user_model.preferences.fitness.exercises.one_rm_formula
+        != input.fitness.exercises.one_rm_formula;
+    let measurements_changed =
+        user_model.preferences.fitness.measurements != input.fitness.measurements;
+    let timezone_changed = user_model.preferences.general.timezone != input.general.timezone;
+    let metadata_languages_changed =
+        user_model.preferences.general.metadata_languages != input.general.metadata_languages;
     let mut user_model = user_model.into_active_model();
     user_model.preferences = ActiveValue::Set(input);
     user_model.update(&ss.db).await?;
+    if one_rm_formula_changed {
+        schedule_user_for_workout_revision(user_id, ss).await?;
+    }
+    if measurements_changed {
+        expire_user_measurements_list_cache(user_id, ss).await?;
+    }
+    if timezone_changed {
+        deploy_job_to_calculate_user_activities_and_summary(user_id, true, ss).await?;
+    }
+    if metadata_languages_changed {
+        cache_service::expire_key(
+            ss,
+            ExpireCacheKeyInput::BySanitizedKey {
+                user_id: Some(user_id.to_owned()),
+                key: ApplicationCacheKeyDiscriminants::UserMetadataDetails,
+            },
+        )
+        .await?;
+    }
     Ok(true)
 }
@@ -0,0 +1,213 @@
+use std::sync::Arc;
+
+use anyhow::{Result, bail};
+use chrono::Utc;
+use common_models::{StringIdAndNamedObject, StringIdObject};
+use database_models::{
+    prelude::{User, WatchGroup, WatchGroupMember},
+    user, watch_group, watch_group_member,
+};
+use database_utils::get_enabled_users_query;
+use dependent_models::UserWatchGroupDetails;
+use itertools::Itertools;
+use media_models::{CreateOrUpdateWatchGroupInput, UpdateWatchGroupExclusionInput};
+use sea_orm::{
+    ActiveModelTrait, ActiveValue, ColumnTrait, EntityTrait, IntoActiveModel, ModelTrait,
+    PaginatorTrait, QueryFilter, QueryOrder, QuerySelect,
+};
+use supporting_service::SupportingService;
+
+async fn owned_watch_group(
+    ss: &Arc<SupportingService>,
+    user_id: &String,
+    watch_group_id: &String,
+) -> Result<watch_group::Model> {
+    let Some(group) = WatchGroup::find_by_id(watch_group_id)
+        .filter(watch_group::Column::CreatedByUserId.eq(user_id))
+        .one(&ss.db)
+        .await?
+    else {
+        bail!("Watch group does not exist or was not created by this user");
+    };
+    Ok(group)
+}
+
+pub async fn user_watch_groups(
+    ss: &Arc<SupportingService>,
+    user_id: &String,
+) -> Result<Vec<UserWatchGroupDetails>> {
+    let memberships = WatchGroupMember::find()
+        .filter(watch_group_member::Column::UserId.eq(user_id))
+        .find_also_related(WatchGroup)
+        .order_by_desc(watch_group_member::Column::JoinedOn)
+        .all(&ss.db)
+        .await?;
+    let mut response = vec![];
+    for (membership, group) in memberships {
+        let Some(group) = group else {
+            continue;
+        };
+        let members = group
+            .find_related(WatchGroupMember)
+            .filter(watch_group_member::Column::AcceptedOn.is_not_null())
+            .find_also_related(User)
+            .order_by_asc(watch_group_member::Column::JoinedOn)
+            .all(&ss.db)
+            .await?
+            .into_iter()
+            .filter_map(|(_, user)| user)
+            .map(|user| StringIdAndNamedObject {
+                id: user.id,
+                name: user.name,
+            })
+            .collect();
+        response.push(UserWatchGroupDetails {
+            members,
+            membership,
+            details: group,
+        });
+    }
+    Ok(response)
+}
+
+pub async fn create_or_update_watch_group(
+    ss: &Arc<SupportingService>,
+    user_id: &String,
+    input: CreateOrUpdateWatchGroupInput,
+) -> Result<StringIdObject> {
+    let mut member_ids = input
+        .member_ids
+        .into_iter()
+        .filter(|id| id != user_id)
+        .unique()
+        .collect_vec();
+    let valid_members = get_enabled_users_query()
+        .filter(user::Column::Id.is_in(member_ids.clone()))
+        .count(&ss.db)
+        .await?;
+    if valid_members != member_ids.len() as u64 {
+        bail!("Some of the members do not exist");
+    }
+    member_ids.push(user_id.to_owned());
+    let group = match input.update_id {
+        Some(id) => {
+            let mut group = owned_watch_group(ss, user_id, &id)
+                .await?
+                .into_active_model();
+            group.name = ActiveValue::Set(input.name);
+            group.update(&ss.db).await?
+        }
+        None => {
+            watch_group::ActiveModel {
+                name: ActiveValue::Set(input.name),
+                created_by_user_id: ActiveValue::Set(user_id.to_owned()),
+                ..Default::default()
+            }
+            .insert(&ss.db)
+            .await?
+        }
+    };
+    WatchGroupMember::delete_many()
+        .filter(watch_group_member::Column::WatchGroupId.eq(&group.id))
+        .filter(watch_group_member::Column::UserId.is_not_in(member_ids.clone()))
+        .exec(&ss.db)
+        .await?;
+    let existing_members: Vec<String> = group
+        .find_related(WatchGroupMember)
+        .select_only()
+        .column(watch_group_member::Column::UserId)
+        .into_tuple()
+        .all(&ss.db)
+        .await?;
+    for member_id in member_ids {
+        if existing_members.contains(&member_id) {
+            continue;
+        }
+        let accepted_on = (&member_id == user_id).then(Utc::now);
+        watch_group_member::ActiveModel {
+            accepted_on: ActiveValue::Set(accepted_on),
+            user_id: ActiveValue::Set(member_id),
+            watch_group_id: ActiveValue::Set(group.id.clone()),
+            excluded_metadata_ids: ActiveValue::Set(vec![]),
+            ..Default::default()
+        }
+        .insert(&ss.db)
+        .await?;
+    }
+    Ok(StringIdObject { id: group.id })
+}
+
+pub async fn delete_watch_group(
+    ss: &Arc<SupportingService>,
+    user_id: &String,
+    watch_group_id: String,
+) -> Result<bool> {
+    let group = owned_watch_group(ss, user_id, &watch_group_id).await?;
+    group.delete(&ss.db).await?;
+    Ok(true)
+}
+
+pub async fn accept_watch_group_invitation(
+    ss: &Arc<SupportingService>,
+    user_id: &String,
+    watch_group_id: String,
+) -> Result<bool> {
+    let Some(membership) = WatchGroupMember::find()
+        .filter(watch_group_member::Column::WatchGroupId.eq(watch_group_id))
+        .filter(watch_group_member::Column::UserId.eq(user_id))
+        .one(&ss.db)
+        .await?
+    else {
+        bail!("User has not been invited to this watch group");
+    };
+    if membership.accepted_on.is_some() {
+        return Ok(true);
+    }
+    let mut membership = membership.into_active_model();
+    membership.accepted_on = ActiveValue::Set(Some(Utc::now()));
+    membership.update(&ss.db).await?;
+    Ok(true)
+}
+
+pub async fn leave_watch_group(
+    ss: &Arc<SupportingService>,
+    user_id: &String,
+    watch_group_id: String,
+) -> Result<bool> {
+    let Some(group) = WatchGroup::find_by_id(&watch_group_id).one(&ss.db).await? else {
+        bail!("Watch group does not exist");
+    };
+    if &group.created_by_user_id == user_id {
+        bail!("The creator of a watch group can not leave it, delete it instead");
+    }
+    WatchGroupMember::delete_many()
+        .filter(watch_group_member::Column::WatchGroupId.eq(watch_group_id))
+        .filter(watch_group_member::Column::UserId.eq(user_id))
+        .exec(&ss.db)
+        .await?;
+    Ok(true)
+}
+
+pub async fn update_watch_group_exclusion(
+    ss: &Arc<SupportingService>,
+    user_id: &String,
+    input: UpdateWatchGroupExclusionInput,
+) -> Result<bool> {
+    let Some(membership) = WatchGroupMember::find()
+        .filter(watch_group_member::Column::WatchGroupId.eq(input.watch_group_id))
+        .filter(watch_group_member::Column::UserId.eq(user_id))
+        .one(&ss.db)
+        .await?
+    else {
+        bail!("User is not a member of this watch group");
+    };
+    let mut excluded = membership.excluded_metadata_ids.clone();
+    excluded.retain(|id| id != &input.metadata_id);
+    if input.is_excluded {
+        excluded.push(input.metadata_id);
+    }
+    let mut membership = membership.into_active_model();
+    membership.excluded_metadata_ids = ActiveValue::Set(excluded);
+    membership.update(&ss.db).await?;
+    Ok(true)
+}
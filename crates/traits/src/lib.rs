@@ -4,14 +4,14 @@ use anyhow::{Result, bail};
 use application_utils::AuthContext;
 use async_graphql::{Context, Error, Result as GraphqlResult};
 use async_trait::async_trait;
-use common_models::{BackendError, PersonSourceSpecifics};
+use common_models::{BackendError, PersonSourceSpecifics, TrendingMediaList};
 use common_utils::ryot_log;
 use database_models::metadata_group::MetadataGroupWithoutId;
-use database_utils::{check_token, deploy_job_to_mark_user_last_activity};
+use database_utils::{check_token, deploy_job_to_mark_user_last_activity, user_lot_guard};
 use dependent_models::{MetadataSearchSourceSpecifics, PersonDetails, SearchResults};
 use media_models::{
-    MetadataDetails, MetadataGroupSearchItem, MetadataSearchItem, PartialMetadataWithoutId,
-    PeopleSearchItem,
+    MetadataDetails, MetadataGroupSearchItem, MetadataSearchItem, MetadataTranslationDetails,
+    PartialMetadataWithoutId, PeopleSearchItem,
 };
 use supporting_service::SupportingService;
 
@@ -35,6 +35,16 @@ pub trait MediaProvider {
         bail!("This provider does not support getting media details")
     }
 
+    /// Get the title and description of a media item in the given language.
+    #[allow(unused_variables)]
+    async fn metadata_translation(
+        &self,
+        identifier: &str,
+        language: &str,
+    ) -> Result<MetadataTranslationDetails> {
+        bail!("This provider does not support translating media")
+    }
+
     /// Search for people via a query.
     #[allow(unused_variables)]
     async fn people_search(
@@ -77,8 +87,12 @@ pub trait MediaProvider {
         bail!("This provider does not support getting group details")
     }
 
-    /// Get trending media.
-    async fn get_trending_media(&self) -> Result<Vec<PartialMetadataWithoutId>> {
+    /// Get the media in one of the trending lists.
+    #[allow(unused_variables)]
+    async fn get_trending_media(
+        &self,
+        list: TrendingMediaList,
+    ) -> Result<Vec<PartialMetadataWithoutId>> {
         bail!("This provider does not support getting trending media")
     }
 }
@@ -90,6 +104,16 @@ pub trait AuthProvider {
         false
     }
 
+    /// Whether this resolver deals with media tracking, which fitness-only users can not access.
+    fn is_media_resolver(&self) -> bool {
+        false
+    }
+
+    /// Whether guests are allowed to call this resolver even though it is a mutation.
+    fn is_allowed_for_guests(&self) -> bool {
+        false
+    }
+
     fn user_session_id_from_ctx(&self, ctx: &Context<'_>) -> GraphqlResult<String> {
         let auth_ctx = ctx.data_unchecked::<AuthContext>();
         auth_ctx
@@ -105,6 +129,15 @@ pub trait AuthProvider {
             check_token(session_id, self.is_mutation(), ss).await?;
         }
         if let Some(user_id) = &auth_ctx.user_id {
+            let is_mutation = self.is_mutation() && !self.is_allowed_for_guests();
+            user_lot_guard(
+                user_id,
+                auth_ctx.user_lot,
+                is_mutation,
+                self.is_media_resolver(),
+                ss,
+            )
+            .await?;
             deploy_job_to_mark_user_last_activity(user_id, ss).await?;
         }
         auth_ctx
@@ -8,6 +8,7 @@ use axum::{
 };
 use chrono::{NaiveDate, NaiveDateTime, Utc};
 use common_utils::{FRONTEND_OAUTH_ENDPOINT, ryot_log};
+use enum_models::UserLot;
 use media_models::{
     GraphqlSortOrder, PodcastEpisode, PodcastSpecifics, ReviewItem, ShowEpisode, ShowSeason,
     ShowSpecifics,
@@ -31,6 +32,7 @@ use supporting_service::SupportingService;
 pub struct AuthContext {
     pub user_id: Option<String>,
     pub session_id: Option<String>,
+    pub user_lot: Option<UserLot>,
 }
 
 impl<S> FromRequestParts<S> for AuthContext
@@ -62,6 +64,7 @@ where
 
             if let Ok(Some(session)) = session_service::validate_session(&ss, session_id).await {
                 ctx.user_id = Some(session.user_id);
+                ctx.user_lot = session.user_lot;
             }
         }
 
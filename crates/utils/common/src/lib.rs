@@ -15,7 +15,10 @@ use sea_orm::{
     sea_query::{PostgresQueryBuilder, SelectStatement},
 };
 use serde::de;
-use tokio::time::sleep;
+use tokio::{
+    sync::Mutex,
+    time::{Instant, sleep},
+};
 
 pub const PAGE_SIZE: u64 = 20;
 pub const AUTHOR: &str = "ignisda";
@@ -25,6 +28,7 @@ pub const FRONTEND_OAUTH_ENDPOINT: &str = "/api/auth";
 pub const AUTHOR_EMAIL: &str = "ignisda2001@gmail.com";
 pub const BULK_APPLICATION_UPDATE_CHUNK_SIZE: usize = 5;
 pub const MAX_IMPORT_RETRIES_FOR_PARTIAL_STATE: usize = 5;
+pub const EXPORT_ARCHIVE_DATA_FILE_NAME: &str = "export.json";
 pub const BULK_DATABASE_UPDATE_OR_DELETE_CHUNK_SIZE: usize = 2000;
 pub const SHOW_SPECIAL_SEASON_NAMES: [&str; 2] = ["Specials", "Extras"];
 pub const APPLICATION_JSON_HEADER: HeaderValue = HeaderValue::from_static("application/json");
@@ -47,28 +51,35 @@ pub fn compute_next_page(page: u64, page_size: u64, total_items: u64) -> Option<
         .flatten()
 }
 
-pub const PEOPLE_SEARCH_SOURCES: [MediaSource; 12] = [
+pub const PEOPLE_SEARCH_SOURCES: [MediaSource; 16] = [
     MediaSource::Vndb,
     MediaSource::Igdb,
     MediaSource::Tmdb,
     MediaSource::Tvdb,
+    MediaSource::Douban,
     MediaSource::Spotify,
     MediaSource::Anilist,
     MediaSource::Audible,
+    MediaSource::Bangumi,
+    MediaSource::ComicVine,
     MediaSource::Hardcover,
     MediaSource::GiantBomb,
+    MediaSource::MusicBrainz,
     MediaSource::Openlibrary,
     MediaSource::MangaUpdates,
     MediaSource::YoutubeMusic,
 ];
 
-pub const MEDIA_SOURCES_WITHOUT_RECOMMENDATIONS: [MediaSource; 6] = [
+pub const MEDIA_SOURCES_WITHOUT_RECOMMENDATIONS: [MediaSource; 9] = [
     MediaSource::Tvdb,
     MediaSource::Vndb,
     MediaSource::Itunes,
     MediaSource::Custom,
     MediaSource::Spotify,
+    MediaSource::ComicVine,
     MediaSource::GoogleBooks,
+    MediaSource::MusicBrainz,
+    MediaSource::BoardGameGeek,
 ];
 
 /// Logging macro that targets the "ryot" tracing target
@@ -165,6 +176,37 @@ pub async fn sleep_for_n_seconds(sec: u64) {
     sleep(Duration::from_secs(sec)).await;
 }
 
+/// Spaces out requests to a service that blocks clients which are too fast. Providers create a
+/// new service for every request, so the throttle should live in a `static`.
+pub struct RequestThrottle {
+    interval: Duration,
+    last_request_at: Mutex<Option<Instant>>,
+}
+
+impl RequestThrottle {
+    pub const fn new(interval: Duration) -> Self {
+        Self {
+            interval,
+            last_request_at: Mutex::const_new(None),
+        }
+    }
+
+    pub fn interval(&self) -> Duration {
+        self.interval
+    }
+
+    /// Waits until at least the interval has passed since the previous request.
+    pub async fn wait(&self) {
+        let mut last_request_at = self.last_request_at.lock().await;
+        if let Some(elapsed) = last_request_at.map(|l| l.elapsed())
+            && elapsed < self.interval
+        {
+            sleep(self.interval - elapsed).await;
+        }
+        *last_request_at = Some(Instant::now());
+    }
+}
+
 pub fn generate_session_id(byte_length: Option<usize>) -> String {
     let length = byte_length.unwrap_or(32);
     let mut token_bytes = vec![0u8; length];
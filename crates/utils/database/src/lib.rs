@@ -3,14 +3,16 @@ use std::sync::Arc;
 use anyhow::{Result, anyhow, bail};
 use background_models::{ApplicationJob, HpApplicationJob, LpApplicationJob};
 use chrono::Utc;
+use chrono_tz::Tz;
 use common_models::{BackendError, SearchInput, StringIdAndNamedObject, UserLevelCacheKey};
 use common_utils::ryot_log;
 use database_models::{
     access_link, collection, collection_entity_membership,
     prelude::{
-        AccessLink, CollectionEntityMembership, Review, Seen, User, Workout, WorkoutTemplate,
+        AccessLink, CollectionEntityMembership, Review, Seen, User, UserFeedEvent, Workout,
+        WorkoutTemplate,
     },
-    review, seen, user, user_to_entity, workout,
+    review, seen, user, user_feed_event, user_to_entity, workout,
 };
 use dependent_models::{
     ApplicationCacheKey, ApplicationCacheValue, CachedResponse, CollectionToEntityDetails,
@@ -27,11 +29,14 @@ use sea_orm::{
     ActiveModelTrait, ActiveValue, ColumnTrait, Condition, EntityTrait, IntoActiveModel,
     QueryFilter, QueryOrder, QuerySelect, Select,
     prelude::Expr,
-    sea_query::{PgFunc, Query, SimpleExpr, extension::postgres::PgExpr},
+    sea_query::{OnConflict, PgFunc, Query, SimpleExpr, extension::postgres::PgExpr},
 };
 use supporting_service::SupportingService;
 use uuid::Uuid;
 
+#[cfg(test)]
+mod tests;
+
 pub async fn revoke_access_link(
     access_link_id: String,
     ss: &Arc<SupportingService>,
@@ -95,6 +100,21 @@ pub async fn user_by_id(user_id: &String, ss: &Arc<SupportingService>) -> Result
     Ok(user)
 }
 
+/// The timezone in which dates and times are shown to the user, falling back to the server
+/// timezone if the user has not set a valid one.
+pub fn get_user_timezone(user: &user::Model, ss: &Arc<SupportingService>) -> Tz {
+    parse_timezone_or(user.preferences.general.timezone.as_deref(), ss.timezone)
+}
+
+pub(crate) fn parse_timezone_or(timezone: Option<&str>, fallback: Tz) -> Tz {
+    timezone.and_then(|tz| tz.parse().ok()).unwrap_or(fallback)
+}
+
+pub async fn user_timezone_by_id(user_id: &String, ss: &Arc<SupportingService>) -> Result<Tz> {
+    let user = user_by_id(user_id, ss).await?;
+    Ok(get_user_timezone(&user, ss))
+}
+
 pub async fn extract_pagination_params(
     search: Option<SearchInput>,
     user_id: &String,
@@ -115,6 +135,29 @@ pub async fn admin_account_guard(user_id: &String, ss: &Arc<SupportingService>)
     Ok(())
 }
 
+/// Deny access to resolvers that the role of the user does not permit. Guests can not perform
+/// any mutations and fitness-only users can not access media related resolvers. The role is
+/// taken from the session when it has one.
+pub async fn user_lot_guard(
+    user_id: &String,
+    user_lot: Option<UserLot>,
+    is_mutation: bool,
+    is_media_resolver: bool,
+    ss: &Arc<SupportingService>,
+) -> Result<()> {
+    let user_lot = match user_lot {
+        Some(lot) => lot,
+        None => user_by_id(user_id, ss).await?.lot,
+    };
+    match user_lot {
+        UserLot::Guest if is_mutation => bail!(BackendError::MutationNotAllowed.to_string()),
+        UserLot::FitnessOnly if is_media_resolver => {
+            bail!(BackendError::RoleNotAllowed.to_string())
+        }
+        _ => Ok(()),
+    }
+}
+
 pub async fn server_key_validation_guard(is_server_key_validated: bool) -> Result<()> {
     if !is_server_key_validated {
         bail!("This feature is only available on the Pro version");
@@ -486,3 +529,23 @@ pub fn get_enabled_users_query() -> Select<User> {
             .or(user::Column::IsDisabled.is_null()),
     )
 }
+
+/// Record an event that can be shown in the feed of the user's followers. Events are unique
+/// per origin, so recording the same origin twice is a no-op.
+pub async fn record_user_feed_event(
+    event: user_feed_event::ActiveModel,
+    ss: &Arc<SupportingService>,
+) -> Result<()> {
+    UserFeedEvent::insert(event)
+        .on_conflict(
+            OnConflict::columns([
+                user_feed_event::Column::Lot,
+                user_feed_event::Column::OriginId,
+            ])
+            .do_nothing()
+            .to_owned(),
+        )
+        .exec_without_returning(&ss.db)
+        .await?;
+    Ok(())
+}
@@ -0,0 +1,4 @@
+use chrono_tz::Tz;
+use rstest::rstest;
+
+mod timezone;
@@ -0,0 +1,15 @@
+use super::*;
+use crate::parse_timezone_or;
+
+#[rstest]
+#[case(Some("Asia/Kolkata"), Tz::Asia__Kolkata)]
+#[case(Some("America/New_York"), Tz::America__New_York)]
+#[case(Some("Mars/Olympus_Mons"), Tz::Europe__Berlin)]
+#[case(Some(""), Tz::Europe__Berlin)]
+#[case(None, Tz::Europe__Berlin)]
+fn test_user_timezone_falls_back_to_server_timezone(
+    #[case] timezone: Option<&str>,
+    #[case] expected: Tz,
+) {
+    assert_eq!(parse_timezone_or(timezone, Tz::Europe__Berlin), expected);
+}
@@ -165,6 +165,15 @@ pub async fn get_daily_user_activities(
             daily_user_activity::Column::PodcastDuration.sum(),
             "total_podcast_duration",
         )
+        .column_as(daily_user_activity::Column::ComicCount.sum(), "comic_count")
+        .column_as(
+            daily_user_activity::Column::BoardGameCount.sum(),
+            "board_game_count",
+        )
+        .column_as(
+            daily_user_activity::Column::BoardGameDuration.sum(),
+            "total_board_game_duration",
+        )
         .column_as(daily_user_activity::Column::MangaCount.sum(), "manga_count")
         .column_as(daily_user_activity::Column::MovieCount.sum(), "movie_count")
         .column_as(
@@ -221,6 +230,10 @@ pub async fn get_daily_user_activities(
             daily_user_activity::Column::TotalMetadataCount.sum(),
             "total_metadata_count",
         )
+        .column_as(
+            daily_user_activity::Column::MirroredMetadataCount.sum(),
+            "mirrored_metadata_count",
+        )
         .column_as(
             daily_user_activity::Column::TotalReviewCount.sum(),
             "total_review_count",
@@ -3,6 +3,7 @@ use std::{collections::HashMap, sync::Arc};
 use anyhow::Result;
 use application_utils::{get_podcast_episode_by_number, get_show_episode_by_numbers};
 use chrono::{NaiveDate, Timelike};
+use chrono_tz::Tz;
 use common_models::{DailyUserActivityHourRecord, DailyUserActivityHourRecordEntity};
 use common_utils::ryot_log;
 use database_models::{
@@ -13,15 +14,16 @@ use database_models::{
     },
     review, seen, user_measurement, user_to_entity, workout,
 };
+use database_utils::user_timezone_by_id;
 use dependent_entity_list_utils::user_collections_list;
 use dependent_models::{ApplicationCacheKeyDiscriminants, ExpireCacheKeyInput};
 use enum_models::{EntityLot, MediaLot, SeenState};
 use futures::{TryStreamExt, try_join};
 use media_models::{
-    AnimeSpecifics, AudioBookSpecifics, BookSpecifics, MangaSpecifics, MovieSpecifics,
-    MusicSpecifics, PodcastSpecifics, SeenAnimeExtraInformation, SeenMangaExtraInformation,
-    SeenPodcastExtraInformation, SeenShowExtraInformation, ShowSpecifics, VideoGameSpecifics,
-    VisualNovelSpecifics,
+    AnimeSpecifics, AudioBookSpecifics, BoardGameSpecifics, BookSpecifics, MangaSpecifics,
+    MovieSpecifics, MusicSpecifics, PodcastSpecifics, SeenAnimeExtraInformation,
+    SeenBoardGameExtraInformation, SeenMangaExtraInformation, SeenPodcastExtraInformation,
+    SeenShowExtraInformation, ShowSpecifics, VideoGameSpecifics, VisualNovelSpecifics,
 };
 use rust_decimal::{Decimal, dec, prelude::ToPrimitive};
 use sea_orm::{
@@ -33,6 +35,80 @@ use sea_orm::{
 use serde::{Deserialize, Serialize};
 use supporting_service::SupportingService;
 
+mod analytics_operations;
+mod daily_activity_operations;
+#[cfg(test)]
+mod tests;
+
+pub use crate::{
+    analytics_operations::user_analytics,
+    daily_activity_operations::{get_daily_user_activities, user_analytics_parameters},
+};
+
+/// An entity to count towards the user's daily activity.
+pub(crate) struct ActivityItem {
+    pub entity_id: String,
+    pub entity_lot: EntityLot,
+    pub metadata_lot: Option<MediaLot>,
+    /// When the activity happened, which decides the day it is counted on.
+    pub date: Option<DateTimeUtc>,
+    /// Decides the hour of the day it is counted on.
+    pub timestamp: DateTimeUtc,
+}
+
+/// Adds the item to the activity of the day it happened on in the user's timezone and
+/// returns that day's activity.
+pub(crate) fn get_activity_count<'a>(
+    activities: &'a mut HashMap<Option<NaiveDate>, daily_user_activity::Model>,
+    user_id: &String,
+    timezone: &Tz,
+    item: ActivityItem,
+) -> &'a mut daily_user_activity::Model {
+    let ActivityItem {
+        entity_id,
+        entity_lot,
+        metadata_lot,
+        date,
+        timestamp,
+    } = item;
+    ryot_log!(debug, "Updating activity counts for id: {:?}", entity_id);
+    let date = date.map(|d| d.with_timezone(timezone).date_naive());
+    let existing = activities
+        .entry(date)
+        .or_insert(daily_user_activity::Model {
+            date,
+            user_id: user_id.to_owned(),
+            ..Default::default()
+        });
+    existing.entity_ids.push(entity_id.clone());
+    let timestamp = timestamp.with_timezone(timezone);
+    let hour = if timestamp.minute() < 30 {
+        timestamp.hour()
+    } else {
+        timestamp.hour() + 1
+    };
+    let maybe_idx = existing.hour_records.iter().position(|hr| hr.hour == hour);
+    if let Some(idx) = maybe_idx {
+        existing.hour_records.get_mut(idx).unwrap().entities.push(
+            DailyUserActivityHourRecordEntity {
+                entity_id,
+                entity_lot,
+                metadata_lot,
+            },
+        );
+    } else {
+        existing.hour_records.push(DailyUserActivityHourRecord {
+            hour,
+            entities: vec![DailyUserActivityHourRecordEntity {
+                entity_id,
+                entity_lot,
+                metadata_lot,
+            }],
+        });
+    }
+    existing
+}
+
 pub async fn calculate_user_activities_and_summary(
     user_id: &String,
     ss: &Arc<SupportingService>,
@@ -45,7 +121,9 @@ pub async fn calculate_user_activities_and_summary(
         podcast_extra_information: Option<SeenPodcastExtraInformation>,
         anime_extra_information: Option<SeenAnimeExtraInformation>,
         manga_extra_information: Option<SeenMangaExtraInformation>,
+        board_game_extra_information: Option<SeenBoardGameExtraInformation>,
         metadata_id: String,
+        mirrored_from_user_id: Option<String>,
         finished_on: Option<DateTimeUtc>,
         last_updated_on: DateTimeUtc,
         metadata_lot: MediaLot,
@@ -60,6 +138,7 @@ pub async fn calculate_user_activities_and_summary(
         visual_novel_specifics: Option<VisualNovelSpecifics>,
         anime_specifics: Option<AnimeSpecifics>,
         manga_specifics: Option<MangaSpecifics>,
+        board_game_specifics: Option<BoardGameSpecifics>,
     }
 
     let start_from = match calculate_from_beginning {
@@ -82,54 +161,9 @@ pub async fn calculate_user_activities_and_summary(
             .and_then(|i| i.date)
             .unwrap_or_default(),
     };
+    let timezone = user_timezone_by_id(user_id, ss).await?;
     let mut activities = HashMap::new();
 
-    fn get_activity_count<'a>(
-        activities: &'a mut HashMap<Option<NaiveDate>, daily_user_activity::Model>,
-        user_id: &'a String,
-        dt: Option<DateTimeUtc>,
-        entity_id: String,
-        entity_lot: EntityLot,
-        metadata_lot: Option<MediaLot>,
-        timestamp: DateTimeUtc,
-    ) -> &'a mut daily_user_activity::Model {
-        ryot_log!(debug, "Updating activity counts for id: {:?}", entity_id);
-        let date = dt.map(|d| d.date_naive());
-        let existing = activities
-            .entry(date)
-            .or_insert(daily_user_activity::Model {
-                date,
-                user_id: user_id.to_owned(),
-                ..Default::default()
-            });
-        existing.entity_ids.push(entity_id.clone());
-        let hour = if timestamp.minute() < 30 {
-            timestamp.hour()
-        } else {
-            timestamp.hour() + 1
-        };
-        let maybe_idx = existing.hour_records.iter().position(|hr| hr.hour == hour);
-        if let Some(idx) = maybe_idx {
-            existing.hour_records.get_mut(idx).unwrap().entities.push(
-                DailyUserActivityHourRecordEntity {
-                    entity_id,
-                    entity_lot,
-                    metadata_lot,
-                },
-            );
-        } else {
-            existing.hour_records.push(DailyUserActivityHourRecord {
-                hour,
-                entities: vec![DailyUserActivityHourRecordEntity {
-                    entity_id,
-                    entity_lot,
-                    metadata_lot,
-                }],
-            });
-        }
-        existing
-    }
-
     let mut seen_stream = Seen::find()
         .filter(seen::Column::UserId.eq(user_id))
         .filter(seen::Column::State.eq(SeenState::Completed))
@@ -142,7 +176,9 @@ pub async fn calculate_user_activities_and_summary(
             seen::Column::PodcastExtraInformation,
             seen::Column::AnimeExtraInformation,
             seen::Column::MangaExtraInformation,
+            seen::Column::BoardGameExtraInformation,
             seen::Column::MetadataId,
+            seen::Column::MirroredFromUserId,
             seen::Column::FinishedOn,
             seen::Column::LastUpdatedOn,
             seen::Column::ManualTimeSpent,
@@ -159,6 +195,7 @@ pub async fn calculate_user_activities_and_summary(
             metadata::Column::VisualNovelSpecifics,
             metadata::Column::AnimeSpecifics,
             metadata::Column::MangaSpecifics,
+            metadata::Column::BoardGameSpecifics,
         ])
         .into_model::<SeenItem>()
         .stream(&ss.db)
@@ -168,11 +205,14 @@ pub async fn calculate_user_activities_and_summary(
         let activity = get_activity_count(
             &mut activities,
             user_id,
-            seen.finished_on,
-            seen.seen_id,
-            EntityLot::Metadata,
-            Some(seen.metadata_lot),
-            seen.last_updated_on,
+            &timezone,
+            ActivityItem {
+                entity_id: seen.seen_id,
+                entity_lot: EntityLot::Metadata,
+                metadata_lot: Some(seen.metadata_lot),
+                date: seen.finished_on,
+                timestamp: seen.last_updated_on,
+            },
         );
         if let (Some(show_seen), Some(show_extra)) =
             (seen.show_specifics, seen.show_extra_information)
@@ -212,15 +252,28 @@ pub async fn calculate_user_activities_and_summary(
             if let Some(runtime) = visual_novel_extra.length {
                 activity.visual_novel_duration += runtime;
             }
+        } else if let Some(board_game_extra) = seen.board_game_specifics {
+            if let Some(duration) = seen
+                .board_game_extra_information
+                .and_then(|e| e.duration)
+                .or(board_game_extra.max_play_time)
+            {
+                activity.board_game_duration += duration;
+            }
         } else if let Some(_video_game_extra) = seen.video_game_specifics
             && let Some(manual_time_spent) = seen.manual_time_spent
         {
             activity.video_game_duration +=
                 (manual_time_spent / dec!(60)).to_i32().unwrap_or_default();
         }
+        if seen.mirrored_from_user_id.is_some() {
+            activity.mirrored_metadata_count += 1;
+        }
         match seen.metadata_lot {
             MediaLot::Book => activity.book_count += 1,
             MediaLot::Show => activity.show_count += 1,
+            MediaLot::Comic => activity.comic_count += 1,
+            MediaLot::BoardGame => activity.board_game_count += 1,
             MediaLot::Music => activity.music_count += 1,
             MediaLot::Anime => activity.anime_count += 1,
             MediaLot::Movie => activity.movie_count += 1,
@@ -248,11 +301,14 @@ pub async fn calculate_user_activities_and_summary(
         let activity = get_activity_count(
             &mut activities,
             user_id,
-            Some(workout.end_time),
-            workout.id,
-            EntityLot::Workout,
-            None,
-            workout.start_time,
+            &timezone,
+            ActivityItem {
+                entity_id: workout.id,
+                entity_lot: EntityLot::Workout,
+                metadata_lot: None,
+                date: Some(workout.end_time),
+                timestamp: workout.start_time,
+            },
         );
         activity.workout_count += 1;
         activity.workout_calories_burnt += workout
@@ -295,11 +351,14 @@ pub async fn calculate_user_activities_and_summary(
         let activity = get_activity_count(
             &mut activities,
             user_id,
-            Some(measurement.timestamp),
-            measurement.timestamp.to_string(),
-            EntityLot::UserMeasurement,
-            None,
-            measurement.timestamp,
+            &timezone,
+            ActivityItem {
+                entity_id: measurement.timestamp.to_string(),
+                entity_lot: EntityLot::UserMeasurement,
+                metadata_lot: None,
+                date: Some(measurement.timestamp),
+                timestamp: measurement.timestamp,
+            },
         );
         activity.measurement_count += 1;
     }
@@ -313,11 +372,14 @@ pub async fn calculate_user_activities_and_summary(
         let activity = get_activity_count(
             &mut activities,
             user_id,
-            Some(review.posted_on),
-            review.id,
-            EntityLot::Review,
-            None,
-            review.posted_on,
+            &timezone,
+            ActivityItem {
+                entity_id: review.id,
+                entity_lot: EntityLot::Review,
+                metadata_lot: None,
+                date: Some(review.posted_on),
+                timestamp: review.posted_on,
+            },
         );
         match review.entity_lot {
             EntityLot::Person => activity.person_review_count += 1,
@@ -348,11 +410,14 @@ pub async fn calculate_user_activities_and_summary(
         let activity = get_activity_count(
             &mut activities,
             user_id,
-            Some(cte.created_on),
-            cte.id.to_string(),
-            cte.entity_lot,
-            None,
-            cte.created_on,
+            &timezone,
+            ActivityItem {
+                entity_id: cte.id.to_string(),
+                entity_lot: cte.entity_lot,
+                metadata_lot: None,
+                date: Some(cte.created_on),
+                timestamp: cte.created_on,
+            },
         );
 
         match cte.entity_lot {
@@ -394,6 +459,8 @@ pub async fn calculate_user_activities_and_summary(
             + activity.music_count
             + activity.audio_book_count
             + activity.book_count
+            + activity.comic_count
+            + activity.board_game_count
             + activity.video_game_count
             + activity.visual_novel_count
             + activity.metadata_collection_count;
@@ -410,6 +477,7 @@ pub async fn calculate_user_activities_and_summary(
             + activity.show_duration
             + activity.music_duration
             + activity.visual_novel_duration
+            + activity.board_game_duration
             + activity.video_game_duration;
         activity.hour_records.sort_by_key(|hr| hr.hour);
         let mut model = activity.clone().into_active_model();
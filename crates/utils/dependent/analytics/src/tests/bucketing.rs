@@ -0,0 +1,74 @@
+use super::*;
+use crate::{ActivityItem, get_activity_count};
+
+fn workout(id: &str, at: DateTime<Utc>) -> ActivityItem {
+    ActivityItem {
+        entity_id: id.to_owned(),
+        entity_lot: EntityLot::Workout,
+        metadata_lot: None,
+        date: Some(at),
+        timestamp: at,
+    }
+}
+
+#[rstest]
+#[case(Tz::UTC, utc(28, 22, 0), date(28), 22)]
+#[case(Tz::Asia__Kolkata, utc(28, 22, 0), date(29), 4)]
+#[case(Tz::America__New_York, utc(28, 2, 10), date(27), 22)]
+#[case(Tz::America__New_York, utc(28, 2, 40), date(27), 23)]
+fn test_activity_is_counted_on_the_local_day_and_hour(
+    #[case] timezone: Tz,
+    #[case] at: DateTime<Utc>,
+    #[case] expected_date: NaiveDate,
+    #[case] expected_hour: u32,
+) {
+    let mut activities = HashMap::new();
+    let activity = get_activity_count(
+        &mut activities,
+        &"usr_1".to_owned(),
+        &timezone,
+        workout("wor_1", at),
+    );
+    assert_eq!(activity.date, Some(expected_date));
+    assert_eq!(activity.entity_ids, vec!["wor_1".to_owned()]);
+    assert_eq!(activity.hour_records.len(), 1);
+    assert_eq!(activity.hour_records[0].hour, expected_hour);
+}
+
+#[test]
+fn test_activities_on_the_same_local_day_share_a_bucket() {
+    let mut activities = HashMap::new();
+    for (id, at) in [
+        ("wor_1", utc(28, 19, 0)),
+        ("wor_2", utc(29, 1, 0)),
+        ("wor_3", utc(29, 1, 20)),
+    ] {
+        get_activity_count(
+            &mut activities,
+            &"usr_1".to_owned(),
+            &Tz::America__New_York,
+            workout(id, at),
+        );
+    }
+    assert_eq!(activities.len(), 1);
+    let activity = &activities[&Some(date(28))];
+    assert_eq!(activity.entity_ids, ["wor_1", "wor_2", "wor_3"]);
+    assert_eq!(
+        activity
+            .hour_records
+            .iter()
+            .map(|record| (record.hour, record.entities.len()))
+            .collect::<Vec<_>>(),
+        vec![(15, 1), (21, 2)]
+    );
+}
+
+#[test]
+fn test_activity_without_a_date_is_counted_separately() {
+    let mut activities = HashMap::new();
+    let mut item = workout("wor_1", utc(28, 12, 0));
+    item.date = None;
+    let activity = get_activity_count(&mut activities, &"usr_1".to_owned(), &Tz::UTC, item);
+    assert_eq!(activity.date, None);
+    assert_eq!(activity.hour_records[0].hour, 12);
+}
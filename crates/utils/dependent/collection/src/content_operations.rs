@@ -2,7 +2,9 @@ use std::sync::Arc;
 
 use anyhow::{Result, bail};
 use application_utils::graphql_to_db_order;
-use common_models::{SearchDetails, UserLevelCacheKey};
+use common_models::{
+    CollectionExtraInformation, CollectionExtraInformationLot, SearchDetails, UserLevelCacheKey,
+};
 use database_models::{
     collection_to_entity, exercise, metadata, metadata_group, person,
     prelude::{Collection, CollectionToEntity, Exercise, Metadata, MetadataGroup, Person, Workout},
@@ -13,14 +15,115 @@ use dependent_models::{
     CollectionContents, CollectionContentsInput, CollectionContentsResponse, SearchResults,
 };
 use enum_models::EntityLot;
-use media_models::{CollectionContentsSortBy, EntityWithLot};
+use media_models::{CollectionContentsInformationFilter, CollectionContentsSortBy, EntityWithLot};
 use sea_orm::{
     ColumnTrait, EntityTrait, ItemsAndPagesNumber, PaginatorTrait, QueryFilter, QueryOrder,
     QueryTrait,
-    sea_query::{Condition, Expr, Func},
+    sea_query::{
+        Alias, Condition, Expr, Func, SimpleExpr,
+        extension::postgres::{PgBinOper, PgExpr},
+    },
 };
+use serde_json::json;
 use supporting_service::SupportingService;
 
+use crate::validate_information_filter_value;
+
+fn find_template_field(
+    template: &Option<Vec<CollectionExtraInformation>>,
+    name: &str,
+) -> Result<CollectionExtraInformation> {
+    let Some(field) = template.iter().flatten().find(|f| f.name == name) else {
+        bail!("Field '{name}' is not part of the collection's information template");
+    };
+    Ok(field.clone())
+}
+
+fn cast_to_field_type(expr: SimpleExpr, lot: CollectionExtraInformationLot) -> SimpleExpr {
+    let db_type = match lot {
+        CollectionExtraInformationLot::Date => "date",
+        CollectionExtraInformationLot::Boolean => "boolean",
+        CollectionExtraInformationLot::DateTime => "timestamptz",
+        CollectionExtraInformationLot::Number | CollectionExtraInformationLot::Rating => "numeric",
+        _ => return expr,
+    };
+    Func::cast_as(expr, Alias::new(db_type)).into()
+}
+
+/// The pattern a stored value has to match before it is cast to the type of its field.
+pub(crate) fn castable_value_pattern(lot: CollectionExtraInformationLot) -> Option<&'static str> {
+    match lot {
+        CollectionExtraInformationLot::Boolean => Some(r"^(true|false)$"),
+        CollectionExtraInformationLot::Number | CollectionExtraInformationLot::Rating => {
+            Some(r"^-?(\d+(\.\d*)?|\.\d+)([eE][-+]?\d+)?$")
+        }
+        CollectionExtraInformationLot::Date => {
+            Some(r"^\d{4}-(0[1-9]|1[0-2])-(0[1-9]|[12]\d|3[01])$")
+        }
+        CollectionExtraInformationLot::DateTime => Some(
+            r"^\d{4}-(0[1-9]|1[0-2])-(0[1-9]|[12]\d|3[01])[Tt ]([01]\d|2[0-3]):[0-5]\d:[0-5]\d(\.\d+)?([Zz]|[-+]([01]\d|2[0-3]):[0-5]\d)$",
+        ),
+        _ => None,
+    }
+}
+
+fn information_field_expr(field: &CollectionExtraInformation) -> SimpleExpr {
+    let information = Expr::col((
+        collection_to_entity::Entity,
+        collection_to_entity::Column::Information,
+    ));
+    let value = match field.lot {
+        CollectionExtraInformationLot::EntityReference => information
+            .get_json_field(field.name.as_str())
+            .cast_json_field("entity_id"),
+        _ => information.cast_json_field(field.name.as_str()),
+    };
+    let Some(pattern) = castable_value_pattern(field.lot) else {
+        return value;
+    };
+    // DEV: Rows stored before the template was validated can hold free-form values, and
+    // optional fields can be stored as empty strings. A failing cast would fail the whole
+    // query, so these are treated as missing instead.
+    Expr::case(
+        Expr::expr(value.clone()).binary(PgBinOper::Regex, Expr::val(pattern)),
+        cast_to_field_type(value, field.lot),
+    )
+    .into()
+}
+
+fn information_filter_condition(
+    field: &CollectionExtraInformation,
+    filter: CollectionContentsInformationFilter,
+) -> Result<Condition> {
+    for value in [&filter.value, &filter.min_value, &filter.max_value]
+        .into_iter()
+        .flatten()
+    {
+        validate_information_filter_value(field, value)?;
+    }
+    let bound = |v: String| cast_to_field_type(Expr::val(v).into(), field.lot);
+    let mut condition = Condition::all();
+    if let Some(value) = filter.value {
+        condition = condition.add(match field.lot {
+            CollectionExtraInformationLot::StringArray
+            | CollectionExtraInformationLot::EnumMultiSelect => Expr::col((
+                collection_to_entity::Entity,
+                collection_to_entity::Column::Information,
+            ))
+            .get_json_field(field.name.as_str())
+            .contains(Expr::val(json!([value]))),
+            _ => Expr::expr(information_field_expr(field)).eq(bound(value)),
+        });
+    }
+    if let Some(min) = filter.min_value {
+        condition = condition.add(Expr::expr(information_field_expr(field)).gte(bound(min)));
+    }
+    if let Some(max) = filter.max_value {
+        condition = condition.add(Expr::expr(information_field_expr(field)).lte(bound(max)));
+    }
+    Ok(condition)
+}
+
 pub async fn collection_contents(
     user_id: &String,
     input: CollectionContentsInput,
@@ -44,6 +147,25 @@ pub async fn collection_contents(
             let Some(details) = maybe_collection else {
                 bail!("Collection not found");
             };
+            let information_condition = match filter.information {
+                None => None,
+                Some(f) => {
+                    let field = find_template_field(&details.information_template, &f.name)?;
+                    Some(information_filter_condition(&field, f)?)
+                }
+            };
+            let information_sort = match (sort.by, sort.information_field) {
+                (CollectionContentsSortBy::Information, Some(name)) => {
+                    Some(information_field_expr(&find_template_field(
+                        &details.information_template,
+                        &name,
+                    )?))
+                }
+                (CollectionContentsSortBy::Information, None) => {
+                    bail!("A template field is needed to sort by information");
+                }
+                _ => None,
+            };
             let paginator = CollectionToEntity::find()
                 .left_join(Metadata)
                 .left_join(MetadataGroup)
@@ -82,6 +204,7 @@ pub async fn collection_contents(
                     };
                     query.filter(collection_to_entity::Column::EntityLot.eq(f))
                 })
+                .apply_if(information_condition, |query, v| query.filter(v))
                 .order_by(
                     match sort.by {
                         CollectionContentsSortBy::Rank => {
@@ -102,6 +225,9 @@ pub async fn collection_contents(
                             Expr::col((person::Entity, person::Column::Name)).into(),
                             Expr::col((exercise::Entity, exercise::Column::Id)).into(),
                         ])),
+                        CollectionContentsSortBy::Information => {
+                            Expr::expr(information_sort.unwrap())
+                        }
                     },
                     graphql_to_db_order(sort.order),
                 )
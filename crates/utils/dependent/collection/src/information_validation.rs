@@ -0,0 +1,129 @@
+use std::collections::HashSet;
+
+use anyhow::{Result, bail};
+use chrono::{DateTime, NaiveDate};
+use common_models::{CollectionExtraInformation, CollectionExtraInformationLot};
+use media_models::EntityWithLot;
+use serde_json::{Number, Value};
+use url::Url;
+
+fn is_valid_value(field: &CollectionExtraInformation, value: &Value) -> bool {
+    let possible_values = field.possible_values.clone().unwrap_or_default();
+    match field.lot {
+        CollectionExtraInformationLot::String => value.is_string(),
+        CollectionExtraInformationLot::Number => value.is_number(),
+        CollectionExtraInformationLot::Boolean => value.is_boolean(),
+        CollectionExtraInformationLot::Date => value
+            .as_str()
+            .is_some_and(|v| NaiveDate::parse_from_str(v, "%Y-%m-%d").is_ok()),
+        CollectionExtraInformationLot::DateTime => value
+            .as_str()
+            .is_some_and(|v| DateTime::parse_from_rfc3339(v).is_ok()),
+        CollectionExtraInformationLot::StringArray => value
+            .as_array()
+            .is_some_and(|a| a.iter().all(|v| v.is_string())),
+        CollectionExtraInformationLot::Rating => {
+            value.as_f64().is_some_and(|v| (0.0..=100.0).contains(&v))
+        }
+        CollectionExtraInformationLot::Url => value.as_str().is_some_and(|v| Url::parse(v).is_ok()),
+        CollectionExtraInformationLot::EnumMultiSelect => value.as_array().is_some_and(|a| {
+            a.iter().all(|v| {
+                v.as_str()
+                    .is_some_and(|v| possible_values.iter().any(|p| p == v))
+            })
+        }),
+        CollectionExtraInformationLot::EntityReference => {
+            serde_json::from_value::<EntityWithLot>(value.clone()).is_ok()
+        }
+    }
+}
+
+/// Reject templates with repeated field names or enum fields without any possible values.
+pub fn validate_information_template(template: &[CollectionExtraInformation]) -> Result<()> {
+    let mut names = HashSet::new();
+    for field in template {
+        if !names.insert(&field.name) {
+            bail!("Field '{}' is defined more than once", field.name);
+        }
+        if field.lot == CollectionExtraInformationLot::EnumMultiSelect
+            && field.possible_values.as_ref().is_none_or(|v| v.is_empty())
+        {
+            bail!("Field '{}' needs at least one possible value", field.name);
+        }
+    }
+    Ok(())
+}
+
+/// Check the information attached to a collection entity against the collection's template.
+pub fn validate_collection_information(
+    template: &[CollectionExtraInformation],
+    information: &Option<Value>,
+) -> Result<()> {
+    let values = match information {
+        None | Some(Value::Null) => Default::default(),
+        Some(Value::Object(values)) => values.clone(),
+        Some(_) => bail!("Collection information must be an object"),
+    };
+    if template.is_empty() {
+        return Ok(());
+    }
+    if let Some(name) = values
+        .keys()
+        .find(|name| !template.iter().any(|f| &f.name == *name))
+    {
+        bail!("Field '{name}' is not part of the collection's information template");
+    }
+    for field in template {
+        let value = values.get(&field.name).filter(|v| match v {
+            Value::Null => false,
+            Value::String(s) => !s.is_empty(),
+            _ => true,
+        });
+        let Some(value) = value else {
+            if field.required == Some(true) && field.default_value.is_none() {
+                bail!("Field '{}' is required", field.name);
+            }
+            continue;
+        };
+        if !is_valid_value(field, value) {
+            bail!("Field '{}' is not a valid {:?}", field.name, field.lot);
+        }
+    }
+    Ok(())
+}
+
+/// Check a value that collection contents are filtered on against the type of the field. List
+/// fields are filtered on a single element.
+pub fn validate_information_filter_value(
+    field: &CollectionExtraInformation,
+    value: &str,
+) -> Result<()> {
+    if value.trim().is_empty() {
+        bail!(
+            "Field '{}' can not be filtered on an empty value",
+            field.name
+        );
+    }
+    let parsed = match field.lot {
+        CollectionExtraInformationLot::EntityReference => return Ok(()),
+        CollectionExtraInformationLot::Number | CollectionExtraInformationLot::Rating => value
+            .parse::<f64>()
+            .ok()
+            .and_then(Number::from_f64)
+            .map(Value::Number),
+        CollectionExtraInformationLot::Boolean => value.parse::<bool>().ok().map(Value::Bool),
+        CollectionExtraInformationLot::StringArray
+        | CollectionExtraInformationLot::EnumMultiSelect => {
+            Some(Value::Array(vec![Value::String(value.to_owned())]))
+        }
+        _ => Some(Value::String(value.to_owned())),
+    };
+    if !parsed.is_some_and(|v| is_valid_value(field, &v)) {
+        bail!(
+            "Field '{}' can not be filtered on '{value}' which is not a valid {:?}",
+            field.name,
+            field.lot
+        );
+    }
+    Ok(())
+}
@@ -11,15 +11,15 @@ use common_utils::ryot_log;
 use database_models::{
     collection, collection_to_entity,
     prelude::{Collection, CollectionToEntity, UserToEntity},
-    user_to_entity,
+    user_feed_event, user_to_entity,
 };
-use database_utils::server_key_validation_guard;
+use database_utils::{record_user_feed_event, server_key_validation_guard};
 use dependent_core_utils::is_server_key_validated;
 use dependent_utility_utils::{
     associate_user_with_entity, expire_entity_details_cache, expire_user_collection_contents_cache,
     expire_user_collections_list_cache,
 };
-use enum_models::EntityLot;
+use enum_models::{EntityLot, UserFeedEventLot};
 use futures::try_join;
 use itertools::Itertools;
 use media_models::CreateOrUpdateCollectionInput;
@@ -32,6 +32,18 @@ use sea_orm::{
 use supporting_service::SupportingService;
 use uuid::Uuid;
 
+mod content_operations;
+mod information_validation;
+mod smart_collections;
+#[cfg(test)]
+mod tests;
+
+use information_validation::{validate_collection_information, validate_information_template};
+
+pub use content_operations::collection_contents;
+pub use information_validation::validate_information_filter_value;
+pub use smart_collections::{sync_all_smart_collections, sync_user_smart_collections};
+
 #[derive(FromQueryResult)]
 struct CollectionEntityRank {
     id: Uuid,
@@ -43,6 +55,7 @@ async fn add_single_entity_to_collection(
     user_id: &String,
     entity: &EntityToCollectionInput,
     collection_name: &String,
+    triggered_by_automation: bool,
     ss: &Arc<SupportingService>,
 ) -> Result<bool> {
     let collection = Collection::find()
@@ -52,6 +65,10 @@ async fn add_single_entity_to_collection(
         .one(&ss.db)
         .await?
         .unwrap();
+    validate_collection_information(
+        &collection.information_template.clone().unwrap_or_default(),
+        &entity.information,
+    )?;
     let mut updated = collection.into_active_model();
     updated.last_updated_on = ActiveValue::Set(Utc::now());
     let collection = updated.update(&ss.db).await?;
@@ -116,29 +133,58 @@ async fn add_single_entity_to_collection(
         associate_user_with_entity(user_id, &entity.entity_id, entity.entity_lot, ss),
         expire_user_collection_contents_cache(user_id, &collection.id, ss),
         ss.perform_application_job(ApplicationJob::Lp(
-            LpApplicationJob::HandleEntityAddedToCollectionEvent(resp.id),
+            LpApplicationJob::HandleEntityAddedToCollectionEvent(resp.id, triggered_by_automation),
         ))
     )?;
     Ok(true)
 }
 
-pub async fn add_entities_to_collection(
+async fn add_entities(
     user_id: &String,
     input: ChangeCollectionToEntitiesInput,
+    triggered_by_automation: bool,
     ss: &Arc<SupportingService>,
 ) -> Result<bool> {
     for entity in &input.entities {
-        add_single_entity_to_collection(user_id, entity, &input.collection_name, ss).await?;
+        add_single_entity_to_collection(
+            user_id,
+            entity,
+            &input.collection_name,
+            triggered_by_automation,
+            ss,
+        )
+        .await?;
     }
     Ok(true)
 }
 
+pub async fn add_entities_to_collection(
+    user_id: &String,
+    input: ChangeCollectionToEntitiesInput,
+    ss: &Arc<SupportingService>,
+) -> Result<bool> {
+    add_entities(user_id, input, false, ss).await
+}
+
+/// Add entities to a collection as the action of an automation rule. The additions are
+/// marked so that they do not set off automation rules again.
+pub async fn add_entities_to_collection_by_automation(
+    user_id: &String,
+    input: ChangeCollectionToEntitiesInput,
+    ss: &Arc<SupportingService>,
+) -> Result<bool> {
+    add_entities(user_id, input, true, ss).await
+}
+
 pub async fn create_or_update_collection(
     user_id: &String,
     ss: &Arc<SupportingService>,
     input: CreateOrUpdateCollectionInput,
 ) -> Result<StringIdObject> {
     ryot_log!(debug, "Creating or updating collection: {:?}", input);
+    if let Some(template) = &input.information_template {
+        validate_information_template(template)?;
+    }
     let txn = ss.db.begin().await?;
     let meta = Collection::find()
         .filter(collection::Column::Name.eq(input.name.clone()))
@@ -146,6 +192,9 @@ pub async fn create_or_update_collection(
         .one(&txn)
         .await?;
     let mut new_name = input.name.clone();
+    let is_new_collection = meta.is_none()
+        && input.update_id.is_none()
+        && !DefaultCollection::iter().any(|c| c.to_string() == input.name);
     let mut collaborators_to_expire_cache: Option<HashSet<String>> = None;
     let created = match meta {
         Some(m) if input.update_id.is_none() => m.id,
@@ -171,6 +220,7 @@ pub async fn create_or_update_collection(
                 last_updated_on: ActiveValue::Set(Utc::now()),
                 description: ActiveValue::Set(input.description),
                 information_template: ActiveValue::Set(input.information_template),
+                smart_filter: ActiveValue::Set(input.smart_filter.clone()),
                 ..Default::default()
             };
             let inserted = col
@@ -220,11 +270,31 @@ pub async fn create_or_update_collection(
     };
     txn.commit().await?;
 
+    if is_new_collection {
+        record_user_feed_event(
+            user_feed_event::ActiveModel {
+                origin_id: ActiveValue::Set(created.clone()),
+                entity_id: ActiveValue::Set(created.clone()),
+                user_id: ActiveValue::Set(user_id.to_owned()),
+                entity_lot: ActiveValue::Set(EntityLot::Collection),
+                lot: ActiveValue::Set(UserFeedEventLot::CollectionCreated),
+                ..Default::default()
+            },
+            ss,
+        )
+        .await?;
+    }
     if let Some(collaborators) = collaborators_to_expire_cache {
         for c in &collaborators {
             expire_user_collections_list_cache(c, ss).await?;
         }
     }
+    if input.smart_filter.is_some() {
+        ss.perform_application_job(ApplicationJob::Lp(
+            LpApplicationJob::SyncUserSmartCollections(user_id.to_owned(), None),
+        ))
+        .await?;
+    }
     Ok(StringIdObject { id: created })
 }
 
@@ -0,0 +1,283 @@
+use std::{collections::HashSet, sync::Arc};
+
+use anyhow::Result;
+use common_models::{ChangeCollectionToEntitiesInput, EntityToCollectionInput};
+use common_utils::ryot_log;
+use database_models::{
+    collection, collection_to_entity, metadata, metadata_to_genre, metadata_to_person,
+    prelude::{
+        Collection, CollectionToEntity, Metadata, MetadataToGenre, MetadataToPerson, Review, Seen,
+        UserToEntity,
+    },
+    review, seen, user_to_entity,
+};
+use database_utils::build_collection_filter_condition;
+use enum_models::EntityLot;
+use itertools::Itertools;
+use media_models::{
+    MediaCollectionFilter, MediaCollectionPresenceFilter, MediaCollectionStrategyFilter,
+    SmartCollectionFilter,
+};
+use sea_orm::{
+    ColumnTrait, EntityTrait, QueryFilter, QueryOrder, QuerySelect, QueryTrait, Select,
+    prelude::Expr,
+    sea_query::{Func, Query, SimpleExpr},
+};
+use supporting_service::SupportingService;
+
+use crate::{add_entities_to_collection, remove_entities_from_collection};
+
+/// Build the query selecting the ids of all media of the user that satisfy every rule of
+/// the filter. Genres and people match if the media is associated with any one of the
+/// given ids. When a metadata id is given, only that media is considered.
+pub fn get_smart_collection_matches(
+    user_id: &String,
+    collection_id: &String,
+    filter: &SmartCollectionFilter,
+    metadata_id: Option<&String>,
+) -> Select<Metadata> {
+    let average_rating_subquery = SimpleExpr::SubQuery(
+        None,
+        Box::new(
+            Query::select()
+                .expr(Func::avg(Expr::col((
+                    review::Entity,
+                    review::Column::Rating,
+                ))))
+                .from(Review)
+                .and_where(Expr::col((review::Entity, review::Column::UserId)).eq(user_id))
+                .and_where(
+                    Expr::col((review::Entity, review::Column::MetadataId))
+                        .equals((metadata::Entity, metadata::Column::Id)),
+                )
+                .and_where(Expr::col((review::Entity, review::Column::Rating)).is_not_null())
+                .to_owned()
+                .into_sub_query_statement(),
+        ),
+    );
+
+    // DEV: A smart collection can not depend on its own membership
+    let collection_filters = filter
+        .in_collection_ids
+        .iter()
+        .flatten()
+        .map(|id| (id, MediaCollectionPresenceFilter::PresentIn))
+        .chain(
+            filter
+                .not_in_collection_ids
+                .iter()
+                .flatten()
+                .map(|id| (id, MediaCollectionPresenceFilter::NotPresentIn)),
+        )
+        .filter(|(id, _)| *id != collection_id)
+        .map(|(id, presence)| MediaCollectionFilter {
+            presence,
+            collection_id: id.to_owned(),
+            strategy: MediaCollectionStrategyFilter::And,
+        })
+        .collect_vec();
+
+    Metadata::find()
+        .select_only()
+        .column(metadata::Column::Id)
+        .inner_join(UserToEntity)
+        .filter(user_to_entity::Column::UserId.eq(user_id))
+        .filter(user_to_entity::Column::MetadataId.is_not_null())
+        .apply_if(metadata_id, |query, v| {
+            query.filter(metadata::Column::Id.eq(v))
+        })
+        .apply_if(filter.lots.clone().filter(|v| !v.is_empty()), |query, v| {
+            query.filter(metadata::Column::Lot.is_in(v))
+        })
+        .apply_if(
+            filter.sources.clone().filter(|v| !v.is_empty()),
+            |query, v| query.filter(metadata::Column::Source.is_in(v)),
+        )
+        .apply_if(filter.min_publish_year, |query, v| {
+            query.filter(metadata::Column::PublishYear.gte(v))
+        })
+        .apply_if(filter.max_publish_year, |query, v| {
+            query.filter(metadata::Column::PublishYear.lte(v))
+        })
+        .apply_if(filter.min_provider_rating, |query, v| {
+            query.filter(metadata::Column::ProviderRating.gte(v))
+        })
+        .apply_if(filter.max_provider_rating, |query, v| {
+            query.filter(metadata::Column::ProviderRating.lte(v))
+        })
+        .apply_if(filter.min_user_rating, |query, v| {
+            query.filter(Expr::expr(average_rating_subquery.clone()).gte(v))
+        })
+        .apply_if(filter.max_user_rating, |query, v| {
+            query.filter(Expr::expr(average_rating_subquery.clone()).lte(v))
+        })
+        .apply_if(
+            filter.genre_ids.clone().filter(|v| !v.is_empty()),
+            |query, v| {
+                query.filter(Expr::exists(
+                    Query::select()
+                        .expr(Expr::val(1))
+                        .from(MetadataToGenre)
+                        .and_where(
+                            Expr::col((
+                                metadata_to_genre::Entity,
+                                metadata_to_genre::Column::MetadataId,
+                            ))
+                            .equals((metadata::Entity, metadata::Column::Id)),
+                        )
+                        .and_where(
+                            Expr::col((
+                                metadata_to_genre::Entity,
+                                metadata_to_genre::Column::GenreId,
+                            ))
+                            .is_in(v),
+                        )
+                        .to_owned(),
+                ))
+            },
+        )
+        .apply_if(
+            filter.person_ids.clone().filter(|v| !v.is_empty()),
+            |query, v| {
+                query.filter(Expr::exists(
+                    Query::select()
+                        .expr(Expr::val(1))
+                        .from(MetadataToPerson)
+                        .and_where(
+                            Expr::col((
+                                metadata_to_person::Entity,
+                                metadata_to_person::Column::MetadataId,
+                            ))
+                            .equals((metadata::Entity, metadata::Column::Id)),
+                        )
+                        .and_where(
+                            Expr::col((
+                                metadata_to_person::Entity,
+                                metadata_to_person::Column::PersonId,
+                            ))
+                            .is_in(v),
+                        )
+                        .to_owned(),
+                ))
+            },
+        )
+        .apply_if(
+            filter.seen_states.clone().filter(|v| !v.is_empty()),
+            |query, v| {
+                query.filter(Expr::exists(
+                    Query::select()
+                        .expr(Expr::val(1))
+                        .from(Seen)
+                        .and_where(Expr::col((seen::Entity, seen::Column::UserId)).eq(user_id))
+                        .and_where(
+                            Expr::col((seen::Entity, seen::Column::MetadataId))
+                                .equals((metadata::Entity, metadata::Column::Id)),
+                        )
+                        .and_where(Expr::col((seen::Entity, seen::Column::State)).is_in(v))
+                        .to_owned(),
+                ))
+            },
+        )
+        .apply_if(
+            build_collection_filter_condition(user_id, &collection_filters),
+            |query, condition| query.filter(condition),
+        )
+}
+
+/// Bring the members of a smart collection in line with its filter. When a metadata id
+/// is given, only the membership of that media is updated.
+pub async fn sync_smart_collection(
+    collection: &collection::Model,
+    metadata_id: Option<&String>,
+    ss: &Arc<SupportingService>,
+) -> Result<()> {
+    let Some(filter) = &collection.smart_filter else {
+        return Ok(());
+    };
+    let user_id = &collection.user_id;
+    let matches: HashSet<String> =
+        get_smart_collection_matches(user_id, &collection.id, filter, metadata_id)
+            .into_tuple::<String>()
+            .all(&ss.db)
+            .await?
+            .into_iter()
+            .collect();
+    let existing: HashSet<String> = CollectionToEntity::find()
+        .select_only()
+        .column(collection_to_entity::Column::MetadataId)
+        .filter(collection_to_entity::Column::CollectionId.eq(&collection.id))
+        .filter(collection_to_entity::Column::MetadataId.is_not_null())
+        .apply_if(metadata_id, |query, v| {
+            query.filter(collection_to_entity::Column::MetadataId.eq(v))
+        })
+        .into_tuple::<String>()
+        .all(&ss.db)
+        .await?
+        .into_iter()
+        .collect();
+    let to_input = |ids: Vec<&String>| ChangeCollectionToEntitiesInput {
+        creator_user_id: user_id.to_owned(),
+        collection_name: collection.name.clone(),
+        entities: ids
+            .into_iter()
+            .map(|id| EntityToCollectionInput {
+                information: None,
+                entity_id: id.to_owned(),
+                entity_lot: EntityLot::Metadata,
+            })
+            .collect(),
+    };
+    let to_add = matches.difference(&existing).collect_vec();
+    let to_remove = existing.difference(&matches).collect_vec();
+    ryot_log!(
+        debug,
+        "Smart collection {} has {} entities to add and {} to remove",
+        collection.id,
+        to_add.len(),
+        to_remove.len()
+    );
+    if !to_add.is_empty() {
+        add_entities_to_collection(user_id, to_input(to_add), ss).await?;
+    }
+    if !to_remove.is_empty() {
+        remove_entities_from_collection(user_id, to_input(to_remove), ss).await?;
+    }
+    Ok(())
+}
+
+pub async fn sync_user_smart_collections(
+    user_id: &String,
+    metadata_id: Option<&String>,
+    ss: &Arc<SupportingService>,
+) -> Result<()> {
+    let collections = Collection::find()
+        .filter(collection::Column::UserId.eq(user_id))
+        .filter(collection::Column::SmartFilter.is_not_null())
+        .order_by_asc(collection::Column::CreatedOn)
+        .all(&ss.db)
+        .await?;
+    for collection in collections {
+        sync_smart_collection(&collection, metadata_id, ss).await?;
+    }
+    Ok(())
+}
+
+pub async fn sync_all_smart_collections(ss: &Arc<SupportingService>) -> Result<()> {
+    let collections = Collection::find()
+        .filter(collection::Column::SmartFilter.is_not_null())
+        .order_by_asc(collection::Column::CreatedOn)
+        .all(&ss.db)
+        .await?;
+    ryot_log!(debug, "Syncing {} smart collections", collections.len());
+    for collection in collections {
+        if let Err(e) = sync_smart_collection(&collection, None, ss).await {
+            ryot_log!(
+                debug,
+                "Failed to sync smart collection {}: {:?}",
+                collection.id,
+                e
+            );
+        }
+    }
+    Ok(())
+}
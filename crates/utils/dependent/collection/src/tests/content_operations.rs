@@ -0,0 +1,61 @@
+use common_models::CollectionExtraInformationLot;
+use regex::Regex;
+use rstest::rstest;
+
+use crate::content_operations::castable_value_pattern;
+
+fn is_castable(lot: CollectionExtraInformationLot, value: &str) -> bool {
+    Regex::new(castable_value_pattern(lot).unwrap())
+        .unwrap()
+        .is_match(value)
+}
+
+#[rstest]
+#[case(CollectionExtraInformationLot::Number, "42")]
+#[case(CollectionExtraInformationLot::Number, "-3.75")]
+#[case(CollectionExtraInformationLot::Number, "1e10")]
+#[case(CollectionExtraInformationLot::Rating, "87.5")]
+#[case(CollectionExtraInformationLot::Boolean, "true")]
+#[case(CollectionExtraInformationLot::Boolean, "false")]
+#[case(CollectionExtraInformationLot::Date, "2025-10-28")]
+#[case(CollectionExtraInformationLot::DateTime, "2025-10-28T12:30:00Z")]
+#[case(
+    CollectionExtraInformationLot::DateTime,
+    "2025-10-28T12:30:00.123+05:30"
+)]
+fn test_valid_stored_values_are_cast(
+    #[case] lot: CollectionExtraInformationLot,
+    #[case] value: &str,
+) {
+    assert!(is_castable(lot, value));
+}
+
+#[rstest]
+#[case(CollectionExtraInformationLot::Number, "")]
+#[case(CollectionExtraInformationLot::Number, "about 5")]
+#[case(CollectionExtraInformationLot::Number, "1.2.3")]
+#[case(CollectionExtraInformationLot::Rating, "great")]
+#[case(CollectionExtraInformationLot::Boolean, "")]
+#[case(CollectionExtraInformationLot::Boolean, "maybe")]
+#[case(CollectionExtraInformationLot::Date, "")]
+#[case(CollectionExtraInformationLot::Date, "last week")]
+#[case(CollectionExtraInformationLot::Date, "2025-13-01")]
+#[case(CollectionExtraInformationLot::Date, "28/10/2025")]
+#[case(CollectionExtraInformationLot::DateTime, "2025-10-28")]
+#[case(CollectionExtraInformationLot::DateTime, "2025-10-28T25:00:00Z")]
+fn test_free_form_stored_values_are_not_cast(
+    #[case] lot: CollectionExtraInformationLot,
+    #[case] value: &str,
+) {
+    assert!(!is_castable(lot, value));
+}
+
+#[rstest]
+#[case(CollectionExtraInformationLot::String)]
+#[case(CollectionExtraInformationLot::Url)]
+#[case(CollectionExtraInformationLot::StringArray)]
+#[case(CollectionExtraInformationLot::EnumMultiSelect)]
+#[case(CollectionExtraInformationLot::EntityReference)]
+fn test_text_fields_are_not_cast(#[case] lot: CollectionExtraInformationLot) {
+    assert!(castable_value_pattern(lot).is_none());
+}
@@ -0,0 +1,152 @@
+use common_models::{CollectionExtraInformation, CollectionExtraInformationLot};
+use serde_json::json;
+
+use crate::information_validation::{
+    validate_collection_information, validate_information_filter_value,
+    validate_information_template,
+};
+
+fn field(name: &str, lot: CollectionExtraInformationLot) -> CollectionExtraInformation {
+    CollectionExtraInformation {
+        lot,
+        name: name.to_owned(),
+        ..Default::default()
+    }
+}
+
+fn required_field(name: &str, lot: CollectionExtraInformationLot) -> CollectionExtraInformation {
+    CollectionExtraInformation {
+        required: Some(true),
+        ..field(name, lot)
+    }
+}
+
+fn enum_field(name: &str, possible_values: &[&str]) -> CollectionExtraInformation {
+    CollectionExtraInformation {
+        possible_values: Some(possible_values.iter().map(|v| v.to_string()).collect()),
+        ..field(name, CollectionExtraInformationLot::EnumMultiSelect)
+    }
+}
+
+#[test]
+fn test_template_accepts_distinct_fields() {
+    let template = vec![
+        field("Owner", CollectionExtraInformationLot::String),
+        enum_field("Platforms", &["PC", "Switch"]),
+    ];
+    assert!(validate_information_template(&template).is_ok());
+}
+
+#[test]
+fn test_template_rejects_repeated_field_names() {
+    let template = vec![
+        field("Owner", CollectionExtraInformationLot::String),
+        field("Owner", CollectionExtraInformationLot::Number),
+    ];
+    assert!(validate_information_template(&template).is_err());
+}
+
+#[test]
+fn test_template_rejects_enum_without_possible_values() {
+    assert!(validate_information_template(&[enum_field("Platforms", &[])]).is_err());
+    let template = vec![field(
+        "Platforms",
+        CollectionExtraInformationLot::EnumMultiSelect,
+    )];
+    assert!(validate_information_template(&template).is_err());
+}
+
+#[test]
+fn test_information_is_not_checked_without_template() {
+    let information = Some(json!({"Anything": 1}));
+    assert!(validate_collection_information(&[], &information).is_ok());
+    assert!(validate_collection_information(&[], &Some(json!([1]))).is_err());
+}
+
+#[test]
+fn test_information_rejects_unknown_fields() {
+    let template = vec![field("Owner", CollectionExtraInformationLot::String)];
+    let information = Some(json!({"Owner": "Me", "Unknown": "value"}));
+    assert!(validate_collection_information(&template, &information).is_err());
+}
+
+#[test]
+fn test_information_requires_required_fields() {
+    let template = vec![required_field(
+        "Price",
+        CollectionExtraInformationLot::Number,
+    )];
+    assert!(validate_collection_information(&template, &None).is_err());
+    assert!(validate_collection_information(&template, &Some(json!({"Price": ""}))).is_err());
+    assert!(validate_collection_information(&template, &Some(json!({"Price": 12}))).is_ok());
+}
+
+#[test]
+fn test_information_allows_missing_required_field_with_default() {
+    let template = vec![CollectionExtraInformation {
+        default_value: Some("0".to_owned()),
+        ..required_field("Price", CollectionExtraInformationLot::Number)
+    }];
+    assert!(validate_collection_information(&template, &None).is_ok());
+}
+
+#[test]
+fn test_information_checks_values_against_field_types() {
+    let template = vec![
+        field("Date", CollectionExtraInformationLot::Date),
+        field("Rating", CollectionExtraInformationLot::Rating),
+        field("Link", CollectionExtraInformationLot::Url),
+        enum_field("Platforms", &["PC", "Switch"]),
+        field("Related", CollectionExtraInformationLot::EntityReference),
+    ];
+    let valid = Some(json!({
+        "Date": "2024-05-01",
+        "Rating": 80,
+        "Link": "https://example.com",
+        "Platforms": ["PC"],
+        "Related": {"entity_id": "met_1", "entity_lot": "metadata"},
+    }));
+    assert!(validate_collection_information(&template, &valid).is_ok());
+    for invalid in [
+        json!({"Date": "01/05/2024"}),
+        json!({"Rating": 120}),
+        json!({"Link": "not a url"}),
+        json!({"Platforms": ["Xbox"]}),
+        json!({"Related": "met_1"}),
+    ] {
+        assert!(validate_collection_information(&template, &Some(invalid)).is_err());
+    }
+}
+
+#[test]
+fn test_filter_value_is_parsed_using_field_type() {
+    let number = field("Price", CollectionExtraInformationLot::Number);
+    assert!(validate_information_filter_value(&number, "12.5").is_ok());
+    assert!(validate_information_filter_value(&number, "cheap").is_err());
+    let boolean = field("Owned", CollectionExtraInformationLot::Boolean);
+    assert!(validate_information_filter_value(&boolean, "true").is_ok());
+    assert!(validate_information_filter_value(&boolean, "yes").is_err());
+    let date = field("Bought", CollectionExtraInformationLot::DateTime);
+    assert!(validate_information_filter_value(&date, "2024-05-01T10:00:00Z").is_ok());
+    assert!(validate_information_filter_value(&date, "yesterday").is_err());
+    let rating = field("Rating", CollectionExtraInformationLot::Rating);
+    assert!(validate_information_filter_value(&rating, "101").is_err());
+}
+
+#[test]
+fn test_filter_value_of_list_fields_is_a_single_element() {
+    let platforms = enum_field("Platforms", &["PC", "Switch"]);
+    assert!(validate_information_filter_value(&platforms, "PC").is_ok());
+    assert!(validate_information_filter_value(&platforms, "Xbox").is_err());
+    let tags = field("Tags", CollectionExtraInformationLot::StringArray);
+    assert!(validate_information_filter_value(&tags, "favourite").is_ok());
+}
+
+#[test]
+fn test_filter_value_can_not_be_empty() {
+    let owner = field("Owner", CollectionExtraInformationLot::String);
+    assert!(validate_information_filter_value(&owner, "").is_err());
+    assert!(validate_information_filter_value(&owner, "  ").is_err());
+    let price = field("Price", CollectionExtraInformationLot::Number);
+    assert!(validate_information_filter_value(&price, "").is_err());
+}
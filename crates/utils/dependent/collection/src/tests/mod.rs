@@ -0,0 +1,25 @@
+use media_models::SmartCollectionFilter;
+use sea_orm::{DbBackend, QueryTrait};
+
+mod content_operations;
+mod information_validation;
+mod smart_collections;
+
+fn user_id() -> String {
+    "usr_1".to_owned()
+}
+
+fn collection_id() -> String {
+    "col_smart".to_owned()
+}
+
+fn build_matches_query(filter: &SmartCollectionFilter, metadata_id: Option<&String>) -> String {
+    crate::smart_collections::get_smart_collection_matches(
+        &user_id(),
+        &collection_id(),
+        filter,
+        metadata_id,
+    )
+    .build(DbBackend::Postgres)
+    .to_string()
+}
@@ -0,0 +1,89 @@
+use super::*;
+
+#[test]
+fn test_empty_filter_matches_all_media_of_user() {
+    let sql = build_matches_query(&SmartCollectionFilter::default(), None);
+    assert!(sql.contains(r#""user_to_entity"."user_id" = 'usr_1'"#));
+    assert!(!sql.contains("EXISTS"));
+    assert!(!sql.contains(r#""metadata"."id" = '"#));
+}
+
+#[test]
+fn test_metadata_id_scopes_the_matches() {
+    let metadata_id = "met_1".to_owned();
+    let sql = build_matches_query(&SmartCollectionFilter::default(), Some(&metadata_id));
+    assert!(sql.contains(r#""metadata"."id" = 'met_1'"#));
+}
+
+#[test]
+fn test_empty_lists_are_ignored() {
+    let filter = SmartCollectionFilter {
+        lots: Some(vec![]),
+        sources: Some(vec![]),
+        genre_ids: Some(vec![]),
+        person_ids: Some(vec![]),
+        seen_states: Some(vec![]),
+        in_collection_ids: Some(vec![]),
+        ..Default::default()
+    };
+    assert_eq!(
+        build_matches_query(&filter, None),
+        build_matches_query(&SmartCollectionFilter::default(), None)
+    );
+}
+
+#[test]
+fn test_publish_year_bounds_are_inclusive() {
+    let filter = SmartCollectionFilter {
+        min_publish_year: Some(2000),
+        max_publish_year: Some(2010),
+        ..Default::default()
+    };
+    let sql = build_matches_query(&filter, None);
+    assert!(sql.contains(r#""metadata"."publish_year" >= 2000"#));
+    assert!(sql.contains(r#""metadata"."publish_year" <= 2010"#));
+}
+
+#[test]
+fn test_genres_and_people_match_any_given_id() {
+    let filter = SmartCollectionFilter {
+        genre_ids: Some(vec!["gen_1".to_owned(), "gen_2".to_owned()]),
+        person_ids: Some(vec!["per_1".to_owned()]),
+        ..Default::default()
+    };
+    let sql = build_matches_query(&filter, None);
+    assert!(sql.contains(r#""metadata_to_genre"."genre_id" IN ('gen_1', 'gen_2')"#));
+    assert!(sql.contains(r#""metadata_to_person"."person_id" IN ('per_1')"#));
+}
+
+#[test]
+fn test_user_rating_uses_the_users_own_reviews() {
+    let filter = SmartCollectionFilter {
+        min_user_rating: Some(80.into()),
+        ..Default::default()
+    };
+    let sql = build_matches_query(&filter, None);
+    assert!(sql.contains(r#"AVG("review"."rating")"#));
+    assert!(sql.contains(r#""review"."user_id" = 'usr_1'"#));
+}
+
+#[test]
+fn test_own_collection_is_ignored_in_collection_rules() {
+    let filter = SmartCollectionFilter {
+        in_collection_ids: Some(vec![collection_id(), "col_other".to_owned()]),
+        not_in_collection_ids: Some(vec![collection_id()]),
+        ..Default::default()
+    };
+    let sql = build_matches_query(&filter, None);
+    assert!(sql.contains("'col_other'"));
+    assert!(!sql.contains(&format!("'{}'", collection_id())));
+}
+
+#[test]
+fn test_only_own_collection_adds_no_collection_rule() {
+    let filter = SmartCollectionFilter {
+        in_collection_ids: Some(vec![collection_id()]),
+        ..Default::default()
+    };
+    assert!(!build_matches_query(&filter, None).contains("collection_entity_membership"));
+}
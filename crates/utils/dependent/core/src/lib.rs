@@ -143,14 +143,19 @@ fn build_provider_language_information(
                 MediaSource::Igdb
                 | MediaSource::Vndb
                 | MediaSource::Custom
+                | MediaSource::Douban
                 | MediaSource::Anilist
+                | MediaSource::Bangumi
                 | MediaSource::Spotify
+                | MediaSource::ComicVine
                 | MediaSource::GiantBomb
                 | MediaSource::Hardcover
                 | MediaSource::Myanimelist
+                | MediaSource::MusicBrainz
                 | MediaSource::GoogleBooks
                 | MediaSource::Listennotes
                 | MediaSource::Openlibrary
+                | MediaSource::BoardGameGeek
                 | MediaSource::MangaUpdates => (vec!["us".to_owned()], "us".to_owned()),
             };
             ProviderLanguageInformation {
@@ -224,6 +224,8 @@ pub async fn metadata_details(
                 publish_year: model.publish_year,
                 book_specifics: model.book_specifics,
                 show_specifics: model.show_specifics,
+                comic_specifics: model.comic_specifics,
+                board_game_specifics: model.board_game_specifics,
                 movie_specifics: model.movie_specifics,
                 music_specifics: model.music_specifics,
                 manga_specifics: model.manga_specifics,
@@ -2,6 +2,7 @@ use std::sync::Arc;
 
 use anyhow::Result;
 use application_utils::graphql_to_db_order;
+use chrono::Duration;
 use common_models::{SearchDetails, SearchInput, UserLevelCacheKey};
 use database_models::{
     collection, collection_entity_membership, collection_to_entity, exercise, genre, metadata,
@@ -14,8 +15,9 @@ use database_models::{
     review, seen, user, user_measurement, user_to_entity, workout, workout_template,
 };
 use database_utils::{
-    apply_columns_search, build_collection_filter_condition, extract_pagination_params,
+    apply_columns_search, build_collection_filter_condition, extract_pagination_params, user_by_id,
 };
+use dependent_fitness_utils::calculate_computed_measurement_statistics;
 use dependent_models::{
     ApplicationCacheKey, ApplicationCacheValue, CachedResponse, SearchResults,
     UserCollectionsListResponse, UserExercisesListResponse, UserMeasurementsListResponse,
@@ -398,6 +400,7 @@ pub async fn user_collections_list(
                     "is_default",
                 )
                 .column(collection::Column::InformationTemplate)
+                .column(collection::Column::SmartFilter)
                 .expr_as(
                     SimpleExpr::SubQuery(None, Box::new(count_subquery.into_sub_query_statement())),
                     "count",
@@ -899,8 +902,21 @@ pub async fn user_measurements_list(
         }),
         ApplicationCacheValue::UserMeasurementsList,
         || async {
-            let resp = UserMeasurement::find()
+            let preferences = user_by_id(user_id, ss)
+                .await?
+                .preferences
+                .fitness
+                .measurements;
+            // Trends need the measurements that fall in their window before the start.
+            let longest_window = preferences
+                .trends
+                .iter()
+                .map(|t| t.window_days)
+                .max()
+                .unwrap_or_default();
+            let mut resp = UserMeasurement::find()
                 .apply_if(input.start_time, |query, v| {
+                    let v = v - Duration::days(longest_window.into());
                     query.filter(user_measurement::Column::Timestamp.gte(v))
                 })
                 .apply_if(input.end_time, |query, v| {
@@ -910,6 +926,10 @@ pub async fn user_measurements_list(
                 .order_by_asc(user_measurement::Column::Timestamp)
                 .all(&ss.db)
                 .await?;
+            calculate_computed_measurement_statistics(&mut resp, &preferences);
+            if let Some(start_time) = input.start_time {
+                resp.retain(|m| m.timestamp >= start_time);
+            }
             Ok(resp)
         },
     )
@@ -9,21 +9,24 @@ use common_utils::{
 };
 use database_models::{
     genre, metadata, metadata_group, metadata_group_to_person, metadata_to_genre,
-    metadata_to_metadata, metadata_to_metadata_group, metadata_to_person, person,
+    metadata_to_metadata, metadata_to_metadata_group, metadata_to_person, metadata_translation,
+    person,
     prelude::{
         Genre, Metadata, MetadataGroup, MetadataGroupToPerson, MetadataToGenre, MetadataToMetadata,
-        MetadataToMetadataGroup, MetadataToPerson, Person,
+        MetadataToMetadataGroup, MetadataToPerson, MetadataTranslation, Person,
     },
 };
+use database_utils::user_by_id;
 use dependent_jobs_utils::deploy_update_metadata_job;
 use dependent_models::MetadataBaseData;
 use dependent_provider_utils::{
     details_from_provider, get_metadata_provider, get_non_metadata_provider,
 };
 use dependent_utility_utils::{
-    expire_metadata_details_cache, expire_metadata_group_details_cache, expire_person_details_cache,
+    expire_metadata_details_cache, expire_metadata_group_details_cache,
+    expire_person_details_cache, expire_user_metadata_details_cache,
 };
-use enum_models::{MetadataToMetadataRelation, UserNotificationContent};
+use enum_models::{EntityLot, MetadataToMetadataRelation, UserNotificationContent};
 use futures::{TryFutureExt, try_join};
 use itertools::Itertools;
 use markdown::{CompileOptions, Options, to_html_with_options as markdown_to_html_opts};
@@ -282,6 +285,11 @@ pub async fn update_metadata(
                 .then_some(())
                 .map(|_| details.watch_providers);
 
+            MetadataTranslation::delete_many()
+                .filter(metadata_translation::Column::MetadataId.eq(metadata_id))
+                .exec(&ss.db)
+                .await?;
+
             let mut meta = meta.into_active_model();
             meta.title = ActiveValue::Set(details.title);
             meta.assets = ActiveValue::Set(details.assets);
@@ -296,6 +304,8 @@ pub async fn update_metadata(
             meta.publish_date = ActiveValue::Set(details.publish_date);
             meta.show_specifics = ActiveValue::Set(details.show_specifics);
             meta.book_specifics = ActiveValue::Set(details.book_specifics);
+            meta.comic_specifics = ActiveValue::Set(details.comic_specifics);
+            meta.board_game_specifics = ActiveValue::Set(details.board_game_specifics);
             meta.anime_specifics = ActiveValue::Set(details.anime_specifics);
             meta.provider_rating = ActiveValue::Set(details.provider_rating);
             meta.manga_specifics = ActiveValue::Set(details.manga_specifics);
@@ -351,6 +361,8 @@ async fn generate_metadata_update_notifications(
         && p1 != p2
     {
         notifications.push(UserNotificationContent::MetadataStatusChanged {
+            entity_id: Some(meta.id.clone()),
+            entity_lot: EntityLot::Metadata,
             old_status: format!("{p1:#?}"),
             new_status: format!("{p2:#?}"),
             entity_title: meta.title.clone(),
@@ -360,6 +372,8 @@ async fn generate_metadata_update_notifications(
         && p1 != p2
     {
         notifications.push(UserNotificationContent::MetadataReleaseDateChanged {
+            entity_id: Some(meta.id.clone()),
+            entity_lot: EntityLot::Metadata,
             season_number: None,
             episode_number: None,
             old_date: format!("{p1:#?}"),
@@ -370,6 +384,8 @@ async fn generate_metadata_update_notifications(
     if let (Some(s1), Some(s2)) = (&meta.show_specifics, &details.show_specifics) {
         if s1.seasons.len() != s2.seasons.len() {
             notifications.push(UserNotificationContent::MetadataNumberOfSeasonsChanged {
+                entity_id: Some(meta.id.clone()),
+                entity_lot: EntityLot::Metadata,
                 old_seasons: s1.seasons.len(),
                 new_seasons: s2.seasons.len(),
                 entity_title: meta.title.clone(),
@@ -384,6 +400,8 @@ async fn generate_metadata_update_notifications(
                 }
                 if s1.episodes.len() != s2.episodes.len() {
                     notifications.push(UserNotificationContent::MetadataEpisodeReleased {
+                        entity_id: Some(meta.id.clone()),
+                        entity_lot: EntityLot::Metadata,
                         entity_title: meta.title.clone(),
                         old_episode_count: s1.episodes.len(),
                         new_episode_count: s2.episodes.len(),
@@ -397,6 +415,8 @@ async fn generate_metadata_update_notifications(
                         if before_episode.name != after_episode.name {
                             notifications.push(
                                 UserNotificationContent::MetadataEpisodeNameChanged {
+                                    entity_id: Some(meta.id.clone()),
+                                    entity_lot: EntityLot::Metadata,
                                     entity_title: meta.title.clone(),
                                     season_number: Some(s1.season_number),
                                     episode_number: before_episode.episode_number,
@@ -408,6 +428,8 @@ async fn generate_metadata_update_notifications(
                         if before_episode.poster_images != after_episode.poster_images {
                             notifications.push(
                                 UserNotificationContent::MetadataEpisodeImagesChanged {
+                                    entity_id: Some(meta.id.clone()),
+                                    entity_lot: EntityLot::Metadata,
                                     entity_title: meta.title.clone(),
                                     season_number: Some(s1.season_number),
                                     episode_number: before_episode.episode_number,
@@ -420,6 +442,8 @@ async fn generate_metadata_update_notifications(
                         {
                             notifications.push(
                                 UserNotificationContent::MetadataReleaseDateChanged {
+                                    entity_id: Some(meta.id.clone()),
+                                    entity_lot: EntityLot::Metadata,
                                     old_date: format!("{:?}", pd1),
                                     new_date: format!("{:?}", pd2),
                                     entity_title: meta.title.clone(),
@@ -438,6 +462,8 @@ async fn generate_metadata_update_notifications(
         && e1 != e2
     {
         notifications.push(UserNotificationContent::MetadataChaptersOrEpisodesChanged {
+            entity_id: Some(meta.id.clone()),
+            entity_lot: EntityLot::Metadata,
             old_count: e1.into(),
             new_count: e2.into(),
             entity_title: meta.title.clone(),
@@ -450,6 +476,8 @@ async fn generate_metadata_update_notifications(
         && c1 != c2
     {
         notifications.push(UserNotificationContent::MetadataChaptersOrEpisodesChanged {
+            entity_id: Some(meta.id.clone()),
+            entity_lot: EntityLot::Metadata,
             old_count: c1,
             new_count: c2,
             entity_title: meta.title.clone(),
@@ -460,6 +488,8 @@ async fn generate_metadata_update_notifications(
     if let (Some(p1), Some(p2)) = (&meta.podcast_specifics, &details.podcast_specifics) {
         if p1.episodes.len() != p2.episodes.len() {
             notifications.push(UserNotificationContent::MetadataEpisodeReleased {
+                entity_id: Some(meta.id.clone()),
+                entity_lot: EntityLot::Metadata,
                 season_number: None,
                 entity_title: meta.title.clone(),
                 old_episode_count: p1.episodes.len(),
@@ -470,6 +500,8 @@ async fn generate_metadata_update_notifications(
             for (before_episode, after_episode) in zip(p1.episodes.iter(), p2.episodes.iter()) {
                 if before_episode.title != after_episode.title {
                     notifications.push(UserNotificationContent::MetadataEpisodeNameChanged {
+                        entity_id: Some(meta.id.clone()),
+                        entity_lot: EntityLot::Metadata,
                         season_number: None,
                         entity_title: meta.title.clone(),
                         episode_number: before_episode.number,
@@ -479,6 +511,8 @@ async fn generate_metadata_update_notifications(
                 }
                 if before_episode.thumbnail != after_episode.thumbnail {
                     notifications.push(UserNotificationContent::MetadataEpisodeImagesChanged {
+                        entity_id: Some(meta.id.clone()),
+                        entity_lot: EntityLot::Metadata,
                         season_number: None,
                         entity_title: meta.title.clone(),
                         episode_number: before_episode.number,
@@ -545,6 +579,7 @@ pub async fn update_person(
         return Ok(UpdateMediaEntityResult::default());
     }
     let mut notifications = vec![];
+    let mut credited_metadata_ids = vec![];
     let provider = get_non_metadata_provider(person.source, ss).await?;
     let Some(provider_person) = provider
         .person_details(&person.identifier, &person.source_specifics)
@@ -601,10 +636,13 @@ pub async fn update_person(
             .contains(&search_for)
         {
             notifications.push(UserNotificationContent::PersonMetadataAssociated {
+                entity_id: Some(pm.id.clone()),
+                entity_lot: EntityLot::Metadata,
                 metadata_title: title,
                 role: data.role.clone(),
                 person_name: person.name.clone(),
             });
+            credited_metadata_ids.push(pm.id.clone());
             current_state_changes.metadata_associated.insert(search_for);
         }
     }
@@ -639,7 +677,7 @@ pub async fn update_person(
         if already_intermediate.is_none() {
             let intermediate = metadata_group_to_person::ActiveModel {
                 role: ActiveValue::Set(data.role.clone()),
-                metadata_group_id: ActiveValue::Set(db_dg),
+                metadata_group_id: ActiveValue::Set(db_dg.clone()),
                 index: ActiveValue::Set(idx.try_into().unwrap()),
                 person_id: ActiveValue::Set(person_id.to_owned()),
             };
@@ -658,6 +696,8 @@ pub async fn update_person(
             .contains(&search_for)
         {
             notifications.push(UserNotificationContent::PersonMetadataGroupAssociated {
+                entity_id: Some(db_dg.clone()),
+                entity_lot: EntityLot::MetadataGroup,
                 person_name: person.name.clone(),
                 metadata_group_title: data.metadata_group.title.clone(),
                 role: data.role.clone(),
@@ -670,7 +710,10 @@ pub async fn update_person(
     to_update_person.state_changes = ActiveValue::Set(Some(current_state_changes));
     to_update_person.update(&ss.db).await.unwrap();
     expire_person_details_cache(&person_id, ss).await?;
-    Ok(UpdateMediaEntityResult { notifications })
+    Ok(UpdateMediaEntityResult {
+        notifications,
+        credited_metadata_ids,
+    })
 }
 
 pub async fn commit_metadata_group(
@@ -753,6 +796,67 @@ pub async fn commit_person(
     }
 }
 
+/// Fetch the translations of a media item for the languages preferred by the user that have
+/// not been fetched yet. A translation that the provider can not give is stored empty so
+/// that it is not requested again until the media item is next updated.
+pub async fn update_user_metadata_translations(
+    user_id: &String,
+    metadata_id: &String,
+    ss: &Arc<SupportingService>,
+) -> Result<()> {
+    let languages = user_by_id(user_id, ss)
+        .await?
+        .preferences
+        .general
+        .metadata_languages;
+    let existing = MetadataTranslation::find()
+        .select_only()
+        .column(metadata_translation::Column::Language)
+        .filter(metadata_translation::Column::MetadataId.eq(metadata_id))
+        .into_tuple::<String>()
+        .all(&ss.db)
+        .await?;
+    let missing = languages
+        .into_iter()
+        .filter(|l| !existing.contains(l))
+        .unique()
+        .collect_vec();
+    if !missing.is_empty() {
+        let meta = Metadata::find_by_id(metadata_id)
+            .one(&ss.db)
+            .await?
+            .ok_or_else(|| anyhow!("Metadata with the given id does not exist"))?;
+        let provider = get_metadata_provider(meta.lot, meta.source, ss).await.ok();
+        for language in missing {
+            let translation = match &provider {
+                None => Default::default(),
+                Some(provider) => provider
+                    .metadata_translation(&meta.identifier, &language)
+                    .await
+                    .trace_ok()
+                    .unwrap_or_default(),
+            };
+            ryot_log!(
+                debug,
+                "Storing {} translation for metadata {}",
+                language,
+                metadata_id
+            );
+            let to_insert = metadata_translation::ActiveModel {
+                language: ActiveValue::Set(language),
+                title: ActiveValue::Set(translation.title),
+                metadata_id: ActiveValue::Set(metadata_id.to_owned()),
+                description: ActiveValue::Set(translation.description),
+                ..Default::default()
+            };
+            // DEV: Another job might have stored this language in the meantime
+            to_insert.insert(&ss.db).await.ok();
+        }
+    }
+    expire_user_metadata_details_cache(user_id, metadata_id, ss).await?;
+    Ok(())
+}
+
 pub async fn generic_metadata(
     metadata_id: &String,
     ss: &Arc<SupportingService>,
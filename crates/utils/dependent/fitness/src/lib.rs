@@ -1,14 +1,15 @@
 use std::{cmp::Reverse, collections::HashMap, sync::Arc};
 
 use anyhow::{Result, anyhow, bail};
+use chrono::Duration;
 use common_models::{ChangeCollectionToEntitiesInput, DefaultCollection, EntityToCollectionInput};
 use common_utils::{get_first_max_index_by, ryot_log};
 use database_models::{
     exercise,
     prelude::{Exercise, UserMeasurement, UserToEntity, Workout},
-    user_measurement, user_to_entity, workout,
+    user_feed_event, user_measurement, user_to_entity, workout,
 };
-use database_utils::schedule_user_for_workout_revision;
+use database_utils::{record_user_feed_event, schedule_user_for_workout_revision, user_by_id};
 use dependent_collection_utils::add_entities_to_collection;
 use dependent_models::UpdateCustomExerciseInput;
 use dependent_notification_utils::send_notification_for_user;
@@ -18,26 +19,37 @@ use dependent_utility_utils::{
 };
 use enum_meta::Meta;
 use enum_models::{
-    EntityLot, ExerciseLot, ExerciseSource, UserNotificationContent, WorkoutSetPersonalBest,
+    EntityLot, ExerciseLot, ExerciseSource, UserFeedEventLot, UserNotificationContent,
+    WorkoutSetPersonalBest,
 };
+use evalexpr::{ContextWithMutableVariables, HashMapContext, Value, build_operator_tree};
 use fitness_models::{
-    ExerciseBestSetRecord, ProcessedExercise, UserExerciseInput,
-    UserToExerciseBestSetExtraInformation, UserToExerciseExtraInformation,
-    UserToExerciseHistoryExtraInformation, UserWorkoutInput, UserWorkoutSetRecord,
-    WorkoutEquipmentFocusedSummary, WorkoutFocusedSummary, WorkoutForceFocusedSummary,
-    WorkoutInformation, WorkoutLevelFocusedSummary, WorkoutLotFocusedSummary,
-    WorkoutMuscleFocusedSummary, WorkoutOrExerciseTotals, WorkoutSetRecord, WorkoutSetStatistic,
-    WorkoutSetTotals, WorkoutSummary, WorkoutSummaryExercise,
+    ExerciseBestSetRecord, ExerciseProgressionAction, ExerciseProgressionSettings,
+    ExerciseProgressionStrategy, OneRmFormula, ProcessedExercise, SetLot, UserExerciseInput,
+    UserMeasurementStatistic, UserToExerciseBestSetExtraInformation,
+    UserToExerciseExtraInformation, UserToExerciseHistoryExtraInformation, UserWorkoutInput,
+    UserWorkoutSetRecord, WorkoutEquipmentFocusedSummary, WorkoutFocusedSummary,
+    WorkoutForceFocusedSummary, WorkoutInformation, WorkoutLevelFocusedSummary,
+    WorkoutLotFocusedSummary, WorkoutMuscleFocusedSummary, WorkoutOrExerciseTotals,
+    WorkoutSetRecord, WorkoutSetStatistic, WorkoutSetTotals, WorkoutSummary,
+    WorkoutSummaryExercise,
 };
 use futures::try_join;
 use itertools::Itertools;
 use nanoid::nanoid;
-use rust_decimal::{Decimal, dec};
+use rust_decimal::{
+    Decimal, dec,
+    prelude::{FromPrimitive, ToPrimitive},
+};
 use sea_orm::{
     ActiveModelTrait, ActiveValue, ColumnTrait, EntityTrait, IntoActiveModel, ModelTrait,
     QueryFilter, prelude::DateTimeUtc,
 };
 use supporting_service::SupportingService;
+use user_models::{UserBiologicalSex, UserFitnessMeasurementsPreferences};
+
+#[cfg(test)]
+mod tests;
 
 pub async fn create_or_update_user_measurement(
     user_id: &String,
@@ -62,6 +74,122 @@ pub async fn create_or_update_user_measurement(
     Ok(um.timestamp)
 }
 
+/// Fills the computed statistics of measurements (sorted by their timestamps) using the
+/// formulas and trends in the measurement preferences. Formulas use the latest logged
+/// value of each statistic, but are only evaluated for measurements that log at least
+/// one of the statistics they reference.
+pub fn calculate_computed_measurement_statistics(
+    measurements: &mut [user_measurement::Model],
+    preferences: &UserFitnessMeasurementsPreferences,
+) {
+    let formulas = preferences
+        .computed_statistics
+        .iter()
+        .filter_map(|c| build_operator_tree(&c.formula).ok().map(|tree| (c, tree)))
+        .collect_vec();
+    let mut latest_values = HashMap::new();
+    for measurement in measurements.iter_mut() {
+        let logged = &measurement.information.statistics;
+        for statistic in logged {
+            if let Some(value) = statistic.value.to_f64() {
+                latest_values.insert(statistic.name.clone(), value);
+            }
+        }
+        let mut context = HashMapContext::new();
+        for (name, value) in latest_values.iter() {
+            context.set_value(name.clone(), Value::Float(*value)).ok();
+        }
+        let profile = &preferences.profile;
+        if !latest_values.contains_key("height")
+            && let Some(height) = profile.height.and_then(|h| h.to_f64())
+        {
+            context
+                .set_value("height".into(), Value::Float(height))
+                .ok();
+        }
+        if let Some(age) = profile
+            .birth_date
+            .and_then(|b| measurement.timestamp.date_naive().years_since(b))
+        {
+            context
+                .set_value("age".into(), Value::Float(age.into()))
+                .ok();
+        }
+        if let Some(sex) = profile.sex {
+            let is_male = if sex == UserBiologicalSex::Male {
+                1.0
+            } else {
+                0.0
+            };
+            context
+                .set_value("is_male".into(), Value::Float(is_male))
+                .ok();
+        }
+        let mut computed = vec![];
+        for (statistic, tree) in formulas.iter() {
+            let references_logged = tree.iter_variable_identifiers().any(|v| {
+                logged
+                    .iter()
+                    .chain(computed.iter())
+                    .any(|s: &UserMeasurementStatistic| s.name == v)
+            });
+            if !references_logged || logged.iter().any(|s| s.name == statistic.name) {
+                continue;
+            }
+            let Some(value) = tree
+                .eval_number_with_context(&context)
+                .ok()
+                .filter(|v| v.is_finite())
+            else {
+                continue;
+            };
+            context
+                .set_value(statistic.name.clone(), Value::Float(value))
+                .ok();
+            if let Some(value) = Decimal::from_f64(value) {
+                computed.push(UserMeasurementStatistic {
+                    value: value.round_dp(2),
+                    name: statistic.name.clone(),
+                });
+            }
+        }
+        measurement.information.computed_statistics = computed;
+    }
+    for trend in preferences.trends.iter() {
+        let values = measurements
+            .iter()
+            .map(|m| {
+                m.information
+                    .statistics
+                    .iter()
+                    .chain(m.information.computed_statistics.iter())
+                    .find(|s| s.name == trend.statistic)
+                    .map(|s| (m.timestamp, s.value))
+            })
+            .collect_vec();
+        let window = Duration::days(trend.window_days.max(1).into());
+        for (idx, measurement) in measurements.iter_mut().enumerate() {
+            let Some((timestamp, _)) = values[idx] else {
+                continue;
+            };
+            let in_window = values[..=idx]
+                .iter()
+                .flatten()
+                .filter(|(t, _)| *t > timestamp - window)
+                .map(|(_, v)| *v)
+                .collect_vec();
+            let average = in_window.iter().sum::<Decimal>() / Decimal::from(in_window.len());
+            measurement
+                .information
+                .computed_statistics
+                .push(UserMeasurementStatistic {
+                    name: trend.name.clone(),
+                    value: average.round_dp(2),
+                });
+        }
+    }
+}
+
 pub fn get_best_set_index(records: &[WorkoutSetRecord]) -> Option<usize> {
     get_first_max_index_by(records, |a, b| {
         let score_a = a.statistic.duration.unwrap_or(dec!(0))
@@ -79,20 +207,71 @@ pub fn get_best_set_index(records: &[WorkoutSetRecord]) -> Option<usize> {
 pub fn get_index_of_highest_pb(
     records: &[WorkoutSetRecord],
     pb_type: &WorkoutSetPersonalBest,
+    one_rm_formula: OneRmFormula,
 ) -> Option<usize> {
     get_first_max_index_by(records, |a, b| {
-        let pb_a = get_personal_best(a, pb_type).unwrap_or(dec!(0));
-        let pb_b = get_personal_best(b, pb_type).unwrap_or(dec!(0));
+        let pb_a = get_personal_best(a, pb_type, one_rm_formula).unwrap_or(dec!(0));
+        let pb_b = get_personal_best(b, pb_type, one_rm_formula).unwrap_or(dec!(0));
         pb_a.cmp(&pb_b)
     })
 }
 
-pub fn calculate_one_rm(value: &WorkoutSetRecord) -> Option<Decimal> {
+/// The percentage of the one rep max that can be lifted for 1 to 12 reps at RPE 10.
+const RPE_CHART_PERCENTAGES: [Decimal; 12] = [
+    dec!(100),
+    dec!(95.5),
+    dec!(92.2),
+    dec!(89.2),
+    dec!(86.3),
+    dec!(83.7),
+    dec!(81.1),
+    dec!(78.6),
+    dec!(76.2),
+    dec!(73.9),
+    dec!(70.7),
+    dec!(68.0),
+];
+
+fn get_rpe_chart_percentage(reps: Decimal) -> Option<Decimal> {
+    let lower = reps.floor();
+    let idx = lower.to_usize()?.checked_sub(1)?;
+    let low = *RPE_CHART_PERCENTAGES.get(idx)?;
+    let high = RPE_CHART_PERCENTAGES.get(idx + 1).copied().unwrap_or(low);
+    Some(low - (low - high) * (reps - lower))
+}
+
+pub fn calculate_one_rm(value: &WorkoutSetRecord, formula: OneRmFormula) -> Option<Decimal> {
     let weight = value.statistic.weight?;
-    let reps = value.statistic.reps?;
-    let val = match reps < dec!(10) {
-        true => (weight * dec!(36.0)).checked_div(dec!(37.0) - reps), // Brzycki
-        false => weight.checked_mul((dec!(1).checked_add(reps.checked_div(dec!(30))?))?), // Epley
+    let reps = match formula {
+        // A set stopped short of failure could have been taken further, so the chart counts
+        // the reps in reserve implied by the RPE towards the estimate.
+        OneRmFormula::RpeChart => {
+            let reps_in_reserve = value
+                .rpe
+                .filter(|rpe| (1..=10).contains(rpe))
+                .map_or(dec!(0), |rpe| Decimal::from(10 - rpe));
+            value.statistic.reps? + reps_in_reserve
+        }
+        _ => value.statistic.reps?,
+    };
+    let brzycki = || (weight * dec!(36.0)).checked_div(dec!(37.0) - reps);
+    let epley = || weight.checked_mul(dec!(1).checked_add(reps.checked_div(dec!(30))?)?);
+    let exponential = |a: f64, b: f64, c: f64| {
+        let factor = 100.0 / (a + b * (-c * reps.to_f64()?).exp());
+        Decimal::from_f64(factor).map(|f| weight * f)
+    };
+    let val = match formula {
+        OneRmFormula::BrzyckiEpley if reps < dec!(10) => brzycki(),
+        OneRmFormula::BrzyckiEpley => epley(),
+        OneRmFormula::Brzycki => brzycki(),
+        OneRmFormula::Epley => epley(),
+        OneRmFormula::Lombardi => Decimal::from_f64(reps.to_f64()?.powf(0.1)).map(|f| weight * f),
+        OneRmFormula::Wathan => exponential(48.8, 53.8, 0.075),
+        OneRmFormula::Mayhew => exponential(52.2, 41.9, 0.055),
+        OneRmFormula::RpeChart => match get_rpe_chart_percentage(reps) {
+            Some(percentage) => (weight * dec!(100)).checked_div(percentage),
+            None => epley(),
+        },
     };
     val.filter(|v| v >= &dec!(0))
 }
@@ -111,11 +290,12 @@ pub fn calculate_pace(value: &WorkoutSetRecord) -> Option<Decimal> {
 pub fn get_personal_best(
     value: &WorkoutSetRecord,
     pb_type: &WorkoutSetPersonalBest,
+    one_rm_formula: OneRmFormula,
 ) -> Option<Decimal> {
     match pb_type {
         WorkoutSetPersonalBest::Reps => value.statistic.reps,
         WorkoutSetPersonalBest::Pace => calculate_pace(value),
-        WorkoutSetPersonalBest::OneRm => calculate_one_rm(value),
+        WorkoutSetPersonalBest::OneRm => calculate_one_rm(value, one_rm_formula),
         WorkoutSetPersonalBest::Time => value.statistic.duration,
         WorkoutSetPersonalBest::Weight => value.statistic.weight,
         WorkoutSetPersonalBest::Volume => calculate_volume(value),
@@ -123,6 +303,175 @@ pub fn get_personal_best(
     }
 }
 
+fn with_computed_statistics(
+    statistic: WorkoutSetStatistic,
+    one_rm_formula: OneRmFormula,
+) -> WorkoutSetStatistic {
+    let mut record = WorkoutSetRecord {
+        statistic,
+        ..Default::default()
+    };
+    record.statistic.one_rm = calculate_one_rm(&record, one_rm_formula);
+    record.statistic.pace = calculate_pace(&record);
+    record.statistic.volume = calculate_volume(&record);
+    record.statistic
+}
+
+fn change_by_percentage(value: Decimal, percentage: Decimal) -> Decimal {
+    (value + value * percentage / dec!(100)).round_dp(2)
+}
+
+fn round_to_increment(value: Decimal, increment: Decimal) -> Decimal {
+    match increment > dec!(0) {
+        true => ((value / increment).round() * increment).normalize(),
+        false => value.round_dp(2),
+    }
+}
+
+/// Compute the targets for the next session of an exercise. `sessions` contains the
+/// working sets of the most recent sessions of the exercise, latest first.
+pub fn get_exercise_progression(
+    lot: ExerciseLot,
+    num_sets: usize,
+    sessions: &[Vec<WorkoutSetRecord>],
+    settings: &ExerciseProgressionSettings,
+    one_rm_formula: OneRmFormula,
+) -> Option<(ExerciseProgressionAction, Vec<WorkoutSetStatistic>)> {
+    let last_session = sessions.first().filter(|s| !s.is_empty())?;
+    let is_failed = |sets: &Vec<WorkoutSetRecord>| sets.iter().any(|s| s.lot == SetLot::Failure);
+    let failed_sessions = sessions.iter().take_while(|s| is_failed(s)).count();
+    let should_deload = settings.deload_after_failed_sessions > 0
+        && failed_sessions >= usize::from(settings.deload_after_failed_sessions);
+    let min_reps = Decimal::from(settings.min_reps);
+    let max_reps = Decimal::from(settings.max_reps.max(settings.min_reps));
+    let num_sets = match num_sets {
+        0 => last_session.len(),
+        n => n,
+    };
+    let previous = (0..num_sets)
+        .map(|idx| {
+            last_session
+                .get(idx)
+                .or_else(|| last_session.last())
+                .map(|s| s.statistic.clone())
+                .unwrap_or_default()
+        })
+        .collect_vec();
+    let reps_reached_max = previous
+        .iter()
+        .all(|s| s.reps.is_some_and(|r| r >= max_reps));
+    let deload = -settings.deload_percentage;
+    let (action, targets) = match lot {
+        _ if should_deload => {
+            let targets = previous
+                .into_iter()
+                .map(|mut s| {
+                    match lot {
+                        ExerciseLot::RepsAndWeight => {
+                            s.weight = s.weight.map(|w| {
+                                round_to_increment(
+                                    change_by_percentage(w, deload),
+                                    settings.load_increment,
+                                )
+                            });
+                        }
+                        ExerciseLot::Reps | ExerciseLot::RepsAndDuration => {
+                            s.reps = s.reps.map(|r| change_by_percentage(r, deload).floor());
+                        }
+                        ExerciseLot::Duration => {
+                            s.duration = s.duration.map(|d| change_by_percentage(d, deload));
+                        }
+                        ExerciseLot::DistanceAndDuration
+                        | ExerciseLot::RepsAndDurationAndDistance => {
+                            s.distance = s.distance.map(|d| change_by_percentage(d, deload));
+                        }
+                    }
+                    s
+                })
+                .collect();
+            (ExerciseProgressionAction::Deload, targets)
+        }
+        _ if is_failed(last_session) => (ExerciseProgressionAction::Repeat, previous),
+        ExerciseLot::RepsAndWeight
+            if settings.strategy == ExerciseProgressionStrategy::LinearLoad || reps_reached_max =>
+        {
+            let targets = previous
+                .into_iter()
+                .map(|mut s| {
+                    s.weight = s.weight.map(|w| w + settings.load_increment);
+                    if settings.strategy == ExerciseProgressionStrategy::DoubleProgression {
+                        s.reps = Some(min_reps);
+                    }
+                    s
+                })
+                .collect();
+            (ExerciseProgressionAction::IncreaseLoad, targets)
+        }
+        ExerciseLot::RepsAndWeight => {
+            let targets = previous
+                .into_iter()
+                .map(|mut s| {
+                    s.reps = Some(
+                        s.reps
+                            .map_or(min_reps, |r| (r + dec!(1)).max(min_reps))
+                            .min(max_reps),
+                    );
+                    s
+                })
+                .collect();
+            (ExerciseProgressionAction::IncreaseReps, targets)
+        }
+        ExerciseLot::Reps | ExerciseLot::RepsAndDuration => {
+            let targets = previous
+                .into_iter()
+                .map(|mut s| {
+                    s.reps = s.reps.map(|r| r + dec!(1));
+                    s
+                })
+                .collect();
+            (ExerciseProgressionAction::IncreaseReps, targets)
+        }
+        ExerciseLot::Duration => {
+            let improvement = settings.cardio_improvement_percentage;
+            let targets = previous
+                .into_iter()
+                .map(|mut s| {
+                    s.duration = s.duration.map(|d| change_by_percentage(d, improvement));
+                    s
+                })
+                .collect();
+            (ExerciseProgressionAction::IncreaseDuration, targets)
+        }
+        ExerciseLot::DistanceAndDuration | ExerciseLot::RepsAndDurationAndDistance => {
+            let improvement = -settings.cardio_improvement_percentage;
+            let targets = previous
+                .into_iter()
+                .map(|mut s| {
+                    s.duration = s.duration.map(|d| change_by_percentage(d, improvement));
+                    s
+                })
+                .collect();
+            (ExerciseProgressionAction::IncreasePace, targets)
+        }
+    };
+    let targets = targets
+        .into_iter()
+        .map(|s| {
+            with_computed_statistics(
+                WorkoutSetStatistic {
+                    reps: s.reps,
+                    weight: s.weight,
+                    distance: s.distance,
+                    duration: s.duration,
+                    ..Default::default()
+                },
+                one_rm_formula,
+            )
+        })
+        .collect();
+    Some((action, targets))
+}
+
 /// Set the invalid statistics to `None` according to the type of exercise.
 pub fn clean_values(value: &mut UserWorkoutSetRecord, exercise_lot: &ExerciseLot) {
     let mut stats = WorkoutSetStatistic::default();
@@ -381,6 +730,12 @@ pub async fn create_or_update_user_workout(
             .unwrap(),
     };
     let mut input = input;
+    let one_rm_formula = user_by_id(user_id, ss)
+        .await?
+        .preferences
+        .fitness
+        .exercises
+        .one_rm_formula;
     let (new_workout_id, to_update_workout) = match &input.update_workout_id {
         Some(id) => {
             // DEV: Unwrap to make sure we error out early if the workout to edit does not exist
@@ -500,7 +855,7 @@ pub async fn create_or_update_user_workout(
                 statistic: set.statistic.clone(),
                 rest_timer_started_at: set.rest_timer_started_at,
             };
-            value.statistic.one_rm = calculate_one_rm(&value);
+            value.statistic.one_rm = calculate_one_rm(&value, one_rm_formula);
             value.statistic.pace = calculate_pace(&value);
             value.statistic.volume = calculate_volume(&value);
             sets.push(value);
@@ -512,7 +867,7 @@ pub async fn create_or_update_user_workout(
             .personal_bests;
         let types_of_prs = db_ex.lot.meta();
         for best_type in types_of_prs.iter() {
-            let set_idx = get_index_of_highest_pb(&sets, best_type).unwrap();
+            let set_idx = get_index_of_highest_pb(&sets, best_type, one_rm_formula).unwrap();
             let possible_record = personal_bests
                 .iter()
                 .find(|pb| pb.lot == *best_type)
@@ -535,7 +890,8 @@ pub async fn create_or_update_user_workout(
                             continue;
                         }
                     };
-                    if get_personal_best(set, best_type) > get_personal_best(workout_set, best_type)
+                    if get_personal_best(set, best_type, one_rm_formula)
+                        > get_personal_best(workout_set, best_type, one_rm_formula)
                     {
                         if let Some(ref mut set_personal_bests) = set.personal_bests {
                             set_personal_bests.push(*best_type);
@@ -663,7 +1019,26 @@ pub async fn create_or_update_user_workout(
                         workout_name: data.name.clone(),
                     },
                 )
-                .await?
+                .await?;
+                record_user_feed_event(
+                    user_feed_event::ActiveModel {
+                        origin_id: ActiveValue::Set(data.id.clone()),
+                        entity_id: ActiveValue::Set(data.id.clone()),
+                        user_id: ActiveValue::Set(user_id.to_owned()),
+                        created_on: ActiveValue::Set(data.end_time),
+                        entity_lot: ActiveValue::Set(EntityLot::Workout),
+                        lot: ActiveValue::Set(UserFeedEventLot::Workout),
+                        personal_bests_achieved: ActiveValue::Set(
+                            data.summary
+                                .total
+                                .as_ref()
+                                .map(|t| t.personal_bests_achieved as i32),
+                        ),
+                        ..Default::default()
+                    },
+                    ss,
+                )
+                .await?;
             }
         }
     };
@@ -0,0 +1,84 @@
+use chrono::{NaiveDate, TimeZone, Utc};
+use fitness_models::UserMeasurementInformation;
+use user_models::{UserComputedMeasurementStatistic, UserMeasurementsProfile};
+
+use super::*;
+
+fn measurement(day: u32, statistics: &[(&str, Decimal)]) -> user_measurement::Model {
+    user_measurement::Model {
+        timestamp: Utc.with_ymd_and_hms(2025, 1, day, 8, 0, 0).unwrap(),
+        information: UserMeasurementInformation {
+            statistics: statistics
+                .iter()
+                .map(|(name, value)| UserMeasurementStatistic {
+                    value: *value,
+                    name: name.to_string(),
+                })
+                .collect(),
+            ..Default::default()
+        },
+        ..Default::default()
+    }
+}
+
+fn computed(measurement: &user_measurement::Model, name: &str) -> Option<Decimal> {
+    measurement
+        .information
+        .computed_statistics
+        .iter()
+        .find(|s| s.name == name)
+        .map(|s| s.value)
+}
+
+fn preferences() -> UserFitnessMeasurementsPreferences {
+    UserFitnessMeasurementsPreferences {
+        profile: UserMeasurementsProfile {
+            height: Some(dec!(180)),
+            sex: Some(UserBiologicalSex::Male),
+            birth_date: NaiveDate::from_ymd_opt(1995, 6, 1),
+        },
+        ..Default::default()
+    }
+}
+
+#[test]
+fn test_formulas_use_profile_and_latest_values() {
+    let mut measurements = vec![
+        measurement(1, &[("body_fat", dec!(20))]),
+        measurement(2, &[("weight", dec!(81))]),
+    ];
+    calculate_computed_measurement_statistics(&mut measurements, &preferences());
+    assert_eq!(computed(&measurements[0], "bmi"), None);
+    assert_eq!(computed(&measurements[1], "bmi"), Some(dec!(25)));
+    assert_eq!(computed(&measurements[1], "lean_mass"), Some(dec!(64.8)));
+    assert_eq!(computed(&measurements[1], "ffmi"), Some(dec!(20)));
+}
+
+#[test]
+fn test_formulas_can_reference_age_and_sex() {
+    let mut preferences = preferences();
+    preferences.computed_statistics = vec![UserComputedMeasurementStatistic {
+        name: "bmr".to_owned(),
+        formula: "10 * weight + 6.25 * height - 5 * age + 5 * is_male - 161 * (1 - is_male)"
+            .to_owned(),
+        ..Default::default()
+    }];
+    let mut measurements = vec![measurement(1, &[("weight", dec!(80))])];
+    calculate_computed_measurement_statistics(&mut measurements, &preferences);
+    assert_eq!(computed(&measurements[0], "bmr"), Some(dec!(1785)));
+}
+
+#[test]
+fn test_trend_is_moving_average_over_window() {
+    let mut measurements = vec![
+        measurement(1, &[("weight", dec!(80))]),
+        measurement(3, &[("weight", dec!(82))]),
+        measurement(6, &[("sugar_level", dec!(90))]),
+        measurement(9, &[("weight", dec!(84))]),
+    ];
+    calculate_computed_measurement_statistics(&mut measurements, &preferences());
+    assert_eq!(computed(&measurements[0], "weight_trend"), Some(dec!(80)));
+    assert_eq!(computed(&measurements[1], "weight_trend"), Some(dec!(81)));
+    assert_eq!(computed(&measurements[2], "weight_trend"), None);
+    assert_eq!(computed(&measurements[3], "weight_trend"), Some(dec!(83)));
+}
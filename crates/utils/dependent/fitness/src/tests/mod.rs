@@ -0,0 +1,22 @@
+use super::*;
+use rstest::rstest;
+
+mod measurements;
+mod one_rm;
+mod progression;
+
+fn set(lot: SetLot, reps: Option<Decimal>, weight: Option<Decimal>) -> WorkoutSetRecord {
+    WorkoutSetRecord {
+        lot,
+        statistic: WorkoutSetStatistic {
+            reps,
+            weight,
+            ..Default::default()
+        },
+        ..Default::default()
+    }
+}
+
+fn weighted(reps: Decimal, weight: Decimal) -> WorkoutSetRecord {
+    set(SetLot::Normal, Some(reps), Some(weight))
+}
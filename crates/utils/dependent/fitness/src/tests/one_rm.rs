@@ -0,0 +1,53 @@
+use super::*;
+
+#[rstest]
+#[case(OneRmFormula::BrzyckiEpley, dec!(112.5))]
+#[case(OneRmFormula::Brzycki, dec!(112.5))]
+#[case(OneRmFormula::Epley, dec!(116.67))]
+#[case(OneRmFormula::Lombardi, dec!(117.46))]
+#[case(OneRmFormula::Wathan, dec!(116.58))]
+#[case(OneRmFormula::Mayhew, dec!(119.01))]
+#[case(OneRmFormula::RpeChart, dec!(115.87))]
+fn test_one_rm_formulas(#[case] formula: OneRmFormula, #[case] expected: Decimal) {
+    let one_rm = calculate_one_rm(&weighted(dec!(5), dec!(100)), formula).unwrap();
+    assert_eq!(one_rm.round_dp(2), expected);
+}
+
+#[test]
+fn test_brzycki_epley_switches_at_ten_reps() {
+    let one_rm = calculate_one_rm(&weighted(dec!(12), dec!(100)), OneRmFormula::BrzyckiEpley);
+    assert_eq!(one_rm, Some(dec!(140)));
+}
+
+#[test]
+fn test_one_rm_counts_reps_in_reserve() {
+    let at_failure = weighted(dec!(5), dec!(100));
+    let mut with_rpe = at_failure.clone();
+    with_rpe.rpe = Some(8);
+    let without = calculate_one_rm(&at_failure, OneRmFormula::RpeChart).unwrap();
+    let with = calculate_one_rm(&with_rpe, OneRmFormula::RpeChart).unwrap();
+    assert!(with > without);
+    assert_eq!(
+        with,
+        calculate_one_rm(&weighted(dec!(7), dec!(100)), OneRmFormula::RpeChart).unwrap()
+    );
+}
+
+#[test]
+fn test_one_rm_ignores_rpe_outside_rpe_chart() {
+    let mut with_rpe = weighted(dec!(5), dec!(100));
+    with_rpe.rpe = Some(8);
+    assert_eq!(
+        calculate_one_rm(&with_rpe, OneRmFormula::BrzyckiEpley),
+        calculate_one_rm(&weighted(dec!(5), dec!(100)), OneRmFormula::BrzyckiEpley)
+    );
+}
+
+#[test]
+fn test_rpe_chart_falls_back_to_epley_beyond_chart() {
+    let set = weighted(dec!(15), dec!(100));
+    assert_eq!(
+        calculate_one_rm(&set, OneRmFormula::RpeChart),
+        calculate_one_rm(&set, OneRmFormula::Epley)
+    );
+}
@@ -0,0 +1,132 @@
+use super::*;
+
+#[test]
+fn test_progression_without_history_is_none() {
+    let settings = ExerciseProgressionSettings::default();
+    assert!(
+        get_exercise_progression(
+            ExerciseLot::RepsAndWeight,
+            3,
+            &[],
+            &settings,
+            OneRmFormula::default()
+        )
+        .is_none()
+    );
+}
+
+#[test]
+fn test_double_progression_adds_reps_within_range() {
+    let settings = ExerciseProgressionSettings::default();
+    let sessions = vec![vec![
+        weighted(dec!(10), dec!(60)),
+        weighted(dec!(8), dec!(60)),
+    ]];
+    let (action, sets) = get_exercise_progression(
+        ExerciseLot::RepsAndWeight,
+        2,
+        &sessions,
+        &settings,
+        OneRmFormula::default(),
+    )
+    .unwrap();
+    assert_eq!(action, ExerciseProgressionAction::IncreaseReps);
+    assert_eq!(sets[0].reps, Some(dec!(11)));
+    assert_eq!(sets[1].reps, Some(dec!(9)));
+    assert_eq!(sets[0].weight, Some(dec!(60)));
+}
+
+#[test]
+fn test_double_progression_increases_load_at_top_of_range() {
+    let settings = ExerciseProgressionSettings::default();
+    let sessions = vec![vec![
+        weighted(dec!(12), dec!(60)),
+        weighted(dec!(12), dec!(60)),
+    ]];
+    let (action, sets) = get_exercise_progression(
+        ExerciseLot::RepsAndWeight,
+        3,
+        &sessions,
+        &settings,
+        OneRmFormula::default(),
+    )
+    .unwrap();
+    assert_eq!(action, ExerciseProgressionAction::IncreaseLoad);
+    assert_eq!(sets.len(), 3);
+    assert!(
+        sets.iter()
+            .all(|s| s.weight == Some(dec!(62.5)) && s.reps == Some(dec!(8)))
+    );
+    assert!(sets[0].one_rm.is_some());
+}
+
+#[test]
+fn test_linear_load_keeps_reps() {
+    let settings = ExerciseProgressionSettings {
+        strategy: ExerciseProgressionStrategy::LinearLoad,
+        ..Default::default()
+    };
+    let sessions = vec![vec![weighted(dec!(5), dec!(100))]];
+    let (action, sets) = get_exercise_progression(
+        ExerciseLot::RepsAndWeight,
+        1,
+        &sessions,
+        &settings,
+        OneRmFormula::default(),
+    )
+    .unwrap();
+    assert_eq!(action, ExerciseProgressionAction::IncreaseLoad);
+    assert_eq!(sets[0].reps, Some(dec!(5)));
+    assert_eq!(sets[0].weight, Some(dec!(102.5)));
+}
+
+#[rstest]
+#[case(1, ExerciseProgressionAction::Repeat, dec!(100))]
+#[case(2, ExerciseProgressionAction::Deload, dec!(90))]
+fn test_failed_sessions_repeat_then_deload(
+    #[case] failed_sessions: usize,
+    #[case] expected_action: ExerciseProgressionAction,
+    #[case] expected_weight: Decimal,
+) {
+    let settings = ExerciseProgressionSettings::default();
+    let failed = vec![
+        weighted(dec!(8), dec!(100)),
+        set(SetLot::Failure, Some(dec!(5)), Some(dec!(100))),
+    ];
+    let sessions = vec![failed; failed_sessions];
+    let (action, sets) = get_exercise_progression(
+        ExerciseLot::RepsAndWeight,
+        2,
+        &sessions,
+        &settings,
+        OneRmFormula::default(),
+    )
+    .unwrap();
+    assert_eq!(action, expected_action);
+    assert_eq!(sets[0].weight, Some(expected_weight));
+}
+
+#[test]
+fn test_cardio_progression_targets_faster_pace() {
+    let settings = ExerciseProgressionSettings::default();
+    let sessions = vec![vec![WorkoutSetRecord {
+        statistic: WorkoutSetStatistic {
+            distance: Some(dec!(5)),
+            duration: Some(dec!(30)),
+            ..Default::default()
+        },
+        ..Default::default()
+    }]];
+    let (action, sets) = get_exercise_progression(
+        ExerciseLot::DistanceAndDuration,
+        1,
+        &sessions,
+        &settings,
+        OneRmFormula::default(),
+    )
+    .unwrap();
+    assert_eq!(action, ExerciseProgressionAction::IncreasePace);
+    assert_eq!(sets[0].distance, Some(dec!(5)));
+    assert_eq!(sets[0].duration, Some(dec!(29.4)));
+    assert!(sets[0].pace > Some(dec!(5) / dec!(30)));
+}
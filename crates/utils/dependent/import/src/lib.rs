@@ -4,9 +4,10 @@ use std::{
     sync::Arc,
 };
 
-use anyhow::Result;
+use anyhow::{Result, bail};
 use common_models::{ChangeCollectionToEntitiesInput, EntityToCollectionInput};
 use common_utils::ryot_log;
+use database_models::{notification_platform, prelude::Review, review};
 use database_utils::{schedule_user_for_workout_revision, user_by_id};
 use dependent_collection_utils::{add_entities_to_collection, create_or_update_collection};
 use dependent_entity_utils::{commit_metadata, commit_metadata_group, commit_person};
@@ -15,18 +16,29 @@ use dependent_fitness_utils::{
     db_workout_to_workout_input,
 };
 use dependent_jobs_utils::{deploy_update_metadata_group_job, deploy_update_person_job};
-use dependent_models::{ImportCompletedItem, ImportOrExportMetadataItem, ImportResult};
+use dependent_models::{
+    ImportCompletedItem, ImportOrExportMetadataItem, ImportOrExportNotificationPlatformItem,
+    ImportResult,
+};
+use dependent_notification_utils::send_notification_platform_verification;
 use dependent_progress_utils::commit_import_seen_item;
 use dependent_review_utils::{convert_review_into_input, post_review};
+use dependent_utility_utils::{
+    create_or_update_user_integration, expire_user_measurements_list_cache,
+    expire_user_workout_templates_list_cache,
+};
 use enum_models::{EntityLot, MediaLot, MediaSource};
 use importer_models::{ImportDetails, ImportFailStep, ImportFailedItem, ImportResultResponse};
 use media_models::{
     CommitMetadataGroupInput, CommitPersonInput, CreateOrUpdateCollectionInput,
-    PartialMetadataWithoutId, UniqueMediaIdentifier,
+    CreateOrUpdateUserIntegrationInput, ImportOrExportItemRating, PartialMetadataWithoutId,
+    UniqueMediaIdentifier,
 };
 use rand::seq::SliceRandom;
 use rust_decimal::{Decimal, dec, prelude::FromPrimitive};
+use sea_orm::{ActiveModelTrait, ActiveValue, EntityTrait, IntoActiveModel, ModelTrait};
 use supporting_service::SupportingService;
+use user_models::{NotificationPlatformSpecifics, UserPreferences};
 
 async fn create_collection_and_add_entity_to_it(
     user_id: &String,
@@ -79,6 +91,74 @@ async fn create_collection_and_add_entity_to_it(
     }
 }
 
+/// Post an imported review and restore the comments that were attached to it.
+async fn commit_imported_review(
+    user_id: &String,
+    review: &ImportOrExportItemRating,
+    preferences: &UserPreferences,
+    entity_id: String,
+    entity_lot: EntityLot,
+    ss: &Arc<SupportingService>,
+) -> Result<()> {
+    let Some(input) = convert_review_into_input(review, preferences, entity_id, entity_lot) else {
+        return Ok(());
+    };
+    let posted = post_review(user_id, input, ss).await?;
+    if let Some(comments) = review.comments.clone().filter(|c| !c.is_empty()) {
+        Review::update(review::ActiveModel {
+            id: ActiveValue::Unchanged(posted.id),
+            comments: ActiveValue::Set(comments),
+            ..Default::default()
+        })
+        .exec(&ss.db)
+        .await?;
+    }
+    Ok(())
+}
+
+/// Recreate an imported notification platform. Email addresses have to be verified again, so
+/// those platforms stay disabled until the code sent to the address is entered.
+async fn commit_imported_notification_platform(
+    user_id: &String,
+    platform: ImportOrExportNotificationPlatformItem,
+    ss: &Arc<SupportingService>,
+) -> Result<()> {
+    let mut is_disabled = platform.is_disabled;
+    let mut platform_specifics = platform.platform_specifics;
+    let pending_verification = match &mut platform_specifics {
+        NotificationPlatformSpecifics::Email { email, is_verified } => {
+            if !ss.config.server.smtp.is_enabled() {
+                bail!("SMTP is not configured on this server");
+            }
+            *is_verified = false;
+            is_disabled = Some(true);
+            Some(email.clone())
+        }
+        _ => None,
+    };
+    let notification = notification_platform::ActiveModel {
+        is_disabled: ActiveValue::Set(is_disabled),
+        lot: ActiveValue::Set(platform.lot),
+        user_id: ActiveValue::Set(user_id.to_owned()),
+        quiet_hours: ActiveValue::Set(platform.quiet_hours),
+        description: ActiveValue::Set(platform.description),
+        delivery_mode: ActiveValue::Set(platform.delivery_mode),
+        platform_specifics: ActiveValue::Set(platform_specifics),
+        configured_events: ActiveValue::Set(platform.configured_events),
+        ..Default::default()
+    }
+    .insert(&ss.db)
+    .await?;
+    if let Some(email) = pending_verification
+        && let Err(e) =
+            send_notification_platform_verification(ss, user_id, &notification.id, &email).await
+    {
+        notification.delete(&ss.db).await?;
+        return Err(e);
+    }
+    Ok(())
+}
+
 pub async fn process_import<F>(
     is_import: bool,
     user_id: &String,
@@ -89,7 +169,7 @@ pub async fn process_import<F>(
 where
     F: Future<Output = Result<()>>,
 {
-    let preferences = user_by_id(user_id, ss).await?.preferences;
+    let mut preferences = user_by_id(user_id, ss).await?.preferences;
 
     let mut aggregated_metadata: HashMap<
         (MediaSource, String, MediaLot),
@@ -146,11 +226,14 @@ where
 
     import.completed.shuffle(&mut rand::rng());
 
-    // DEV: We need to make sure that exercises are created first because workouts are
+    // DEV: We need to make sure that preferences are applied first since reviews are converted
+    // using them, and that exercises and templates are created before the workouts which are
     // dependent on them.
     import.completed.sort_by_key(|i| match i {
-        ImportCompletedItem::Exercise(_) => 0,
-        _ => 1,
+        ImportCompletedItem::Preferences(_) => 0,
+        ImportCompletedItem::Exercise(_) => 1,
+        ImportCompletedItem::ApplicationWorkoutTemplate(_) => 2,
+        _ => 3,
     });
 
     let source_result = import.clone();
@@ -209,12 +292,15 @@ where
                     };
                 }
                 for review in metadata.reviews.iter() {
-                    if let Some(input) = convert_review_into_input(
+                    if let Err(e) = commit_imported_review(
+                        user_id,
                         review,
                         &preferences,
                         db_metadata_id.clone(),
                         EntityLot::Metadata,
-                    ) && let Err(e) = post_review(user_id, input, ss).await
+                        ss,
+                    )
+                    .await
                     {
                         import.failed.push(ImportFailedItem {
                             lot: Some(metadata.lot),
@@ -265,12 +351,15 @@ where
                 };
                 deploy_update_metadata_group_job(&db_metadata_group_id, ss).await?;
                 for review in metadata_group.reviews.iter() {
-                    if let Some(input) = convert_review_into_input(
+                    if let Err(e) = commit_imported_review(
+                        user_id,
                         review,
                         &preferences,
                         db_metadata_group_id.clone(),
                         EntityLot::MetadataGroup,
-                    ) && let Err(e) = post_review(user_id, input, ss).await
+                        ss,
+                    )
+                    .await
                     {
                         import.failed.push(ImportFailedItem {
                             error: Some(e.to_string()),
@@ -319,12 +408,15 @@ where
                 };
                 deploy_update_person_job(&db_person_id, ss).await?;
                 for review in person.reviews.iter() {
-                    if let Some(input) = convert_review_into_input(
+                    if let Err(e) = commit_imported_review(
+                        user_id,
                         review,
                         &preferences,
                         db_person_id.clone(),
                         EntityLot::Person,
-                    ) && let Err(e) = post_review(user_id, input, ss).await
+                        ss,
+                    )
+                    .await
                     {
                         import.failed.push(ImportFailedItem {
                             error: Some(e.to_string()),
@@ -408,6 +500,129 @@ where
                     }
                 }
             }
+            ImportCompletedItem::Preferences(new_preferences) => {
+                let mut user = user_by_id(user_id, ss).await?.into_active_model();
+                user.preferences = ActiveValue::Set(*new_preferences.clone());
+                match user.update(&ss.db).await {
+                    Ok(_) => {
+                        preferences = *new_preferences;
+                        expire_user_measurements_list_cache(user_id, ss).await?;
+                    }
+                    Err(e) => {
+                        import.failed.push(ImportFailedItem {
+                            error: Some(e.to_string()),
+                            identifier: "Preferences".to_owned(),
+                            step: ImportFailStep::DatabaseCommit,
+                            ..Default::default()
+                        });
+                    }
+                }
+            }
+            ImportCompletedItem::ApplicationExercise(exercise) => {
+                for review in exercise.reviews.iter() {
+                    if let Err(e) = commit_imported_review(
+                        user_id,
+                        review,
+                        &preferences,
+                        exercise.id.clone(),
+                        EntityLot::Exercise,
+                        ss,
+                    )
+                    .await
+                    {
+                        import.failed.push(ImportFailedItem {
+                            error: Some(e.to_string()),
+                            identifier: exercise.name.to_owned(),
+                            step: ImportFailStep::DatabaseCommit,
+                            ..Default::default()
+                        });
+                    };
+                }
+                for col in exercise.collections.into_iter() {
+                    create_collection_and_add_entity_to_it(
+                        user_id,
+                        exercise.id.clone(),
+                        EntityLot::Exercise,
+                        col.collection_name,
+                        ss,
+                        col.information,
+                        &mut import.failed,
+                    )
+                    .await;
+                }
+            }
+            ImportCompletedItem::ApplicationWorkoutTemplate(template) => {
+                let template = *template;
+                let mut details = template.details;
+                details.user_id = user_id.to_owned();
+                let name = details.name.clone();
+                match details.into_active_model().insert(&ss.db).await {
+                    Err(err) => {
+                        import.failed.push(ImportFailedItem {
+                            identifier: name,
+                            error: Some(err.to_string()),
+                            step: ImportFailStep::DatabaseCommit,
+                            ..Default::default()
+                        });
+                    }
+                    Ok(db_template) => {
+                        for col in template.collections.into_iter() {
+                            create_collection_and_add_entity_to_it(
+                                user_id,
+                                db_template.id.clone(),
+                                EntityLot::WorkoutTemplate,
+                                col.collection_name,
+                                ss,
+                                col.information,
+                                &mut import.failed,
+                            )
+                            .await;
+                        }
+                        expire_user_workout_templates_list_cache(user_id, ss).await?;
+                    }
+                }
+            }
+            ImportCompletedItem::Integration(integration) => {
+                let integration = *integration;
+                let identifier = integration
+                    .name
+                    .clone()
+                    .unwrap_or_else(|| integration.provider.to_string());
+                let input = CreateOrUpdateUserIntegrationInput {
+                    integration_id: None,
+                    name: integration.name,
+                    is_disabled: integration.is_disabled,
+                    provider: Some(integration.provider),
+                    extra_settings: integration.extra_settings,
+                    minimum_progress: integration.minimum_progress,
+                    maximum_progress: integration.maximum_progress,
+                    provider_specifics: integration.provider_specifics,
+                    sync_to_owned_collection: integration.sync_to_owned_collection,
+                };
+                if let Err(err) =
+                    create_or_update_user_integration(ss, user_id.to_owned(), input).await
+                {
+                    import.failed.push(ImportFailedItem {
+                        identifier,
+                        error: Some(err.to_string()),
+                        step: ImportFailStep::DatabaseCommit,
+                        ..Default::default()
+                    });
+                }
+            }
+            ImportCompletedItem::NotificationPlatform(platform) => {
+                let identifier = platform.description.clone();
+                if let Err(err) =
+                    commit_imported_notification_platform(user_id, *platform, ss).await
+                {
+                    import.failed.push(ImportFailedItem {
+                        identifier,
+                        error: Some(err.to_string()),
+                        step: ImportFailStep::DatabaseCommit,
+                        ..Default::default()
+                    });
+                }
+            }
             ImportCompletedItem::Measurement(measurement) => {
                 if let Err(err) =
                     create_or_update_user_measurement(user_id, measurement.clone(), ss).await
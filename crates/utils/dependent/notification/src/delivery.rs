@@ -0,0 +1,318 @@
+use std::sync::Arc;
+
+use anyhow::Result;
+use chrono::{DateTime, Duration, NaiveDateTime, NaiveTime, TimeZone, Timelike, Utc};
+use chrono_tz::Tz;
+use common_utils::ryot_log;
+use convert_case::{Case, Casing};
+use database_models::{
+    notification_delivery, notification_platform,
+    prelude::{NotificationDelivery, NotificationPlatform},
+};
+use database_utils::user_timezone_by_id;
+use enum_models::{NotificationDeliveryMode, NotificationDeliveryStatus, UserNotificationContent};
+use itertools::Itertools;
+use media_models::NotificationQuietHours;
+use notification_service::send_notification;
+use sea_orm::{
+    ActiveModelTrait, ActiveValue, ColumnTrait, EntityTrait, IntoActiveModel, QueryFilter,
+    QueryOrder, prelude::Expr,
+};
+use supporting_service::SupportingService;
+use traits::TraceOk;
+
+const MAXIMUM_DELIVERY_ATTEMPTS: i32 = 5;
+const DAILY_DIGEST_TIME: NaiveTime = NaiveTime::from_hms_opt(9, 0, 0).unwrap();
+/// How long a delivery that is being sent is hidden from other runs. If the send never
+/// finishes, the delivery becomes due again once this has passed.
+const DELIVERY_CLAIM_DURATION: Duration = Duration::minutes(10);
+
+/// When a digest collecting an event that happened at `now` should be sent. Returns `None`
+/// for platforms that deliver instantly.
+pub fn get_next_digest_time(
+    mode: NotificationDeliveryMode,
+    now: NaiveDateTime,
+) -> Option<NaiveDateTime> {
+    match mode {
+        NotificationDeliveryMode::Instant => None,
+        NotificationDeliveryMode::HourlyDigest => {
+            let hour_start = now.date().and_hms_opt(now.hour(), 0, 0).unwrap();
+            Some(hour_start + Duration::hours(1))
+        }
+        NotificationDeliveryMode::DailyDigest => {
+            let today = now.date().and_time(DAILY_DIGEST_TIME);
+            Some(match today > now {
+                true => today,
+                false => today + Duration::days(1),
+            })
+        }
+    }
+}
+
+/// When the quiet hours surrounding `at` end. Returns `None` if `at` is outside them.
+pub fn get_quiet_hours_end(
+    quiet_hours: Option<&NotificationQuietHours>,
+    at: NaiveDateTime,
+) -> Option<NaiveDateTime> {
+    let quiet_hours = quiet_hours.filter(|q| q.contains(at.time()))?;
+    let end = at.date().and_time(quiet_hours.end_time);
+    Some(match end > at {
+        true => end,
+        false => end + Duration::days(1),
+    })
+}
+
+/// When a notification for `platform` created at `now` should be sent, taking its delivery
+/// mode and quiet hours into account. Returns `None` if it can be sent right away.
+pub fn get_scheduled_delivery_time(
+    platform: &notification_platform::Model,
+    now: NaiveDateTime,
+) -> Option<NaiveDateTime> {
+    let send_at = get_next_digest_time(platform.delivery_mode, now).unwrap_or(now);
+    let send_at = get_quiet_hours_end(platform.quiet_hours.as_ref(), send_at).unwrap_or(send_at);
+    (send_at > now).then_some(send_at)
+}
+
+fn get_retry_delay(attempts: i32) -> Duration {
+    Duration::minutes(2_i64.pow(attempts.clamp(0, 10) as u32))
+}
+
+fn get_local_now(timezone: &Tz) -> NaiveDateTime {
+    Utc::now().with_timezone(timezone).naive_local()
+}
+
+fn local_to_utc(timezone: &Tz, local: NaiveDateTime) -> DateTime<Utc> {
+    timezone
+        .from_local_datetime(&local)
+        .earliest()
+        .map(|d| d.with_timezone(&Utc))
+        .unwrap_or_else(|| local.and_utc())
+}
+
+/// The heading under which an event is listed in a digest, so that several updates about
+/// the same media item end up together.
+fn get_digest_group(content: &UserNotificationContent) -> String {
+    match content {
+        UserNotificationContent::ReviewPosted { entity_title, .. }
+        | UserNotificationContent::MetadataPublished { entity_title, .. }
+        | UserNotificationContent::OutdatedSeenEntries { entity_title, .. }
+        | UserNotificationContent::MetadataStatusChanged { entity_title, .. }
+        | UserNotificationContent::MetadataEpisodeReleased { entity_title, .. }
+        | UserNotificationContent::MetadataEpisodeNameChanged { entity_title, .. }
+        | UserNotificationContent::MetadataReleaseDateChanged { entity_title, .. }
+        | UserNotificationContent::MetadataEpisodeImagesChanged { entity_title, .. }
+        | UserNotificationContent::MetadataNumberOfSeasonsChanged { entity_title, .. }
+        | UserNotificationContent::MetadataChaptersOrEpisodesChanged { entity_title, .. }
+        | UserNotificationContent::MetadataMovedFromCompletedToWatchlistCollection {
+            entity_title,
+            ..
+        }
+        | UserNotificationContent::AutomationRuleTriggered { entity_title, .. } => {
+            entity_title.clone()
+        }
+        UserNotificationContent::PersonMetadataAssociated { person_name, .. }
+        | UserNotificationContent::PersonMetadataGroupAssociated { person_name, .. } => {
+            person_name.clone()
+        }
+        _ => content.to_string().to_case(Case::Title),
+    }
+}
+
+fn render_digest(deliveries: &[notification_delivery::Model]) -> String {
+    deliveries
+        .iter()
+        .into_group_map_by(|d| get_digest_group(&d.content))
+        .into_iter()
+        .sorted_by_key(|(_, items)| items[0].created_on)
+        .map(|(group, items)| {
+            let lines = items.iter().map(|d| format!("- {}", d.message)).join("\n");
+            format!("{group}\n{lines}")
+        })
+        .join("\n\n")
+}
+
+/// Sends the given deliveries for a platform, as a single message if there is one and as a
+/// digest otherwise, and records the outcome on each of them.
+pub async fn attempt_notification_deliveries(
+    ss: &Arc<SupportingService>,
+    platform: &notification_platform::Model,
+    deliveries: Vec<notification_delivery::Model>,
+) -> Result<()> {
+    let result = match deliveries.as_slice() {
+        [] => return Ok(()),
+        [delivery] => {
+            send_notification(
+                platform.platform_specifics.clone(),
+                &ss.config.server.smtp,
+                &delivery.content.to_string().to_case(Case::Title),
+                &delivery.message,
+                Some(&delivery.content),
+            )
+            .await
+        }
+        _ => {
+            send_notification(
+                platform.platform_specifics.clone(),
+                &ss.config.server.smtp,
+                &format!("{} new notifications", deliveries.len()),
+                &render_digest(&deliveries),
+                None,
+            )
+            .await
+        }
+    };
+    let now = Utc::now();
+    if let Err(err) = &result {
+        ryot_log!(
+            debug,
+            "Error delivering notifications to platform {}: {:?}",
+            platform.id,
+            err
+        );
+    }
+    for delivery in deliveries {
+        let attempts = delivery.attempts + 1;
+        let mut delivery = delivery.into_active_model();
+        delivery.attempts = ActiveValue::Set(attempts);
+        match &result {
+            Ok(_) => {
+                delivery.error = ActiveValue::Set(None);
+                delivery.sent_on = ActiveValue::Set(Some(now));
+                delivery.next_attempt_on = ActiveValue::Set(None);
+                delivery.status = ActiveValue::Set(NotificationDeliveryStatus::Sent);
+            }
+            Err(err) => {
+                delivery.error = ActiveValue::Set(Some(err.to_string()));
+                if attempts >= MAXIMUM_DELIVERY_ATTEMPTS {
+                    delivery.next_attempt_on = ActiveValue::Set(None);
+                    delivery.status = ActiveValue::Set(NotificationDeliveryStatus::Failed);
+                } else {
+                    let next_attempt_on = now + get_retry_delay(attempts);
+                    delivery.next_attempt_on = ActiveValue::Set(Some(next_attempt_on));
+                }
+            }
+        }
+        delivery.update(&ss.db).await?;
+    }
+    Ok(())
+}
+
+/// Queues a notification for a platform, sending it straight away unless it has to wait for
+/// a digest or for quiet hours to end.
+pub async fn queue_notification_delivery(
+    ss: &Arc<SupportingService>,
+    platform: &notification_platform::Model,
+    message: String,
+    content: UserNotificationContent,
+) -> Result<()> {
+    let timezone = user_timezone_by_id(&platform.user_id, ss).await?;
+    let scheduled_for = get_scheduled_delivery_time(platform, get_local_now(&timezone));
+    // deliveries sent right away are inserted as already claimed so that the pending
+    // deliveries job does not send them as well
+    let next_attempt_on = scheduled_for.map_or_else(
+        || Utc::now() + DELIVERY_CLAIM_DURATION,
+        |at| local_to_utc(&timezone, at),
+    );
+    let delivery = notification_delivery::ActiveModel {
+        message: ActiveValue::Set(message),
+        event: ActiveValue::Set(content.clone().into()),
+        content: ActiveValue::Set(content),
+        user_id: ActiveValue::Set(platform.user_id.clone()),
+        platform_id: ActiveValue::Set(platform.id.clone()),
+        status: ActiveValue::Set(NotificationDeliveryStatus::Pending),
+        next_attempt_on: ActiveValue::Set(Some(next_attempt_on)),
+        ..Default::default()
+    };
+    let delivery = delivery.insert(&ss.db).await?;
+    if scheduled_for.is_none() {
+        attempt_notification_deliveries(ss, platform, vec![delivery]).await?;
+    }
+    Ok(())
+}
+
+/// Pushes back the next attempt of the given deliveries if they are still due, and returns
+/// the ones that were claimed this way. A concurrent run will not see them as due anymore.
+async fn claim_due_deliveries(
+    ss: &Arc<SupportingService>,
+    deliveries: Vec<notification_delivery::Model>,
+) -> Result<Vec<notification_delivery::Model>> {
+    let now = Utc::now();
+    let claimed = NotificationDelivery::update_many()
+        .col_expr(
+            notification_delivery::Column::NextAttemptOn,
+            Expr::value(now + DELIVERY_CLAIM_DURATION),
+        )
+        .filter(notification_delivery::Column::Id.is_in(deliveries.into_iter().map(|d| d.id)))
+        .filter(notification_delivery::Column::Status.eq(NotificationDeliveryStatus::Pending))
+        .filter(notification_delivery::Column::NextAttemptOn.lte(now))
+        .exec_with_returning(&ss.db)
+        .await?;
+    Ok(claimed
+        .into_iter()
+        .sorted_by_key(|d| d.created_on)
+        .collect())
+}
+
+/// Sends every pending delivery that is due, grouping the ones for the same platform into a
+/// single digest. Deliveries that come due during quiet hours are pushed to their end.
+pub async fn process_pending_notification_deliveries(ss: &Arc<SupportingService>) -> Result<()> {
+    let due_deliveries = NotificationDelivery::find()
+        .filter(notification_delivery::Column::Status.eq(NotificationDeliveryStatus::Pending))
+        .filter(notification_delivery::Column::NextAttemptOn.lte(Utc::now()))
+        .order_by_asc(notification_delivery::Column::CreatedOn)
+        .all(&ss.db)
+        .await?;
+    ryot_log!(
+        debug,
+        "Processing {} due notification deliveries",
+        due_deliveries.len()
+    );
+    for (platform_id, deliveries) in due_deliveries
+        .into_iter()
+        .into_group_map_by(|d| d.platform_id.clone())
+    {
+        let platform = NotificationPlatform::find_by_id(&platform_id)
+            .one(&ss.db)
+            .await?
+            .filter(|p| p.is_disabled != Some(true));
+        let Some(platform) = platform else {
+            NotificationDelivery::update_many()
+                .col_expr(
+                    notification_delivery::Column::Status,
+                    Expr::value(NotificationDeliveryStatus::Skipped),
+                )
+                .col_expr(
+                    notification_delivery::Column::NextAttemptOn,
+                    Expr::value(Option::<DateTime<Utc>>::None),
+                )
+                .filter(
+                    notification_delivery::Column::Id
+                        .is_in(deliveries.iter().map(|d| d.id.clone())),
+                )
+                .exec(&ss.db)
+                .await?;
+            continue;
+        };
+        let timezone = user_timezone_by_id(&platform.user_id, ss).await?;
+        let local_now = get_local_now(&timezone);
+        if let Some(end) = get_quiet_hours_end(platform.quiet_hours.as_ref(), local_now) {
+            NotificationDelivery::update_many()
+                .col_expr(
+                    notification_delivery::Column::NextAttemptOn,
+                    Expr::value(local_to_utc(&timezone, end)),
+                )
+                .filter(
+                    notification_delivery::Column::Id
+                        .is_in(deliveries.iter().map(|d| d.id.clone())),
+                )
+                .exec(&ss.db)
+                .await?;
+            continue;
+        }
+        let deliveries = claim_due_deliveries(ss, deliveries).await?;
+        attempt_notification_deliveries(ss, &platform, deliveries)
+            .await
+            .trace_ok();
+    }
+    Ok(())
+}
@@ -1,23 +1,38 @@
 use std::sync::Arc;
 
-use anyhow::Result;
-use chrono::Utc;
-use common_models::DefaultCollection;
+use anyhow::{Result, bail};
+use background_models::{ApplicationJob, LpApplicationJob};
+use chrono::{DateTime, Duration, Utc};
+use common_models::{DefaultCollection, UserLevelCacheKey};
 use common_utils::ryot_log;
 use database_models::{
     collection_entity_membership, collection_to_entity, notification_platform,
     prelude::{CollectionEntityMembership, CollectionToEntity, NotificationPlatform},
+    user_notification,
 };
 use dependent_entity_utils::{update_metadata, update_metadata_group, update_person};
-use enum_models::{EntityLot, UserNotificationContent};
+use dependent_models::{
+    ApplicationCacheKey, ApplicationCacheValue, NotificationPlatformVerificationCacheValue,
+};
+use enum_models::{AutomationRuleTrigger, EntityLot, UserNotificationContent};
 use itertools::Itertools;
-use media_models::UpdateMediaEntityResult;
-use notification_service::send_notification;
-use sea_orm::{ColumnTrait, EntityTrait, QueryFilter, QuerySelect, prelude::Expr};
+use media_models::{AutomationRuleEvent, UpdateMediaEntityResult};
+use notification_service::send_email;
+use rand::Rng;
+use sea_orm::{
+    ActiveModelTrait, ActiveValue, ColumnTrait, EntityTrait, QueryFilter, QuerySelect,
+    prelude::Expr,
+};
 use supporting_service::SupportingService;
 use traits::TraceOk;
 use uuid::Uuid;
 
+mod delivery;
+#[cfg(test)]
+mod tests;
+
+pub use delivery::process_pending_notification_deliveries;
+
 fn get_entity_details_frontend_url(
     id: String,
     entity_lot: EntityLot,
@@ -129,6 +144,7 @@ async fn get_notification_message(
             entity_title,
             days_threshold,
             last_updated_on,
+            ..
         } => Ok(format!(
             "{} ({}) has been kept {} for more than {} days. Last updated on: {}.",
             entity_title, entity_lot, seen_state, days_threshold, last_updated_on
@@ -137,6 +153,7 @@ async fn get_notification_message(
             old_status,
             new_status,
             entity_title,
+            ..
         } => Ok(format!(
             "Status of {} changed from {} to {}",
             entity_title, old_status, new_status
@@ -146,6 +163,7 @@ async fn get_notification_message(
             old_episode_count,
             new_episode_count,
             season_number,
+            ..
         } => Ok(if let Some(season) = season_number {
             format!(
                 "Number of episodes changed from {} to {} (Season {}) for {}",
@@ -161,6 +179,7 @@ async fn get_notification_message(
             role,
             person_name,
             metadata_title,
+            ..
         } => Ok(format!(
             "{} has been associated with {} as {}",
             person_name, metadata_title, role
@@ -171,6 +190,7 @@ async fn get_notification_message(
             new_date,
             season_number,
             episode_number,
+            ..
         } => Ok(
             if let (Some(season), Some(episode)) = (season_number, episode_number) {
                 format!(
@@ -190,6 +210,7 @@ async fn get_notification_message(
             new_name,
             season_number,
             episode_number,
+            ..
         } => Ok(if let Some(season) = season_number {
             format!(
                 "Episode name changed from {} to {} (S{}E{}) for {}",
@@ -205,6 +226,7 @@ async fn get_notification_message(
             entity_title,
             season_number,
             episode_number,
+            ..
         } => Ok(if let Some(season) = season_number {
             format!(
                 "Episode image changed for S{}E{} in {}",
@@ -220,6 +242,7 @@ async fn get_notification_message(
             role,
             person_name,
             metadata_group_title,
+            ..
         } => Ok(format!(
             "{} has been associated with {} as {}",
             person_name, metadata_group_title, role
@@ -228,6 +251,7 @@ async fn get_notification_message(
             entity_title,
             old_seasons,
             new_seasons,
+            ..
         } => Ok(format!(
             "Number of seasons changed from {} to {} for {}",
             old_seasons, new_seasons, entity_title
@@ -237,6 +261,7 @@ async fn get_notification_message(
             old_count,
             new_count,
             content_type,
+            ..
         } => Ok(format!(
             "Number of {} changed from {} to {} for {}",
             content_type, old_count, new_count, entity_title
@@ -261,6 +286,104 @@ async fn get_notification_message(
                 entity_title, url
             ))
         }
+        UserNotificationContent::AutomationRuleTriggered {
+            message,
+            rule_name,
+            entity_id,
+            entity_lot,
+            entity_title,
+        } => Ok(match message {
+            Some(message) => message,
+            None => {
+                let url = get_entity_details_frontend_url(entity_id, entity_lot, None, ss);
+                format!(
+                    "Automation rule {} was triggered for {} ({})",
+                    rule_name, entity_title, url
+                )
+            }
+        }),
+    }
+}
+
+/// The entity a notification is about, if it carries enough information to link to it.
+pub(crate) fn get_notification_entity(
+    content: &UserNotificationContent,
+) -> Option<(String, EntityLot)> {
+    match content {
+        UserNotificationContent::ReviewPosted {
+            entity_id,
+            entity_lot,
+            ..
+        }
+        | UserNotificationContent::MetadataPublished {
+            entity_id,
+            entity_lot,
+            ..
+        }
+        | UserNotificationContent::MetadataMovedFromCompletedToWatchlistCollection {
+            entity_id,
+            entity_lot,
+            ..
+        }
+        | UserNotificationContent::AutomationRuleTriggered {
+            entity_id,
+            entity_lot,
+            ..
+        } => Some((entity_id.clone(), *entity_lot)),
+        UserNotificationContent::OutdatedSeenEntries {
+            entity_id,
+            entity_lot,
+            ..
+        }
+        | UserNotificationContent::MetadataStatusChanged {
+            entity_id,
+            entity_lot,
+            ..
+        }
+        | UserNotificationContent::MetadataEpisodeReleased {
+            entity_id,
+            entity_lot,
+            ..
+        }
+        | UserNotificationContent::PersonMetadataAssociated {
+            entity_id,
+            entity_lot,
+            ..
+        }
+        | UserNotificationContent::MetadataReleaseDateChanged {
+            entity_id,
+            entity_lot,
+            ..
+        }
+        | UserNotificationContent::MetadataEpisodeNameChanged {
+            entity_id,
+            entity_lot,
+            ..
+        }
+        | UserNotificationContent::MetadataEpisodeImagesChanged {
+            entity_id,
+            entity_lot,
+            ..
+        }
+        | UserNotificationContent::PersonMetadataGroupAssociated {
+            entity_id,
+            entity_lot,
+            ..
+        }
+        | UserNotificationContent::MetadataNumberOfSeasonsChanged {
+            entity_id,
+            entity_lot,
+            ..
+        }
+        | UserNotificationContent::MetadataChaptersOrEpisodesChanged {
+            entity_id,
+            entity_lot,
+            ..
+        } => entity_id.clone().map(|id| (id, *entity_lot)),
+        UserNotificationContent::NewWorkoutCreated { workout_id, .. } => {
+            Some((workout_id.clone(), EntityLot::Workout))
+        }
+        _ => None,
     }
 }
 
@@ -269,6 +392,18 @@ pub async fn send_notification_for_user(
     ss: &Arc<SupportingService>,
     notification: UserNotificationContent,
 ) -> Result<()> {
+    let msg = get_notification_message(notification.clone(), ss).await?;
+    let entity = get_notification_entity(&notification);
+    let inbox_notification = user_notification::ActiveModel {
+        message: ActiveValue::Set(msg.clone()),
+        user_id: ActiveValue::Set(user_id.clone()),
+        content: ActiveValue::Set(notification.clone()),
+        event: ActiveValue::Set(notification.clone().into()),
+        entity_lot: ActiveValue::Set(entity.as_ref().map(|(_, lot)| *lot)),
+        entity_id: ActiveValue::Set(entity.map(|(id, _)| id)),
+        ..Default::default()
+    };
+    inbox_notification.insert(&ss.db).await?;
     let notification_platforms = NotificationPlatform::find()
         .filter(notification_platform::Column::UserId.eq(user_id))
         .filter(
@@ -278,10 +413,9 @@ pub async fn send_notification_for_user(
         )
         .all(&ss.db)
         .await?;
-    let msg = get_notification_message(notification.clone(), ss).await?;
     for platform in notification_platforms {
-        let notification = notification.clone().into();
-        if !platform.configured_events.contains(&notification) {
+        let event = notification.clone().into();
+        if !platform.configured_events.contains(&event) {
             ryot_log!(
                 debug,
                 "Skipping sending notification to user: {} for platform: {} since it is not configured for this event",
@@ -290,9 +424,9 @@ pub async fn send_notification_for_user(
             );
             continue;
         }
-        if let Err(err) = send_notification(platform.platform_specifics, &msg).await {
-            ryot_log!(trace, "Error sending notification: {:?}", err);
-        }
+        delivery::queue_notification_delivery(ss, &platform, msg.clone(), notification.clone())
+            .await
+            .trace_ok();
     }
     Ok(())
 }
@@ -356,6 +490,22 @@ pub async fn update_person_and_notify_users(
                     .trace_ok();
             }
         }
+        for metadata_id in result.credited_metadata_ids.iter() {
+            for (user_id, _) in users_to_notify.iter() {
+                ss.perform_application_job(ApplicationJob::Lp(
+                    LpApplicationJob::HandleAutomationRuleEvent(AutomationRuleEvent {
+                        collection_name: None,
+                        user_id: user_id.clone(),
+                        entity_lot: EntityLot::Metadata,
+                        entity_id: metadata_id.clone(),
+                        trigger: AutomationRuleTrigger::MonitoredPersonCredited,
+                        triggered_by_automation: false,
+                    }),
+                ))
+                .await
+                .trace_ok();
+            }
+        }
     }
     Ok(result)
 }
@@ -382,3 +532,75 @@ pub async fn update_metadata_group_and_notify_users(
     }
     Ok(result)
 }
+
+/// The number of wrong codes after which a verification code stops being accepted.
+pub const MAX_VERIFICATION_ATTEMPTS: u8 = 5;
+
+/// The minimum time between two verification emails sent to the same platform.
+const VERIFICATION_RESEND_COOLDOWN: Duration = Duration::minutes(1);
+
+/// Checks whether a new verification code can be sent given the one sent before it, and
+/// returns the number of failed attempts that carries over to the new code.
+pub(crate) fn get_verification_failed_attempts(
+    previous: Option<&NotificationPlatformVerificationCacheValue>,
+    now: DateTime<Utc>,
+) -> Result<u8> {
+    let Some(previous) = previous else {
+        return Ok(0);
+    };
+    if previous.failed_attempts >= MAX_VERIFICATION_ATTEMPTS {
+        bail!("Too many invalid attempts, please try again later");
+    }
+    if previous
+        .sent_at
+        .is_some_and(|sent_at| now - sent_at < VERIFICATION_RESEND_COOLDOWN)
+    {
+        bail!("A verification code was sent recently, please wait before requesting a new one");
+    }
+    Ok(previous.failed_attempts)
+}
+
+/// Send a code to the address of an email notification platform which the user has to enter
+/// before the platform can be enabled.
+pub async fn send_notification_platform_verification(
+    ss: &Arc<SupportingService>,
+    user_id: &String,
+    notification_id: &String,
+    email: &str,
+) -> Result<()> {
+    let cache_key = ApplicationCacheKey::NotificationPlatformVerification(UserLevelCacheKey {
+        user_id: user_id.to_owned(),
+        input: notification_id.to_owned(),
+    });
+    let previous = cache_service::get_value::<NotificationPlatformVerificationCacheValue>(
+        ss,
+        cache_key.clone(),
+    )
+    .await
+    .map(|(_, value)| value);
+    let now = Utc::now();
+    let failed_attempts = get_verification_failed_attempts(previous.as_ref(), now)?;
+    let code = rand::rng().random_range(100_000..1_000_000).to_string();
+    send_email(
+        &ss.config.server.smtp,
+        email,
+        "Verify your email address",
+        &format!(
+            "Your verification code is {code}. Enter it within the next hour to start receiving notifications at this address."
+        ),
+    )
+    .await?;
+    cache_service::set_key(
+        ss,
+        cache_key,
+        ApplicationCacheValue::NotificationPlatformVerification(
+            NotificationPlatformVerificationCacheValue {
+                code,
+                failed_attempts,
+                sent_at: Some(now),
+            },
+        ),
+    )
+    .await?;
+    Ok(())
+}
@@ -0,0 +1,66 @@
+use enum_models::NotificationDeliveryMode;
+use media_models::NotificationQuietHours;
+
+use super::*;
+use crate::delivery::{get_next_digest_time, get_quiet_hours_end};
+
+#[test]
+fn test_instant_delivery_has_no_digest_time() {
+    assert_eq!(
+        get_next_digest_time(NotificationDeliveryMode::Instant, at(28, 3, 15)),
+        None
+    );
+}
+
+#[rstest]
+#[case(at(28, 3, 15), at(28, 4, 0))]
+#[case(at(28, 3, 0), at(28, 4, 0))]
+#[case(at(28, 23, 59), at(29, 0, 0))]
+fn test_hourly_digest_is_sent_at_the_next_hour(
+    #[case] now: NaiveDateTime,
+    #[case] expected: NaiveDateTime,
+) {
+    assert_eq!(
+        get_next_digest_time(NotificationDeliveryMode::HourlyDigest, now),
+        Some(expected)
+    );
+}
+
+#[rstest]
+#[case(at(28, 3, 15), at(28, 9, 0))]
+#[case(at(28, 9, 0), at(29, 9, 0))]
+#[case(at(28, 18, 30), at(29, 9, 0))]
+fn test_daily_digest_is_sent_at_the_next_morning(
+    #[case] now: NaiveDateTime,
+    #[case] expected: NaiveDateTime,
+) {
+    assert_eq!(
+        get_next_digest_time(NotificationDeliveryMode::DailyDigest, now),
+        Some(expected)
+    );
+}
+
+#[rstest]
+#[case(time(22, 0), time(7, 0), at(28, 3, 0), Some(at(28, 7, 0)))]
+#[case(time(22, 0), time(7, 0), at(28, 23, 0), Some(at(29, 7, 0)))]
+#[case(time(22, 0), time(7, 0), at(28, 7, 0), None)]
+#[case(time(22, 0), time(7, 0), at(28, 12, 0), None)]
+#[case(time(13, 0), time(15, 0), at(28, 14, 0), Some(at(28, 15, 0)))]
+#[case(time(13, 0), time(15, 0), at(28, 12, 59), None)]
+fn test_quiet_hours_end(
+    #[case] start_time: NaiveTime,
+    #[case] end_time: NaiveTime,
+    #[case] now: NaiveDateTime,
+    #[case] expected: Option<NaiveDateTime>,
+) {
+    let quiet_hours = NotificationQuietHours {
+        start_time,
+        end_time,
+    };
+    assert_eq!(get_quiet_hours_end(Some(&quiet_hours), now), expected);
+}
+
+#[test]
+fn test_no_quiet_hours_never_defers() {
+    assert_eq!(get_quiet_hours_end(None, at(28, 3, 0)), None);
+}
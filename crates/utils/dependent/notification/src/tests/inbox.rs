@@ -0,0 +1,74 @@
+use enum_models::{EntityLot, UserNotificationContent};
+use serde_json::json;
+
+use super::*;
+use crate::get_notification_entity;
+
+fn status_changed(entity_id: Option<&str>) -> UserNotificationContent {
+    UserNotificationContent::MetadataStatusChanged {
+        entity_id: entity_id.map(str::to_owned),
+        entity_lot: EntityLot::Metadata,
+        old_status: "Ongoing".to_owned(),
+        new_status: "Ended".to_owned(),
+        entity_title: "Dark".to_owned(),
+    }
+}
+
+#[rstest]
+#[case(status_changed(Some("met_1")), Some(("met_1", EntityLot::Metadata)))]
+#[case(status_changed(None), None)]
+#[case(
+    UserNotificationContent::PersonMetadataAssociated {
+        entity_id: Some("per_1".to_owned()),
+        entity_lot: EntityLot::Person,
+        role: "Director".to_owned(),
+        person_name: "Baran bo Odar".to_owned(),
+        metadata_title: "Dark".to_owned(),
+    },
+    Some(("per_1", EntityLot::Person))
+)]
+#[case(
+    UserNotificationContent::ReviewPosted {
+        entity_id: "met_2".to_owned(),
+        entity_title: "1899".to_owned(),
+        entity_lot: EntityLot::Metadata,
+        triggered_by_username: "jane".to_owned(),
+    },
+    Some(("met_2", EntityLot::Metadata))
+)]
+#[case(
+    UserNotificationContent::NewWorkoutCreated {
+        workout_id: "wor_1".to_owned(),
+        workout_name: "Push day".to_owned(),
+    },
+    Some(("wor_1", EntityLot::Workout))
+)]
+#[case(
+    UserNotificationContent::NotificationFromReminderCollection {
+        reminder_text: "Watch Dark".to_owned(),
+    },
+    None
+)]
+fn test_get_notification_entity(
+    #[case] content: UserNotificationContent,
+    #[case] expected: Option<(&str, EntityLot)>,
+) {
+    assert_eq!(
+        get_notification_entity(&content),
+        expected.map(|(id, lot)| (id.to_owned(), lot))
+    );
+}
+
+#[test]
+fn test_notification_stored_without_entity_has_no_link() {
+    let content: UserNotificationContent = serde_json::from_value(json!({
+        "MetadataStatusChanged": {
+            "old_status": "Ongoing",
+            "new_status": "Ended",
+            "entity_title": "Dark",
+        }
+    }))
+    .unwrap();
+    assert_eq!(content, status_changed(None));
+    assert_eq!(get_notification_entity(&content), None);
+}
@@ -0,0 +1,17 @@
+use chrono::{NaiveDate, NaiveDateTime, NaiveTime};
+use rstest::rstest;
+
+mod delivery;
+mod inbox;
+mod verification;
+
+fn at(day: u32, hour: u32, minute: u32) -> NaiveDateTime {
+    NaiveDate::from_ymd_opt(2025, 10, day)
+        .unwrap()
+        .and_hms_opt(hour, minute, 0)
+        .unwrap()
+}
+
+fn time(hour: u32, minute: u32) -> NaiveTime {
+    NaiveTime::from_hms_opt(hour, minute, 0).unwrap()
+}
@@ -0,0 +1,52 @@
+use chrono::{DateTime, Duration, TimeZone, Utc};
+use dependent_models::NotificationPlatformVerificationCacheValue;
+
+use crate::{MAX_VERIFICATION_ATTEMPTS, get_verification_failed_attempts};
+
+fn sent(minutes_ago: i64, failed_attempts: u8) -> NotificationPlatformVerificationCacheValue {
+    NotificationPlatformVerificationCacheValue {
+        failed_attempts,
+        code: "123456".to_owned(),
+        sent_at: Some(now() - Duration::minutes(minutes_ago)),
+    }
+}
+
+fn now() -> DateTime<Utc> {
+    Utc.with_ymd_and_hms(2025, 10, 28, 12, 0, 0).unwrap()
+}
+
+#[test]
+fn test_first_verification_starts_without_failed_attempts() {
+    assert_eq!(get_verification_failed_attempts(None, now()).unwrap(), 0);
+}
+
+#[test]
+fn test_resend_is_rejected_during_cooldown() {
+    assert!(get_verification_failed_attempts(Some(&sent(0, 0)), now()).is_err());
+}
+
+#[test]
+fn test_resend_keeps_failed_attempts() {
+    assert_eq!(
+        get_verification_failed_attempts(Some(&sent(5, 3)), now()).unwrap(),
+        3
+    );
+}
+
+#[test]
+fn test_resend_is_rejected_after_too_many_attempts() {
+    let previous = sent(30, MAX_VERIFICATION_ATTEMPTS);
+    assert!(get_verification_failed_attempts(Some(&previous), now()).is_err());
+}
+
+#[test]
+fn test_codes_cached_before_the_cooldown_can_be_resent() {
+    let previous = NotificationPlatformVerificationCacheValue {
+        sent_at: None,
+        ..sent(0, 1)
+    };
+    assert_eq!(
+        get_verification_failed_attempts(Some(&previous), now()).unwrap(),
+        1
+    );
+}
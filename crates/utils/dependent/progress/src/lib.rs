@@ -1,11 +1,14 @@
-use std::sync::Arc;
+use std::{collections::HashSet, sync::Arc};
 
 use anyhow::{Result, anyhow, bail};
 use background_models::{ApplicationJob, LpApplicationJob};
 use chrono::Utc;
 use common_models::UserLevelCacheKey;
 use common_utils::ryot_log;
-use database_models::{prelude::Seen, seen};
+use database_models::{
+    prelude::{Seen, WatchGroupMember},
+    seen, watch_group_member,
+};
 use dependent_details_utils::metadata_details;
 use dependent_models::{
     ApplicationCacheKey, ApplicationCacheValue, EmptyCacheValue, ExpireCacheKeyInput,
@@ -19,7 +22,8 @@ use media_models::{
     MetadataProgressUpdateInput, MetadataProgressUpdateNewInProgressInput,
     MetadataProgressUpdateStartedAndFinishedOnDateInput,
     MetadataProgressUpdateStartedOrFinishedOnDateInput, SeenAnimeExtraInformation,
-    SeenMangaExtraInformation, SeenPodcastExtraInformation, SeenShowExtraInformation,
+    SeenBoardGameExtraInformation, SeenMangaExtraInformation, SeenPodcastExtraInformation,
+    SeenShowExtraInformation,
 };
 use rust_decimal::{Decimal, dec};
 use sea_orm::{
@@ -28,6 +32,9 @@ use sea_orm::{
 };
 use supporting_service::SupportingService;
 
+#[cfg(test)]
+mod tests;
+
 pub async fn commit_import_seen_item(
     is_import: bool,
     user_id: &String,
@@ -42,6 +49,9 @@ pub async fn commit_import_seen_item(
         show_episode_number: input.show_episode_number,
         anime_episode_number: input.anime_episode_number,
         manga_chapter_number: input.manga_chapter_number,
+        board_game_winner: input.board_game_winner,
+        board_game_duration: input.board_game_duration,
+        board_game_player_count: input.board_game_player_count,
         providers_consumed_on: input.providers_consumed_on,
         podcast_episode_number: input.podcast_episode_number,
     };
@@ -120,7 +130,7 @@ pub async fn commit_import_seen_item(
                 started_on: Utc::now(),
             },
         );
-        metadata_progress_update(
+        update_and_mirror_metadata_progress(
             user_id,
             ss,
             MetadataProgressUpdateInput {
@@ -138,7 +148,7 @@ pub async fn commit_import_seen_item(
         ryot_log!(debug, "Updating in-progress seen for: {}", metadata_id);
         let change = MetadataProgressUpdateChange::ChangeLatestInProgress(progress);
 
-        metadata_progress_update(
+        update_and_mirror_metadata_progress(
             user_id,
             ss,
             MetadataProgressUpdateInput {
@@ -174,6 +184,7 @@ struct ExtraInformation {
     anime_ei: Option<SeenAnimeExtraInformation>,
     manga_ei: Option<SeenMangaExtraInformation>,
     podcast_ei: Option<SeenPodcastExtraInformation>,
+    board_game_ei: Option<SeenBoardGameExtraInformation>,
 }
 
 fn create_extra_information(
@@ -218,11 +229,21 @@ fn create_extra_information(
         _ => None,
     };
 
+    let board_game_ei = match media_lot {
+        MediaLot::BoardGame => Some(SeenBoardGameExtraInformation {
+            winner: payload.board_game_winner.clone(),
+            duration: payload.board_game_duration,
+            player_count: payload.board_game_player_count,
+        }),
+        _ => None,
+    };
+
     Ok(ExtraInformation {
         show_ei,
         anime_ei,
         manga_ei,
         podcast_ei,
+        board_game_ei,
     })
 }
 
@@ -235,6 +256,7 @@ struct CommitInput<'a> {
     ss: &'a Arc<SupportingService>,
     started_on: Option<DateTimeUtc>,
     finished_on: Option<DateTimeUtc>,
+    mirrored_from_user_id: Option<String>,
     payload: MetadataProgressUpdateCommonInput,
 }
 
@@ -252,7 +274,9 @@ async fn commit(input: CommitInput<'_>) -> Result<seen::Model> {
         anime_extra_information: ActiveValue::Set(extra_info.anime_ei),
         manga_extra_information: ActiveValue::Set(extra_info.manga_ei),
         podcast_extra_information: ActiveValue::Set(extra_info.podcast_ei),
+        board_game_extra_information: ActiveValue::Set(extra_info.board_game_ei),
         manual_time_spent: ActiveValue::Set(input.payload.manual_time_spent),
+        mirrored_from_user_id: ActiveValue::Set(input.mirrored_from_user_id),
         providers_consumed_on: ActiveValue::Set(
             input.payload.providers_consumed_on.unwrap_or_default(),
         ),
@@ -290,6 +314,15 @@ pub async fn metadata_progress_update(
     user_id: &String,
     ss: &Arc<SupportingService>,
     input: MetadataProgressUpdateInput,
+) -> Result<()> {
+    commit_metadata_progress_update(user_id, ss, input, None).await
+}
+
+async fn commit_metadata_progress_update(
+    user_id: &String,
+    ss: &Arc<SupportingService>,
+    input: MetadataProgressUpdateInput,
+    mirrored_from_user_id: Option<String>,
 ) -> Result<()> {
     let meta = metadata_details(ss, &input.metadata_id).await?.response;
     ryot_log!(debug, "Metadata progress update: {:?}", input);
@@ -365,6 +398,7 @@ pub async fn metadata_progress_update(
                 finished_on: None,
                 metadata_lot: meta.lot,
                 state: SeenState::InProgress,
+                mirrored_from_user_id,
                 metadata_id: &input.metadata_id,
                 payload: create_new_in_progress.data,
                 started_on: Some(create_new_in_progress.started_on),
@@ -398,6 +432,7 @@ pub async fn metadata_progress_update(
                 finished_on,
                 progress: dec!(100),
                 metadata_lot: meta.lot,
+                mirrored_from_user_id,
                 state: SeenState::Completed,
                 metadata_id: &input.metadata_id,
             })
@@ -409,3 +444,130 @@ pub async fn metadata_progress_update(
     ryot_log!(debug, "Progress update completed: {}", input.metadata_id);
     Ok(())
 }
+
+/// Whether a progress update is shared with watch group members. Only new seen entries and
+/// completions are, intermediate progress only concerns the user who made it.
+pub(crate) fn is_mirrored_progress_change(change: &MetadataProgressUpdateChange) -> bool {
+    match change {
+        MetadataProgressUpdateChange::CreateNewInProgress(_)
+        | MetadataProgressUpdateChange::CreateNewCompleted(_) => true,
+        MetadataProgressUpdateChange::ChangeLatestInProgress(progress) => *progress >= dec!(100),
+        MetadataProgressUpdateChange::ChangeLatestState(state) => *state == SeenState::Completed,
+    }
+}
+
+/// The members that receive a progress update for the given media. Only accepted members take
+/// part, and a member who excluded the media neither receives nor sends progress for it
+/// through that group.
+pub(crate) fn get_mirror_recipients(
+    own_memberships: &[watch_group_member::Model],
+    group_members: &[watch_group_member::Model],
+    metadata_id: &String,
+) -> HashSet<String> {
+    let takes_part = |m: &watch_group_member::Model| {
+        m.accepted_on.is_some() && !m.excluded_metadata_ids.contains(metadata_id)
+    };
+    own_memberships
+        .iter()
+        .filter(|own| takes_part(own))
+        .flat_map(|own| {
+            group_members.iter().filter(move |m| {
+                m.watch_group_id == own.watch_group_id && m.user_id != own.user_id && takes_part(m)
+            })
+        })
+        .map(|m| m.user_id.clone())
+        .collect()
+}
+
+async fn update_and_mirror_metadata_progress(
+    user_id: &String,
+    ss: &Arc<SupportingService>,
+    input: MetadataProgressUpdateInput,
+) -> Result<()> {
+    metadata_progress_update(user_id, ss, input.clone()).await?;
+    deploy_job_to_mirror_metadata_progress_updates(user_id, vec![input], ss).await
+}
+
+pub async fn deploy_job_to_mirror_metadata_progress_updates(
+    user_id: &String,
+    input: Vec<MetadataProgressUpdateInput>,
+    ss: &Arc<SupportingService>,
+) -> Result<()> {
+    let input = input
+        .into_iter()
+        .filter(|i| is_mirrored_progress_change(&i.change))
+        .collect::<Vec<_>>();
+    if input.is_empty() {
+        return Ok(());
+    }
+    ss.perform_application_job(ApplicationJob::Lp(
+        LpApplicationJob::MirrorMetadataProgressUpdates(user_id.to_owned(), input),
+    ))
+    .await?;
+    Ok(())
+}
+
+/// Apply progress updates made by a user to the other members of their watch groups. A
+/// completion of the latest seen entry is only mirrored to members who have one in progress.
+pub async fn mirror_metadata_progress_updates(
+    user_id: &String,
+    input: Vec<MetadataProgressUpdateInput>,
+    ss: &Arc<SupportingService>,
+) -> Result<()> {
+    let own_memberships = WatchGroupMember::find()
+        .filter(watch_group_member::Column::UserId.eq(user_id))
+        .filter(watch_group_member::Column::AcceptedOn.is_not_null())
+        .all(&ss.db)
+        .await?;
+    if own_memberships.is_empty() {
+        return Ok(());
+    }
+    let group_members = WatchGroupMember::find()
+        .filter(
+            watch_group_member::Column::WatchGroupId
+                .is_in(own_memberships.iter().map(|m| m.watch_group_id.clone())),
+        )
+        .filter(watch_group_member::Column::UserId.ne(user_id))
+        .filter(watch_group_member::Column::AcceptedOn.is_not_null())
+        .all(&ss.db)
+        .await?;
+    for update in input {
+        if !is_mirrored_progress_change(&update.change) {
+            continue;
+        }
+        let completes_latest = matches!(
+            update.change,
+            MetadataProgressUpdateChange::ChangeLatestInProgress(_)
+                | MetadataProgressUpdateChange::ChangeLatestState(_)
+        );
+        for member_id in
+            get_mirror_recipients(&own_memberships, &group_members, &update.metadata_id)
+        {
+            if completes_latest
+                && get_previous_seen_item(&member_id, &update.metadata_id, false, ss)
+                    .await?
+                    .is_none()
+            {
+                ryot_log!(debug, "{} has no progress to complete", member_id);
+                continue;
+            }
+            ryot_log!(debug, "Mirroring progress of {} to {}", user_id, member_id);
+            if let Err(err) = commit_metadata_progress_update(
+                &member_id,
+                ss,
+                update.clone(),
+                Some(user_id.to_owned()),
+            )
+            .await
+            {
+                ryot_log!(
+                    warn,
+                    "Could not mirror progress to {}: {:?}",
+                    member_id,
+                    err
+                );
+            }
+        }
+    }
+    Ok(())
+}
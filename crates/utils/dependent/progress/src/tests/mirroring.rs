@@ -0,0 +1,99 @@
+use super::*;
+use crate::{get_mirror_recipients, is_mirrored_progress_change};
+
+fn recipients(
+    own: &[watch_group_member::Model],
+    members: &[watch_group_member::Model],
+) -> Vec<String> {
+    let mut recipients = get_mirror_recipients(own, members, &"met_1".to_owned())
+        .into_iter()
+        .collect::<Vec<_>>();
+    recipients.sort();
+    recipients
+}
+
+#[rstest]
+#[case(
+    MetadataProgressUpdateChange::CreateNewInProgress(
+        MetadataProgressUpdateNewInProgressInput::default()
+    ),
+    true
+)]
+#[case(
+    MetadataProgressUpdateChange::CreateNewCompleted(
+        MetadataProgressUpdateChangeCreateNewCompletedInput::WithoutDates(Default::default())
+    ),
+    true
+)]
+#[case(MetadataProgressUpdateChange::ChangeLatestInProgress(dec!(100)), true)]
+#[case(MetadataProgressUpdateChange::ChangeLatestInProgress(dec!(42.5)), false)]
+#[case(
+    MetadataProgressUpdateChange::ChangeLatestState(SeenState::Completed),
+    true
+)]
+#[case(
+    MetadataProgressUpdateChange::ChangeLatestState(SeenState::OnAHold),
+    false
+)]
+#[case(
+    MetadataProgressUpdateChange::ChangeLatestState(SeenState::Dropped),
+    false
+)]
+fn test_only_new_seen_entries_and_completions_are_mirrored(
+    #[case] change: MetadataProgressUpdateChange,
+    #[case] expected: bool,
+) {
+    assert_eq!(is_mirrored_progress_change(&change), expected);
+}
+
+#[test]
+fn test_progress_is_mirrored_to_accepted_members_of_shared_groups() {
+    let own = [member("usr_1", "wgr_1", true, &[])];
+    let members = [
+        member("usr_2", "wgr_1", true, &[]),
+        member("usr_3", "wgr_1", false, &[]),
+        member("usr_4", "wgr_2", true, &[]),
+    ];
+    assert_eq!(recipients(&own, &members), ["usr_2"]);
+}
+
+#[test]
+fn test_progress_is_not_mirrored_from_a_pending_membership() {
+    let own = [member("usr_1", "wgr_1", false, &[])];
+    let members = [member("usr_2", "wgr_1", true, &[])];
+    assert!(recipients(&own, &members).is_empty());
+}
+
+#[test]
+fn test_members_who_excluded_the_media_do_not_receive_it() {
+    let own = [member("usr_1", "wgr_1", true, &[])];
+    let members = [
+        member("usr_2", "wgr_1", true, &["met_1"]),
+        member("usr_3", "wgr_1", true, &["met_2"]),
+    ];
+    assert_eq!(recipients(&own, &members), ["usr_3"]);
+}
+
+#[test]
+fn test_media_excluded_in_one_group_is_still_mirrored_through_another() {
+    let own = [
+        member("usr_1", "wgr_1", true, &["met_1"]),
+        member("usr_1", "wgr_2", true, &[]),
+    ];
+    let members = [
+        member("usr_2", "wgr_1", true, &[]),
+        member("usr_2", "wgr_2", true, &[]),
+        member("usr_3", "wgr_1", true, &[]),
+    ];
+    assert_eq!(recipients(&own, &members), ["usr_2"]);
+}
+
+#[test]
+fn test_progress_is_not_mirrored_back_to_the_user() {
+    let own = [member("usr_1", "wgr_1", true, &[])];
+    let members = [
+        member("usr_1", "wgr_1", true, &[]),
+        member("usr_2", "wgr_1", true, &[]),
+    ];
+    assert_eq!(recipients(&own, &members), ["usr_2"]);
+}
@@ -0,0 +1,29 @@
+use database_models::watch_group_member;
+use enum_models::SeenState;
+use media_models::{
+    MetadataProgressUpdateChange, MetadataProgressUpdateChangeCreateNewCompletedInput,
+    MetadataProgressUpdateNewInProgressInput,
+};
+use rstest::rstest;
+use rust_decimal::dec;
+
+mod mirroring;
+
+fn member(
+    user_id: &str,
+    watch_group_id: &str,
+    accepted: bool,
+    excluded_metadata_ids: &[&str],
+) -> watch_group_member::Model {
+    watch_group_member::Model {
+        id: format!("wgm_{user_id}_{watch_group_id}"),
+        user_id: user_id.to_owned(),
+        joined_on: Default::default(),
+        accepted_on: accepted.then(Default::default),
+        watch_group_id: watch_group_id.to_owned(),
+        excluded_metadata_ids: excluded_metadata_ids
+            .iter()
+            .map(|id| id.to_string())
+            .collect(),
+    }
+}
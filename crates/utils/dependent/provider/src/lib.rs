@@ -3,6 +3,10 @@ use std::sync::Arc;
 use anilist_provider::{AnilistAnimeService, AnilistMangaService, NonMediaAnilistService};
 use anyhow::{Result, anyhow, bail};
 use audible_provider::AudibleService;
+use bangumi_provider::{BangumiSubjectService, NonMediaBangumiService};
+use board_game_geek_provider::BoardGameGeekService;
+use comic_vine_provider::ComicVineService;
+use douban_provider::{DoubanMovieService, DoubanShowService, NonMediaDoubanService};
 use enum_models::{MediaLot, MediaSource};
 use giant_bomb_provider::GiantBombService;
 use google_books_provider::GoogleBooksService;
@@ -12,6 +16,7 @@ use itunes_provider::ITunesService;
 use listennotes_provider::ListennotesService;
 use manga_updates_provider::MangaUpdatesService;
 use media_models::MetadataDetails;
+use musicbrainz_provider::MusicBrainzService;
 use myanimelist_provider::{MalAnimeService, MalMangaService, NonMediaMalService};
 use openlibrary_provider::OpenlibraryService;
 use spotify_provider::SpotifyService;
@@ -75,6 +80,17 @@ pub async fn get_metadata_provider(
             MediaLot::Movie => Box::new(TmdbMovieService::new(ss.clone()).await?),
             _ => return err(),
         },
+        MediaSource::Bangumi => match lot {
+            MediaLot::Anime | MediaLot::Manga | MediaLot::VideoGame | MediaLot::VisualNovel => {
+                Box::new(BangumiSubjectService::new(lot, &ss.config.anime_and_manga.bangumi).await?)
+            }
+            _ => return err(),
+        },
+        MediaSource::Douban => match lot {
+            MediaLot::Show => Box::new(DoubanShowService::new().await?),
+            MediaLot::Movie => Box::new(DoubanMovieService::new().await?),
+            _ => return err(),
+        },
         MediaSource::Anilist => match lot {
             MediaLot::Anime => {
                 Box::new(AnilistAnimeService::new(&ss.config.anime_and_manga.anilist).await?)
@@ -95,6 +111,12 @@ pub async fn get_metadata_provider(
         },
         MediaSource::Igdb => Box::new(IgdbService::new(ss.clone()).await?),
         MediaSource::GiantBomb => Box::new(GiantBombService::new(ss.clone()).await?),
+        MediaSource::ComicVine => {
+            Box::new(ComicVineService::new(&ss.config.comics.comic_vine).await?)
+        }
+        MediaSource::BoardGameGeek => {
+            Box::new(BoardGameGeekService::new(&ss.config.board_games.board_game_geek).await?)
+        }
         MediaSource::MangaUpdates => {
             Box::new(MangaUpdatesService::new(&ss.config.anime_and_manga.manga_updates).await?)
         }
@@ -102,6 +124,7 @@ pub async fn get_metadata_provider(
         MediaSource::Spotify => {
             Box::new(SpotifyService::new(&ss.config.music.spotify, ss.clone()).await?)
         }
+        MediaSource::MusicBrainz => Box::new(MusicBrainzService::new().await?),
     };
     Ok(service)
 }
@@ -125,6 +148,12 @@ pub async fn get_non_metadata_provider(
         MediaSource::Listennotes => Box::new(ListennotesService::new(ss.clone()).await?),
         MediaSource::Igdb => Box::new(IgdbService::new(ss.clone()).await?),
         MediaSource::GiantBomb => Box::new(GiantBombService::new(ss.clone()).await?),
+        MediaSource::ComicVine => {
+            Box::new(ComicVineService::new(&ss.config.comics.comic_vine).await?)
+        }
+        MediaSource::BoardGameGeek => {
+            Box::new(BoardGameGeekService::new(&ss.config.board_games.board_game_geek).await?)
+        }
         MediaSource::MangaUpdates => {
             Box::new(MangaUpdatesService::new(&ss.config.anime_and_manga.manga_updates).await?)
         }
@@ -133,9 +162,14 @@ pub async fn get_non_metadata_provider(
             Box::new(NonMediaAnilistService::new(&ss.config.anime_and_manga.anilist).await?)
         }
         MediaSource::Myanimelist => Box::new(NonMediaMalService::new().await?),
+        MediaSource::Douban => Box::new(NonMediaDoubanService::new().await?),
+        MediaSource::Bangumi => {
+            Box::new(NonMediaBangumiService::new(&ss.config.anime_and_manga.bangumi).await?)
+        }
         MediaSource::Spotify => {
             Box::new(SpotifyService::new(&ss.config.music.spotify, ss.clone()).await?)
         }
+        MediaSource::MusicBrainz => Box::new(MusicBrainzService::new().await?),
         MediaSource::Custom => return err(),
     };
     Ok(service)
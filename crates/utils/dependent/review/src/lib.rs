@@ -1,19 +1,19 @@
 use std::sync::Arc;
 
 use anyhow::{Result, bail};
-use background_models::{ApplicationJob, HpApplicationJob};
+use background_models::{ApplicationJob, HpApplicationJob, LpApplicationJob};
 use common_models::StringIdObject;
 use common_utils::ryot_log;
 use database_models::{
     prelude::{Collection, Exercise, Genre, Workout, WorkoutTemplate},
-    review,
+    review, user_feed_event,
 };
-use database_utils::user_by_id;
+use database_utils::{record_user_feed_event, user_by_id};
 use dependent_details_utils::{metadata_details, metadata_group_details, person_details};
 use dependent_utility_utils::associate_user_with_entity;
-use enum_models::{EntityLot, Visibility};
+use enum_models::{AutomationRuleTrigger, EntityLot, UserFeedEventLot, Visibility};
 use media_models::{
-    CreateOrUpdateReviewInput, ImportOrExportItemRating, ReviewPostedEvent,
+    AutomationRuleEvent, CreateOrUpdateReviewInput, ImportOrExportItemRating, ReviewPostedEvent,
     SeenAnimeExtraInformation, SeenMangaExtraInformation, SeenPodcastExtraOptionalInformation,
     SeenShowExtraOptionalInformation,
 };
@@ -112,15 +112,52 @@ pub async fn post_review(
                 ReviewPostedEvent {
                     obj_title,
                     entity_lot,
-                    obj_id: id,
+                    obj_id: id.clone(),
                     username: user.name,
                     review_id: insert.id.clone().unwrap(),
                 },
             )))
             .await?;
         }
+        // DEV: A review that is made public later is recorded when it is updated
+        record_user_feed_event(
+            user_feed_event::ActiveModel {
+                entity_lot: ActiveValue::Set(entity_lot),
+                entity_id: ActiveValue::Set(id.clone()),
+                user_id: ActiveValue::Set(user_id.to_owned()),
+                lot: ActiveValue::Set(UserFeedEventLot::Review),
+                origin_id: ActiveValue::Set(insert.id.clone().unwrap()),
+                rating: ActiveValue::Set(insert.rating.clone().unwrap()),
+                created_on: ActiveValue::Set(insert.posted_on.clone().unwrap()),
+                ..Default::default()
+            },
+            ss,
+        )
+        .await?;
     }
     associate_user_with_entity(user_id, &input.entity_id, input.entity_lot, ss).await?;
+    if input.entity_lot == EntityLot::Metadata {
+        ss.perform_application_job(ApplicationJob::Lp(
+            LpApplicationJob::SyncUserSmartCollections(
+                user_id.to_owned(),
+                Some(input.entity_id.clone()),
+            ),
+        ))
+        .await?;
+    }
+    if input.review_id.is_none() {
+        ss.perform_application_job(ApplicationJob::Lp(
+            LpApplicationJob::HandleAutomationRuleEvent(AutomationRuleEvent {
+                collection_name: None,
+                user_id: user_id.to_owned(),
+                entity_lot: input.entity_lot,
+                entity_id: input.entity_id.clone(),
+                trigger: AutomationRuleTrigger::ReviewPosted,
+                triggered_by_automation: false,
+            }),
+        ))
+        .await?;
+    }
     Ok(StringIdObject {
         id: insert.id.unwrap(),
     })
@@ -160,7 +197,7 @@ pub fn convert_review_into_input(
     })
 }
 
-async fn get_entity_title_from_id_and_lot(
+pub async fn get_entity_title_from_id_and_lot(
     id: &String,
     lot: EntityLot,
     ss: &Arc<SupportingService>,
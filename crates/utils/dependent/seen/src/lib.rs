@@ -1,22 +1,25 @@
 use std::{collections::HashMap, sync::Arc};
 
 use anyhow::Result;
+use background_models::{ApplicationJob, LpApplicationJob};
 use common_models::{ChangeCollectionToEntitiesInput, DefaultCollection, EntityToCollectionInput};
 use common_utils::SHOW_SPECIAL_SEASON_NAMES;
 use database_models::{
     prelude::{Metadata, Seen},
-    seen,
+    seen, user_feed_event,
 };
+use database_utils::record_user_feed_event;
 use dependent_collection_utils::{add_entities_to_collection, remove_entities_from_collection};
 use dependent_details_utils::metadata_details;
 use dependent_models::{ApplicationCacheKeyDiscriminants, ExpireCacheKeyInput};
-use enum_models::{EntityLot, MediaLot, SeenState};
+use enum_models::{AutomationRuleTrigger, EntityLot, MediaLot, SeenState, UserFeedEventLot};
 use itertools::Itertools;
+use media_models::AutomationRuleEvent;
 use rust_decimal::{
     Decimal,
     prelude::{One, ToPrimitive},
 };
-use sea_orm::{ColumnTrait, EntityTrait, QueryFilter, QueryOrder};
+use sea_orm::{ActiveValue, ColumnTrait, EntityTrait, QueryFilter, QueryOrder};
 use supporting_service::SupportingService;
 
 pub async fn seen_history(
@@ -152,6 +155,7 @@ pub async fn handle_after_metadata_seen_tasks(
         )
     };
     remove_entities_from_collection(&DefaultCollection::Watchlist.to_string()).await?;
+    let mut automation_trigger = None;
     match seen.state {
         SeenState::InProgress => {
             for col in &[DefaultCollection::InProgress, DefaultCollection::Monitoring] {
@@ -162,6 +166,9 @@ pub async fn handle_after_metadata_seen_tasks(
             remove_entities_from_collection(&DefaultCollection::InProgress.to_string())
                 .await
                 .ok();
+            if seen.state == SeenState::Dropped {
+                automation_trigger = Some(AutomationRuleTrigger::SeenDropped);
+            }
         }
         SeenState::Completed => {
             let metadata = metadata_details(ss, &seen.metadata_id).await?;
@@ -175,6 +182,7 @@ pub async fn handle_after_metadata_seen_tasks(
                     remove_entities_from_collection(&DefaultCollection::InProgress.to_string())
                         .await?;
                     add_entities_to_collection(&DefaultCollection::Completed.to_string()).await?;
+                    automation_trigger = Some(AutomationRuleTrigger::SeenCompleted);
                 } else {
                     for col in &[DefaultCollection::InProgress, DefaultCollection::Monitoring] {
                         add_entities_to_collection(&col.to_string()).await.ok();
@@ -187,9 +195,45 @@ pub async fn handle_after_metadata_seen_tasks(
                 for col in &[DefaultCollection::InProgress, DefaultCollection::Monitoring] {
                     remove_entities_from_collection(&col.to_string()).await.ok();
                 }
+                automation_trigger = Some(AutomationRuleTrigger::SeenCompleted);
             };
         }
     };
+    ss.perform_application_job(ApplicationJob::Lp(
+        LpApplicationJob::SyncUserSmartCollections(
+            seen.user_id.clone(),
+            Some(seen.metadata_id.clone()),
+        ),
+    ))
+    .await?;
+    if automation_trigger == Some(AutomationRuleTrigger::SeenCompleted) {
+        record_user_feed_event(
+            user_feed_event::ActiveModel {
+                entity_lot: ActiveValue::Set(EntityLot::Metadata),
+                origin_id: ActiveValue::Set(seen.id.clone()),
+                user_id: ActiveValue::Set(seen.user_id.clone()),
+                entity_id: ActiveValue::Set(seen.metadata_id.clone()),
+                lot: ActiveValue::Set(UserFeedEventLot::SeenCompleted),
+                created_on: ActiveValue::Set(seen.finished_on.unwrap_or(seen.last_updated_on)),
+                ..Default::default()
+            },
+            ss,
+        )
+        .await?;
+    }
+    if let Some(trigger) = automation_trigger {
+        ss.perform_application_job(ApplicationJob::Lp(
+            LpApplicationJob::HandleAutomationRuleEvent(AutomationRuleEvent {
+                trigger,
+                collection_name: None,
+                user_id: seen.user_id.clone(),
+                entity_lot: EntityLot::Metadata,
+                entity_id: seen.metadata_id.clone(),
+                triggered_by_automation: false,
+            }),
+        ))
+        .await?;
+    }
     cache_service::expire_key(
         ss,
         ExpireCacheKeyInput::BySanitizedKey {
@@ -1,16 +1,24 @@
 use std::sync::Arc;
 
-use anyhow::Result;
+use anyhow::{Result, bail};
 use chrono::Utc;
-use common_models::{EntityRecentlyConsumedCacheInput, UserLevelCacheKey};
-use database_models::{functions::get_user_to_entity_association, user_to_entity};
+use common_models::{BackendError, EntityRecentlyConsumedCacheInput, UserLevelCacheKey};
+use database_models::{
+    functions::get_user_to_entity_association, integration, prelude::Integration, user_to_entity,
+};
+use database_utils::{server_key_validation_guard, user_by_id};
+use dependent_core_utils::is_server_key_validated;
 use dependent_models::{
     ApplicationCacheKey, ApplicationCacheKeyDiscriminants, ApplicationCacheValue, EmptyCacheValue,
     ExpireCacheKeyInput,
 };
-use enum_models::EntityLot;
+use enum_models::{EntityLot, IntegrationLot, IntegrationProvider, UserLot};
 use futures::try_join;
-use sea_orm::{ActiveModelTrait, ActiveValue, IntoActiveModel};
+use media_models::CreateOrUpdateUserIntegrationInput;
+use sea_orm::{
+    ActiveModelTrait, ActiveValue, ColumnTrait, EntityTrait, IntoActiveModel, PaginatorTrait,
+    QueryFilter,
+};
 use supporting_service::SupportingService;
 
 async fn mark_entity_as_recently_consumed(
@@ -345,3 +353,74 @@ pub async fn expire_user_workout_details_cache(
     )
     .await
 }
+
+pub async fn create_or_update_user_integration(
+    ss: &Arc<SupportingService>,
+    user_id: String,
+    input: CreateOrUpdateUserIntegrationInput,
+) -> Result<bool> {
+    let user = user_by_id(&user_id, ss).await?;
+    // DEV: Every integration syncs media, which fitness-only users can not track
+    if user.lot == UserLot::FitnessOnly {
+        bail!(BackendError::RoleNotAllowed.to_string());
+    }
+    let mut lot = ActiveValue::NotSet;
+    let mut provider = ActiveValue::NotSet;
+    if let Some(p) = input.provider {
+        match p {
+            IntegrationProvider::JellyfinPush
+            | IntegrationProvider::YoutubeMusic
+            | IntegrationProvider::RyotBrowserExtension => {
+                server_key_validation_guard(is_server_key_validated(ss).await?).await?;
+            }
+            _ => {}
+        }
+        let l = match p {
+            IntegrationProvider::Komga
+            | IntegrationProvider::Kavita
+            | IntegrationProvider::PlexYank
+            | IntegrationProvider::YoutubeMusic
+            | IntegrationProvider::Audiobookshelf => IntegrationLot::Yank,
+            IntegrationProvider::Radarr
+            | IntegrationProvider::Sonarr
+            | IntegrationProvider::JellyfinPush => IntegrationLot::Push,
+            _ => IntegrationLot::Sink,
+        };
+        lot = ActiveValue::Set(l);
+        provider = ActiveValue::Set(p);
+    };
+    if input.minimum_progress > input.maximum_progress {
+        bail!("Minimum progress cannot be greater than maximum progress");
+    }
+    if input.integration_id.is_none()
+        && let Some(max) = user.limits.and_then(|l| l.max_integrations)
+    {
+        let existing = Integration::find()
+            .filter(integration::Column::UserId.eq(&user_id))
+            .count(&ss.db)
+            .await?;
+        if existing >= max {
+            bail!("You can not create more than {max} integrations");
+        }
+    }
+    let id = match input.integration_id {
+        None => ActiveValue::NotSet,
+        Some(id) => ActiveValue::Set(id),
+    };
+    let to_insert = integration::ActiveModel {
+        id,
+        lot,
+        provider,
+        name: ActiveValue::Set(input.name),
+        user_id: ActiveValue::Set(user_id),
+        is_disabled: ActiveValue::Set(input.is_disabled),
+        extra_settings: ActiveValue::Set(input.extra_settings),
+        minimum_progress: ActiveValue::Set(input.minimum_progress),
+        maximum_progress: ActiveValue::Set(input.maximum_progress),
+        provider_specifics: ActiveValue::Set(input.provider_specifics),
+        sync_to_owned_collection: ActiveValue::Set(input.sync_to_owned_collection),
+        ..Default::default()
+    };
+    to_insert.save(&ss.db).await?;
+    Ok(true)
+}